@@ -0,0 +1,137 @@
+use cosmwasm_std::{CanonicalAddr, StdError, StdResult, Storage, Uint128};
+use cosmwasm_storage::ReadonlyPrefixedStorage;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::state::{json_may_load, PREFIX_AUCTIONS};
+
+/// the type of an auction
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum AuctionKind {
+    /// ascending price auction won by the highest bid when the auction ends
+    English {
+        /// minimum first bid
+        reserve_price: Uint128,
+        /// minimum amount each bid must exceed the previous one by
+        min_increment: Uint128,
+        /// a bid placed less than this many seconds before the end pushes the end back to
+        /// this many seconds after the bid
+        extension: u64,
+    },
+    /// descending price auction won by the first bid.  The price decays linearly from the
+    /// start price to the end price over the duration of the auction
+    Dutch {
+        /// price when the auction starts
+        start_price: Uint128,
+        /// price when the auction ends
+        end_price: Uint128,
+    },
+}
+
+/// auction of a token
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
+pub struct Auction {
+    /// id of the auctioned token
+    pub token_id: String,
+    /// denomination bids are made in
+    pub denom: String,
+    /// the type of the auction
+    pub kind: AuctionKind,
+    /// time the auction started in seconds since 01/01/1970
+    pub starts_at: u64,
+    /// time the auction ends in seconds since 01/01/1970
+    pub ends_at: u64,
+    /// amount of the highest bid of an English auction
+    pub highest_bid: Option<Uint128>,
+    /// current price of a Dutch auction that has not ended
+    pub current_price: Option<Uint128>,
+}
+
+/// bid escrowed by the contract
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct StoredBid {
+    /// address that made the bid
+    pub bidder: CanonicalAddr,
+    /// amount of the bid
+    pub amount: Uint128,
+}
+
+/// stored auction of a token
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct StoredAuction {
+    /// owner that started the auction
+    pub seller: CanonicalAddr,
+    /// denomination bids are made in
+    pub denom: String,
+    /// the type of the auction
+    pub kind: AuctionKind,
+    /// time the auction started in seconds since 01/01/1970
+    pub starts_at: u64,
+    /// time the auction ends in seconds since 01/01/1970
+    pub ends_at: u64,
+    /// highest bid of an English auction
+    pub highest_bid: Option<StoredBid>,
+}
+
+impl StoredAuction {
+    /// Returns Option<Uint128> of the current price of a Dutch auction, or None if this is
+    /// an English auction or the auction has ended
+    ///
+    /// # Arguments
+    ///
+    /// * `now` - current time in seconds since 01/01/1970
+    pub fn current_price(&self, now: u64) -> Option<Uint128> {
+        match self.kind {
+            AuctionKind::Dutch {
+                start_price,
+                end_price,
+            } if now < self.ends_at => {
+                let elapsed = now.saturating_sub(self.starts_at);
+                let duration = self.ends_at - self.starts_at;
+                // the start price was checked to exceed the end price when the auction started
+                let decay = (start_price - end_price).multiply_ratio(elapsed, duration);
+                Some(start_price - decay)
+            }
+            _ => None,
+        }
+    }
+
+    /// Returns Auction from converting a StoredAuction to a displayable Auction
+    ///
+    /// # Arguments
+    ///
+    /// * `token_id` - id of the auctioned token
+    /// * `now` - current time in seconds since 01/01/1970
+    pub fn into_display(self, token_id: String, now: u64) -> Auction {
+        let current_price = self.current_price(now);
+        Auction {
+            token_id,
+            denom: self.denom,
+            kind: self.kind,
+            starts_at: self.starts_at,
+            ends_at: self.ends_at,
+            highest_bid: self.highest_bid.map(|b| b.amount),
+            current_price,
+        }
+    }
+}
+
+/// Returns StdResult<()>, or an error if the token is being auctioned.  A token stays in
+/// its auction until the auction is settled
+///
+/// # Arguments
+///
+/// * `storage` - a reference to the contract's storage
+/// * `token_id` - string slice of the token id
+/// * `idx` - index of the token
+pub fn check_not_auctioned(storage: &dyn Storage, token_id: &str, idx: u32) -> StdResult<()> {
+    let auction_store = ReadonlyPrefixedStorage::new(storage, PREFIX_AUCTIONS);
+    if json_may_load::<StoredAuction>(&auction_store, &idx.to_le_bytes())?.is_some() {
+        return Err(StdError::generic_err(format!(
+            "Token ID: {} is being auctioned",
+            token_id
+        )));
+    }
+    Ok(())
+}
@@ -0,0 +1,59 @@
+use cosmwasm_std::{Addr, Api, CanonicalAddr, StdResult, Storage};
+use cosmwasm_storage::ReadonlyPrefixedStorage;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::state::{may_load, PREFIX_REVOKED_BADGES};
+
+/// revocation of a non-transferable token by its issuer
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
+pub struct Revocation {
+    /// address that revoked the token
+    pub issuer: Addr,
+    /// time of the revocation (in seconds since 01/01/1970)
+    pub time: u64,
+    /// why the token was revoked
+    pub reason: String,
+}
+
+/// stored revocation of a non-transferable token
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct StoredRevocation {
+    /// address that revoked the token
+    pub issuer: CanonicalAddr,
+    /// time of the revocation (in seconds since 01/01/1970)
+    pub time: u64,
+    /// why the token was revoked
+    pub reason: String,
+}
+
+impl StoredRevocation {
+    /// Returns StdResult<Revocation> from converting a StoredRevocation to a displayable
+    /// Revocation
+    ///
+    /// # Arguments
+    ///
+    /// * `api` - a reference to the Api used to convert human and canonical addresses
+    pub fn into_humanized(self, api: &dyn Api) -> StdResult<Revocation> {
+        Ok(Revocation {
+            issuer: api.addr_humanize(&self.issuer)?,
+            time: self.time,
+            reason: self.reason,
+        })
+    }
+}
+
+/// Returns StdResult<Option<Revocation>> of the token's revocation, or None if it was not
+/// revoked
+///
+/// # Arguments
+///
+/// * `storage` - a reference to the contract's storage
+/// * `api` - a reference to the Api used to convert human and canonical addresses
+/// * `idx` - the token's idx
+pub fn revocation(storage: &dyn Storage, api: &dyn Api, idx: u32) -> StdResult<Option<Revocation>> {
+    let revoke_store = ReadonlyPrefixedStorage::new(storage, PREFIX_REVOKED_BADGES);
+    may_load::<StoredRevocation>(&revoke_store, &idx.to_le_bytes())?
+        .map(|r| r.into_humanized(api))
+        .transpose()
+}
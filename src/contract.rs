@@ -4,37 +4,77 @@ use std::collections::HashSet;
 
 use base64::{engine::general_purpose, Engine as _};
 use cosmwasm_std::{
-    attr, entry_point, to_binary, Addr, Api, Binary, BlockInfo, CanonicalAddr, CosmosMsg, Deps,
-    DepsMut, Env, MessageInfo, Response, StdError, StdResult, Storage, WasmMsg,
+    attr, entry_point, from_binary, to_binary, Addr, Api, BankMsg, Binary, BlockInfo,
+    CanonicalAddr, Coin, CosmosMsg, Deps, DepsMut, Env, MessageInfo, Response, StdError, StdResult,
+    Storage, Uint128, WasmMsg,
 };
 use cosmwasm_storage::{PrefixedStorage, ReadonlyPrefixedStorage};
 use primitive_types::U256;
 use secret_toolkit::{
     crypto::sha_256,
     permit::{validate, Permit, RevokedPermits},
-    utils::{pad_handle_result, pad_query_result},
+    snip20::{register_receive_msg, transfer_msg},
+    utils::{pad_handle_result, pad_query_result, HandleCallback},
     viewing_key::{ViewingKey, ViewingKeyStore},
 };
 
+use crate::auction::{check_not_auctioned, AuctionKind, StoredAuction, StoredBid};
+use crate::badge::{revocation, StoredRevocation};
+use crate::edition::{
+    edition_balance, edition_supply, save_edition_balance, save_edition_supply, EditionBalance,
+    EditionInfo,
+};
 use crate::expiration::Expiration;
 use crate::inventory::{Inventory, InventoryIter};
-use crate::mint_run::{SerialNumber, StoredMintRunInfo};
+use crate::lock::{active_lock, check_unlocked, is_approved_locker, StoredLock};
+use crate::market::{split_payment, Listing, StoredListing};
+use crate::migrate::{migrate_storage, CONTRACT_VERSION};
+use crate::mint_phase::MintPhase;
+use crate::mint_run::{SerialNumber, SerialOverride, StoredMintRun, StoredMintRunInfo};
+use crate::minter_quota::{MinterQuota, MinterUsage};
 use crate::msg::{
-    AccessLevel, BatchNftDossierElement, Burn, ContractStatus, Cw721Approval, Cw721OwnerOfResponse,
-    ExecuteAnswer, ExecuteMsg, InstantiateMsg, Mint, QueryAnswer, QueryMsg, QueryWithPermit,
-    ReceiverInfo, ResponseStatus::Success, Send, Snip721Approval, Transfer, ViewerInfo,
+    AccessLevel, BatchNftDossierElement, Burn, ConfigRatchets, ContractStatus, Cw721Approval,
+    Cw721OwnerOfResponse, ExecuteAnswer, ExecuteMsg, InstantiateConfig, InstantiateMsg, MigrateMsg,
+    Mint, QueryAnswer, QueryMsg, QueryWithPermit, Ratchet, ReceiveMsg, ReceiverInfo,
+    ResponseStatus::Success, Role, RoleHolders, Send, Snip721Approval, Transfer, ViewerInfo,
+};
+use crate::offer::{
+    add_offer_id, may_load_offer, offer_ids, remove_offer_id, Offer, OfferSnip20, StoredOffer,
+    StoredOfferSnip20,
 };
 use crate::receiver::{batch_receive_nft_msg, receive_nft_msg};
+use crate::recipe::Recipe;
+use crate::rental::{active_user, remove_user, StoredTokenUser};
+use crate::reveal_pool::{chain_hash, shuffled_position, RevealPool};
 use crate::royalties::{RoyaltyInfo, StoredRoyaltyInfo};
+use crate::sale::{PoolEntry, Sale, SaleCounts, SaleMetadata, Snip20Price, StoredSnip20Payment};
 use crate::state::{
-    get_txs, json_may_load, json_save, load, may_load, remove, save, store_burn, store_mint,
-    store_transfer, AuthList, Config, Permission, PermissionType, ReceiveRegistration, CONFIG_KEY,
-    CREATOR_KEY, DEFAULT_ROYALTY_KEY, MINTERS_KEY, PREFIX_ALL_PERMISSIONS, PREFIX_AUTHLIST,
-    PREFIX_INFOS, PREFIX_MAP_TO_ID, PREFIX_MAP_TO_INDEX, PREFIX_MINT_RUN, PREFIX_MINT_RUN_NUM,
-    PREFIX_OWNER_PRIV, PREFIX_PRIV_META, PREFIX_PUB_META, PREFIX_RECEIVERS, PREFIX_REVOKED_PERMITS,
-    PREFIX_ROYALTY_INFO, VIEWING_KEY_ERR_MSG,
+    get_txs, json_load, json_may_load, json_save, load, load_role, may_load, remove, save,
+    save_role, store_badge_revocation, store_burn, store_edition_mint, store_edition_transfer,
+    store_mint, store_transfer, take_minter_grants, AuthList, Config, PendingAdmin, Permission,
+    PermissionType, ReceiveRegistration, CONFIG_KEY, CONTRACT_VERSION_KEY, CREATOR_KEY,
+    DEFAULT_ROYALTY_KEY, MAX_SUPPLY_KEY, MINT_PHASES_KEY, OFFER_COUNT_KEY, OFFER_SNIP20S_KEY,
+    PENDING_ADMIN_KEY, PREFIX_ALL_PERMISSIONS, PREFIX_AUCTIONS, PREFIX_AUTHLIST,
+    PREFIX_BUYER_OFFERS, PREFIX_INFOS, PREFIX_LISTINGS, PREFIX_LOCKER_APPROVALS, PREFIX_LOCKS,
+    PREFIX_MAP_TO_ID, PREFIX_MAP_TO_INDEX, PREFIX_MINTER_QUOTA, PREFIX_MINTER_USAGE,
+    PREFIX_MINT_RUN, PREFIX_MINT_RUN_CAP, PREFIX_MINT_RUN_NUM, PREFIX_MINT_RUN_REGISTRY,
+    PREFIX_MINT_RUN_SUPPLY, PREFIX_OFFERS, PREFIX_OWNER_PRIV, PREFIX_PHASE_USAGE, PREFIX_PRIV_META,
+    PREFIX_PUB_META, PREFIX_RECEIVERS, PREFIX_REVEAL_POOL, PREFIX_REVOKED_BADGES,
+    PREFIX_REVOKED_PERMITS, PREFIX_ROYALTY_INFO, PREFIX_SALE_POOL, PREFIX_SALE_WALLET,
+    PREFIX_SWAPS, PREFIX_SWAP_CONTRACTS, PREFIX_TOKEN_OFFERS, PREFIX_TOKEN_USERS,
+    PREFIX_USED_SERIALS, PREFIX_VOUCHER_NONCES, RATCHETS_KEY, RECIPES_KEY, REVEAL_POOL_KEY,
+    REVEAL_SCHEDULE_KEY, REVEAL_SEED_KEY, SALE_COUNTS_KEY, SALE_KEY, SALE_PROCEEDS_KEY,
+    SNIP20_PAYMENTS_KEY, SWAP_COUNT_KEY, TOKEN_ID_SCHEME_KEY, VIEWING_KEY_ERR_MSG,
+    VOUCHER_SIGNER_KEY,
+};
+use crate::swap::{
+    depositor, remove_deposit, save_deposit, Snip721HandleMsg, StoredSwap, StoredSwapToken,
+    SwapToken,
 };
 use crate::token::{Metadata, Token};
+use crate::token_id::TokenIdScheme;
+use crate::tombstone::{remove_tombstone, save_tombstone, tombstone, StoredTombstone};
+use crate::voucher::{MintVoucher, VoucherMetadata};
 
 /// pad handle responses and log attributes to blocks of 256 bytes to prevent leaking info based on
 /// response size
@@ -96,9 +136,12 @@ pub fn instantiate(
         burn_is_enabled: init_config.enable_burn.unwrap_or(false),
     };
 
-    let minters = vec![admin_raw];
+    let admin_roles = vec![admin_raw];
     save(deps.storage, CONFIG_KEY, &config)?;
-    save(deps.storage, MINTERS_KEY, &minters)?;
+    for role in [Role::Minter, Role::MetadataEditor, Role::RoyaltyManager] {
+        save_role(deps.storage, role, &admin_roles)?;
+    }
+    save(deps.storage, CONTRACT_VERSION_KEY, &CONTRACT_VERSION)?;
 
     if msg.royalty_info.is_some() {
         store_royalties(
@@ -125,6 +168,25 @@ pub fn instantiate(
     Ok(Response::new().add_messages(messages))
 }
 
+//////////////////////////////////// Migrate //////////////////////////////////////
+/// Returns StdResult<Response>
+///
+/// Upgrades the contract's storage to the layout used by this code
+///
+/// # Arguments
+///
+/// * `deps` - mutable reference to Extern containing all the contract's external dependencies
+/// * `_env` - Env of contract's environment
+/// * `_msg` - MigrateMsg passed in with the migrate message
+#[entry_point]
+pub fn migrate(deps: DepsMut, _env: Env, _msg: MigrateMsg) -> StdResult<Response> {
+    let from = migrate_storage(deps.storage)?;
+    Ok(Response::new().add_attributes(vec![
+        attr("migrated_from", from.to_string()),
+        attr("migrated_to", CONTRACT_VERSION.to_string()),
+    ]))
+}
+
 ///////////////////////////////////// Handle //////////////////////////////////////
 /// Returns StdResult<Response>
 ///
@@ -178,6 +240,7 @@ pub fn execute(deps: DepsMut, env: Env, info: MessageInfo, msg: ExecuteMsg) -> S
             owner,
             public_metadata,
             private_metadata,
+            serial_overrides,
             royalty_info,
             memo,
             ..
@@ -192,6 +255,7 @@ pub fn execute(deps: DepsMut, env: Env, info: MessageInfo, msg: ExecuteMsg) -> S
             owner,
             public_metadata,
             private_metadata,
+            serial_overrides,
             royalty_info,
             memo,
         ),
@@ -202,6 +266,7 @@ pub fn execute(deps: DepsMut, env: Env, info: MessageInfo, msg: ExecuteMsg) -> S
             ..
         } => set_metadata(
             deps,
+            &env,
             &info.sender,
             &config,
             ContractStatus::StopTransactions.to_u8(),
@@ -454,533 +519,4028 @@ pub fn execute(deps: DepsMut, env: Env, info: MessageInfo, msg: ExecuteMsg) -> S
             ContractStatus::StopTransactions.to_u8(),
             &address,
         ),
-        ExecuteMsg::SetContractStatus { level, .. } => {
-            set_contract_status(deps, &info.sender, &mut config, level)
-        }
-        ExecuteMsg::RevokePermit { permit_name, .. } => {
-            revoke_permit(deps.storage, &info.sender, &permit_name)
-        }
-    };
-    pad_handle_result(response, BLOCK_SIZE)
-}
-
-/// Returns StdResult<Response>
-///
-/// mint a new token
-///
-/// # Arguments
-///
-/// * `deps` - mutable reference to Extern containing all the contract's external dependencies
-/// * `env` - a reference to the Env of contract's environment
-/// * `sender` - a reference to the message sender address
-/// * `config` - a mutable reference to the Config
-/// * `priority` - u8 representation of highest status level this action is permitted at
-/// * `token_id` - optional token id, if not specified, use token index
-/// * `owner` - optional owner of this token, if not specified, use the minter's address
-/// * `public_metadata` - optional public metadata viewable by everyone
-/// * `private_metadata` - optional private metadata viewable only by owner and whitelist
-/// * `serial_number` - optional serial number information for this token
-/// * `royalty_info` - optional royalties information for this token
-/// * `transferable` - optionally true if this token is transferable
-/// * `memo` - optional memo for the mint tx
-#[allow(clippy::too_many_arguments)]
-pub fn mint(
-    deps: DepsMut,
-    env: &Env,
-    sender: &Addr,
-    config: &mut Config,
-    priority: u8,
-    token_id: Option<String>,
-    owner: Option<String>,
-    public_metadata: Option<Metadata>,
-    private_metadata: Option<Metadata>,
-    serial_number: Option<SerialNumber>,
-    royalty_info: Option<RoyaltyInfo>,
-    transferable: Option<bool>,
-    memo: Option<String>,
-) -> StdResult<Response> {
-    check_status(config.status, priority)?;
-    let sender_raw = deps.api.addr_canonicalize(sender.as_str())?;
-    let minters: Vec<CanonicalAddr> = may_load(deps.storage, MINTERS_KEY)?.unwrap_or_default();
-    if !minters.contains(&sender_raw) {
-        return Err(StdError::generic_err(
-            "Only designated minters are allowed to mint",
-        ));
-    }
-    let mints = vec![Mint {
-        token_id,
-        owner,
-        public_metadata,
-        private_metadata,
-        serial_number,
-        royalty_info,
-        transferable,
-        memo,
-    }];
-    let mut minted = mint_list(deps, env, config, &sender_raw, mints)?;
-    let minted_str = minted.pop().unwrap_or_default();
-    Ok(Response::new()
-        .add_attributes(vec![attr("minted", &minted_str)])
-        .set_data(to_binary(&ExecuteAnswer::MintNft {
-            token_id: minted_str,
-        })?))
-}
-
-/// Returns StdResult<Response>
-///
-/// mints many tokens
-///
-/// # Arguments
-///
-/// * `deps` - mutable reference to Extern containing all the contract's external dependencies
-/// * `env` - a reference to the Env of contract's environment
-/// * `sender` - a reference to the message sender address
-/// * `config` - a mutable reference to the Config
-/// * `priority` - u8 representation of highest ContractStatus level this action is permitted
-/// * `mints` - the list of mints to perform
-pub fn batch_mint(
-    deps: DepsMut,
-    env: &Env,
-    sender: &Addr,
-    config: &mut Config,
-    priority: u8,
-    mints: Vec<Mint>,
-) -> StdResult<Response> {
-    check_status(config.status, priority)?;
-    let sender_raw = deps.api.addr_canonicalize(sender.as_str())?;
-    let minters: Vec<CanonicalAddr> = may_load(deps.storage, MINTERS_KEY)?.unwrap_or_default();
-    if !minters.contains(&sender_raw) {
-        return Err(StdError::generic_err(
-            "Only designated minters are allowed to mint",
-        ));
-    }
-    let minted = mint_list(deps, env, config, &sender_raw, mints)?;
-    Ok(Response::new()
-        .add_attributes(vec![attr("minted", format!("{:?}", &minted))])
-        .set_data(to_binary(&ExecuteAnswer::BatchMintNft {
-            token_ids: minted,
-        })?))
-}
-
-/// Returns StdResult<Response>
-///
-/// mints clones of a token
-///
-/// # Arguments
-///
-/// * `deps` - mutable reference to Extern containing all the contract's external dependencies
-/// * `env` - a reference to the Env of contract's environment
-/// * `sender` - a reference to the message sender address
-/// * `config` - a mutable reference to the Config
-/// * `priority` - u8 representation of highest status level this action is permitted at
-/// * `mint_run_id` - optional id used to track subsequent mint runs
-/// * `quantity` - number of clones to mint
-/// * `owner` - optional owner of this token, if not specified, use the minter's address
-/// * `public_metadata` - optional public metadata viewable by everyone
-/// * `private_metadata` - optional private metadata viewable only by owner and whitelist
-/// * `royalty_info` - optional royalties information for these clones
-/// * `memo` - optional memo for the mint txs
-#[allow(clippy::too_many_arguments)]
-pub fn mint_clones(
-    deps: DepsMut,
-    env: &Env,
-    sender: &Addr,
-    config: &mut Config,
-    priority: u8,
-    mint_run_id: Option<&String>,
-    quantity: u32,
-    owner: Option<String>,
-    public_metadata: Option<Metadata>,
-    private_metadata: Option<Metadata>,
-    royalty_info: Option<RoyaltyInfo>,
-    memo: Option<String>,
-) -> StdResult<Response> {
-    check_status(config.status, priority)?;
-    let sender_raw = deps.api.addr_canonicalize(sender.as_str())?;
-    let minters: Vec<CanonicalAddr> = may_load(deps.storage, MINTERS_KEY)?.unwrap_or_default();
-    if !minters.contains(&sender_raw) {
-        return Err(StdError::generic_err(
-            "Only designated minters are allowed to mint",
-        ));
-    }
-    if quantity == 0 {
-        return Err(StdError::generic_err("Quantity can not be zero"));
-    }
-    let mint_run = mint_run_id
-        .map(|i| {
-            let key = i.as_bytes();
-            let mut run_store = PrefixedStorage::new(deps.storage, PREFIX_MINT_RUN_NUM);
-            let last_num: u32 = may_load(&run_store, key)?.unwrap_or(0);
-            let this_num: u32 = last_num.checked_add(1).ok_or_else(|| {
-                StdError::generic_err(format!(
-                    "Mint run ID {} has already reached its maximum possible value",
-                    i
-                ))
-            })?;
-            save(&mut run_store, key, &this_num)?;
-            Ok::<u32, StdError>(this_num)
-        })
-        .transpose()?;
-    let mut serial_number = SerialNumber {
-        mint_run,
-        serial_number: 1,
-        quantity_minted_this_run: Some(quantity),
-    };
-    let mut mints: Vec<Mint> = Vec::new();
-    for _ in 0..quantity {
-        mints.push(Mint {
-            token_id: None,
-            owner: owner.clone(),
-            public_metadata: public_metadata.clone(),
-            private_metadata: private_metadata.clone(),
-            serial_number: Some(serial_number.clone()),
-            royalty_info: royalty_info.clone(),
-            transferable: Some(true),
-            memo: memo.clone(),
-        });
-        serial_number.serial_number += 1;
-    }
-    let mut minted = mint_list(deps, env, config, &sender_raw, mints)?;
-    // if mint_list did not error, there must be at least one token id
-    let first_minted = minted
-        .first()
-        .ok_or_else(|| StdError::generic_err("List of minted tokens is empty"))?
-        .clone();
-    let last_minted = minted
-        .pop()
-        .ok_or_else(|| StdError::generic_err("List of minted tokens is empty"))?;
-
-    Ok(Response::new()
-        .add_attributes(vec![
-            attr("first_minted", &first_minted),
-            attr("last_minted", &last_minted),
-        ])
-        .set_data(to_binary(&ExecuteAnswer::MintNftClones {
-            first_minted,
-            last_minted,
-        })?))
-}
-
-/// Returns StdResult<Response>
-///
-/// sets new public and/or private metadata
-///
-/// # Arguments
-///
-/// * `deps` - mutable reference to Extern containing all the contract's external dependencies
-/// * `sender` - a reference to the message sender address
-/// * `config` - a reference to the Config
-/// * `priority` - u8 representation of highest status level this action is permitted at
-/// * `token_id` - token id String slice of token whose metadata should be updated
-/// * `public_metadata` - the optional new public metadata viewable by everyone
-/// * `private_metadata` - the optional new private metadata viewable by everyone
-pub fn set_metadata(
-    deps: DepsMut,
-    sender: &Addr,
-    config: &Config,
-    priority: u8,
-    token_id: &str,
-    public_metadata: Option<Metadata>,
-    private_metadata: Option<Metadata>,
-) -> StdResult<Response> {
-    check_status(config.status, priority)?;
-    let custom_err = format!("Not authorized to update metadata of token {}", token_id);
-    // if token supply is private, don't leak that the token id does not exist
-    // instead just say they are not authorized for that token
-    let opt_err = if config.token_supply_is_public {
-        None
-    } else {
-        Some(&*custom_err)
-    };
-    let (token, idx) = get_token(deps.storage, token_id, opt_err)?;
-    let sender_raw = deps.api.addr_canonicalize(sender.as_str())?;
-    if !(token.owner == sender_raw && config.owner_may_update_metadata) {
-        let minters: Vec<CanonicalAddr> = may_load(deps.storage, MINTERS_KEY)?.unwrap_or_default();
-        if !(minters.contains(&sender_raw) && config.minter_may_update_metadata) {
-            return Err(StdError::generic_err(custom_err));
-        }
-    }
-    if let Some(public) = public_metadata {
-        set_metadata_impl(deps.storage, &token, idx, PREFIX_PUB_META, &public)?;
-    }
-    if let Some(private) = private_metadata {
-        set_metadata_impl(deps.storage, &token, idx, PREFIX_PRIV_META, &private)?;
-    }
+        ExecuteMsg::GrantRole {
+            role, addresses, ..
+        } => grant_role(
+            deps,
+            &info.sender,
+            &config,
+            ContractStatus::StopTransactions.to_u8(),
+            role,
+            &addresses,
+        ),
+        ExecuteMsg::RevokeRole {
+            role, addresses, ..
+        } => revoke_role(
+            deps,
+            &info.sender,
+            &config,
+            ContractStatus::StopTransactions.to_u8(),
+            role,
+            &addresses,
+        ),
+        ExecuteMsg::SetMinterQuota { minter, quota, .. } => set_minter_quota(
+            deps,
+            &info.sender,
+            &config,
+            ContractStatus::StopTransactions.to_u8(),
+            &minter,
+            quota,
+        ),
+        ExecuteMsg::SetMaxSupply {
+            mint_run_id,
+            max_supply,
+            ..
+        } => set_max_supply(
+            deps,
+            &info.sender,
+            &config,
+            ContractStatus::StopTransactions.to_u8(),
+            mint_run_id.as_deref(),
+            max_supply,
+        ),
+        ExecuteMsg::SetSale { sale, .. } => set_sale(
+            deps,
+            &info.sender,
+            &config,
+            ContractStatus::StopTransactions.to_u8(),
+            sale,
+        ),
+        ExecuteMsg::LoadSalePool { metadata, .. } => load_sale_pool(
+            deps,
+            &info.sender,
+            &config,
+            ContractStatus::StopTransactions.to_u8(),
+            metadata,
+        ),
+        ExecuteMsg::PublicMint { quantity, memo, .. } => public_mint(
+            deps,
+            &env,
+            &info.sender,
+            &info.funds,
+            &mut config,
+            ContractStatus::Normal.to_u8(),
+            quantity,
+            memo,
+        ),
+        ExecuteMsg::SetSnip20Price {
+            contract_address,
+            code_hash,
+            price,
+            ..
+        } => set_snip20_price(
+            deps,
+            &env,
+            &info.sender,
+            &config,
+            ContractStatus::StopTransactions.to_u8(),
+            &contract_address,
+            code_hash,
+            price,
+        ),
+        ExecuteMsg::Receive {
+            from, amount, msg, ..
+        } => receive_snip20(
+            deps,
+            &env,
+            &info.sender,
+            &mut config,
+            ContractStatus::Normal.to_u8(),
+            &from,
+            amount,
+            msg,
+        ),
+        ExecuteMsg::SetMintPhases { phases, .. } => set_mint_phases(
+            deps,
+            &info.sender,
+            &config,
+            ContractStatus::StopTransactions.to_u8(),
+            phases,
+        ),
+        ExecuteMsg::AllowlistMint {
+            phase,
+            allocation,
+            proof,
+            quantity,
+            memo,
+            ..
+        } => allowlist_mint(
+            deps,
+            &env,
+            &info.sender,
+            &info.funds,
+            &mut config,
+            ContractStatus::Normal.to_u8(),
+            &phase,
+            allocation,
+            &proof,
+            quantity,
+            memo,
+        ),
+        ExecuteMsg::SetVoucherSigner { public_key, .. } => set_voucher_signer(
+            deps,
+            &info.sender,
+            &config,
+            ContractStatus::StopTransactions.to_u8(),
+            public_key,
+        ),
+        ExecuteMsg::RedeemVoucher {
+            voucher,
+            signature,
+            metadata,
+            ..
+        } => redeem_voucher(
+            deps,
+            &env,
+            &info.sender,
+            &mut config,
+            ContractStatus::Normal.to_u8(),
+            voucher,
+            &signature,
+            metadata,
+        ),
+        ExecuteMsg::CommitRevealPool {
+            provenance_hash,
+            size,
+            seed_hash,
+            ..
+        } => commit_reveal_pool(
+            deps,
+            &info.sender,
+            &config,
+            ContractStatus::StopTransactions.to_u8(),
+            provenance_hash,
+            size,
+            seed_hash,
+        ),
+        ExecuteMsg::RevealSeed { seed, .. } => reveal_seed(
+            deps,
+            &info.sender,
+            &config,
+            ContractStatus::StopTransactions.to_u8(),
+            seed,
+        ),
+        ExecuteMsg::LoadRevealPool { metadata, .. } => load_reveal_pool(
+            deps,
+            &info.sender,
+            &config,
+            ContractStatus::StopTransactions.to_u8(),
+            metadata,
+        ),
+        ExecuteMsg::MintEdition {
+            token_id,
+            owner,
+            amount,
+            public_metadata,
+            private_metadata,
+            royalty_info,
+            memo,
+            ..
+        } => mint_edition(
+            deps,
+            &env,
+            &info.sender,
+            &mut config,
+            ContractStatus::Normal.to_u8(),
+            token_id,
+            owner,
+            amount,
+            public_metadata,
+            private_metadata,
+            royalty_info,
+            memo,
+        ),
+        ExecuteMsg::TransferEdition {
+            recipient,
+            token_id,
+            amount,
+            memo,
+            ..
+        } => transfer_edition(
+            deps,
+            &env,
+            &info.sender,
+            &mut config,
+            ContractStatus::Normal.to_u8(),
+            &recipient,
+            token_id,
+            amount,
+            memo,
+        ),
+        ExecuteMsg::ScheduleReveal { at, .. } => schedule_reveal(
+            deps,
+            &env,
+            &info.sender,
+            &config,
+            ContractStatus::StopTransactions.to_u8(),
+            at,
+        ),
+        ExecuteMsg::SetTokenIdScheme { scheme, .. } => set_token_id_scheme(
+            deps,
+            &info.sender,
+            &config,
+            ContractStatus::StopTransactions.to_u8(),
+            scheme,
+        ),
+        ExecuteMsg::SetRecipes { recipes, .. } => set_recipes(
+            deps,
+            &info.sender,
+            &config,
+            ContractStatus::StopTransactions.to_u8(),
+            recipes,
+        ),
+        ExecuteMsg::Redeem {
+            recipe,
+            token_ids,
+            memo,
+            ..
+        } => redeem(
+            deps,
+            &env,
+            &info.sender,
+            &mut config,
+            ContractStatus::Normal.to_u8(),
+            &recipe,
+            token_ids,
+            memo,
+        ),
+        ExecuteMsg::RevokeBadge {
+            token_id, reason, ..
+        } => revoke_badge(
+            deps,
+            &env,
+            &info.sender,
+            &mut config,
+            ContractStatus::Normal.to_u8(),
+            token_id,
+            reason,
+        ),
+        ExecuteMsg::ApproveLocker {
+            locker, expires, ..
+        } => approve_revoke_locker(
+            deps,
+            &info.sender,
+            &config,
+            ContractStatus::Normal.to_u8(),
+            &locker,
+            expires,
+            true,
+        ),
+        ExecuteMsg::RevokeLocker { locker, .. } => approve_revoke_locker(
+            deps,
+            &info.sender,
+            &config,
+            ContractStatus::Normal.to_u8(),
+            &locker,
+            None,
+            false,
+        ),
+        ExecuteMsg::Lock {
+            token_id, expires, ..
+        } => lock_token(
+            deps,
+            &env,
+            &info.sender,
+            &config,
+            ContractStatus::Normal.to_u8(),
+            &token_id,
+            expires,
+        ),
+        ExecuteMsg::Unlock { token_id, .. } => unlock_token(
+            deps,
+            &env,
+            &info.sender,
+            &config,
+            ContractStatus::Normal.to_u8(),
+            &token_id,
+        ),
+        ExecuteMsg::SetUser {
+            token_id,
+            user,
+            expires,
+            ..
+        } => set_user(
+            deps,
+            &env,
+            &info.sender,
+            &config,
+            ContractStatus::Normal.to_u8(),
+            &token_id,
+            user.as_deref(),
+            expires,
+        ),
+        ExecuteMsg::List {
+            token_id, price, ..
+        } => list(
+            deps,
+            &env,
+            &info.sender,
+            &config,
+            ContractStatus::Normal.to_u8(),
+            &token_id,
+            price,
+        ),
+        ExecuteMsg::Delist { token_id, .. } => delist(
+            deps,
+            &info.sender,
+            &config,
+            ContractStatus::Normal.to_u8(),
+            &token_id,
+        ),
+        ExecuteMsg::Buy { token_id, memo, .. } => buy(
+            deps,
+            &env,
+            &info.sender,
+            &info.funds,
+            &mut config,
+            ContractStatus::Normal.to_u8(),
+            token_id,
+            memo,
+        ),
+        ExecuteMsg::StartAuction {
+            token_id,
+            denom,
+            kind,
+            duration,
+            ..
+        } => start_auction(
+            deps,
+            &env,
+            &info.sender,
+            &config,
+            ContractStatus::Normal.to_u8(),
+            &token_id,
+            denom,
+            kind,
+            duration,
+        ),
+        ExecuteMsg::Bid { token_id, .. } => bid(
+            deps,
+            &env,
+            &info.sender,
+            &info.funds,
+            &mut config,
+            ContractStatus::Normal.to_u8(),
+            token_id,
+        ),
+        ExecuteMsg::SettleAuction { token_id, .. } => settle_auction(
+            deps,
+            &env,
+            &mut config,
+            ContractStatus::Normal.to_u8(),
+            token_id,
+        ),
+        ExecuteMsg::SetOfferSnip20 {
+            contract_address,
+            code_hash,
+            min_amount,
+            ..
+        } => set_offer_snip20(
+            deps,
+            &env,
+            &info.sender,
+            &config,
+            ContractStatus::StopTransactions.to_u8(),
+            &contract_address,
+            code_hash,
+            min_amount,
+        ),
+        ExecuteMsg::AcceptOffer { offer_id, memo, .. } => accept_offer(
+            deps,
+            &env,
+            &info.sender,
+            &mut config,
+            ContractStatus::Normal.to_u8(),
+            offer_id,
+            memo,
+        ),
+        ExecuteMsg::WithdrawOffer { offer_id, .. } => withdraw_offer(
+            deps,
+            &info.sender,
+            &config,
+            ContractStatus::Normal.to_u8(),
+            offer_id,
+        ),
+        ExecuteMsg::RegisterSwapContract {
+            contract_address,
+            code_hash,
+            ..
+        } => register_swap_contract(
+            deps,
+            &env,
+            &info.sender,
+            &config,
+            ContractStatus::StopTransactions.to_u8(),
+            &contract_address,
+            code_hash,
+        ),
+        ExecuteMsg::BatchReceiveNft {
+            from, token_ids, ..
+        } => receive_deposits(
+            deps,
+            &info.sender,
+            &config,
+            ContractStatus::Normal.to_u8(),
+            &from,
+            token_ids,
+        ),
+        ExecuteMsg::WithdrawDeposits {
+            contract_address,
+            token_ids,
+            ..
+        } => withdraw_deposits(
+            deps,
+            &info.sender,
+            &config,
+            ContractStatus::Normal.to_u8(),
+            &contract_address,
+            token_ids,
+        ),
+        ExecuteMsg::ProposeSwap {
+            counterparty,
+            offered,
+            requested,
+            expires,
+            ..
+        } => propose_swap(
+            deps,
+            &env,
+            &info.sender,
+            &info.funds,
+            &config,
+            ContractStatus::Normal.to_u8(),
+            &counterparty,
+            offered,
+            requested,
+            expires,
+        ),
+        ExecuteMsg::AcceptSwap { swap_id, .. } => accept_swap(
+            deps,
+            &env,
+            &info.sender,
+            &mut config,
+            ContractStatus::Normal.to_u8(),
+            swap_id,
+        ),
+        ExecuteMsg::CancelSwap { swap_id, .. } => cancel_swap(
+            deps,
+            &info.sender,
+            &config,
+            ContractStatus::Normal.to_u8(),
+            swap_id,
+        ),
+        ExecuteMsg::WithdrawProceeds { recipient, .. } => withdraw_proceeds(
+            deps,
+            &info.sender,
+            &config,
+            ContractStatus::StopTransactions.to_u8(),
+            recipient.as_deref(),
+        ),
+        ExecuteMsg::ProposeAdmin {
+            address,
+            expiration,
+            ..
+        } => propose_admin(
+            deps,
+            &info.sender,
+            &config,
+            ContractStatus::StopTransactions.to_u8(),
+            &address,
+            expiration,
+        ),
+        ExecuteMsg::AcceptAdmin { .. } => accept_admin(
+            deps,
+            &env,
+            &info.sender,
+            &mut config,
+            ContractStatus::StopTransactions.to_u8(),
+        ),
+        ExecuteMsg::CancelAdminProposal { .. } => cancel_admin_proposal(
+            deps,
+            &info.sender,
+            &config,
+            ContractStatus::StopTransactions.to_u8(),
+        ),
+        ExecuteMsg::UpdateConfig {
+            config: updates,
+            ratchets,
+            ..
+        } => update_config(
+            deps,
+            &info.sender,
+            &mut config,
+            ContractStatus::StopTransactions.to_u8(),
+            updates,
+            ratchets,
+        ),
+        ExecuteMsg::SetContractStatus { level, .. } => {
+            set_contract_status(deps, &info.sender, &mut config, level)
+        }
+        ExecuteMsg::RevokePermit { permit_name, .. } => {
+            revoke_permit(deps.storage, &info.sender, &permit_name)
+        }
+    };
+    pad_handle_result(response, BLOCK_SIZE)
+}
+
+/// Returns StdResult<Response>
+///
+/// mint a new token
+///
+/// # Arguments
+///
+/// * `deps` - mutable reference to Extern containing all the contract's external dependencies
+/// * `env` - a reference to the Env of contract's environment
+/// * `sender` - a reference to the message sender address
+/// * `config` - a mutable reference to the Config
+/// * `priority` - u8 representation of highest status level this action is permitted at
+/// * `token_id` - optional token id, if not specified, use token index
+/// * `owner` - optional owner of this token, if not specified, use the minter's address
+/// * `public_metadata` - optional public metadata viewable by everyone
+/// * `private_metadata` - optional private metadata viewable only by owner and whitelist
+/// * `serial_number` - optional serial number information for this token
+/// * `royalty_info` - optional royalties information for this token
+/// * `transferable` - optionally true if this token is transferable
+/// * `memo` - optional memo for the mint tx
+#[allow(clippy::too_many_arguments)]
+pub fn mint(
+    deps: DepsMut,
+    env: &Env,
+    sender: &Addr,
+    config: &mut Config,
+    priority: u8,
+    token_id: Option<String>,
+    owner: Option<String>,
+    public_metadata: Option<Metadata>,
+    private_metadata: Option<Metadata>,
+    serial_number: Option<SerialNumber>,
+    royalty_info: Option<RoyaltyInfo>,
+    transferable: Option<bool>,
+    memo: Option<String>,
+) -> StdResult<Response> {
+    check_status(config.status, priority)?;
+    let sender_raw = deps.api.addr_canonicalize(sender.as_str())?;
+    let minters = load_role(deps.storage, Role::Minter)?;
+    if !minters.contains(&sender_raw) {
+        return Err(StdError::generic_err(
+            "Only designated minters are allowed to mint",
+        ));
+    }
+    use_mint_quota(deps.storage, &env.block, &sender_raw, 1)?;
+    let mints = vec![Mint {
+        token_id,
+        owner,
+        public_metadata,
+        private_metadata,
+        serial_number,
+        royalty_info,
+        transferable,
+        memo,
+    }];
+    let mut minted = mint_list(deps, env, config, &sender_raw, mints, None, None, false)?;
+    let minted_str = minted.pop().unwrap_or_default();
+    Ok(Response::new()
+        .add_attributes(vec![attr("minted", &minted_str)])
+        .set_data(to_binary(&ExecuteAnswer::MintNft {
+            token_id: minted_str,
+        })?))
+}
+
+/// Returns StdResult<Response>
+///
+/// mints many tokens
+///
+/// # Arguments
+///
+/// * `deps` - mutable reference to Extern containing all the contract's external dependencies
+/// * `env` - a reference to the Env of contract's environment
+/// * `sender` - a reference to the message sender address
+/// * `config` - a mutable reference to the Config
+/// * `priority` - u8 representation of highest ContractStatus level this action is permitted
+/// * `mints` - the list of mints to perform
+pub fn batch_mint(
+    deps: DepsMut,
+    env: &Env,
+    sender: &Addr,
+    config: &mut Config,
+    priority: u8,
+    mints: Vec<Mint>,
+) -> StdResult<Response> {
+    check_status(config.status, priority)?;
+    let sender_raw = deps.api.addr_canonicalize(sender.as_str())?;
+    let minters = load_role(deps.storage, Role::Minter)?;
+    if !minters.contains(&sender_raw) {
+        return Err(StdError::generic_err(
+            "Only designated minters are allowed to mint",
+        ));
+    }
+    let count = u32::try_from(mints.len()).map_err(|_| {
+        StdError::generic_err("Attempting to mint more tokens than the implementation limit")
+    })?;
+    use_mint_quota(deps.storage, &env.block, &sender_raw, count)?;
+    let minted = mint_list(deps, env, config, &sender_raw, mints, None, None, false)?;
+    Ok(Response::new()
+        .add_attributes(vec![attr("minted", format!("{:?}", &minted))])
+        .set_data(to_binary(&ExecuteAnswer::BatchMintNft {
+            token_ids: minted,
+        })?))
+}
+
+/// Returns StdResult<Response>
+///
+/// mints clones of a token
+///
+/// # Arguments
+///
+/// * `deps` - mutable reference to Extern containing all the contract's external dependencies
+/// * `env` - a reference to the Env of contract's environment
+/// * `sender` - a reference to the message sender address
+/// * `config` - a mutable reference to the Config
+/// * `priority` - u8 representation of highest status level this action is permitted at
+/// * `mint_run_id` - optional id used to track subsequent mint runs
+/// * `quantity` - number of clones to mint
+/// * `owner` - optional owner of this token, if not specified, use the minter's address
+/// * `public_metadata` - optional public metadata template viewable by everyone
+/// * `private_metadata` - optional private metadata template viewable only by owner and whitelist
+/// * `serial_overrides` - optional list of metadata that replaces the templates for a serial number
+/// * `royalty_info` - optional royalties information for these clones
+/// * `memo` - optional memo for the mint txs
+#[allow(clippy::too_many_arguments)]
+pub fn mint_clones(
+    mut deps: DepsMut,
+    env: &Env,
+    sender: &Addr,
+    config: &mut Config,
+    priority: u8,
+    mint_run_id: Option<&String>,
+    quantity: u32,
+    owner: Option<String>,
+    public_metadata: Option<Metadata>,
+    private_metadata: Option<Metadata>,
+    serial_overrides: Option<Vec<SerialOverride>>,
+    royalty_info: Option<RoyaltyInfo>,
+    memo: Option<String>,
+) -> StdResult<Response> {
+    check_status(config.status, priority)?;
+    let sender_raw = deps.api.addr_canonicalize(sender.as_str())?;
+    let minters = load_role(deps.storage, Role::Minter)?;
+    if !minters.contains(&sender_raw) {
+        return Err(StdError::generic_err(
+            "Only designated minters are allowed to mint",
+        ));
+    }
+    if quantity == 0 {
+        return Err(StdError::generic_err("Quantity can not be zero"));
+    }
+    let mut overrides = serial_overrides.unwrap_or_default();
+    for (i, ovr) in overrides.iter().enumerate() {
+        if ovr.serial_number == 0 || ovr.serial_number > quantity {
+            return Err(StdError::generic_err(format!(
+                "Serial number {} is not one of the {} clones being minted",
+                ovr.serial_number, quantity
+            )));
+        }
+        if overrides[..i]
+            .iter()
+            .any(|o| o.serial_number == ovr.serial_number)
+        {
+            return Err(StdError::generic_err(format!(
+                "Serial number {} is overridden more than once",
+                ovr.serial_number
+            )));
+        }
+    }
+    use_mint_quota(deps.storage, &env.block, &sender_raw, quantity)?;
+    let mint_run = mint_run_id
+        .map(|i| {
+            let key = i.as_bytes();
+            let mut run_store = PrefixedStorage::new(deps.storage, PREFIX_MINT_RUN_NUM);
+            let last_num: u32 = may_load(&run_store, key)?.unwrap_or(0);
+            let this_num: u32 = last_num.checked_add(1).ok_or_else(|| {
+                StdError::generic_err(format!(
+                    "Mint run ID {} has already reached its maximum possible value",
+                    i
+                ))
+            })?;
+            save(&mut run_store, key, &this_num)?;
+            Ok::<u32, StdError>(this_num)
+        })
+        .transpose()?;
+    let mut serial_number = SerialNumber {
+        mint_run,
+        serial_number: 1,
+        quantity_minted_this_run: Some(quantity),
+    };
+    let quantity_str = quantity.to_string();
+    let mint_run_str = mint_run.map(|r| r.to_string()).unwrap_or_default();
+    let mut mints: Vec<Mint> = Vec::new();
+    for _ in 0..quantity {
+        // use the serial number's override if there is one, otherwise the templates
+        let (mut public, mut private) = if let Some(pos) = overrides
+            .iter()
+            .position(|o| o.serial_number == serial_number.serial_number)
+        {
+            let ovr = overrides.swap_remove(pos);
+            (ovr.public_metadata, ovr.private_metadata)
+        } else {
+            (public_metadata.clone(), private_metadata.clone())
+        };
+        let serial_str = serial_number.serial_number.to_string();
+        let values = [
+            ("{serial}", serial_str.as_str()),
+            ("{quantity}", quantity_str.as_str()),
+            ("{mint_run}", mint_run_str.as_str()),
+        ];
+        for meta in public.iter_mut().chain(private.iter_mut()) {
+            meta.fill_placeholders(&values);
+        }
+        mints.push(Mint {
+            token_id: None,
+            owner: owner.clone(),
+            public_metadata: public,
+            private_metadata: private,
+            serial_number: Some(serial_number.clone()),
+            royalty_info: royalty_info.clone(),
+            transferable: Some(true),
+            memo: memo.clone(),
+        });
+        serial_number.serial_number += 1;
+    }
+    let first_index = config.mint_cnt;
+    let mut minted = mint_list(
+        deps.branch(),
+        env,
+        config,
+        &sender_raw,
+        mints,
+        mint_run_id.map(|i| i.as_str()),
+        None,
+        true,
+    )?;
+    // if mint_list did not error, there must be at least one token id
+    let first_minted = minted
+        .first()
+        .ok_or_else(|| StdError::generic_err("List of minted tokens is empty"))?
+        .clone();
+    let last_minted = minted
+        .pop()
+        .ok_or_else(|| StdError::generic_err("List of minted tokens is empty"))?;
+    // add the run to its mint run id's registry
+    if let (Some(id), Some(run)) = (mint_run_id, mint_run) {
+        let mut reg_store =
+            PrefixedStorage::multilevel(deps.storage, &[PREFIX_MINT_RUN_REGISTRY, id.as_bytes()]);
+        save(
+            &mut reg_store,
+            &run.to_le_bytes(),
+            &StoredMintRun {
+                creator: sender_raw,
+                time_of_minting: env.block.time.seconds(),
+                quantity,
+                first_index,
+                first_token_id: first_minted.clone(),
+                last_token_id: last_minted.clone(),
+            },
+        )?;
+    }
+
+    Ok(Response::new()
+        .add_attributes(vec![
+            attr("first_minted", &first_minted),
+            attr("last_minted", &last_minted),
+        ])
+        .set_data(to_binary(&ExecuteAnswer::MintNftClones {
+            first_minted,
+            last_minted,
+        })?))
+}
+
+/// Returns StdResult<Response>
+///
+/// sets new public and/or private metadata
+///
+/// # Arguments
+///
+/// * `deps` - mutable reference to Extern containing all the contract's external dependencies
+/// * `env` - a reference to the Env of contract's environment
+/// * `sender` - a reference to the message sender address
+/// * `config` - a reference to the Config
+/// * `priority` - u8 representation of highest status level this action is permitted at
+/// * `token_id` - token id String slice of token whose metadata should be updated
+/// * `public_metadata` - the optional new public metadata viewable by everyone
+/// * `private_metadata` - the optional new private metadata viewable by everyone
+#[allow(clippy::too_many_arguments)]
+pub fn set_metadata(
+    deps: DepsMut,
+    env: &Env,
+    sender: &Addr,
+    config: &Config,
+    priority: u8,
+    token_id: &str,
+    public_metadata: Option<Metadata>,
+    private_metadata: Option<Metadata>,
+) -> StdResult<Response> {
+    check_status(config.status, priority)?;
+    let custom_err = format!("Not authorized to update metadata of token {}", token_id);
+    // if token supply is private, don't leak that the token id does not exist
+    // instead just say they are not authorized for that token
+    let opt_err = if config.token_supply_is_public {
+        None
+    } else {
+        Some(&*custom_err)
+    };
+    let (mut token, idx) = get_token(deps.storage, token_id, opt_err)?;
+    let sender_raw = deps.api.addr_canonicalize(sender.as_str())?;
+    if !(token.owner == sender_raw && config.owner_may_update_metadata) {
+        let editors = load_role(deps.storage, Role::MetadataEditor)?;
+        if !(editors.contains(&sender_raw) && config.minter_may_update_metadata) {
+            return Err(StdError::generic_err(custom_err));
+        }
+    }
+    // write a scheduled reveal before the metadata is changed
+    if !token.unwrapped && scheduled_reveal_passed(deps.storage, &env.block)? {
+        unwrap_token(deps.storage, config, &mut token, idx)?;
+    }
+    if let Some(public) = public_metadata {
+        set_metadata_impl(deps.storage, &token, idx, PREFIX_PUB_META, &public)?;
+    }
+    if let Some(private) = private_metadata {
+        set_metadata_impl(deps.storage, &token, idx, PREFIX_PRIV_META, &private)?;
+    }
     Ok(Response::new().set_data(to_binary(&ExecuteAnswer::SetMetadata { status: Success })?))
 }
 
 /// Returns StdResult<Response>
 ///
-/// sets new royalty information for a specified token or if no token ID is provided, sets new
-/// royalty information as the contract's default
+/// sets new royalty information for a specified token or if no token ID is provided, sets new
+/// royalty information as the contract's default
+///
+/// # Arguments
+///
+/// * `deps` - mutable reference to Extern containing all the contract's external dependencies
+/// * `sender` - a reference to the message sender address
+/// * `config` - a reference to the Config
+/// * `priority` - u8 representation of highest status level this action is permitted at
+/// * `token_id` - optional token id String slice of token whose royalty info should be updated
+/// * `royalty_info` - a optional reference to the new RoyaltyInfo
+pub fn set_royalty_info(
+    deps: DepsMut,
+    sender: &Addr,
+    config: &Config,
+    priority: u8,
+    token_id: Option<&str>,
+    royalty_info: Option<&RoyaltyInfo>,
+) -> StdResult<Response> {
+    check_status(config.status, priority)?;
+    let sender_raw = deps.api.addr_canonicalize(sender.as_str())?;
+    // set a token's royalties
+    if let Some(id) = token_id {
+        let custom_err = "A token's RoyaltyInfo may only be set by the token creator when they are also the token owner";
+        // if token supply is private, don't leak that the token id does not exist
+        // instead just say they are not authorized for that token
+        let opt_err = if config.token_supply_is_public {
+            None
+        } else {
+            Some(custom_err)
+        };
+        let (token, idx) = get_token(deps.storage, id, opt_err)?;
+        if !token.transferable {
+            return Err(StdError::generic_err(
+                "Non-transferable tokens can not be sold, so royalties are meaningless",
+            ));
+        }
+        let token_key = idx.to_le_bytes();
+        let run_store = ReadonlyPrefixedStorage::new(deps.storage, PREFIX_MINT_RUN);
+        let mint_run: StoredMintRunInfo = load(&run_store, &token_key)?;
+        if sender_raw != mint_run.token_creator || sender_raw != token.owner {
+            return Err(StdError::generic_err(custom_err));
+        }
+        let default_roy = royalty_info.as_ref().map_or_else(
+            || may_load::<StoredRoyaltyInfo>(deps.storage, DEFAULT_ROYALTY_KEY),
+            |_r| Ok(None),
+        )?;
+        let mut roy_store = PrefixedStorage::new(deps.storage, PREFIX_ROYALTY_INFO);
+        store_royalties(
+            &mut roy_store,
+            deps.api,
+            royalty_info,
+            default_roy.as_ref(),
+            &token_key,
+        )?;
+        // set default royalty
+    } else {
+        let managers = load_role(deps.storage, Role::RoyaltyManager)?;
+        if !managers.contains(&sender_raw) {
+            return Err(StdError::generic_err(
+                "Only designated royalty managers can set default royalties for the contract",
+            ));
+        }
+        store_royalties(
+            deps.storage,
+            deps.api,
+            royalty_info,
+            None,
+            DEFAULT_ROYALTY_KEY,
+        )?;
+    };
+
+    Ok(
+        Response::new().set_data(to_binary(&ExecuteAnswer::SetRoyaltyInfo {
+            status: Success,
+        })?),
+    )
+}
+
+/// Returns StdResult<Response>
+///
+/// makes the sealed private metadata public, or gives the token a random item of the reveal
+/// pool if there is one
+///
+/// # Arguments
+///
+/// * `deps` - mutable reference to Extern containing all the contract's external dependencies
+/// * `sender` - a reference to the message sender address
+/// * `config` - a reference to the Config
+/// * `priority` - u8 representation of highest status level this action is permitted at
+/// * `token_id` - token id String slice of token whose metadata should be updated
+pub fn reveal(
+    deps: DepsMut,
+    sender: &Addr,
+    config: &Config,
+    priority: u8,
+    token_id: &str,
+) -> StdResult<Response> {
+    check_status(config.status, priority)?;
+    if !config.sealed_metadata_is_enabled {
+        return Err(StdError::generic_err(
+            "Sealed metadata functionality is not enabled for this contract",
+        ));
+    }
+    let sender_raw = deps.api.addr_canonicalize(sender.as_str())?;
+    let custom_err = format!("You do not own token {}", token_id);
+    // if token supply is private, don't leak that the token id does not exist
+    // instead just say they do not own that token
+    let opt_err = if config.token_supply_is_public {
+        None
+    } else {
+        Some(&*custom_err)
+    };
+    let (mut token, idx) = get_token(deps.storage, token_id, opt_err)?;
+    if token.unwrapped {
+        return Err(StdError::generic_err(
+            "This token has already been unwrapped",
+        ));
+    }
+    if token.owner != sender_raw {
+        return Err(StdError::generic_err(custom_err));
+    }
+    let may_pool: Option<RevealPool> = may_load(deps.storage, REVEAL_POOL_KEY)?;
+    if let Some(pool) = may_pool {
+        if !pool.is_loaded() {
+            return Err(StdError::generic_err(
+                "The reveal pool has not been fully loaded",
+            ));
+        }
+        let seed: Vec<u8> = may_load(deps.storage, REVEAL_SEED_KEY)?
+            .ok_or_else(|| StdError::generic_err("The reveal pool's seed has not been revealed"))?;
+        // minting closed when the seed was revealed, so every token's position is fixed
+        let position = shuffled_position(&seed, config.mint_cnt, idx);
+        if position >= pool.size {
+            return Err(StdError::generic_err(format!(
+                "Token {} is not given an item because the reveal pool has fewer items than tokens",
+                token_id
+            )));
+        }
+        token.unwrapped = true;
+        let token_key = idx.to_le_bytes();
+        let mut info_store = PrefixedStorage::new(deps.storage, PREFIX_INFOS);
+        json_save(&mut info_store, &token_key, &token)?;
+        assign_reveal_item(deps.storage, &token_key, position, pool)?;
+    } else {
+        unwrap_token(deps.storage, config, &mut token, idx)?;
+    }
+    Ok(Response::new().set_data(to_binary(&ExecuteAnswer::Reveal { status: Success })?))
+}
+
+/// Returns StdResult<Response>
+///
+/// grants/revokes trasfer permission on a token
+///
+/// # Arguments
+///
+/// * `deps` - mutable reference to Extern containing all the contract's external dependencies
+/// * `env` - a reference to the Env of contract's environment
+/// * `sender` - a reference to the message sender address
+/// * `config` - a reference to the Config
+/// * `priority` - u8 representation of highest status level this action is permitted at
+/// * `spender` - a reference to the address being granted permission
+/// * `token_id` - string slice of the token id to grant permission to
+/// * `expires` - optional Expiration for this approval
+/// * `is_approve` - true if this is an Approve call
+#[allow(clippy::too_many_arguments)]
+pub fn approve_revoke(
+    deps: DepsMut,
+    env: &Env,
+    sender: &Addr,
+    config: &Config,
+    priority: u8,
+    spender: &str,
+    token_id: &str,
+    expires: Option<Expiration>,
+    is_approve: bool,
+) -> StdResult<Response> {
+    check_status(config.status, priority)?;
+    let address_raw = deps
+        .api
+        .addr_canonicalize(deps.api.addr_validate(spender)?.as_str())?;
+    let sender_raw = deps.api.addr_canonicalize(sender.as_str())?;
+    let custom_err = format!(
+        "Not authorized to grant/revoke transfer permission for token {}",
+        token_id
+    );
+    // if token supply is private, don't leak that the token id does not exist
+    // instead just say they are not authorized for that token
+    let opt_err = if config.token_supply_is_public {
+        None
+    } else {
+        Some(&*custom_err)
+    };
+    let (token, idx) = get_token(deps.storage, token_id, opt_err)?;
+    if edition_supply(deps.storage, idx)?.is_some() {
+        return Err(StdError::generic_err(format!(
+            "Token ID: {} is an edition and can not have token approvals",
+            token_id
+        )));
+    }
+    let mut all_perm: Option<Vec<Permission>> = None;
+    let mut from_oper = false;
+    let transfer_idx = PermissionType::Transfer.to_usize();
+    // if not called by the owner, check if message sender has operator status
+    if token.owner != sender_raw {
+        let all_store = ReadonlyPrefixedStorage::new(deps.storage, PREFIX_ALL_PERMISSIONS);
+        let may_list: Option<Vec<Permission>> = json_may_load(&all_store, token.owner.as_slice())?;
+        if let Some(list) = may_list.clone() {
+            if let Some(perm) = list.iter().find(|&p| p.address == sender_raw) {
+                if let Some(exp) = perm.expirations[transfer_idx] {
+                    if exp.is_expired(&env.block) {
+                        return Err(StdError::generic_err(format!(
+                            "Transfer authority for all tokens of {} has expired",
+                            &deps.api.addr_humanize(&token.owner)?
+                        )));
+                    } else {
+                        from_oper = true;
+                    }
+                }
+            }
+        }
+        if !from_oper {
+            return Err(StdError::generic_err(custom_err));
+        }
+        all_perm = may_list;
+    }
+    let mut accesses: [Option<AccessLevel>; 3] = [None, None, None];
+    let response: ExecuteAnswer;
+    if is_approve {
+        accesses[transfer_idx] = Some(AccessLevel::ApproveToken);
+        response = ExecuteAnswer::Approve { status: Success };
+    } else {
+        accesses[transfer_idx] = Some(AccessLevel::RevokeToken);
+        response = ExecuteAnswer::Revoke { status: Success };
+    }
+    let owner = token.owner.clone();
+    let mut proc_info = ProcessAccInfo {
+        token,
+        idx,
+        token_given: true,
+        accesses,
+        expires,
+        from_oper,
+    };
+    process_accesses(
+        deps.storage,
+        env,
+        &address_raw,
+        &owner,
+        &mut proc_info,
+        all_perm,
+    )?;
+    let res = Response::new().set_data(to_binary(&response)?);
+    Ok(res)
+}
+
+/// Returns StdResult<Response>
+///
+/// makes an address' token ownership private
+///
+/// # Arguments
+///
+/// * `deps` - mutable reference to Extern containing all the contract's external dependencies
+/// * `sender` - a reference to the message sender address
+/// * `config` - a reference to the Config
+/// * `priority` - u8 representation of highest status level this action is permitted at
+pub fn make_owner_private(
+    deps: DepsMut,
+    sender: &Addr,
+    config: &Config,
+    priority: u8,
+) -> StdResult<Response> {
+    check_status(config.status, priority)?;
+    let sender_raw = deps.api.addr_canonicalize(sender.as_str())?;
+    // only need to do this if the contract has public ownership
+    if config.owner_is_public {
+        let mut priv_store = PrefixedStorage::new(deps.storage, PREFIX_OWNER_PRIV);
+        save(&mut priv_store, sender_raw.as_slice(), &false)?
+    }
+    Ok(
+        Response::new().set_data(to_binary(&ExecuteAnswer::MakeOwnershipPrivate {
+            status: Success,
+        })?),
+    )
+}
+
+/// Returns StdResult<Response>
+///
+/// adds/revokes access for everyone
+///
+/// # Arguments
+///
+/// * `deps` - mutable reference to Extern containing all the contract's external dependencies
+/// * `env` - a reference to the Env of contract's environment
+/// * `sender` - a reference to the message sender address
+/// * `config` - a reference to the Config
+/// * `priority` - u8 representation of highest status level this action is permitted at
+/// * `token_id` - optional token id to apply approvals to
+/// * `view_owner` - optional access level for viewing token ownership
+/// * `view_private_metadata` - optional access level for viewing private metadata
+/// * `expires` - optional Expiration for this approval
+#[allow(clippy::too_many_arguments)]
+pub fn set_global_approval(
+    deps: DepsMut,
+    env: &Env,
+    sender: &Addr,
+    config: &Config,
+    priority: u8,
+    token_id: Option<String>,
+    view_owner: Option<AccessLevel>,
+    view_private_metadata: Option<AccessLevel>,
+    expires: Option<Expiration>,
+) -> StdResult<Response> {
+    check_status(config.status, priority)?;
+    let token_given: bool;
+    // use this "address" to represent global permission
+    let global_raw = CanonicalAddr(Binary::from(b"public"));
+    let sender_raw = deps.api.addr_canonicalize(sender.as_str())?;
+    let mut custom_err = String::new();
+    let (token, idx) = if let Some(id) = token_id {
+        token_given = true;
+        custom_err = format!("You do not own token {}", id);
+        // if token supply is private, don't leak that the token id does not exist
+        // instead just say they do not own that token
+        let opt_err = if config.token_supply_is_public {
+            None
+        } else {
+            Some(&*custom_err)
+        };
+        let (token, idx) = get_token(deps.storage, &id, opt_err)?;
+        if token.owner == sender_raw && edition_supply(deps.storage, idx)?.is_some() {
+            return Err(StdError::generic_err(format!(
+                "Token ID: {} is an edition and can not have token approvals",
+                id
+            )));
+        }
+        (token, idx)
+    } else {
+        token_given = false;
+        (
+            Token {
+                owner: sender_raw.clone(),
+                permissions: Vec::new(),
+                unwrapped: false,
+                transferable: true,
+            },
+            0,
+        )
+    };
+    // if trying to set token permissions when you are not the owner
+    if token_given && token.owner != sender_raw {
+        return Err(StdError::generic_err(custom_err));
+    }
+    let mut accesses: [Option<AccessLevel>; 3] = [None, None, None];
+    accesses[PermissionType::ViewOwner.to_usize()] = view_owner;
+    accesses[PermissionType::ViewMetadata.to_usize()] = view_private_metadata;
+    let mut proc_info = ProcessAccInfo {
+        token,
+        idx,
+        token_given,
+        accesses,
+        expires,
+        from_oper: false,
+    };
+    process_accesses(
+        deps.storage,
+        env,
+        &global_raw,
+        &sender_raw,
+        &mut proc_info,
+        None,
+    )?;
+    Ok(
+        Response::new().set_data(to_binary(&ExecuteAnswer::SetGlobalApproval {
+            status: Success,
+        })?),
+    )
+}
+
+/// Returns StdResult<Response>
+///
+/// sets specified permissions for an address
+///
+/// # Arguments
+///
+/// * `deps` - mutable reference to Extern containing all the contract's external dependencies
+/// * `env` - a reference to the Env of contract's environment
+/// * `sender` - a reference to the message sender address
+/// * `config` - a reference to the Config
+/// * `priority` - u8 representation of highest status level this action is permitted at
+/// * `address` - a reference to the address being granted permission
+/// * `token_id` - optional token id to apply approvals to
+/// * `view_owner` - optional access level for viewing token ownership
+/// * `view_private_metadata` - optional access level for viewing private metadata
+/// * `transfer` - optional access level for transferring tokens
+/// * `expires` - optional Expiration for this approval
+/// * `response_type` - which response to return for SetWhitelistedApproval, ApproveAll, or RevokeAll
+#[allow(clippy::too_many_arguments)]
+pub fn set_whitelisted_approval(
+    deps: DepsMut,
+    env: &Env,
+    sender: &Addr,
+    config: &Config,
+    priority: u8,
+    address: &str,
+    token_id: Option<String>,
+    view_owner: Option<AccessLevel>,
+    view_private_metadata: Option<AccessLevel>,
+    transfer: Option<AccessLevel>,
+    expires: Option<Expiration>,
+    response_type: SetAppResp,
+) -> StdResult<Response> {
+    check_status(config.status, priority)?;
+    let token_given: bool;
+    let address_raw = deps
+        .api
+        .addr_canonicalize(deps.api.addr_validate(address)?.as_str())?;
+    let sender_raw = deps.api.addr_canonicalize(sender.as_str())?;
+    let mut custom_err = String::new();
+    let (token, idx) = if let Some(id) = token_id {
+        token_given = true;
+        custom_err = format!("You do not own token {}", id);
+        // if token supply is private, don't leak that the token id does not exist
+        // instead just say they do not own that token
+        let opt_err = if config.token_supply_is_public {
+            None
+        } else {
+            Some(&*custom_err)
+        };
+        get_token(deps.storage, &id, opt_err)?
+    } else {
+        token_given = false;
+        (
+            Token {
+                owner: sender_raw.clone(),
+                permissions: Vec::new(),
+                unwrapped: false,
+                transferable: true,
+            },
+            0,
+        )
+    };
+    // if trying to set token permissions when you are not the owner
+    if token_given && token.owner != sender_raw {
+        return Err(StdError::generic_err(custom_err));
+    }
+    let mut accesses: [Option<AccessLevel>; 3] = [None, None, None];
+    accesses[PermissionType::ViewOwner.to_usize()] = view_owner;
+    accesses[PermissionType::ViewMetadata.to_usize()] = view_private_metadata;
+    accesses[PermissionType::Transfer.to_usize()] = transfer;
+    let mut proc_info = ProcessAccInfo {
+        token,
+        idx,
+        token_given,
+        accesses,
+        expires,
+        from_oper: false,
+    };
+    process_accesses(
+        deps.storage,
+        env,
+        &address_raw,
+        &sender_raw,
+        &mut proc_info,
+        None,
+    )?;
+    let response = match response_type {
+        SetAppResp::SetWhitelistedApproval => {
+            ExecuteAnswer::SetWhitelistedApproval { status: Success }
+        }
+        SetAppResp::ApproveAll => ExecuteAnswer::ApproveAll { status: Success },
+        SetAppResp::RevokeAll => ExecuteAnswer::RevokeAll { status: Success },
+    };
+    let res = Response::new().set_data(to_binary(&response)?);
+    Ok(res)
+}
+
+/// Returns StdResult<Response>
+///
+/// burns many tokens
+///
+/// # Arguments
+///
+/// * `deps` - mutable reference to Extern containing all the contract's external dependencies
+/// * `env` - a reference to the Env of contract's environment
+/// * `sender` - a reference to the message sender address
+/// * `config` - a mutable reference to the Config
+/// * `priority` - u8 representation of highest ContractStatus level this action is permitted
+/// * `burns` - the list of burns to perform
+pub fn batch_burn_nft(
+    deps: DepsMut,
+    env: &Env,
+    sender: &Addr,
+    config: &mut Config,
+    priority: u8,
+    burns: Vec<Burn>,
+) -> StdResult<Response> {
+    check_status(config.status, priority)?;
+    let sender_raw = deps.api.addr_canonicalize(sender.as_str())?;
+    let refunds = burn_list(deps, &env.block, config, &sender_raw, burns, false)?;
+    let res = Response::new()
+        .add_messages(refunds)
+        .set_data(to_binary(&ExecuteAnswer::BatchBurnNft { status: Success })?);
+    Ok(res)
+}
+
+/// Returns StdResult<Response>
+///
+/// burns a token
+///
+/// # Arguments
+///
+/// * `deps` - mutable reference to Extern containing all the contract's external dependencies
+/// * `env` - a reference to the Env of contract's environment
+/// * `sender` - a reference to the message sender address
+/// * `config` - a mutable reference to the Config
+/// * `priority` - u8 representation of highest ContractStatus level this action is permitted
+/// * `token_id` - token id String of token to be burnt
+/// * `memo` - optional memo for the burn tx
+fn burn_nft(
+    deps: DepsMut,
+    env: &Env,
+    sender: &Addr,
+    config: &mut Config,
+    priority: u8,
+    token_id: String,
+    memo: Option<String>,
+) -> StdResult<Response> {
+    check_status(config.status, priority)?;
+    let sender_raw = deps.api.addr_canonicalize(sender.as_str())?;
+    let burns = vec![Burn {
+        token_ids: vec![token_id],
+        memo,
+    }];
+    let refunds = burn_list(deps, &env.block, config, &sender_raw, burns, false)?;
+    let res = Response::new()
+        .add_messages(refunds)
+        .set_data(to_binary(&ExecuteAnswer::BurnNft { status: Success })?);
+    Ok(res)
+}
+
+/// Returns StdResult<Response>
+///
+/// transfer many tokens
+///
+/// # Arguments
+///
+/// * `deps` - mutable reference to Extern containing all the contract's external dependencies
+/// * `env` - a reference to the Env of contract's environment
+/// * `sender` - a reference to the message sender address
+/// * `config` - a mutable reference to the Config
+/// * `priority` - u8 representation of highest ContractStatus level this action is permitted
+/// * `transfers` - list of transfers to perform
+pub fn batch_transfer_nft(
+    deps: DepsMut,
+    env: &Env,
+    sender: &Addr,
+    config: &mut Config,
+    priority: u8,
+    transfers: Vec<Transfer>,
+) -> StdResult<Response> {
+    check_status(config.status, priority)?;
+    let _m = send_list(deps, env, sender, config, Some(transfers), None)?;
+
+    let res = Response::new().set_data(to_binary(&ExecuteAnswer::BatchTransferNft {
+        status: Success,
+    })?);
+    Ok(res)
+}
+
+/// Returns StdResult<Response>
+///
+/// transfer a token
+///
+/// # Arguments
+///
+/// * `deps` - mutable reference to Extern containing all the contract's external dependencies
+/// * `env` - a reference to the Env of contract's environment
+/// * `sender` - a reference to the message sender address
+/// * `config` - a mutable reference to the Config
+/// * `priority` - u8 representation of highest ContractStatus level this action is permitted
+/// * `recipient` - the address receiving the token
+/// * `token_id` - token id String of token to be transferred
+/// * `memo` - optional memo for the mint tx
+#[allow(clippy::too_many_arguments)]
+pub fn transfer_nft(
+    deps: DepsMut,
+    env: &Env,
+    sender: &Addr,
+    config: &mut Config,
+    priority: u8,
+    recipient: String,
+    token_id: String,
+    memo: Option<String>,
+) -> StdResult<Response> {
+    check_status(config.status, priority)?;
+    let transfers = Some(vec![Transfer {
+        recipient,
+        token_ids: vec![token_id],
+        memo,
+    }]);
+    let _m = send_list(deps, env, sender, config, transfers, None)?;
+
+    let res = Response::new().set_data(to_binary(&ExecuteAnswer::TransferNft { status: Success })?);
+    Ok(res)
+}
+
+/// Returns StdResult<Response>
+///
+/// sends tokens to contracts, and calls those contracts' ReceiveNft.  Will error if any
+/// contract has not registered its ReceiveNft
+///
+/// # Arguments
+///
+/// * `deps` - mutable reference to Extern containing all the contract's external dependencies
+/// * `env` - a reference to the Env of contract's environment
+/// * `sender` - a reference to the message sender address
+/// * `config` - a mutable reference to the Config
+/// * `priority` - u8 representation of highest ContractStatus level this action is permitted
+/// * `sends` - list of SendNfts to perform
+fn batch_send_nft(
+    deps: DepsMut,
+    env: &Env,
+    sender: &Addr,
+    config: &mut Config,
+    priority: u8,
+    sends: Vec<Send>,
+) -> StdResult<Response> {
+    check_status(config.status, priority)?;
+    let messages = send_list(deps, env, sender, config, None, Some(sends))?;
+
+    let res = Response::new()
+        .add_messages(messages)
+        .set_data(to_binary(&ExecuteAnswer::BatchSendNft { status: Success })?);
+    Ok(res)
+}
+
+/// Returns StdResult<Response>
+///
+/// sends a token to a contract, and calls that contract's ReceiveNft.  Will error if the
+/// contract has not registered its ReceiveNft
+///
+/// # Arguments
+///
+/// * `deps` - mutable reference to Extern containing all the contract's external dependencies
+/// * `env` - a reference to the Env of contract's environment
+/// * `sender` - a reference to the message sender address
+/// * `config` - a mutable reference to the Config
+/// * `priority` - u8 representation of highest ContractStatus level this action is permitted
+/// * `contract` - the address of the contract receiving the token
+/// * `receiver_info` - optional code hash and BatchReceiveNft implementation status of
+///                     the recipient contract
+/// * `token_id` - ID String of the token that was sent
+/// * `msg` - optional msg used to control ReceiveNft logic
+/// * `memo` - optional memo for the mint tx
+#[allow(clippy::too_many_arguments)]
+fn send_nft(
+    deps: DepsMut,
+    env: &Env,
+    sender: &Addr,
+    config: &mut Config,
+    priority: u8,
+    contract: String,
+    receiver_info: Option<ReceiverInfo>,
+    token_id: String,
+    msg: Option<Binary>,
+    memo: Option<String>,
+) -> StdResult<Response> {
+    check_status(config.status, priority)?;
+    let sends = Some(vec![Send {
+        contract,
+        receiver_info,
+        token_ids: vec![token_id],
+        msg,
+        memo,
+    }]);
+    let messages = send_list(deps, env, sender, config, None, sends)?;
+
+    let res = Response::new()
+        .add_messages(messages)
+        .set_data(to_binary(&ExecuteAnswer::SendNft { status: Success })?);
+    Ok(res)
+}
+
+/// Returns StdResult<Response>
+///
+/// registers a contract's ReceiveNft
+///
+/// # Arguments
+///
+/// * `deps` - mutable reference to Extern containing all the contract's external dependencies
+/// * `sender` - a reference to the message sender address
+/// * `config` - a reference to the Config
+/// * `priority` - u8 representation of highest ContractStatus level this action is permitted
+/// * `code_hash` - code hash String of the registering contract
+/// * `impl_batch` - optionally true if the contract also implements BatchReceiveNft
+pub fn register_receive_nft(
+    deps: DepsMut,
+    sender: &Addr,
+    config: &Config,
+    priority: u8,
+    code_hash: String,
+    impl_batch: Option<bool>,
+) -> StdResult<Response> {
+    check_status(config.status, priority)?;
+    let sender_raw = deps.api.addr_canonicalize(sender.as_str())?;
+    let regrec = ReceiveRegistration {
+        code_hash,
+        impl_batch: impl_batch.unwrap_or(false),
+    };
+    let mut store = PrefixedStorage::new(deps.storage, PREFIX_RECEIVERS);
+    save(&mut store, sender_raw.as_slice(), &regrec)?;
+    let res = Response::new().set_data(to_binary(&ExecuteAnswer::RegisterReceiveNft {
+        status: Success,
+    })?);
+    Ok(res)
+}
+
+/// Returns StdResult<Response>
+///
+/// creates a viewing key
+///
+/// # Arguments
+///
+/// * `deps` - mutable reference to Extern containing all the contract's external dependencies
+/// * `env` - a reference to the Env of contract's environment
+/// * `info` - contract execution info for authorization - identity of the call, and payment.
+/// * `config` - a reference to the Config
+/// * `priority` - u8 representation of highest ContractStatus level this action is permitted
+/// * `entropy` - string slice of the input String to be used as entropy in randomization
+pub fn create_key(
+    deps: DepsMut,
+    env: &Env,
+    info: &MessageInfo,
+    config: &Config,
+    priority: u8,
+    entropy: &str,
+) -> StdResult<Response> {
+    check_status(config.status, priority)?;
+    let key = ViewingKey::create(
+        deps.storage,
+        info,
+        env,
+        info.sender.as_str(),
+        entropy.as_ref(),
+    );
+
+    Ok(Response::new().set_data(to_binary(&ExecuteAnswer::ViewingKey { key })?))
+}
+
+/// Returns StdResult<Response>
+///
+/// sets the viewing key to the input String
+///
+/// # Arguments
+///
+/// * `deps` - mutable reference to Extern containing all the contract's external dependencies
+/// * `sender` - a reference to the message sender address
+/// * `config` - a reference to the Config
+/// * `priority` - u8 representation of highest ContractStatus level this action is permitted
+/// * `key` - String to be used as the viewing key
+pub fn set_key(
+    deps: DepsMut,
+    sender: &Addr,
+    config: &Config,
+    priority: u8,
+    key: String,
+) -> StdResult<Response> {
+    check_status(config.status, priority)?;
+    ViewingKey::set(deps.storage, sender.as_str(), &key);
+    Ok(Response::new().set_data(to_binary(&ExecuteAnswer::ViewingKey { key })?))
+}
+
+/// Returns StdResult<Response>
+///
+/// add a list of minters
+///
+/// # Arguments
+///
+/// * `deps` - mutable reference to Extern containing all the contract's external dependencies
+/// * `sender` - a reference to the message sender address
+/// * `config` - a reference to the Config
+/// * `priority` - u8 representation of highest ContractStatus level this action is permitted
+/// * `new_minters` - list of minter addresses to add
+pub fn add_minters(
+    deps: DepsMut,
+    sender: &Addr,
+    config: &Config,
+    priority: u8,
+    new_minters: &[String],
+) -> StdResult<Response> {
+    check_status(config.status, priority)?;
+    let sender_raw = deps.api.addr_canonicalize(sender.as_str())?;
+    if config.admin != sender_raw {
+        return Err(StdError::generic_err(
+            "This is an admin command and can only be run from the admin address",
+        ));
+    }
+    grant_role_impl(deps, Role::Minter, new_minters)?;
+    Ok(Response::new().set_data(to_binary(&ExecuteAnswer::AddMinters { status: Success })?))
+}
+
+/// Returns StdResult<Response>
+///
+/// remove a list of minters
+///
+/// # Arguments
+///
+/// * `deps` - mutable reference to Extern containing all the contract's external dependencies
+/// * `sender` - a reference to the message sender address
+/// * `config` - a reference to the Config
+/// * `priority` - u8 representation of highest ContractStatus level this action is permitted
+/// * `no_minters` - list of minter addresses to remove
+pub fn remove_minters(
+    deps: DepsMut,
+    sender: &Addr,
+    config: &Config,
+    priority: u8,
+    no_minters: &[String],
+) -> StdResult<Response> {
+    check_status(config.status, priority)?;
+    let sender_raw = deps.api.addr_canonicalize(sender.as_str())?;
+    if config.admin != sender_raw {
+        return Err(StdError::generic_err(
+            "This is an admin command and can only be run from the admin address",
+        ));
+    }
+    revoke_role_impl(deps, Role::Minter, no_minters)?;
+    Ok(
+        Response::new().set_data(to_binary(&ExecuteAnswer::RemoveMinters {
+            status: Success,
+        })?),
+    )
+}
+
+/// Returns StdResult<Response>
+///
+/// define the exact list of minters
+///
+/// # Arguments
+///
+/// * `deps` - mutable reference to Extern containing all the contract's external dependencies
+/// * `sender` - a reference to the message sender address
+/// * `config` - a reference to the Config
+/// * `priority` - u8 representation of highest ContractStatus level this action is permitted
+/// * `human_minters` - exact list of minter addresses
+pub fn set_minters(
+    deps: DepsMut,
+    sender: &Addr,
+    config: &Config,
+    priority: u8,
+    human_minters: &[String],
+) -> StdResult<Response> {
+    check_status(config.status, priority)?;
+    let sender_raw = deps.api.addr_canonicalize(sender.as_str())?;
+    if config.admin != sender_raw {
+        return Err(StdError::generic_err(
+            "This is an admin command and can only be run from the admin address",
+        ));
+    }
+    // remove duplicates from the minters list
+    let minters_raw: Vec<CanonicalAddr> = human_minters
+        .iter()
+        .map(|x| {
+            deps.api
+                .addr_canonicalize(deps.api.addr_validate(x)?.as_str())
+        })
+        .collect::<StdResult<Vec<CanonicalAddr>>>()?;
+    let mut sortable: Vec<&[u8]> = minters_raw.iter().map(|x| x.as_slice()).collect();
+    sortable.sort_unstable();
+    sortable.dedup();
+    let minters: Vec<CanonicalAddr> = sortable
+        .iter()
+        .map(|x| CanonicalAddr(Binary(x.to_vec())))
+        .collect();
+    let mut former = load_role(deps.storage, Role::Minter)?;
+    former.retain(|m| !minters.contains(m));
+    save_role(deps.storage, Role::Minter, &minters)?;
+    revoke_minter_grants(deps.storage, &former)?;
+    Ok(Response::new().set_data(to_binary(&ExecuteAnswer::SetMinters { status: Success })?))
+}
+
+/// Returns StdResult<Response>
+///
+/// grant a role to a list of addresses
+///
+/// # Arguments
+///
+/// * `deps` - mutable reference to Extern containing all the contract's external dependencies
+/// * `sender` - a reference to the message sender address
+/// * `config` - a reference to the Config
+/// * `priority` - u8 representation of highest ContractStatus level this action is permitted
+/// * `role` - the role to grant
+/// * `addresses` - list of addresses receiving the role
+pub fn grant_role(
+    deps: DepsMut,
+    sender: &Addr,
+    config: &Config,
+    priority: u8,
+    role: Role,
+    addresses: &[String],
+) -> StdResult<Response> {
+    check_status(config.status, priority)?;
+    let sender_raw = deps.api.addr_canonicalize(sender.as_str())?;
+    if config.admin != sender_raw {
+        return Err(StdError::generic_err(
+            "This is an admin command and can only be run from the admin address",
+        ));
+    }
+    grant_role_impl(deps, role, addresses)?;
+    Ok(Response::new().set_data(to_binary(&ExecuteAnswer::GrantRole { status: Success })?))
+}
+
+/// Returns StdResult<Response>
+///
+/// revoke a role from a list of addresses
+///
+/// # Arguments
+///
+/// * `deps` - mutable reference to Extern containing all the contract's external dependencies
+/// * `sender` - a reference to the message sender address
+/// * `config` - a reference to the Config
+/// * `priority` - u8 representation of highest ContractStatus level this action is permitted
+/// * `role` - the role to revoke
+/// * `addresses` - list of addresses losing the role
+pub fn revoke_role(
+    deps: DepsMut,
+    sender: &Addr,
+    config: &Config,
+    priority: u8,
+    role: Role,
+    addresses: &[String],
+) -> StdResult<Response> {
+    check_status(config.status, priority)?;
+    let sender_raw = deps.api.addr_canonicalize(sender.as_str())?;
+    if config.admin != sender_raw {
+        return Err(StdError::generic_err(
+            "This is an admin command and can only be run from the admin address",
+        ));
+    }
+    revoke_role_impl(deps, role, addresses)?;
+    Ok(Response::new().set_data(to_binary(&ExecuteAnswer::RevokeRole { status: Success })?))
+}
+
+/// Returns StdResult<()>
+///
+/// adds a list of addresses to a role's holders
+///
+/// # Arguments
+///
+/// * `deps` - mutable reference to Extern containing all the contract's external dependencies
+/// * `role` - the role to grant
+/// * `addresses` - list of addresses receiving the role
+fn grant_role_impl(deps: DepsMut, role: Role, addresses: &[String]) -> StdResult<()> {
+    let mut holders = load_role(deps.storage, role)?;
+    let mut update = false;
+    let mut granted: Vec<CanonicalAddr> = Vec::new();
+    for address in addresses {
+        let raw = deps
+            .api
+            .addr_canonicalize(deps.api.addr_validate(address)?.as_str())?;
+        if !holders.contains(&raw) {
+            holders.push(raw.clone());
+            update = true;
+        }
+        granted.push(raw);
+    }
+    // only save if the list changed
+    if update {
+        save_role(deps.storage, role, &holders)?;
+    }
+    // an explicit grant should outlive the address' minter role
+    if role != Role::Minter {
+        take_minter_grants(deps.storage, role, &granted)?;
+    }
+    Ok(())
+}
+
+/// Returns StdResult<()>
+///
+/// removes a list of addresses from a role's holders
+///
+/// # Arguments
+///
+/// * `deps` - mutable reference to Extern containing all the contract's external dependencies
+/// * `role` - the role to revoke
+/// * `addresses` - list of addresses losing the role
+fn revoke_role_impl(deps: DepsMut, role: Role, addresses: &[String]) -> StdResult<()> {
+    let mut holders = load_role(deps.storage, role)?;
+    let old_len = holders.len();
+    let no_raw: Vec<CanonicalAddr> = addresses
+        .iter()
+        .map(|x| {
+            deps.api
+                .addr_canonicalize(deps.api.addr_validate(x)?.as_str())
+        })
+        .collect::<StdResult<Vec<CanonicalAddr>>>()?;
+    holders.retain(|h| !no_raw.contains(h));
+    if holders.len() != old_len {
+        save_role(deps.storage, role, &holders)?;
+    }
+    if role == Role::Minter {
+        revoke_minter_grants(deps.storage, &no_raw)?;
+    } else {
+        take_minter_grants(deps.storage, role, &no_raw)?;
+    }
+    Ok(())
+}
+
+/// Returns StdResult<()>
+///
+/// revokes the metadata editor and royalty manager roles that the v1 to v2 migration granted
+/// to addresses because they were minters, once they are no longer minters
+///
+/// # Arguments
+///
+/// * `storage` - a mutable reference to the contract's storage
+/// * `former_minters` - the addresses that lost the minter role
+fn revoke_minter_grants(
+    storage: &mut dyn Storage,
+    former_minters: &[CanonicalAddr],
+) -> StdResult<()> {
+    if former_minters.is_empty() {
+        return Ok(());
+    }
+    for role in [Role::MetadataEditor, Role::RoyaltyManager] {
+        let taken = take_minter_grants(storage, role, former_minters)?;
+        if !taken.is_empty() {
+            let mut holders = load_role(storage, role)?;
+            holders.retain(|h| !taken.contains(h));
+            save_role(storage, role, &holders)?;
+        }
+    }
+    Ok(())
+}
+
+/// Returns StdResult<Response>
+///
+/// change the admin address
+///
+/// # Arguments
+///
+/// * `deps` - mutable reference to Extern containing all the contract's external dependencies
+/// * `sender` - a reference to the message sender address
+/// * `config` - a mutable reference to the Config
+/// * `priority` - u8 representation of highest ContractStatus level this action is permitted
+/// * `address` - new admin address
+pub fn change_admin(
+    deps: DepsMut,
+    sender: &Addr,
+    config: &mut Config,
+    priority: u8,
+    address: &str,
+) -> StdResult<Response> {
+    check_status(config.status, priority)?;
+    let sender_raw = deps.api.addr_canonicalize(sender.as_str())?;
+    if config.admin != sender_raw {
+        return Err(StdError::generic_err(
+            "This is an admin command and can only be run from the admin address",
+        ));
+    }
+    let new_admin = deps
+        .api
+        .addr_canonicalize(deps.api.addr_validate(address)?.as_str())?;
+    if new_admin != config.admin {
+        config.admin = new_admin;
+        save(deps.storage, CONFIG_KEY, &config)?;
+    }
+    // a direct change supersedes any pending proposal
+    remove(deps.storage, PENDING_ADMIN_KEY);
+    Ok(Response::new().set_data(to_binary(&ExecuteAnswer::ChangeAdmin { status: Success })?))
+}
+
+/// Returns StdResult<Response>
+///
+/// set or remove a minter's limits
+///
+/// # Arguments
+///
+/// * `deps` - mutable reference to Extern containing all the contract's external dependencies
+/// * `sender` - a reference to the message sender address
+/// * `config` - a reference to the Config
+/// * `priority` - u8 representation of highest ContractStatus level this action is permitted
+/// * `minter` - the minter whose limits are being set
+/// * `quota` - the optional new limits
+pub fn set_minter_quota(
+    deps: DepsMut,
+    sender: &Addr,
+    config: &Config,
+    priority: u8,
+    minter: &str,
+    quota: Option<MinterQuota>,
+) -> StdResult<Response> {
+    check_status(config.status, priority)?;
+    let sender_raw = deps.api.addr_canonicalize(sender.as_str())?;
+    if config.admin != sender_raw {
+        return Err(StdError::generic_err(
+            "This is an admin command and can only be run from the admin address",
+        ));
+    }
+    let minter_raw = deps
+        .api
+        .addr_canonicalize(deps.api.addr_validate(minter)?.as_str())?;
+    let mut quota_store = PrefixedStorage::new(deps.storage, PREFIX_MINTER_QUOTA);
+    if let Some(quota) = quota {
+        if quota.window.as_ref().map_or(false, |w| w.length.is_zero()) {
+            return Err(StdError::generic_err(
+                "A quota window's length can not be zero",
+            ));
+        }
+        json_save(&mut quota_store, minter_raw.as_slice(), &quota)?;
+    } else {
+        remove(&mut quota_store, minter_raw.as_slice());
+    }
+    // a new window definition starts counting from scratch
+    let mut usage_store = PrefixedStorage::new(deps.storage, PREFIX_MINTER_USAGE);
+    let may_usage: Option<MinterUsage> = may_load(&usage_store, minter_raw.as_slice())?;
+    if let Some(mut usage) = may_usage {
+        usage.window_cnt = 0;
+        save(&mut usage_store, minter_raw.as_slice(), &usage)?;
+    }
+    Ok(
+        Response::new().set_data(to_binary(&ExecuteAnswer::SetMinterQuota {
+            status: Success,
+        })?),
+    )
+}
+
+/// Returns StdResult<Response>
+///
+/// set or remove the max supply of the collection or of a mint run id
+///
+/// # Arguments
+///
+/// * `deps` - mutable reference to Extern containing all the contract's external dependencies
+/// * `sender` - a reference to the message sender address
+/// * `config` - a reference to the Config
+/// * `priority` - u8 representation of highest ContractStatus level this action is permitted
+/// * `mint_run_id` - optional mint run id whose cap is being set
+/// * `max_supply` - the optional new maximum
+pub fn set_max_supply(
+    deps: DepsMut,
+    sender: &Addr,
+    config: &Config,
+    priority: u8,
+    mint_run_id: Option<&str>,
+    max_supply: Option<u32>,
+) -> StdResult<Response> {
+    check_status(config.status, priority)?;
+    let sender_raw = deps.api.addr_canonicalize(sender.as_str())?;
+    if config.admin != sender_raw {
+        return Err(StdError::generic_err(
+            "This is an admin command and can only be run from the admin address",
+        ));
+    }
+    if let Some(id) = mint_run_id {
+        let supply_store = ReadonlyPrefixedStorage::new(deps.storage, PREFIX_MINT_RUN_SUPPLY);
+        let minted: u32 = may_load(&supply_store, id.as_bytes())?.unwrap_or(0);
+        let mut cap_store = PrefixedStorage::new(deps.storage, PREFIX_MINT_RUN_CAP);
+        if let Some(max) = max_supply {
+            if max < minted {
+                return Err(StdError::generic_err(format!(
+                    "Mint run ID {} has already minted {} tokens",
+                    id, minted
+                )));
+            }
+            save(&mut cap_store, id.as_bytes(), &max)?;
+        } else {
+            remove(&mut cap_store, id.as_bytes());
+        }
+    } else if let Some(max) = max_supply {
+        if max < config.mint_cnt {
+            return Err(StdError::generic_err(format!(
+                "The contract has already minted {} tokens",
+                config.mint_cnt
+            )));
+        }
+        save(deps.storage, MAX_SUPPLY_KEY, &max)?;
+    } else {
+        remove(deps.storage, MAX_SUPPLY_KEY);
+    }
+    Ok(Response::new().set_data(to_binary(&ExecuteAnswer::SetMaxSupply { status: Success })?))
+}
+
+/// Returns StdResult<Response>
+///
+/// set or remove the public sale
+///
+/// # Arguments
+///
+/// * `deps` - mutable reference to Extern containing all the contract's external dependencies
+/// * `sender` - a reference to the message sender address
+/// * `config` - a reference to the Config
+/// * `priority` - u8 representation of highest ContractStatus level this action is permitted
+/// * `sale` - the optional new sale
+pub fn set_sale(
+    deps: DepsMut,
+    sender: &Addr,
+    config: &Config,
+    priority: u8,
+    sale: Option<Sale>,
+) -> StdResult<Response> {
+    check_status(config.status, priority)?;
+    let sender_raw = deps.api.addr_canonicalize(sender.as_str())?;
+    if config.admin != sender_raw {
+        return Err(StdError::generic_err(
+            "This is an admin command and can only be run from the admin address",
+        ));
+    }
+    if let Some(sale) = sale {
+        if let (Some(start), Some(end)) = (sale.start, sale.end) {
+            if end <= start {
+                return Err(StdError::generic_err("The sale must end after it starts"));
+            }
+        }
+        if let SaleMetadata::Template {
+            public_metadata,
+            private_metadata,
+        } = &sale.metadata
+        {
+            for meta in [public_metadata, private_metadata].into_iter().flatten() {
+                enforce_metadata_field_exclusion(meta)?;
+            }
+        }
+        json_save(deps.storage, SALE_KEY, &sale)?;
+    } else {
+        remove(deps.storage, SALE_KEY);
+    }
+    Ok(Response::new().set_data(to_binary(&ExecuteAnswer::SetSale { status: Success })?))
+}
+
+/// Returns StdResult<Response>
+///
+/// add metadata to the end of the public sale's metadata pool
+///
+/// # Arguments
+///
+/// * `deps` - mutable reference to Extern containing all the contract's external dependencies
+/// * `sender` - a reference to the message sender address
+/// * `config` - a reference to the Config
+/// * `priority` - u8 representation of highest ContractStatus level this action is permitted
+/// * `metadata` - the metadata of the tokens to sell
+pub fn load_sale_pool(
+    deps: DepsMut,
+    sender: &Addr,
+    config: &Config,
+    priority: u8,
+    metadata: Vec<PoolEntry>,
+) -> StdResult<Response> {
+    check_status(config.status, priority)?;
+    let sender_raw = deps.api.addr_canonicalize(sender.as_str())?;
+    if config.admin != sender_raw {
+        return Err(StdError::generic_err(
+            "This is an admin command and can only be run from the admin address",
+        ));
+    }
+    let mut counts: SaleCounts = may_load(deps.storage, SALE_COUNTS_KEY)?.unwrap_or_default();
+    let mut pool_store = PrefixedStorage::new(deps.storage, PREFIX_SALE_POOL);
+    for entry in metadata.into_iter() {
+        for meta in [&entry.public_metadata, &entry.private_metadata]
+            .into_iter()
+            .flatten()
+        {
+            enforce_metadata_field_exclusion(meta)?;
+        }
+        save(&mut pool_store, &counts.pool_loaded.to_le_bytes(), &entry)?;
+        counts.pool_loaded = counts.pool_loaded.checked_add(1).ok_or_else(|| {
+            StdError::generic_err("Attempting to load more metadata than the implementation limit")
+        })?;
+    }
+    save(deps.storage, SALE_COUNTS_KEY, &counts)?;
+    Ok(Response::new().set_data(to_binary(&ExecuteAnswer::LoadSalePool { status: Success })?))
+}
+
+/// Returns StdResult<Response>
+///
+/// mints tokens bought from the public sale with native coins, and returns any overpayment
+///
+/// # Arguments
+///
+/// * `deps` - mutable reference to Extern containing all the contract's external dependencies
+/// * `env` - a reference to the Env of contract's environment
+/// * `sender` - a reference to the message sender address
+/// * `funds` - the coins sent with the message
+/// * `config` - a mutable reference to the Config
+/// * `priority` - u8 representation of highest ContractStatus level this action is permitted
+/// * `quantity` - optional number of tokens to buy
+/// * `memo` - optional memo for the mint txs
+#[allow(clippy::too_many_arguments)]
+pub fn public_mint(
+    deps: DepsMut,
+    env: &Env,
+    sender: &Addr,
+    funds: &[Coin],
+    config: &mut Config,
+    priority: u8,
+    quantity: Option<u32>,
+    memo: Option<String>,
+) -> StdResult<Response> {
+    check_status(config.status, priority)?;
+    let sale = load_open_sale(deps.storage, &env.block)?;
+    let price = sale
+        .price
+        .clone()
+        .ok_or_else(|| StdError::generic_err("The sale does not accept native coins"))?;
+    let quantity = quantity.unwrap_or(1);
+    let sender_raw = deps.api.addr_canonicalize(sender.as_str())?;
+    let refund = take_native_payment(deps.storage, sender, funds, &price, quantity)?;
+    let token_ids = sale_mint(deps, env, config, &sale, true, &sender_raw, quantity, memo)?;
+    let mut resp = Response::new();
+    if let Some(refund) = refund {
+        resp = resp.add_message(refund);
+    }
+    Ok(resp
+        .add_attributes(vec![attr("minted", format!("{:?}", &token_ids))])
+        .set_data(to_binary(&ExecuteAnswer::PublicMint { token_ids })?))
+}
+
+/// Returns StdResult<Response>
+///
+/// set or remove the price of a token in a SNIP-20, registering with the SNIP-20 the first
+/// time it is given a price
+///
+/// # Arguments
+///
+/// * `deps` - mutable reference to Extern containing all the contract's external dependencies
+/// * `env` - a reference to the Env of contract's environment
+/// * `sender` - a reference to the message sender address
+/// * `config` - a reference to the Config
+/// * `priority` - u8 representation of highest ContractStatus level this action is permitted
+/// * `contract_address` - address of the SNIP-20 contract
+/// * `code_hash` - code hash of the SNIP-20 contract
+/// * `price` - the optional new price of one token
+#[allow(clippy::too_many_arguments)]
+pub fn set_snip20_price(
+    deps: DepsMut,
+    env: &Env,
+    sender: &Addr,
+    config: &Config,
+    priority: u8,
+    contract_address: &str,
+    code_hash: String,
+    price: Option<Uint128>,
+) -> StdResult<Response> {
+    check_status(config.status, priority)?;
+    let sender_raw = deps.api.addr_canonicalize(sender.as_str())?;
+    if config.admin != sender_raw {
+        return Err(StdError::generic_err(
+            "This is an admin command and can only be run from the admin address",
+        ));
+    }
+    let contract = deps.api.addr_validate(contract_address)?;
+    let contract_raw = deps.api.addr_canonicalize(contract.as_str())?;
+    let mut payments: Vec<StoredSnip20Payment> =
+        may_load(deps.storage, SNIP20_PAYMENTS_KEY)?.unwrap_or_default();
+    let mut resp = Response::new();
+    if let Some(payment) = payments.iter_mut().find(|p| p.address == contract_raw) {
+        payment.code_hash = code_hash;
+        payment.price = price;
+    } else if price.is_some() {
+        resp = resp.add_message(register_receive_msg(
+            env.contract.code_hash.clone(),
+            None,
+            BLOCK_SIZE,
+            code_hash.clone(),
+            contract.to_string(),
+        )?);
+        payments.push(StoredSnip20Payment {
+            address: contract_raw,
+            code_hash,
+            price,
+            proceeds: Uint128::zero(),
+        });
+    }
+    save(deps.storage, SNIP20_PAYMENTS_KEY, &payments)?;
+    Ok(resp.set_data(to_binary(&ExecuteAnswer::SetSnip20Price {
+        status: Success,
+    })?))
+}
+
+/// Returns StdResult<Response>
+///
+/// handles a SNIP-20 payment by either minting the tokens bought from the public sale and
+/// returning any overpayment, or escrowing the payment as an offer on a token
+///
+/// # Arguments
+///
+/// * `deps` - mutable reference to Extern containing all the contract's external dependencies
+/// * `env` - a reference to the Env of contract's environment
+/// * `sender` - a reference to the message sender address, which is the SNIP-20 contract
+/// * `config` - a mutable reference to the Config
+/// * `priority` - u8 representation of highest ContractStatus level this action is permitted
+/// * `from` - the owner of the SNIP-20 tokens that were sent
+/// * `amount` - the amount of SNIP-20 tokens sent
+/// * `msg` - the optional ReceiveMsg
+#[allow(clippy::too_many_arguments)]
+pub fn receive_snip20(
+    deps: DepsMut,
+    env: &Env,
+    sender: &Addr,
+    config: &mut Config,
+    priority: u8,
+    from: &str,
+    amount: Uint128,
+    msg: Option<Binary>,
+) -> StdResult<Response> {
+    check_status(config.status, priority)?;
+    let sender_raw = deps.api.addr_canonicalize(sender.as_str())?;
+    let receive_msg: ReceiveMsg = msg
+        .map(|m| from_binary(&m))
+        .transpose()?
+        .ok_or_else(|| StdError::generic_err("A ReceiveMsg is required with the payment"))?;
+    let (quantity, memo) = match receive_msg {
+        ReceiveMsg::PublicMint { quantity, memo } => (quantity.unwrap_or(1), memo),
+        ReceiveMsg::MakeOffer { token_id, expires } => {
+            return make_offer(deps, env, sender, config, from, amount, &token_id, expires);
+        }
+    };
+    let mut payments: Vec<StoredSnip20Payment> =
+        may_load(deps.storage, SNIP20_PAYMENTS_KEY)?.unwrap_or_default();
+    let payment = payments
+        .iter_mut()
+        .find(|p| p.address == sender_raw && p.price.is_some())
+        .ok_or_else(|| {
+            StdError::generic_err(format!("The sale does not accept payment in {}", sender))
+        })?;
+    let sale = load_open_sale(deps.storage, &env.block)?;
+    let cost = payment
+        .price
+        .unwrap_or_default()
+        .checked_mul(Uint128::from(quantity))?;
+    if amount < cost {
+        return Err(StdError::generic_err(format!(
+            "Insufficient payment.  {} tokens cost {}",
+            quantity, cost
+        )));
+    }
+    payment.proceeds = payment.proceeds.checked_add(cost)?;
+    let code_hash = payment.code_hash.clone();
+    save(deps.storage, SNIP20_PAYMENTS_KEY, &payments)?;
+    let buyer = deps.api.addr_validate(from)?;
+    let buyer_raw = deps.api.addr_canonicalize(buyer.as_str())?;
+    let token_ids = sale_mint(deps, env, config, &sale, true, &buyer_raw, quantity, memo)?;
+    let mut resp = Response::new();
+    let change = amount - cost;
+    if !change.is_zero() {
+        resp = resp.add_message(transfer_msg(
+            buyer.to_string(),
+            change,
+            None,
+            None,
+            BLOCK_SIZE,
+            code_hash,
+            sender.to_string(),
+        )?);
+    }
+    Ok(resp
+        .add_attributes(vec![attr("minted", format!("{:?}", &token_ids))])
+        .set_data(to_binary(&ExecuteAnswer::PublicMint { token_ids })?))
+}
+
+/// Returns StdResult<Response>
+///
+/// replace the allowlist mint phases
+///
+/// # Arguments
+///
+/// * `deps` - mutable reference to Extern containing all the contract's external dependencies
+/// * `sender` - a reference to the message sender address
+/// * `config` - a reference to the Config
+/// * `priority` - u8 representation of highest ContractStatus level this action is permitted
+/// * `phases` - the new mint phases
+pub fn set_mint_phases(
+    deps: DepsMut,
+    sender: &Addr,
+    config: &Config,
+    priority: u8,
+    phases: Vec<MintPhase>,
+) -> StdResult<Response> {
+    check_status(config.status, priority)?;
+    let sender_raw = deps.api.addr_canonicalize(sender.as_str())?;
+    if config.admin != sender_raw {
+        return Err(StdError::generic_err(
+            "This is an admin command and can only be run from the admin address",
+        ));
+    }
+    let mut names: HashSet<&str> = HashSet::new();
+    for phase in phases.iter() {
+        if !names.insert(&phase.name) {
+            return Err(StdError::generic_err(format!(
+                "Mint phase name {} is used more than once",
+                phase.name
+            )));
+        }
+        if phase.merkle_root.len() != 32 {
+            return Err(StdError::generic_err(format!(
+                "The Merkle root of mint phase {} must be a 32 byte sha256 hash",
+                phase.name
+            )));
+        }
+        if let (Some(start), Some(end)) = (phase.start, phase.end) {
+            if end <= start {
+                return Err(StdError::generic_err(format!(
+                    "Mint phase {} must end after it starts",
+                    phase.name
+                )));
+            }
+        }
+    }
+    save(deps.storage, MINT_PHASES_KEY, &phases)?;
+    Ok(
+        Response::new().set_data(to_binary(&ExecuteAnswer::SetMintPhases {
+            status: Success,
+        })?),
+    )
+}
+
+/// Returns StdResult<Response>
+///
+/// mints tokens from the public sale's metadata for an address on a mint phase's allowlist,
+/// and returns any overpayment
+///
+/// # Arguments
+///
+/// * `deps` - mutable reference to Extern containing all the contract's external dependencies
+/// * `env` - a reference to the Env of contract's environment
+/// * `sender` - a reference to the message sender address
+/// * `funds` - the coins sent with the message
+/// * `config` - a mutable reference to the Config
+/// * `priority` - u8 representation of highest ContractStatus level this action is permitted
+/// * `phase_name` - name of the mint phase
+/// * `allocation` - number of tokens the sender was allocated in the phase
+/// * `proof` - the Merkle proof of the sender's allocation
+/// * `quantity` - optional number of tokens to mint
+/// * `memo` - optional memo for the mint txs
+#[allow(clippy::too_many_arguments)]
+pub fn allowlist_mint(
+    mut deps: DepsMut,
+    env: &Env,
+    sender: &Addr,
+    funds: &[Coin],
+    config: &mut Config,
+    priority: u8,
+    phase_name: &str,
+    allocation: u32,
+    proof: &[Binary],
+    quantity: Option<u32>,
+    memo: Option<String>,
+) -> StdResult<Response> {
+    check_status(config.status, priority)?;
+    let phases: Vec<MintPhase> = may_load(deps.storage, MINT_PHASES_KEY)?.unwrap_or_default();
+    let phase = phases
+        .into_iter()
+        .find(|p| p.name == phase_name)
+        .ok_or_else(|| {
+            StdError::generic_err(format!("There is no mint phase named {}", phase_name))
+        })?;
+    phase.check_open(&env.block)?;
+    phase.verify(sender.as_str(), allocation, proof)?;
+    let sale: Sale = json_may_load(deps.storage, SALE_KEY)?
+        .ok_or_else(|| StdError::generic_err("There is no public sale to mint from"))?;
+    let quantity = quantity.unwrap_or(1);
+    let sender_raw = deps.api.addr_canonicalize(sender.as_str())?;
+    let usage_key = [PREFIX_PHASE_USAGE, phase.name.as_bytes()];
+    let usage_store = ReadonlyPrefixedStorage::multilevel(deps.storage, &usage_key);
+    let minted: u32 = may_load(&usage_store, sender_raw.as_slice())?.unwrap_or(0);
+    if minted.saturating_add(quantity) > allocation {
+        return Err(StdError::generic_err(format!(
+            "Your allocation in mint phase {} is {}, and you have already minted {}",
+            phase.name, allocation, minted
+        )));
+    }
+    // a free phase has nowhere to record payment, so do not let funds be stranded
+    if phase.price.is_none() && funds.iter().any(|c| !c.amount.is_zero()) {
+        return Err(StdError::generic_err(format!(
+            "Mint phase {} is free, so no funds should be sent",
+            phase.name
+        )));
+    }
+    let refund = phase
+        .price
+        .as_ref()
+        .map(|price| take_native_payment(deps.storage, sender, funds, price, quantity))
+        .transpose()?
+        .flatten();
+    let token_ids = sale_mint(
+        deps.branch(),
+        env,
+        config,
+        &sale,
+        false,
+        &sender_raw,
+        quantity,
+        memo,
+    )?;
+    let mut usage_store = PrefixedStorage::multilevel(deps.storage, &usage_key);
+    save(
+        &mut usage_store,
+        sender_raw.as_slice(),
+        &(minted + quantity),
+    )?;
+    let mut resp = Response::new();
+    if let Some(refund) = refund {
+        resp = resp.add_message(refund);
+    }
+    Ok(resp
+        .add_attributes(vec![attr("minted", format!("{:?}", &token_ids))])
+        .set_data(to_binary(&ExecuteAnswer::AllowlistMint { token_ids })?))
+}
+
+/// Returns StdResult<Response>
+///
+/// set or remove the public key of the mint voucher signer
+///
+/// # Arguments
+///
+/// * `deps` - mutable reference to Extern containing all the contract's external dependencies
+/// * `sender` - a reference to the message sender address
+/// * `config` - a reference to the Config
+/// * `priority` - u8 representation of highest ContractStatus level this action is permitted
+/// * `public_key` - optional secp256k1 public key of the signer
+pub fn set_voucher_signer(
+    deps: DepsMut,
+    sender: &Addr,
+    config: &Config,
+    priority: u8,
+    public_key: Option<Binary>,
+) -> StdResult<Response> {
+    check_status(config.status, priority)?;
+    let sender_raw = deps.api.addr_canonicalize(sender.as_str())?;
+    if config.admin != sender_raw {
+        return Err(StdError::generic_err(
+            "This is an admin command and can only be run from the admin address",
+        ));
+    }
+    if let Some(key) = public_key {
+        if key.len() != 33 && key.len() != 65 {
+            return Err(StdError::generic_err(
+                "The voucher signer must be a 33 byte compressed or 65 byte uncompressed secp256k1 public key",
+            ));
+        }
+        save(deps.storage, VOUCHER_SIGNER_KEY, &key)?;
+    } else {
+        remove(deps.storage, VOUCHER_SIGNER_KEY);
+    }
+    Ok(
+        Response::new().set_data(to_binary(&ExecuteAnswer::SetVoucherSigner {
+            status: Success,
+        })?),
+    )
+}
+
+/// Returns StdResult<Response>
+///
+/// mints the token authorized by a mint voucher
+///
+/// # Arguments
+///
+/// * `deps` - mutable reference to Extern containing all the contract's external dependencies
+/// * `env` - a reference to the Env of contract's environment
+/// * `sender` - a reference to the message sender address
+/// * `config` - a mutable reference to the Config
+/// * `priority` - u8 representation of highest ContractStatus level this action is permitted
+/// * `voucher` - the mint voucher
+/// * `signature` - the signer's signature of the voucher
+/// * `metadata` - the token's metadata
+#[allow(clippy::too_many_arguments)]
+pub fn redeem_voucher(
+    mut deps: DepsMut,
+    env: &Env,
+    sender: &Addr,
+    config: &mut Config,
+    priority: u8,
+    voucher: MintVoucher,
+    signature: &Binary,
+    metadata: VoucherMetadata,
+) -> StdResult<Response> {
+    check_status(config.status, priority)?;
+    let signer: Binary = may_load(deps.storage, VOUCHER_SIGNER_KEY)?
+        .ok_or_else(|| StdError::generic_err("There is no voucher signer"))?;
+    if voucher.chain_id != env.block.chain_id || voucher.contract != env.contract.address {
+        return Err(StdError::generic_err(
+            "The voucher is not for this contract",
+        ));
+    }
+    if env.block.time.seconds() >= voucher.expiry {
+        return Err(StdError::generic_err("The voucher has expired"));
+    }
+    if metadata.hash()?.as_slice() != voucher.metadata_hash.as_slice() {
+        return Err(StdError::generic_err(
+            "The metadata does not match the voucher's metadata hash",
+        ));
+    }
+    let verified = deps
+        .api
+        .secp256k1_verify(
+            &voucher.message_hash()?,
+            signature.as_slice(),
+            signer.as_slice(),
+        )
+        .map_err(|e| StdError::generic_err(e.to_string()))?;
+    if !verified {
+        return Err(StdError::generic_err(
+            "The voucher was not signed by the voucher signer",
+        ));
+    }
+    let nonce_key = voucher.nonce.to_be_bytes();
+    let nonce_store = ReadonlyPrefixedStorage::new(deps.storage, PREFIX_VOUCHER_NONCES);
+    if nonce_store.get(&nonce_key).is_some() {
+        return Err(StdError::generic_err(format!(
+            "Voucher nonce {} has already been used",
+            voucher.nonce
+        )));
+    }
+    let sender_raw = deps.api.addr_canonicalize(sender.as_str())?;
+    let mints = vec![Mint {
+        token_id: Some(voucher.token_id),
+        owner: Some(voucher.owner),
+        public_metadata: metadata.public_metadata,
+        private_metadata: metadata.private_metadata,
+        serial_number: None,
+        royalty_info: None,
+        transferable: Some(true),
+        memo: None,
+    }];
+    let mut minted = mint_list(
+        deps.branch(),
+        env,
+        config,
+        &sender_raw,
+        mints,
+        None,
+        None,
+        false,
+    )?;
+    let mut nonce_store = PrefixedStorage::new(deps.storage, PREFIX_VOUCHER_NONCES);
+    nonce_store.set(&nonce_key, &[1]);
+    let token_id = minted.pop().unwrap_or_default();
+    Ok(Response::new()
+        .add_attribute("minted", &token_id)
+        .set_data(to_binary(&ExecuteAnswer::RedeemVoucher { token_id })?))
+}
+
+/// Returns StdResult<Response>
+///
+/// commit to the provenance hash of the reveal pool and the hash of its secret seed
+///
+/// # Arguments
+///
+/// * `deps` - mutable reference to Extern containing all the contract's external dependencies
+/// * `sender` - a reference to the message sender address
+/// * `config` - a reference to the Config
+/// * `priority` - u8 representation of highest ContractStatus level this action is permitted
+/// * `provenance_hash` - the provenance hash of the pool's items
+/// * `size` - number of items in the pool
+/// * `seed_hash` - sha256 hash of the secret seed of the random assignment of items
+pub fn commit_reveal_pool(
+    deps: DepsMut,
+    sender: &Addr,
+    config: &Config,
+    priority: u8,
+    provenance_hash: Binary,
+    size: u32,
+    seed_hash: Binary,
+) -> StdResult<Response> {
+    check_status(config.status, priority)?;
+    let sender_raw = deps.api.addr_canonicalize(sender.as_str())?;
+    if config.admin != sender_raw {
+        return Err(StdError::generic_err(
+            "This is an admin command and can only be run from the admin address",
+        ));
+    }
+    if json_may_load::<Expiration>(deps.storage, REVEAL_SCHEDULE_KEY)?.is_some() {
+        return Err(StdError::generic_err(
+            "A reveal pool can not be committed when a collection-wide reveal is scheduled",
+        ));
+    }
+    let may_pool: Option<RevealPool> = may_load(deps.storage, REVEAL_POOL_KEY)?;
+    if may_pool.map_or(false, |p| p.loaded > 0) {
+        return Err(StdError::generic_err(
+            "The reveal pool can not be changed once loading has started",
+        ));
+    }
+    if provenance_hash.len() != 32 {
+        return Err(StdError::generic_err(
+            "The provenance hash must be a 32 byte sha256 hash",
+        ));
+    }
+    if seed_hash.len() != 32 {
+        return Err(StdError::generic_err(
+            "The seed hash must be a 32 byte sha256 hash",
+        ));
+    }
+    if size == 0 {
+        return Err(StdError::generic_err(
+            "The reveal pool must have at least one item",
+        ));
+    }
+    let pool = RevealPool {
+        provenance_hash: provenance_hash.0,
+        size,
+        loaded: 0,
+        loaded_hash: Vec::new(),
+        remaining: 0,
+        seed_hash: seed_hash.0,
+    };
+    save(deps.storage, REVEAL_POOL_KEY, &pool)?;
+    Ok(
+        Response::new().set_data(to_binary(&ExecuteAnswer::CommitRevealPool {
+            status: Success,
+        })?),
+    )
+}
+
+/// Returns StdResult<Response>
+///
+/// reveal the secret seed of the reveal pool, which allows items to be assigned and ends
+/// minting
+///
+/// # Arguments
+///
+/// * `deps` - mutable reference to Extern containing all the contract's external dependencies
+/// * `sender` - a reference to the message sender address
+/// * `config` - a reference to the Config
+/// * `priority` - u8 representation of highest ContractStatus level this action is permitted
+/// * `seed` - the secret seed
+pub fn reveal_seed(
+    deps: DepsMut,
+    sender: &Addr,
+    config: &Config,
+    priority: u8,
+    seed: Binary,
+) -> StdResult<Response> {
+    check_status(config.status, priority)?;
+    let sender_raw = deps.api.addr_canonicalize(sender.as_str())?;
+    if config.admin != sender_raw {
+        return Err(StdError::generic_err(
+            "This is an admin command and can only be run from the admin address",
+        ));
+    }
+    let pool: RevealPool = may_load(deps.storage, REVEAL_POOL_KEY)?
+        .ok_or_else(|| StdError::generic_err("There is no committed reveal pool"))?;
+    if may_load::<Vec<u8>>(deps.storage, REVEAL_SEED_KEY)?.is_some() {
+        return Err(StdError::generic_err(
+            "The reveal pool's seed has already been revealed",
+        ));
+    }
+    if pool.loaded < pool.size {
+        return Err(StdError::generic_err(
+            "The reveal pool must be fully loaded before its seed is revealed",
+        ));
+    }
+    if sha_256(seed.as_slice()).as_slice() != pool.seed_hash.as_slice() {
+        return Err(StdError::generic_err(
+            "The seed does not match the committed seed hash",
+        ));
+    }
+    save(deps.storage, REVEAL_SEED_KEY, &seed.0)?;
+    Ok(Response::new().set_data(to_binary(&ExecuteAnswer::RevealSeed { status: Success })?))
+}
+
+/// Returns StdResult<Response>
+///
+/// add items to the end of the reveal pool
+///
+/// # Arguments
+///
+/// * `deps` - mutable reference to Extern containing all the contract's external dependencies
+/// * `sender` - a reference to the message sender address
+/// * `config` - a reference to the Config
+/// * `priority` - u8 representation of highest ContractStatus level this action is permitted
+/// * `metadata` - the items to add
+pub fn load_reveal_pool(
+    deps: DepsMut,
+    sender: &Addr,
+    config: &Config,
+    priority: u8,
+    metadata: Vec<PoolEntry>,
+) -> StdResult<Response> {
+    check_status(config.status, priority)?;
+    let sender_raw = deps.api.addr_canonicalize(sender.as_str())?;
+    if config.admin != sender_raw {
+        return Err(StdError::generic_err(
+            "This is an admin command and can only be run from the admin address",
+        ));
+    }
+    let mut pool: RevealPool = may_load(deps.storage, REVEAL_POOL_KEY)?
+        .ok_or_else(|| StdError::generic_err("There is no committed reveal pool"))?;
+    let room = pool.size - pool.loaded;
+    if metadata.len() > room as usize {
+        return Err(StdError::generic_err(format!(
+            "The reveal pool only has room for {} more items",
+            room
+        )));
+    }
+    for item in metadata.iter() {
+        pool.loaded_hash = chain_hash(&pool.loaded_hash, item)?.to_vec();
+    }
+    let count = metadata.len() as u32;
+    if pool.loaded + count == pool.size && pool.loaded_hash != pool.provenance_hash {
+        return Err(StdError::generic_err(
+            "The reveal pool does not match the committed provenance hash",
+        ));
+    }
+    let mut item_store = PrefixedStorage::new(deps.storage, PREFIX_REVEAL_POOL);
+    for item in metadata.iter() {
+        save(&mut item_store, &pool.loaded.to_le_bytes(), item)?;
+        pool.loaded += 1;
+    }
+    pool.remaining += count;
+    save(deps.storage, REVEAL_POOL_KEY, &pool)?;
+    Ok(
+        Response::new().set_data(to_binary(&ExecuteAnswer::LoadRevealPool {
+            status: Success,
+        })?),
+    )
+}
+
+/// Returns StdResult<Response>
+///
+/// mints prints of an edition, creating the edition if the token id does not exist yet
+///
+/// # Arguments
+///
+/// * `deps` - mutable reference to Extern containing all the contract's external dependencies
+/// * `env` - a reference to the Env of contract's environment
+/// * `sender` - a reference to the message sender address
+/// * `config` - a mutable reference to the Config
+/// * `priority` - u8 representation of highest ContractStatus level this action is permitted
+/// * `token_id` - optional token id of the edition
+/// * `owner` - optional address to receive the prints, if not specified, use the minter's address
+/// * `amount` - number of prints to mint
+/// * `public_metadata` - optional public metadata of a new edition
+/// * `private_metadata` - optional private metadata of a new edition
+/// * `royalty_info` - optional royalties information of a new edition
+/// * `memo` - optional memo for the mint tx
+#[allow(clippy::too_many_arguments)]
+pub fn mint_edition(
+    deps: DepsMut,
+    env: &Env,
+    sender: &Addr,
+    config: &mut Config,
+    priority: u8,
+    token_id: Option<String>,
+    owner: Option<String>,
+    amount: Uint128,
+    public_metadata: Option<Metadata>,
+    private_metadata: Option<Metadata>,
+    royalty_info: Option<RoyaltyInfo>,
+    memo: Option<String>,
+) -> StdResult<Response> {
+    check_status(config.status, priority)?;
+    let sender_raw = deps.api.addr_canonicalize(sender.as_str())?;
+    let minters = load_role(deps.storage, Role::Minter)?;
+    if !minters.contains(&sender_raw) {
+        return Err(StdError::generic_err(
+            "Only designated minters are allowed to mint",
+        ));
+    }
+    if amount.is_zero() {
+        return Err(StdError::generic_err("Amount can not be zero"));
+    }
+    let prints = u32::try_from(amount.u128()).map_err(|_| {
+        StdError::generic_err("Attempting to mint more prints than the implementation limit")
+    })?;
+    let map2idx = ReadonlyPrefixedStorage::new(deps.storage, PREFIX_MAP_TO_INDEX);
+    let may_idx: Option<u32> = token_id
+        .as_ref()
+        .map(|id| may_load(&map2idx, id.as_bytes()))
+        .transpose()?
+        .flatten();
+    let idx = if let Some(idx) = may_idx {
+        idx
+    } else {
+        // a new edition is minted like any other token, but every print counts towards the
+        // minter's quota
+        use_mint_quota(deps.storage, &env.block, &sender_raw, prints)?;
+        let mints = vec![Mint {
+            token_id,
+            owner,
+            public_metadata,
+            private_metadata,
+            serial_number: None,
+            royalty_info,
+            transferable: Some(true),
+            memo,
+        }];
+        let mut minted = mint_list(
+            deps,
+            env,
+            config,
+            &sender_raw,
+            mints,
+            None,
+            Some(amount.u128()),
+            false,
+        )?;
+        let minted_str = minted.pop().unwrap_or_default();
+        return Ok(Response::new()
+            .add_attributes(vec![attr("minted", &minted_str)])
+            .set_data(to_binary(&ExecuteAnswer::MintEdition {
+                token_id: minted_str,
+            })?));
+    };
+    // otherwise add prints to the existing edition
+    let id = token_id.unwrap_or_default();
+    let supply = edition_supply(deps.storage, idx)?
+        .ok_or_else(|| StdError::generic_err(format!("Token ID {} is already in use", id)))?;
+    let info_store = ReadonlyPrefixedStorage::new(deps.storage, PREFIX_INFOS);
+    let token: Token = json_load(&info_store, &idx.to_le_bytes())?;
+    if token.owner != sender_raw {
+        return Err(StdError::generic_err(format!(
+            "Only the issuer of edition {} may mint more of its prints",
+            id
+        )));
+    }
+    if public_metadata.is_some() || private_metadata.is_some() || royalty_info.is_some() {
+        return Err(StdError::generic_err(format!(
+            "Metadata and royalties can only be set when edition {} is created",
+            id
+        )));
+    }
+    use_mint_quota(deps.storage, &env.block, &sender_raw, prints)?;
+    let recipient = if let Some(o) = owner {
+        deps.api
+            .addr_canonicalize(deps.api.addr_validate(&o)?.as_str())?
+    } else {
+        sender_raw.clone()
+    };
+    let new_supply = supply
+        .checked_add(amount.u128())
+        .ok_or_else(|| StdError::generic_err("Minting would overflow the edition's supply"))?;
+    let balance = edition_balance(deps.storage, idx, &recipient)?;
+    if balance == 0 {
+        let mut inventory = Inventory::new(deps.storage, recipient.clone())?;
+        inventory.insert(deps.storage, idx, true)?;
+    }
+    save_edition_supply(deps.storage, idx, new_supply)?;
+    save_edition_balance(deps.storage, idx, &recipient, balance + amount.u128())?;
+    store_edition_mint(
+        deps.storage,
+        config,
+        &env.block,
+        id.clone(),
+        sender_raw,
+        recipient,
+        amount.u128(),
+        memo,
+    )?;
+    save(deps.storage, CONFIG_KEY, &config)?;
+    Ok(Response::new()
+        .add_attributes(vec![attr("minted", &id)])
+        .set_data(to_binary(&ExecuteAnswer::MintEdition { token_id: id })?))
+}
+
+/// Returns StdResult<Response>
+///
+/// transfers some of the sender's prints of an edition
+///
+/// # Arguments
+///
+/// * `deps` - mutable reference to Extern containing all the contract's external dependencies
+/// * `env` - a reference to the Env of contract's environment
+/// * `sender` - a reference to the message sender address
+/// * `config` - a mutable reference to the Config
+/// * `priority` - u8 representation of highest ContractStatus level this action is permitted
+/// * `recipient` - the address receiving the prints
+/// * `token_id` - token id of the edition
+/// * `amount` - number of prints to transfer
+/// * `memo` - optional memo for the transfer tx
+#[allow(clippy::too_many_arguments)]
+pub fn transfer_edition(
+    deps: DepsMut,
+    env: &Env,
+    sender: &Addr,
+    config: &mut Config,
+    priority: u8,
+    recipient: &str,
+    token_id: String,
+    amount: Uint128,
+    memo: Option<String>,
+) -> StdResult<Response> {
+    check_status(config.status, priority)?;
+    let sender_raw = deps.api.addr_canonicalize(sender.as_str())?;
+    let recipient_raw = deps
+        .api
+        .addr_canonicalize(deps.api.addr_validate(recipient)?.as_str())?;
+    if amount.is_zero() {
+        return Err(StdError::generic_err("Amount can not be zero"));
+    }
+    let custom_err = format!(
+        "You are not authorized to perform this action on token {}",
+        token_id
+    );
+    // if token supply is private, don't leak that the token id does not exist
+    // instead just say they are not authorized for that token
+    let opt_err = if config.token_supply_is_public {
+        None
+    } else {
+        Some(&*custom_err)
+    };
+    let (_, idx) = get_token(deps.storage, &token_id, opt_err)?;
+    if edition_supply(deps.storage, idx)?.is_none() {
+        return Err(StdError::generic_err(format!(
+            "Token ID: {} is not an edition",
+            token_id
+        )));
+    }
+    if recipient_raw == sender_raw {
+        return Err(StdError::generic_err(format!(
+            "Attempting to transfer prints of token ID: {} to the address that already holds them",
+            token_id
+        )));
+    }
+    let balance = edition_balance(deps.storage, idx, &sender_raw)?;
+    if balance < amount.u128() {
+        return Err(StdError::generic_err(format!(
+            "Your balance of token ID: {} is {}, which is less than {}",
+            token_id, balance, amount
+        )));
+    }
+    let rcpt_balance = edition_balance(deps.storage, idx, &recipient_raw)?;
+    // update the inventories of holders that gained or lost the edition
+    if balance == amount.u128() {
+        let mut inventory = Inventory::new(deps.storage, sender_raw.clone())?;
+        inventory.remove(deps.storage, idx, true)?;
+    }
+    if rcpt_balance == 0 {
+        let mut inventory = Inventory::new(deps.storage, recipient_raw.clone())?;
+        inventory.insert(deps.storage, idx, true)?;
+    }
+    save_edition_balance(deps.storage, idx, &sender_raw, balance - amount.u128())?;
+    save_edition_balance(
+        deps.storage,
+        idx,
+        &recipient_raw,
+        rcpt_balance + amount.u128(),
+    )?;
+    store_edition_transfer(
+        deps.storage,
+        config,
+        &env.block,
+        token_id,
+        sender_raw,
+        recipient_raw,
+        amount.u128(),
+        memo,
+    )?;
+    save(deps.storage, CONFIG_KEY, &config)?;
+    Ok(
+        Response::new().set_data(to_binary(&ExecuteAnswer::TransferEdition {
+            status: Success,
+        })?),
+    )
+}
+
+/// Returns StdResult<Response>
+///
+/// schedule when every sealed token will be unwrapped
+///
+/// # Arguments
+///
+/// * `deps` - mutable reference to Extern containing all the contract's external dependencies
+/// * `env` - a reference to the Env of contract's environment
+/// * `sender` - a reference to the message sender address
+/// * `config` - a reference to the Config
+/// * `priority` - u8 representation of highest ContractStatus level this action is permitted
+/// * `at` - optional time or block height of the reveal
+pub fn schedule_reveal(
+    deps: DepsMut,
+    env: &Env,
+    sender: &Addr,
+    config: &Config,
+    priority: u8,
+    at: Option<Expiration>,
+) -> StdResult<Response> {
+    check_status(config.status, priority)?;
+    let sender_raw = deps.api.addr_canonicalize(sender.as_str())?;
+    if config.admin != sender_raw {
+        return Err(StdError::generic_err(
+            "This is an admin command and can only be run from the admin address",
+        ));
+    }
+    if !config.sealed_metadata_is_enabled {
+        return Err(StdError::generic_err(
+            "Sealed metadata functionality is not enabled for this contract",
+        ));
+    }
+    if may_load::<RevealPool>(deps.storage, REVEAL_POOL_KEY)?.is_some() {
+        return Err(StdError::generic_err(
+            "A collection-wide reveal can not be scheduled when there is a reveal pool",
+        ));
+    }
+    if scheduled_reveal_passed(deps.storage, &env.block)? {
+        return Err(StdError::generic_err(
+            "The scheduled reveal has already happened",
+        ));
+    }
+    if let Some(exp) = at {
+        json_save(deps.storage, REVEAL_SCHEDULE_KEY, &exp)?;
+    } else {
+        remove(deps.storage, REVEAL_SCHEDULE_KEY);
+    }
+    Ok(
+        Response::new().set_data(to_binary(&ExecuteAnswer::ScheduleReveal {
+            status: Success,
+        })?),
+    )
+}
+
+/// Returns StdResult<Response>
+///
+/// set or remove the scheme used to generate token ids
+///
+/// # Arguments
+///
+/// * `deps` - mutable reference to Extern containing all the contract's external dependencies
+/// * `sender` - a reference to the message sender address
+/// * `config` - a reference to the Config
+/// * `priority` - u8 representation of highest ContractStatus level this action is permitted
+/// * `scheme` - the optional new token id scheme
+pub fn set_token_id_scheme(
+    deps: DepsMut,
+    sender: &Addr,
+    config: &Config,
+    priority: u8,
+    scheme: Option<TokenIdScheme>,
+) -> StdResult<Response> {
+    check_status(config.status, priority)?;
+    let sender_raw = deps.api.addr_canonicalize(sender.as_str())?;
+    if config.admin != sender_raw {
+        return Err(StdError::generic_err(
+            "This is an admin command and can only be run from the admin address",
+        ));
+    }
+    if let Some(scheme) = scheme {
+        json_save(deps.storage, TOKEN_ID_SCHEME_KEY, &scheme)?;
+    } else {
+        remove(deps.storage, TOKEN_ID_SCHEME_KEY);
+    }
+    Ok(
+        Response::new().set_data(to_binary(&ExecuteAnswer::SetTokenIdScheme {
+            status: Success,
+        })?),
+    )
+}
+
+/// Returns StdResult<Response>
+///
+/// replace the burn-to-redeem recipes
+///
+/// # Arguments
+///
+/// * `deps` - mutable reference to Extern containing all the contract's external dependencies
+/// * `sender` - a reference to the message sender address
+/// * `config` - a reference to the Config
+/// * `priority` - u8 representation of highest ContractStatus level this action is permitted
+/// * `recipes` - the new recipes
+pub fn set_recipes(
+    deps: DepsMut,
+    sender: &Addr,
+    config: &Config,
+    priority: u8,
+    recipes: Vec<Recipe>,
+) -> StdResult<Response> {
+    check_status(config.status, priority)?;
+    let sender_raw = deps.api.addr_canonicalize(sender.as_str())?;
+    if config.admin != sender_raw {
+        return Err(StdError::generic_err(
+            "This is an admin command and can only be run from the admin address",
+        ));
+    }
+    let mut names: HashSet<&str> = HashSet::new();
+    for recipe in recipes.iter() {
+        if !names.insert(&recipe.name) {
+            return Err(StdError::generic_err(format!(
+                "Recipe name {} is used more than once",
+                recipe.name
+            )));
+        }
+        if recipe.inputs.is_empty() {
+            return Err(StdError::generic_err(format!(
+                "Recipe {} must have at least one input",
+                recipe.name
+            )));
+        }
+    }
+    json_save(deps.storage, RECIPES_KEY, &recipes)?;
+    Ok(Response::new().set_data(to_binary(&ExecuteAnswer::SetRecipes { status: Success })?))
+}
+
+/// Returns StdResult<Response>
+///
+/// burns tokens the sender owns that satisfy a recipe's inputs and mints the recipe's output
+/// to the sender
+///
+/// # Arguments
+///
+/// * `deps` - mutable reference to Extern containing all the contract's external dependencies
+/// * `env` - a reference to the Env of contract's environment
+/// * `sender` - a reference to the message sender address
+/// * `config` - a mutable reference to the Config
+/// * `priority` - u8 representation of highest ContractStatus level this action is permitted
+/// * `recipe_name` - name of the recipe
+/// * `token_ids` - the tokens to burn, in the order of the recipe's inputs
+/// * `memo` - optional memo for the burn and mint txs
+#[allow(clippy::too_many_arguments)]
+pub fn redeem(
+    mut deps: DepsMut,
+    env: &Env,
+    sender: &Addr,
+    config: &mut Config,
+    priority: u8,
+    recipe_name: &str,
+    token_ids: Vec<String>,
+    memo: Option<String>,
+) -> StdResult<Response> {
+    check_status(config.status, priority)?;
+    let recipes: Vec<Recipe> = json_may_load(deps.storage, RECIPES_KEY)?.unwrap_or_default();
+    let recipe = recipes
+        .into_iter()
+        .find(|r| r.name == recipe_name)
+        .ok_or_else(|| {
+            StdError::generic_err(format!("There is no recipe named {}", recipe_name))
+        })?;
+    if token_ids.len() != recipe.inputs.len() {
+        return Err(StdError::generic_err(format!(
+            "Recipe {} requires {} tokens",
+            recipe.name,
+            recipe.inputs.len()
+        )));
+    }
+    let sender_raw = deps.api.addr_canonicalize(sender.as_str())?;
+    let reveal_passed = scheduled_reveal_passed(deps.storage, &env.block)?;
+    for (i, (token_id, input)) in token_ids.iter().zip(recipe.inputs.iter()).enumerate() {
+        if token_ids[..i].contains(token_id) {
+            return Err(StdError::generic_err(format!(
+                "Token ID: {} is listed more than once",
+                token_id
+            )));
+        }
+        // do not reveal whether a token the sender does not own exists
+        let not_owned = format!("You do not own token {}", token_id);
+        let (mut token, idx) = get_token(deps.storage, token_id, Some(&not_owned))?;
+        if token.owner != sender_raw {
+            return Err(StdError::generic_err(not_owned));
+        }
+        // traits are matched against the public metadata as it is currently displayed
+        let (public, _) =
+            load_revealed_metadata(deps.storage, config, reveal_passed, &mut token, idx)?;
+        if !input.is_satisfied_by(deps.storage, token_id, idx, public.as_ref())? {
+            return Err(StdError::generic_err(format!(
+                "Token ID: {} does not satisfy input {} of recipe {}",
+                token_id,
+                i + 1,
+                recipe.name
+            )));
+        }
+    }
+    let burns = vec![Burn {
+        token_ids,
+        memo: memo.clone(),
+    }];
+    let refunds = burn_list(deps.branch(), &env.block, config, &sender_raw, burns, true)?;
+    let mints = vec![Mint {
+        token_id: None,
+        owner: None,
+        public_metadata: recipe.public_metadata,
+        private_metadata: recipe.private_metadata,
+        serial_number: None,
+        royalty_info: recipe.royalty_info,
+        transferable: Some(true),
+        memo,
+    }];
+    let mut minted = mint_list(deps, env, config, &sender_raw, mints, None, None, false)?;
+    let minted_str = minted.pop().unwrap_or_default();
+    Ok(Response::new()
+        .add_messages(refunds)
+        .add_attributes(vec![attr("minted", &minted_str)])
+        .set_data(to_binary(&ExecuteAnswer::Redeem {
+            token_id: minted_str,
+        })?))
+}
+
+/// Returns StdResult<Response>
+///
+/// marks a non-transferable token as revoked by the minter that issued it
+///
+/// # Arguments
+///
+/// * `deps` - mutable reference to Extern containing all the contract's external dependencies
+/// * `env` - a reference to the Env of contract's environment
+/// * `sender` - a reference to the message sender address
+/// * `config` - a mutable reference to the Config
+/// * `priority` - u8 representation of highest ContractStatus level this action is permitted
+/// * `token_id` - id of the token to revoke
+/// * `reason` - why the token is revoked
+pub fn revoke_badge(
+    deps: DepsMut,
+    env: &Env,
+    sender: &Addr,
+    config: &mut Config,
+    priority: u8,
+    token_id: String,
+    reason: String,
+) -> StdResult<Response> {
+    check_status(config.status, priority)?;
+    let sender_raw = deps.api.addr_canonicalize(sender.as_str())?;
+    let minters = load_role(deps.storage, Role::Minter)?;
+    if !minters.contains(&sender_raw) {
+        return Err(StdError::generic_err(
+            "Only designated minters are allowed to revoke badges",
+        ));
+    }
+    let (token, idx) = get_token(deps.storage, &token_id, None)?;
+    let token_key = idx.to_le_bytes();
+    let run_store = ReadonlyPrefixedStorage::new(deps.storage, PREFIX_MINT_RUN);
+    let mint_info: StoredMintRunInfo = load(&run_store, &token_key)?;
+    if mint_info.token_creator != sender_raw {
+        return Err(StdError::generic_err(format!(
+            "Only the minter that issued token {} may revoke it",
+            token_id
+        )));
+    }
+    if token.transferable {
+        return Err(StdError::generic_err(format!(
+            "Token ID: {} is transferable and can not be revoked",
+            token_id
+        )));
+    }
+    if revocation(deps.storage, deps.api, idx)?.is_some() {
+        return Err(StdError::generic_err(format!(
+            "Token ID: {} has already been revoked",
+            token_id
+        )));
+    }
+    let mut revoke_store = PrefixedStorage::new(deps.storage, PREFIX_REVOKED_BADGES);
+    save(
+        &mut revoke_store,
+        &token_key,
+        &StoredRevocation {
+            issuer: sender_raw.clone(),
+            time: env.block.time.seconds(),
+            reason: reason.clone(),
+        },
+    )?;
+    store_badge_revocation(
+        deps.storage,
+        config,
+        &env.block,
+        token_id,
+        sender_raw,
+        token.owner,
+        reason,
+    )?;
+    save(deps.storage, CONFIG_KEY, &config)?;
+    Ok(Response::new().set_data(to_binary(&ExecuteAnswer::RevokeBadge { status: Success })?))
+}
+
+/// Returns StdResult<Response>
+///
+/// grants/revokes an address' approval to lock the sender's tokens
+///
+/// # Arguments
+///
+/// * `deps` - mutable reference to Extern containing all the contract's external dependencies
+/// * `sender` - a reference to the message sender address
+/// * `config` - a reference to the Config
+/// * `priority` - u8 representation of highest ContractStatus level this action is permitted
+/// * `locker` - string slice of the address being approved or revoked
+/// * `expires` - optional Expiration for the approval
+/// * `is_approve` - true if this is an ApproveLocker call
+pub fn approve_revoke_locker(
+    deps: DepsMut,
+    sender: &Addr,
+    config: &Config,
+    priority: u8,
+    locker: &str,
+    expires: Option<Expiration>,
+    is_approve: bool,
+) -> StdResult<Response> {
+    check_status(config.status, priority)?;
+    let sender_raw = deps.api.addr_canonicalize(sender.as_str())?;
+    let locker_raw = deps
+        .api
+        .addr_canonicalize(deps.api.addr_validate(locker)?.as_str())?;
+    let mut approval_store = PrefixedStorage::multilevel(
+        deps.storage,
+        &[PREFIX_LOCKER_APPROVALS, sender_raw.as_slice()],
+    );
+    let answer = if is_approve {
+        json_save(
+            &mut approval_store,
+            locker_raw.as_slice(),
+            &expires.unwrap_or_default(),
+        )?;
+        ExecuteAnswer::ApproveLocker { status: Success }
+    } else {
+        remove(&mut approval_store, locker_raw.as_slice());
+        ExecuteAnswer::RevokeLocker { status: Success }
+    };
+    Ok(Response::new().set_data(to_binary(&answer)?))
+}
+
+/// Returns StdResult<Response>
+///
+/// locks a token so it can not be transferred, sent, or burned
+///
+/// # Arguments
+///
+/// * `deps` - mutable reference to Extern containing all the contract's external dependencies
+/// * `env` - a reference to the Env of contract's environment
+/// * `sender` - a reference to the message sender address
+/// * `config` - a reference to the Config
+/// * `priority` - u8 representation of highest ContractStatus level this action is permitted
+/// * `token_id` - string slice of the token id to lock
+/// * `expires` - Expiration of the lock
+pub fn lock_token(
+    deps: DepsMut,
+    env: &Env,
+    sender: &Addr,
+    config: &Config,
+    priority: u8,
+    token_id: &str,
+    expires: Expiration,
+) -> StdResult<Response> {
+    check_status(config.status, priority)?;
+    let sender_raw = deps.api.addr_canonicalize(sender.as_str())?;
+    let custom_err = format!("You are not authorized to lock token {}", token_id);
+    // if token supply is private, don't leak that the token id does not exist
+    // instead just say they are not authorized to lock it
+    let opt_err = if config.token_supply_is_public {
+        None
+    } else {
+        Some(&*custom_err)
+    };
+    let (token, idx) = get_token(deps.storage, token_id, opt_err)?;
+    if token.owner != sender_raw
+        && !is_approved_locker(deps.storage, &env.block, &token.owner, &sender_raw)?
+    {
+        return Err(StdError::generic_err(custom_err));
+    }
+    if expires.is_expired(&env.block) {
+        return Err(StdError::generic_err(
+            "The lock expiration has already passed",
+        ));
+    }
+    if active_lock(deps.storage, &env.block, idx)?.is_some() {
+        return Err(StdError::generic_err(format!(
+            "Token ID: {} is already locked",
+            token_id
+        )));
+    }
+    check_not_auctioned(deps.storage, token_id, idx)?;
+    let mut lock_store = PrefixedStorage::new(deps.storage, PREFIX_LOCKS);
+    json_save(
+        &mut lock_store,
+        &idx.to_le_bytes(),
+        &StoredLock {
+            locker: sender_raw,
+            expires,
+        },
+    )?;
+    Ok(Response::new().set_data(to_binary(&ExecuteAnswer::Lock { status: Success })?))
+}
+
+/// Returns StdResult<Response>
+///
+/// removes the lock of a token.  Only the address that locked the token may unlock it
+///
+/// # Arguments
+///
+/// * `deps` - mutable reference to Extern containing all the contract's external dependencies
+/// * `env` - a reference to the Env of contract's environment
+/// * `sender` - a reference to the message sender address
+/// * `config` - a reference to the Config
+/// * `priority` - u8 representation of highest ContractStatus level this action is permitted
+/// * `token_id` - string slice of the token id to unlock
+pub fn unlock_token(
+    deps: DepsMut,
+    env: &Env,
+    sender: &Addr,
+    config: &Config,
+    priority: u8,
+    token_id: &str,
+) -> StdResult<Response> {
+    check_status(config.status, priority)?;
+    let sender_raw = deps.api.addr_canonicalize(sender.as_str())?;
+    let not_locked = format!("Token ID: {} is not locked", token_id);
+    let (_, idx) = get_token(deps.storage, token_id, Some(&not_locked))?;
+    let lock = active_lock(deps.storage, &env.block, idx)?
+        .ok_or_else(|| StdError::generic_err(not_locked))?;
+    if lock.locker != sender_raw {
+        return Err(StdError::generic_err(format!(
+            "Only the address that locked token {} may unlock it",
+            token_id
+        )));
+    }
+    let mut lock_store = PrefixedStorage::new(deps.storage, PREFIX_LOCKS);
+    remove(&mut lock_store, &idx.to_le_bytes());
+    Ok(Response::new().set_data(to_binary(&ExecuteAnswer::Unlock { status: Success })?))
+}
+
+/// Returns StdResult<Response>
+///
+/// sets or removes the address renting a token
 ///
 /// # Arguments
 ///
 /// * `deps` - mutable reference to Extern containing all the contract's external dependencies
+/// * `env` - a reference to the Env of contract's environment
 /// * `sender` - a reference to the message sender address
 /// * `config` - a reference to the Config
-/// * `priority` - u8 representation of highest status level this action is permitted at
-/// * `token_id` - optional token id String slice of token whose royalty info should be updated
-/// * `royalty_info` - a optional reference to the new RoyaltyInfo
-pub fn set_royalty_info(
+/// * `priority` - u8 representation of highest ContractStatus level this action is permitted
+/// * `token_id` - string slice of the token id being rented
+/// * `user` - optional address renting the token.  None removes the current user
+/// * `expires` - optional Expiration of the rental
+#[allow(clippy::too_many_arguments)]
+pub fn set_user(
     deps: DepsMut,
+    env: &Env,
     sender: &Addr,
     config: &Config,
     priority: u8,
-    token_id: Option<&str>,
-    royalty_info: Option<&RoyaltyInfo>,
+    token_id: &str,
+    user: Option<&str>,
+    expires: Option<Expiration>,
 ) -> StdResult<Response> {
     check_status(config.status, priority)?;
     let sender_raw = deps.api.addr_canonicalize(sender.as_str())?;
-    // set a token's royalties
-    if let Some(id) = token_id {
-        let custom_err = "A token's RoyaltyInfo may only be set by the token creator when they are also the token owner";
-        // if token supply is private, don't leak that the token id does not exist
-        // instead just say they are not authorized for that token
-        let opt_err = if config.token_supply_is_public {
-            None
-        } else {
-            Some(custom_err)
-        };
-        let (token, idx) = get_token(deps.storage, id, opt_err)?;
-        if !token.transferable {
+    let mut oper_for: Vec<CanonicalAddr> = Vec::new();
+    // only the owner and addresses with transfer approval may rent out the token
+    get_token_if_permitted(
+        deps.as_ref(),
+        &env.block,
+        token_id,
+        Some(&sender_raw),
+        PermissionType::Transfer,
+        &mut oper_for,
+        config,
+    )?;
+    if let Some(usr) = user {
+        let user_raw = deps
+            .api
+            .addr_canonicalize(deps.api.addr_validate(usr)?.as_str())?;
+        let expires = expires.unwrap_or_default();
+        if expires.is_expired(&env.block) {
             return Err(StdError::generic_err(
-                "Non-transferable tokens can not be sold, so royalties are meaningless",
+                "The rental expiration has already passed",
             ));
         }
-        let token_key = idx.to_le_bytes();
-        let run_store = ReadonlyPrefixedStorage::new(deps.storage, PREFIX_MINT_RUN);
-        let mint_run: StoredMintRunInfo = load(&run_store, &token_key)?;
-        if sender_raw != mint_run.token_creator || sender_raw != token.owner {
-            return Err(StdError::generic_err(custom_err));
-        }
-        let default_roy = royalty_info.as_ref().map_or_else(
-            || may_load::<StoredRoyaltyInfo>(deps.storage, DEFAULT_ROYALTY_KEY),
-            |_r| Ok(None),
-        )?;
-        let mut roy_store = PrefixedStorage::new(deps.storage, PREFIX_ROYALTY_INFO);
-        store_royalties(
-            &mut roy_store,
-            deps.api,
-            royalty_info,
-            default_roy.as_ref(),
-            &token_key,
+        let mut user_store = PrefixedStorage::new(deps.storage, PREFIX_TOKEN_USERS);
+        json_save(
+            &mut user_store,
+            token_id.as_bytes(),
+            &StoredTokenUser {
+                user: user_raw,
+                expires,
+            },
         )?;
-        // set default royalty
     } else {
-        let minters: Vec<CanonicalAddr> = may_load(deps.storage, MINTERS_KEY)?.unwrap_or_default();
-        if !minters.contains(&sender_raw) {
-            return Err(StdError::generic_err(
-                "Only designated minters can set default royalties for the contract",
-            ));
-        }
-        store_royalties(
-            deps.storage,
-            deps.api,
-            royalty_info,
-            None,
-            DEFAULT_ROYALTY_KEY,
-        )?;
+        remove_user(deps.storage, token_id);
+    }
+    Ok(Response::new().set_data(to_binary(&ExecuteAnswer::SetUser { status: Success })?))
+}
+
+/// Returns StdResult<Response>
+///
+/// lists a token for sale at a fixed price
+///
+/// # Arguments
+///
+/// * `deps` - mutable reference to Extern containing all the contract's external dependencies
+/// * `env` - a reference to the Env of contract's environment
+/// * `sender` - a reference to the message sender address
+/// * `config` - a reference to the Config
+/// * `priority` - u8 representation of highest ContractStatus level this action is permitted
+/// * `token_id` - string slice of the token id to list
+/// * `price` - price of the token
+pub fn list(
+    deps: DepsMut,
+    env: &Env,
+    sender: &Addr,
+    config: &Config,
+    priority: u8,
+    token_id: &str,
+    price: Coin,
+) -> StdResult<Response> {
+    check_status(config.status, priority)?;
+    let sender_raw = deps.api.addr_canonicalize(sender.as_str())?;
+    let custom_err = format!("You are not authorized to list token {}", token_id);
+    // if token supply is private, don't leak that the token id does not exist
+    // instead just say they are not authorized to list it
+    let opt_err = if config.token_supply_is_public {
+        None
+    } else {
+        Some(&*custom_err)
     };
+    let (token, idx) = get_token(deps.storage, token_id, opt_err)?;
+    if token.owner != sender_raw {
+        return Err(StdError::generic_err(custom_err));
+    }
+    if !token.transferable {
+        return Err(StdError::generic_err(format!(
+            "Token ID: {} is non-transferable",
+            token_id
+        )));
+    }
+    if edition_supply(deps.storage, idx)?.is_some() {
+        return Err(StdError::generic_err(format!(
+            "Token ID: {} is an edition and can not be listed",
+            token_id
+        )));
+    }
+    check_unlocked(deps.storage, &env.block, token_id, idx)?;
+    check_not_auctioned(deps.storage, token_id, idx)?;
+    if price.amount.is_zero() {
+        return Err(StdError::generic_err(
+            "The listing price must be greater than zero",
+        ));
+    }
+    let mut list_store = PrefixedStorage::new(deps.storage, PREFIX_LISTINGS);
+    save(
+        &mut list_store,
+        &idx.to_le_bytes(),
+        &StoredListing {
+            seller: sender_raw,
+            price,
+        },
+    )?;
+    Ok(Response::new().set_data(to_binary(&ExecuteAnswer::List { status: Success })?))
+}
+
+/// Returns StdResult<Response>
+///
+/// ends the listing of a token
+///
+/// # Arguments
+///
+/// * `deps` - mutable reference to Extern containing all the contract's external dependencies
+/// * `sender` - a reference to the message sender address
+/// * `config` - a reference to the Config
+/// * `priority` - u8 representation of highest ContractStatus level this action is permitted
+/// * `token_id` - string slice of the token id to delist
+pub fn delist(
+    deps: DepsMut,
+    sender: &Addr,
+    config: &Config,
+    priority: u8,
+    token_id: &str,
+) -> StdResult<Response> {
+    check_status(config.status, priority)?;
+    let sender_raw = deps.api.addr_canonicalize(sender.as_str())?;
+    let not_listed = format!("Token ID: {} is not listed", token_id);
+    let (_, idx) = get_token(deps.storage, token_id, Some(&not_listed))?;
+    let mut list_store = PrefixedStorage::new(deps.storage, PREFIX_LISTINGS);
+    let listing: StoredListing = may_load(&list_store, &idx.to_le_bytes())?
+        .ok_or_else(|| StdError::generic_err(not_listed))?;
+    if listing.seller != sender_raw {
+        return Err(StdError::generic_err(format!(
+            "Only the seller may delist token {}",
+            token_id
+        )));
+    }
+    remove(&mut list_store, &idx.to_le_bytes());
+    Ok(Response::new().set_data(to_binary(&ExecuteAnswer::Delist { status: Success })?))
+}
+
+/// Returns StdResult<Response>
+///
+/// buys a listed token, paying the royalty recipients and the seller
+///
+/// # Arguments
+///
+/// * `deps` - mutable reference to Extern containing all the contract's external dependencies
+/// * `env` - a reference to the Env of contract's environment
+/// * `sender` - a reference to the message sender address
+/// * `funds` - the coins sent with the message
+/// * `config` - a mutable reference to the Config
+/// * `priority` - u8 representation of highest ContractStatus level this action is permitted
+/// * `token_id` - the token id to buy
+/// * `memo` - optional memo for the transfer tx
+#[allow(clippy::too_many_arguments)]
+pub fn buy(
+    deps: DepsMut,
+    env: &Env,
+    sender: &Addr,
+    funds: &[Coin],
+    config: &mut Config,
+    priority: u8,
+    token_id: String,
+    memo: Option<String>,
+) -> StdResult<Response> {
+    check_status(config.status, priority)?;
+    let sender_raw = deps.api.addr_canonicalize(sender.as_str())?;
+    let not_listed = format!("Token ID: {} is not listed", token_id);
+    let (_, idx) = get_token(deps.storage, &token_id, Some(&not_listed))?;
+    let list_store = ReadonlyPrefixedStorage::new(deps.storage, PREFIX_LISTINGS);
+    let listing: StoredListing = may_load(&list_store, &idx.to_le_bytes())?
+        .ok_or_else(|| StdError::generic_err(not_listed))?;
+    if listing.seller == sender_raw {
+        return Err(StdError::generic_err(format!(
+            "You already own token {}",
+            token_id
+        )));
+    }
+    let price = listing.price;
+    let mut paid = Uint128::zero();
+    for coin in funds.iter() {
+        if coin.denom != price.denom {
+            return Err(StdError::generic_err(format!(
+                "Token ID: {} is only sold for {}",
+                token_id, price.denom
+            )));
+        }
+        paid = paid.checked_add(coin.amount)?;
+    }
+    if paid < price.amount {
+        return Err(StdError::generic_err(format!(
+            "Insufficient payment.  Token ID: {} costs {}{}",
+            token_id, price.amount, price.denom
+        )));
+    }
+    let mut payments = native_sale_payments(deps.as_ref(), idx, &listing.seller, &price)?;
+    let change = paid - price.amount;
+    if !change.is_zero() {
+        payments.push(BankMsg::Send {
+            to_address: sender.to_string(),
+            amount: vec![Coin {
+                denom: price.denom,
+                amount: change,
+            }],
+        });
+    }
+    // the seller still owns the token, so transfer it on their behalf.  This also ends
+    // the listing
+    let seller = deps.api.addr_humanize(&listing.seller)?;
+    let transfers = Some(vec![Transfer {
+        recipient: sender.to_string(),
+        token_ids: vec![token_id],
+        memo,
+    }]);
+    send_list(deps, env, &seller, config, transfers, None)?;
+    Ok(Response::new()
+        .add_messages(payments)
+        .set_data(to_binary(&ExecuteAnswer::Buy { status: Success })?))
+}
 
+/// Returns StdResult<Vec<BankMsg>> of the native coin payments that split a sale price
+/// between the token's royalty recipients and the seller
+///
+/// # Arguments
+///
+/// * `deps` - a reference to Extern containing all the contract's external dependencies
+/// * `idx` - index of the token sold
+/// * `seller` - a reference to the seller's address
+/// * `price` - a reference to the sale price
+fn native_sale_payments(
+    deps: Deps,
+    idx: u32,
+    seller: &CanonicalAddr,
+    price: &Coin,
+) -> StdResult<Vec<BankMsg>> {
+    let roy_store = ReadonlyPrefixedStorage::new(deps.storage, PREFIX_ROYALTY_INFO);
+    let royalty_info: Option<StoredRoyaltyInfo> = may_load(&roy_store, &idx.to_le_bytes())?;
     Ok(
-        Response::new().set_data(to_binary(&ExecuteAnswer::SetRoyaltyInfo {
-            status: Success,
-        })?),
+        split_payment(deps.api, royalty_info.as_ref(), seller, price.amount)?
+            .into_iter()
+            .map(|(recipient, amount)| BankMsg::Send {
+                to_address: recipient.to_string(),
+                amount: vec![Coin {
+                    denom: price.denom.clone(),
+                    amount,
+                }],
+            })
+            .collect(),
     )
 }
 
 /// Returns StdResult<Response>
 ///
-/// makes the sealed private metadata public
+/// starts an auction of a token
 ///
 /// # Arguments
 ///
 /// * `deps` - mutable reference to Extern containing all the contract's external dependencies
+/// * `env` - a reference to the Env of contract's environment
 /// * `sender` - a reference to the message sender address
 /// * `config` - a reference to the Config
-/// * `priority` - u8 representation of highest status level this action is permitted at
-/// * `token_id` - token id String slice of token whose metadata should be updated
-pub fn reveal(
+/// * `priority` - u8 representation of highest ContractStatus level this action is permitted
+/// * `token_id` - string slice of the token id to auction
+/// * `denom` - denomination bids are made in
+/// * `kind` - the type of the auction
+/// * `duration` - number of seconds the auction runs for
+#[allow(clippy::too_many_arguments)]
+pub fn start_auction(
     deps: DepsMut,
+    env: &Env,
     sender: &Addr,
     config: &Config,
     priority: u8,
     token_id: &str,
+    denom: String,
+    kind: AuctionKind,
+    duration: u64,
 ) -> StdResult<Response> {
     check_status(config.status, priority)?;
-    if !config.sealed_metadata_is_enabled {
-        return Err(StdError::generic_err(
-            "Sealed metadata functionality is not enabled for this contract",
-        ));
-    }
     let sender_raw = deps.api.addr_canonicalize(sender.as_str())?;
-    let custom_err = format!("You do not own token {}", token_id);
+    let custom_err = format!("You are not authorized to auction token {}", token_id);
     // if token supply is private, don't leak that the token id does not exist
-    // instead just say they do not own that token
+    // instead just say they are not authorized to auction it
     let opt_err = if config.token_supply_is_public {
         None
     } else {
         Some(&*custom_err)
     };
-    let (mut token, idx) = get_token(deps.storage, token_id, opt_err)?;
-    if token.unwrapped {
-        return Err(StdError::generic_err(
-            "This token has already been unwrapped",
-        ));
-    }
+    let (token, idx) = get_token(deps.storage, token_id, opt_err)?;
     if token.owner != sender_raw {
         return Err(StdError::generic_err(custom_err));
     }
-    token.unwrapped = true;
-    let token_key = idx.to_le_bytes();
-    let mut info_store = PrefixedStorage::new(deps.storage, PREFIX_INFOS);
-    json_save(&mut info_store, &token_key, &token)?;
-    if !config.unwrap_to_private {
-        let mut priv_store = PrefixedStorage::new(deps.storage, PREFIX_PRIV_META);
-        let may_priv: Option<Metadata> = may_load(&priv_store, &token_key)?;
-        if let Some(metadata) = may_priv {
-            remove(&mut priv_store, &token_key);
-            let mut pub_store = PrefixedStorage::new(deps.storage, PREFIX_PUB_META);
-            save(&mut pub_store, &token_key, &metadata)?;
+    if !token.transferable {
+        return Err(StdError::generic_err(format!(
+            "Token ID: {} is non-transferable",
+            token_id
+        )));
+    }
+    if edition_supply(deps.storage, idx)?.is_some() {
+        return Err(StdError::generic_err(format!(
+            "Token ID: {} is an edition and can not be auctioned",
+            token_id
+        )));
+    }
+    check_unlocked(deps.storage, &env.block, token_id, idx)?;
+    check_not_auctioned(deps.storage, token_id, idx)?;
+    let list_store = ReadonlyPrefixedStorage::new(deps.storage, PREFIX_LISTINGS);
+    if may_load::<StoredListing>(&list_store, &idx.to_le_bytes())?.is_some() {
+        return Err(StdError::generic_err(format!(
+            "Token ID: {} must be delisted before it is auctioned",
+            token_id
+        )));
+    }
+    if duration == 0 {
+        return Err(StdError::generic_err(
+            "The auction duration must be greater than zero",
+        ));
+    }
+    match kind {
+        AuctionKind::English { min_increment, .. } if min_increment.is_zero() => {
+            return Err(StdError::generic_err(
+                "The minimum bid increment must be greater than zero",
+            ));
+        }
+        AuctionKind::Dutch {
+            start_price,
+            end_price,
+        } if start_price <= end_price => {
+            return Err(StdError::generic_err(
+                "The start price of a Dutch auction must be greater than its end price",
+            ));
         }
+        _ => (),
     }
-    Ok(Response::new().set_data(to_binary(&ExecuteAnswer::Reveal { status: Success })?))
+    let starts_at = env.block.time.seconds();
+    let ends_at = starts_at
+        .checked_add(duration)
+        .ok_or_else(|| StdError::generic_err("The auction duration is too long"))?;
+    let mut auction_store = PrefixedStorage::new(deps.storage, PREFIX_AUCTIONS);
+    json_save(
+        &mut auction_store,
+        &idx.to_le_bytes(),
+        &StoredAuction {
+            seller: sender_raw,
+            denom,
+            kind,
+            starts_at,
+            ends_at,
+            highest_bid: None,
+        },
+    )?;
+    Ok(Response::new().set_data(to_binary(&ExecuteAnswer::StartAuction { status: Success })?))
 }
 
 /// Returns StdResult<Response>
 ///
-/// grants/revokes trasfer permission on a token
+/// bids on an auctioned token.  A bid on an English auction becomes the highest bid and
+/// refunds the previous one, and a bid on a Dutch auction buys the token at the current price
 ///
 /// # Arguments
 ///
 /// * `deps` - mutable reference to Extern containing all the contract's external dependencies
 /// * `env` - a reference to the Env of contract's environment
 /// * `sender` - a reference to the message sender address
-/// * `config` - a reference to the Config
-/// * `priority` - u8 representation of highest status level this action is permitted at
-/// * `spender` - a reference to the address being granted permission
-/// * `token_id` - string slice of the token id to grant permission to
-/// * `expires` - optional Expiration for this approval
-/// * `is_approve` - true if this is an Approve call
+/// * `funds` - the coins sent with the message
+/// * `config` - a mutable reference to the Config
+/// * `priority` - u8 representation of highest ContractStatus level this action is permitted
+/// * `token_id` - the token id being auctioned
 #[allow(clippy::too_many_arguments)]
-pub fn approve_revoke(
-    deps: DepsMut,
+pub fn bid(
+    mut deps: DepsMut,
     env: &Env,
     sender: &Addr,
-    config: &Config,
+    funds: &[Coin],
+    config: &mut Config,
     priority: u8,
-    spender: &str,
-    token_id: &str,
-    expires: Option<Expiration>,
-    is_approve: bool,
+    token_id: String,
 ) -> StdResult<Response> {
     check_status(config.status, priority)?;
-    let address_raw = deps
-        .api
-        .addr_canonicalize(deps.api.addr_validate(spender)?.as_str())?;
     let sender_raw = deps.api.addr_canonicalize(sender.as_str())?;
-    let custom_err = format!(
-        "Not authorized to grant/revoke transfer permission for token {}",
-        token_id
-    );
-    // if token supply is private, don't leak that the token id does not exist
-    // instead just say they are not authorized for that token
-    let opt_err = if config.token_supply_is_public {
-        None
-    } else {
-        Some(&*custom_err)
-    };
-    let (token, idx) = get_token(deps.storage, token_id, opt_err)?;
-    let mut all_perm: Option<Vec<Permission>> = None;
-    let mut from_oper = false;
-    let transfer_idx = PermissionType::Transfer.to_usize();
-    // if not called by the owner, check if message sender has operator status
-    if token.owner != sender_raw {
-        let all_store = ReadonlyPrefixedStorage::new(deps.storage, PREFIX_ALL_PERMISSIONS);
-        let may_list: Option<Vec<Permission>> = json_may_load(&all_store, token.owner.as_slice())?;
-        if let Some(list) = may_list.clone() {
-            if let Some(perm) = list.iter().find(|&p| p.address == sender_raw) {
-                if let Some(exp) = perm.expirations[transfer_idx] {
-                    if exp.is_expired(&env.block) {
-                        return Err(StdError::generic_err(format!(
-                            "Transfer authority for all tokens of {} has expired",
-                            &deps.api.addr_humanize(&token.owner)?
-                        )));
-                    } else {
-                        from_oper = true;
-                    }
-                }
-            }
-        }
-        if !from_oper {
-            return Err(StdError::generic_err(custom_err));
+    let not_auctioned = format!("Token ID: {} is not being auctioned", token_id);
+    let (_, idx) = get_token(deps.storage, &token_id, Some(&not_auctioned))?;
+    let auction_store = ReadonlyPrefixedStorage::new(deps.storage, PREFIX_AUCTIONS);
+    let mut auction: StoredAuction = json_may_load(&auction_store, &idx.to_le_bytes())?
+        .ok_or_else(|| StdError::generic_err(not_auctioned))?;
+    if auction.seller == sender_raw {
+        return Err(StdError::generic_err(format!(
+            "You can not bid on your own auction of token {}",
+            token_id
+        )));
+    }
+    let now = env.block.time.seconds();
+    if now >= auction.ends_at {
+        return Err(StdError::generic_err(format!(
+            "The auction of token {} has ended",
+            token_id
+        )));
+    }
+    let mut paid = Uint128::zero();
+    for coin in funds.iter() {
+        if coin.denom != auction.denom {
+            return Err(StdError::generic_err(format!(
+                "Bids on token {} must be made in {}",
+                token_id, auction.denom
+            )));
         }
-        all_perm = may_list;
+        paid = paid.checked_add(coin.amount)?;
     }
-    let mut accesses: [Option<AccessLevel>; 3] = [None, None, None];
-    let response: ExecuteAnswer;
-    if is_approve {
-        accesses[transfer_idx] = Some(AccessLevel::ApproveToken);
-        response = ExecuteAnswer::Approve { status: Success };
+    let mut payments: Vec<BankMsg> = Vec::new();
+    if let AuctionKind::English {
+        reserve_price,
+        min_increment,
+        extension,
+    } = auction.kind
+    {
+        let min_bid = match &auction.highest_bid {
+            Some(highest) => highest.amount.checked_add(min_increment)?,
+            None => reserve_price.max(Uint128::new(1)),
+        };
+        if paid < min_bid {
+            return Err(StdError::generic_err(format!(
+                "Bid too low.  The minimum bid on token {} is {}{}",
+                token_id, min_bid, auction.denom
+            )));
+        }
+        // refund the bid that was outbid
+        if let Some(outbid) = auction.highest_bid.take() {
+            payments.push(BankMsg::Send {
+                to_address: deps.api.addr_humanize(&outbid.bidder)?.to_string(),
+                amount: vec![Coin {
+                    denom: auction.denom.clone(),
+                    amount: outbid.amount,
+                }],
+            });
+        }
+        auction.highest_bid = Some(StoredBid {
+            bidder: sender_raw,
+            amount: paid,
+        });
+        // late bids extend the auction
+        auction.ends_at = auction.ends_at.max(now.saturating_add(extension));
+        let mut auction_store = PrefixedStorage::new(deps.storage, PREFIX_AUCTIONS);
+        json_save(&mut auction_store, &idx.to_le_bytes(), &auction)?;
     } else {
-        accesses[transfer_idx] = Some(AccessLevel::RevokeToken);
-        response = ExecuteAnswer::Revoke { status: Success };
+        // the auction has not ended, so it has a current price
+        let price = Coin {
+            denom: auction.denom.clone(),
+            amount: auction.current_price(now).unwrap_or_default(),
+        };
+        if paid < price.amount {
+            return Err(StdError::generic_err(format!(
+                "Bid too low.  The current price of token {} is {}{}",
+                token_id, price.amount, price.denom
+            )));
+        }
+        payments = native_sale_payments(deps.as_ref(), idx, &auction.seller, &price)?;
+        let change = paid - price.amount;
+        if !change.is_zero() {
+            payments.push(BankMsg::Send {
+                to_address: sender.to_string(),
+                amount: vec![Coin {
+                    denom: price.denom,
+                    amount: change,
+                }],
+            });
+        }
+        // the auction must end before the token can be transferred
+        let mut auction_store = PrefixedStorage::new(deps.storage, PREFIX_AUCTIONS);
+        remove(&mut auction_store, &idx.to_le_bytes());
+        let seller = deps.api.addr_humanize(&auction.seller)?;
+        let transfers = Some(vec![Transfer {
+            recipient: sender.to_string(),
+            token_ids: vec![token_id],
+            memo: None,
+        }]);
+        send_list(deps.branch(), env, &seller, config, transfers, None)?;
     }
-    let owner = token.owner.clone();
-    let mut proc_info = ProcessAccInfo {
-        token,
-        idx,
-        token_given: true,
-        accesses,
-        expires,
-        from_oper,
-    };
-    process_accesses(
-        deps.storage,
-        env,
-        &address_raw,
-        &owner,
-        &mut proc_info,
-        all_perm,
-    )?;
-    let res = Response::new().set_data(to_binary(&response)?);
-    Ok(res)
+    Ok(Response::new()
+        .add_messages(payments)
+        .set_data(to_binary(&ExecuteAnswer::Bid { status: Success })?))
 }
 
 /// Returns StdResult<Response>
 ///
-/// makes an address' token ownership private
+/// settles an auction that has ended, transferring the token to the winning bidder and paying
+/// the royalty recipients and the seller
 ///
 /// # Arguments
 ///
 /// * `deps` - mutable reference to Extern containing all the contract's external dependencies
-/// * `sender` - a reference to the message sender address
-/// * `config` - a reference to the Config
-/// * `priority` - u8 representation of highest status level this action is permitted at
-pub fn make_owner_private(
-    deps: DepsMut,
-    sender: &Addr,
-    config: &Config,
+/// * `env` - a reference to the Env of contract's environment
+/// * `config` - a mutable reference to the Config
+/// * `priority` - u8 representation of highest ContractStatus level this action is permitted
+/// * `token_id` - the token id being auctioned
+pub fn settle_auction(
+    mut deps: DepsMut,
+    env: &Env,
+    config: &mut Config,
     priority: u8,
+    token_id: String,
 ) -> StdResult<Response> {
     check_status(config.status, priority)?;
-    let sender_raw = deps.api.addr_canonicalize(sender.as_str())?;
-    // only need to do this if the contract has public ownership
-    if config.owner_is_public {
-        let mut priv_store = PrefixedStorage::new(deps.storage, PREFIX_OWNER_PRIV);
-        save(&mut priv_store, sender_raw.as_slice(), &false)?
+    let not_auctioned = format!("Token ID: {} is not being auctioned", token_id);
+    let (_, idx) = get_token(deps.storage, &token_id, Some(&not_auctioned))?;
+    let mut auction_store = PrefixedStorage::new(deps.storage, PREFIX_AUCTIONS);
+    let auction: StoredAuction = json_may_load(&auction_store, &idx.to_le_bytes())?
+        .ok_or_else(|| StdError::generic_err(not_auctioned))?;
+    if env.block.time.seconds() < auction.ends_at {
+        return Err(StdError::generic_err(format!(
+            "The auction of token {} has not ended",
+            token_id
+        )));
     }
-    Ok(
-        Response::new().set_data(to_binary(&ExecuteAnswer::MakeOwnershipPrivate {
+    // the auction must end before the token can be transferred
+    remove(&mut auction_store, &idx.to_le_bytes());
+    let mut payments: Vec<BankMsg> = Vec::new();
+    // the token stays with the seller if there was no winning bid
+    if let Some(winner) = auction.highest_bid {
+        let price = Coin {
+            denom: auction.denom,
+            amount: winner.amount,
+        };
+        payments = native_sale_payments(deps.as_ref(), idx, &auction.seller, &price)?;
+        let seller = deps.api.addr_humanize(&auction.seller)?;
+        let transfers = Some(vec![Transfer {
+            recipient: deps.api.addr_humanize(&winner.bidder)?.to_string(),
+            token_ids: vec![token_id],
+            memo: None,
+        }]);
+        send_list(deps.branch(), env, &seller, config, transfers, None)?;
+    }
+    Ok(Response::new()
+        .add_messages(payments)
+        .set_data(to_binary(&ExecuteAnswer::SettleAuction {
             status: Success,
-        })?),
-    )
+        })?))
 }
 
 /// Returns StdResult<Response>
 ///
-/// adds/revokes access for everyone
+/// set or remove the minimum amount of an offer in a SNIP-20, registering with the SNIP-20 the
+/// first time it is given a minimum
 ///
 /// # Arguments
 ///
@@ -988,183 +4548,174 @@ pub fn make_owner_private(
 /// * `env` - a reference to the Env of contract's environment
 /// * `sender` - a reference to the message sender address
 /// * `config` - a reference to the Config
-/// * `priority` - u8 representation of highest status level this action is permitted at
-/// * `token_id` - optional token id to apply approvals to
-/// * `view_owner` - optional access level for viewing token ownership
-/// * `view_private_metadata` - optional access level for viewing private metadata
-/// * `expires` - optional Expiration for this approval
+/// * `priority` - u8 representation of highest ContractStatus level this action is permitted
+/// * `contract_address` - address of the SNIP-20 contract
+/// * `code_hash` - code hash of the SNIP-20 contract
+/// * `min_amount` - the optional new minimum amount of an offer
 #[allow(clippy::too_many_arguments)]
-pub fn set_global_approval(
+pub fn set_offer_snip20(
     deps: DepsMut,
     env: &Env,
     sender: &Addr,
     config: &Config,
     priority: u8,
-    token_id: Option<String>,
-    view_owner: Option<AccessLevel>,
-    view_private_metadata: Option<AccessLevel>,
-    expires: Option<Expiration>,
+    contract_address: &str,
+    code_hash: String,
+    min_amount: Option<Uint128>,
 ) -> StdResult<Response> {
     check_status(config.status, priority)?;
-    let token_given: bool;
-    // use this "address" to represent global permission
-    let global_raw = CanonicalAddr(Binary::from(b"public"));
     let sender_raw = deps.api.addr_canonicalize(sender.as_str())?;
-    let mut custom_err = String::new();
-    let (token, idx) = if let Some(id) = token_id {
-        token_given = true;
-        custom_err = format!("You do not own token {}", id);
-        // if token supply is private, don't leak that the token id does not exist
-        // instead just say they do not own that token
-        let opt_err = if config.token_supply_is_public {
-            None
-        } else {
-            Some(&*custom_err)
-        };
-        get_token(deps.storage, &id, opt_err)?
-    } else {
-        token_given = false;
-        (
-            Token {
-                owner: sender_raw.clone(),
-                permissions: Vec::new(),
-                unwrapped: false,
-                transferable: true,
-            },
-            0,
-        )
-    };
-    // if trying to set token permissions when you are not the owner
-    if token_given && token.owner != sender_raw {
-        return Err(StdError::generic_err(custom_err));
+    if config.admin != sender_raw {
+        return Err(StdError::generic_err(
+            "This is an admin command and can only be run from the admin address",
+        ));
     }
-    let mut accesses: [Option<AccessLevel>; 3] = [None, None, None];
-    accesses[PermissionType::ViewOwner.to_usize()] = view_owner;
-    accesses[PermissionType::ViewMetadata.to_usize()] = view_private_metadata;
-    let mut proc_info = ProcessAccInfo {
-        token,
-        idx,
-        token_given,
-        accesses,
-        expires,
-        from_oper: false,
-    };
-    process_accesses(
-        deps.storage,
-        env,
-        &global_raw,
-        &sender_raw,
-        &mut proc_info,
-        None,
-    )?;
-    Ok(
-        Response::new().set_data(to_binary(&ExecuteAnswer::SetGlobalApproval {
-            status: Success,
-        })?),
-    )
+    let contract = deps.api.addr_validate(contract_address)?;
+    let contract_raw = deps.api.addr_canonicalize(contract.as_str())?;
+    let mut snip20s: Vec<StoredOfferSnip20> =
+        may_load(deps.storage, OFFER_SNIP20S_KEY)?.unwrap_or_default();
+    let mut resp = Response::new();
+    if let Some(snip20) = snip20s.iter_mut().find(|s| s.address == contract_raw) {
+        snip20.code_hash = code_hash;
+        snip20.min_amount = min_amount;
+    } else if min_amount.is_some() {
+        resp = resp.add_message(register_receive_msg(
+            env.contract.code_hash.clone(),
+            None,
+            BLOCK_SIZE,
+            code_hash.clone(),
+            contract.to_string(),
+        )?);
+        snip20s.push(StoredOfferSnip20 {
+            address: contract_raw,
+            code_hash,
+            min_amount,
+        });
+    }
+    save(deps.storage, OFFER_SNIP20S_KEY, &snip20s)?;
+    Ok(resp.set_data(to_binary(&ExecuteAnswer::SetOfferSnip20 {
+        status: Success,
+    })?))
 }
 
 /// Returns StdResult<Response>
 ///
-/// sets specified permissions for an address
+/// escrows SNIP-20 tokens as an offer on a token
 ///
 /// # Arguments
 ///
 /// * `deps` - mutable reference to Extern containing all the contract's external dependencies
 /// * `env` - a reference to the Env of contract's environment
-/// * `sender` - a reference to the message sender address
+/// * `snip20` - a reference to the address of the SNIP-20 contract that sent the tokens
 /// * `config` - a reference to the Config
-/// * `priority` - u8 representation of highest status level this action is permitted at
-/// * `address` - a reference to the address being granted permission
-/// * `token_id` - optional token id to apply approvals to
-/// * `view_owner` - optional access level for viewing token ownership
-/// * `view_private_metadata` - optional access level for viewing private metadata
-/// * `transfer` - optional access level for transferring tokens
-/// * `expires` - optional Expiration for this approval
-/// * `response_type` - which response to return for SetWhitelistedApproval, ApproveAll, or RevokeAll
+/// * `from` - the owner of the SNIP-20 tokens that were sent
+/// * `amount` - the amount of SNIP-20 tokens sent
+/// * `token_id` - string slice of the token id the offer is for
+/// * `expires` - expiration of the offer
 #[allow(clippy::too_many_arguments)]
-pub fn set_whitelisted_approval(
+fn make_offer(
     deps: DepsMut,
     env: &Env,
-    sender: &Addr,
+    snip20: &Addr,
     config: &Config,
-    priority: u8,
-    address: &str,
-    token_id: Option<String>,
-    view_owner: Option<AccessLevel>,
-    view_private_metadata: Option<AccessLevel>,
-    transfer: Option<AccessLevel>,
-    expires: Option<Expiration>,
-    response_type: SetAppResp,
+    from: &str,
+    amount: Uint128,
+    token_id: &str,
+    expires: Expiration,
 ) -> StdResult<Response> {
-    check_status(config.status, priority)?;
-    let token_given: bool;
-    let address_raw = deps
-        .api
-        .addr_canonicalize(deps.api.addr_validate(address)?.as_str())?;
-    let sender_raw = deps.api.addr_canonicalize(sender.as_str())?;
-    let mut custom_err = String::new();
-    let (token, idx) = if let Some(id) = token_id {
-        token_given = true;
-        custom_err = format!("You do not own token {}", id);
-        // if token supply is private, don't leak that the token id does not exist
-        // instead just say they do not own that token
-        let opt_err = if config.token_supply_is_public {
-            None
-        } else {
-            Some(&*custom_err)
-        };
-        get_token(deps.storage, &id, opt_err)?
+    let snip20_raw = deps.api.addr_canonicalize(snip20.as_str())?;
+    let snip20s: Vec<StoredOfferSnip20> =
+        may_load(deps.storage, OFFER_SNIP20S_KEY)?.unwrap_or_default();
+    let (code_hash, min_amount) = snip20s
+        .into_iter()
+        .find(|s| s.address == snip20_raw)
+        .and_then(|s| s.min_amount.map(|m| (s.code_hash, m)))
+        .ok_or_else(|| StdError::generic_err(format!("Offers can not be made in {}", snip20)))?;
+    if amount.is_zero() {
+        return Err(StdError::generic_err(
+            "The offer amount must be greater than zero",
+        ));
+    }
+    if amount < min_amount {
+        return Err(StdError::generic_err(format!(
+            "Offers in {} must be at least {}",
+            snip20, min_amount
+        )));
+    }
+    if expires.is_expired(&env.block) {
+        return Err(StdError::generic_err(
+            "The offer expiration has already passed",
+        ));
+    }
+    let buyer_raw = deps
+        .api
+        .addr_canonicalize(deps.api.addr_validate(from)?.as_str())?;
+    let custom_err = format!("You can not make an offer on token {}", token_id);
+    // if token supply is private, don't leak that the token id does not exist
+    let opt_err = if config.token_supply_is_public {
+        None
     } else {
-        token_given = false;
-        (
-            Token {
-                owner: sender_raw.clone(),
-                permissions: Vec::new(),
-                unwrapped: false,
-                transferable: true,
-            },
-            0,
-        )
+        Some(&*custom_err)
     };
-    // if trying to set token permissions when you are not the owner
-    if token_given && token.owner != sender_raw {
-        return Err(StdError::generic_err(custom_err));
+    let (token, idx) = get_token(deps.storage, token_id, opt_err)?;
+    if token.owner == buyer_raw {
+        return Err(StdError::generic_err(format!(
+            "You already own token {}",
+            token_id
+        )));
     }
-    let mut accesses: [Option<AccessLevel>; 3] = [None, None, None];
-    accesses[PermissionType::ViewOwner.to_usize()] = view_owner;
-    accesses[PermissionType::ViewMetadata.to_usize()] = view_private_metadata;
-    accesses[PermissionType::Transfer.to_usize()] = transfer;
-    let mut proc_info = ProcessAccInfo {
-        token,
-        idx,
-        token_given,
-        accesses,
-        expires,
-        from_oper: false,
-    };
-    process_accesses(
+    if !token.transferable {
+        return Err(StdError::generic_err(format!(
+            "Token ID: {} is non-transferable",
+            token_id
+        )));
+    }
+    if edition_supply(deps.storage, idx)?.is_some() {
+        return Err(StdError::generic_err(format!(
+            "Token ID: {} is an edition and can not receive offers",
+            token_id
+        )));
+    }
+    let offer_id: u64 = may_load(deps.storage, OFFER_COUNT_KEY)?.unwrap_or(0);
+    save(
         deps.storage,
-        env,
-        &address_raw,
-        &sender_raw,
-        &mut proc_info,
-        None,
+        OFFER_COUNT_KEY,
+        &offer_id
+            .checked_add(1)
+            .ok_or_else(|| StdError::generic_err("Reached the maximum number of offers"))?,
     )?;
-    let response = match response_type {
-        SetAppResp::SetWhitelistedApproval => {
-            ExecuteAnswer::SetWhitelistedApproval { status: Success }
-        }
-        SetAppResp::ApproveAll => ExecuteAnswer::ApproveAll { status: Success },
-        SetAppResp::RevokeAll => ExecuteAnswer::RevokeAll { status: Success },
-    };
-    let res = Response::new().set_data(to_binary(&response)?);
-    Ok(res)
+    let mut offer_store = PrefixedStorage::new(deps.storage, PREFIX_OFFERS);
+    json_save(
+        &mut offer_store,
+        &offer_id.to_le_bytes(),
+        &StoredOffer {
+            token_id: token_id.to_string(),
+            buyer: buyer_raw.clone(),
+            snip20: snip20_raw,
+            code_hash,
+            amount,
+            expires,
+        },
+    )?;
+    add_offer_id(
+        deps.storage,
+        PREFIX_TOKEN_OFFERS,
+        token_id.as_bytes(),
+        offer_id,
+    )?;
+    add_offer_id(
+        deps.storage,
+        PREFIX_BUYER_OFFERS,
+        buyer_raw.as_slice(),
+        offer_id,
+    )?;
+    Ok(Response::new().set_data(to_binary(&ExecuteAnswer::MakeOffer { offer_id })?))
 }
 
 /// Returns StdResult<Response>
 ///
-/// burns many tokens
+/// accepts an offer on a token, transferring the token to the buyer and paying the royalty
+/// recipients and the owner from the escrowed SNIP-20 tokens
 ///
 /// # Arguments
 ///
@@ -1173,156 +4724,395 @@ pub fn set_whitelisted_approval(
 /// * `sender` - a reference to the message sender address
 /// * `config` - a mutable reference to the Config
 /// * `priority` - u8 representation of highest ContractStatus level this action is permitted
-/// * `burns` - the list of burns to perform
-pub fn batch_burn_nft(
-    deps: DepsMut,
+/// * `offer_id` - id of the offer to accept
+/// * `memo` - optional memo for the transfer tx
+pub fn accept_offer(
+    mut deps: DepsMut,
     env: &Env,
     sender: &Addr,
     config: &mut Config,
     priority: u8,
-    burns: Vec<Burn>,
+    offer_id: u64,
+    memo: Option<String>,
 ) -> StdResult<Response> {
     check_status(config.status, priority)?;
     let sender_raw = deps.api.addr_canonicalize(sender.as_str())?;
-    burn_list(deps, &env.block, config, &sender_raw, burns)?;
-    let res =
-        Response::new().set_data(to_binary(&ExecuteAnswer::BatchBurnNft { status: Success })?);
-    Ok(res)
+    let offer = may_load_offer(deps.storage, offer_id)?
+        .ok_or_else(|| StdError::generic_err(format!("Offer {} not found", offer_id)))?;
+    let custom_err = format!(
+        "Only the owner of token {} may accept offer {}",
+        offer.token_id, offer_id
+    );
+    let (token, idx) = get_token(deps.storage, &offer.token_id, Some(&custom_err))?;
+    if token.owner != sender_raw {
+        return Err(StdError::generic_err(custom_err));
+    }
+    if offer.buyer == sender_raw {
+        return Err(StdError::generic_err(format!(
+            "You already own token {}",
+            offer.token_id
+        )));
+    }
+    if offer.expires.is_expired(&env.block) {
+        return Err(StdError::generic_err(format!(
+            "Offer {} has expired",
+            offer_id
+        )));
+    }
+    let roy_store = ReadonlyPrefixedStorage::new(deps.storage, PREFIX_ROYALTY_INFO);
+    let royalty_info: Option<StoredRoyaltyInfo> = may_load(&roy_store, &idx.to_le_bytes())?;
+    let snip20 = deps.api.addr_humanize(&offer.snip20)?;
+    let payments = split_payment(deps.api, royalty_info.as_ref(), &token.owner, offer.amount)?
+        .into_iter()
+        .map(|(recipient, amount)| {
+            transfer_msg(
+                recipient.to_string(),
+                amount,
+                None,
+                None,
+                BLOCK_SIZE,
+                offer.code_hash.clone(),
+                snip20.to_string(),
+            )
+        })
+        .collect::<StdResult<Vec<CosmosMsg>>>()?;
+    let transfers = Some(vec![Transfer {
+        recipient: deps.api.addr_humanize(&offer.buyer)?.to_string(),
+        token_ids: vec![offer.token_id.clone()],
+        memo,
+    }]);
+    send_list(deps.branch(), env, sender, config, transfers, None)?;
+    remove_offer(deps.storage, offer_id, &offer)?;
+    Ok(Response::new()
+        .add_messages(payments)
+        .set_data(to_binary(&ExecuteAnswer::AcceptOffer { status: Success })?))
 }
 
 /// Returns StdResult<Response>
 ///
-/// burns a token
+/// withdraws an offer and returns the escrowed SNIP-20 tokens to the buyer
 ///
 /// # Arguments
 ///
 /// * `deps` - mutable reference to Extern containing all the contract's external dependencies
-/// * `env` - a reference to the Env of contract's environment
 /// * `sender` - a reference to the message sender address
-/// * `config` - a mutable reference to the Config
+/// * `config` - a reference to the Config
 /// * `priority` - u8 representation of highest ContractStatus level this action is permitted
-/// * `token_id` - token id String of token to be burnt
-/// * `memo` - optional memo for the burn tx
-fn burn_nft(
+/// * `offer_id` - id of the offer to withdraw
+pub fn withdraw_offer(
     deps: DepsMut,
-    env: &Env,
     sender: &Addr,
-    config: &mut Config,
+    config: &Config,
     priority: u8,
-    token_id: String,
-    memo: Option<String>,
+    offer_id: u64,
 ) -> StdResult<Response> {
     check_status(config.status, priority)?;
     let sender_raw = deps.api.addr_canonicalize(sender.as_str())?;
-    let burns = vec![Burn {
-        token_ids: vec![token_id],
-        memo,
-    }];
-    burn_list(deps, &env.block, config, &sender_raw, burns)?;
-    let res = Response::new().set_data(to_binary(&ExecuteAnswer::BurnNft { status: Success })?);
-    Ok(res)
+    let offer = may_load_offer(deps.storage, offer_id)?
+        .ok_or_else(|| StdError::generic_err(format!("Offer {} not found", offer_id)))?;
+    if offer.buyer != sender_raw {
+        return Err(StdError::generic_err(format!(
+            "Only the buyer may withdraw offer {}",
+            offer_id
+        )));
+    }
+    remove_offer(deps.storage, offer_id, &offer)?;
+    let refund = transfer_msg(
+        sender.to_string(),
+        offer.amount,
+        None,
+        None,
+        BLOCK_SIZE,
+        offer.code_hash,
+        deps.api.addr_humanize(&offer.snip20)?.to_string(),
+    )?;
+    Ok(Response::new()
+        .add_message(refund)
+        .set_data(to_binary(&ExecuteAnswer::WithdrawOffer {
+            status: Success,
+        })?))
+}
+
+/// Returns StdResult<()> after removing an offer and its index entries
+///
+/// # Arguments
+///
+/// * `storage` - a mutable reference to the contract's storage
+/// * `offer_id` - id of the offer
+/// * `offer` - a reference to the StoredOffer
+fn remove_offer(storage: &mut dyn Storage, offer_id: u64, offer: &StoredOffer) -> StdResult<()> {
+    let mut offer_store = PrefixedStorage::new(storage, PREFIX_OFFERS);
+    remove(&mut offer_store, &offer_id.to_le_bytes());
+    remove_offer_id(
+        storage,
+        PREFIX_TOKEN_OFFERS,
+        offer.token_id.as_bytes(),
+        offer_id,
+    )?;
+    remove_offer_id(
+        storage,
+        PREFIX_BUYER_OFFERS,
+        offer.buyer.as_slice(),
+        offer_id,
+    )
+}
+
+/// Returns StdResult<Vec<CosmosMsg>> of the messages returning the escrowed SNIP-20 tokens
+/// of every offer on a token after removing the offers
+///
+/// # Arguments
+///
+/// * `deps` - a mutable reference to Extern containing all the contract's external dependencies
+/// * `token_id` - string slice of the token id
+fn refund_token_offers(deps: &mut DepsMut, token_id: &str) -> StdResult<Vec<CosmosMsg>> {
+    let ids = offer_ids(
+        deps.storage,
+        PREFIX_TOKEN_OFFERS,
+        token_id.as_bytes(),
+        None,
+        u32::MAX,
+    )?;
+    let mut refunds = Vec::new();
+    for offer_id in ids.into_iter() {
+        if let Some(offer) = may_load_offer(deps.storage, offer_id)? {
+            remove_offer(deps.storage, offer_id, &offer)?;
+            refunds.push(transfer_msg(
+                deps.api.addr_humanize(&offer.buyer)?.to_string(),
+                offer.amount,
+                None,
+                None,
+                BLOCK_SIZE,
+                offer.code_hash,
+                deps.api.addr_humanize(&offer.snip20)?.to_string(),
+            )?);
+        }
+    }
+    Ok(refunds)
 }
 
 /// Returns StdResult<Response>
 ///
-/// transfer many tokens
+/// registers with another SNIP-721 contract so its tokens may be deposited for swaps
 ///
 /// # Arguments
 ///
 /// * `deps` - mutable reference to Extern containing all the contract's external dependencies
 /// * `env` - a reference to the Env of contract's environment
 /// * `sender` - a reference to the message sender address
-/// * `config` - a mutable reference to the Config
+/// * `config` - a reference to the Config
 /// * `priority` - u8 representation of highest ContractStatus level this action is permitted
-/// * `transfers` - list of transfers to perform
-pub fn batch_transfer_nft(
+/// * `contract_address` - address of the SNIP-721 contract
+/// * `code_hash` - code hash of the SNIP-721 contract
+pub fn register_swap_contract(
     deps: DepsMut,
     env: &Env,
     sender: &Addr,
-    config: &mut Config,
+    config: &Config,
     priority: u8,
-    transfers: Vec<Transfer>,
+    contract_address: &str,
+    code_hash: String,
 ) -> StdResult<Response> {
     check_status(config.status, priority)?;
-    let _m = send_list(deps, env, sender, config, Some(transfers), None)?;
+    let sender_raw = deps.api.addr_canonicalize(sender.as_str())?;
+    if config.admin != sender_raw {
+        return Err(StdError::generic_err(
+            "This is an admin command and can only be run from the admin address",
+        ));
+    }
+    let contract = deps.api.addr_validate(contract_address)?;
+    let contract_raw = deps.api.addr_canonicalize(contract.as_str())?;
+    let mut hash_store = PrefixedStorage::new(deps.storage, PREFIX_SWAP_CONTRACTS);
+    save(&mut hash_store, contract_raw.as_slice(), &code_hash)?;
+    let register_msg = Snip721HandleMsg::RegisterReceiveNft {
+        code_hash: env.contract.code_hash.clone(),
+        also_implements_batch_receive_nft: Some(true),
+        padding: None,
+    }
+    .to_cosmos_msg(code_hash, contract.to_string(), None)?;
+    Ok(Response::new()
+        .add_message(register_msg)
+        .set_data(to_binary(&ExecuteAnswer::RegisterSwapContract {
+            status: Success,
+        })?))
+}
 
-    let res = Response::new().set_data(to_binary(&ExecuteAnswer::BatchTransferNft {
-        status: Success,
-    })?);
-    Ok(res)
+/// Returns StdResult<Response>
+///
+/// records the tokens a registered SNIP-721 contract sent to this contract as deposits of
+/// their previous owner
+///
+/// # Arguments
+///
+/// * `deps` - mutable reference to Extern containing all the contract's external dependencies
+/// * `sender` - a reference to the message sender address, which is the SNIP-721 contract
+/// * `config` - a reference to the Config
+/// * `priority` - u8 representation of highest ContractStatus level this action is permitted
+/// * `from` - the previous owner of the tokens
+/// * `token_ids` - the tokens that were sent
+pub fn receive_deposits(
+    deps: DepsMut,
+    sender: &Addr,
+    config: &Config,
+    priority: u8,
+    from: &str,
+    token_ids: Vec<String>,
+) -> StdResult<Response> {
+    check_status(config.status, priority)?;
+    let contract_raw = deps.api.addr_canonicalize(sender.as_str())?;
+    swap_contract_hash(deps.storage, &contract_raw, sender.as_str())?;
+    let owner_raw = deps
+        .api
+        .addr_canonicalize(deps.api.addr_validate(from)?.as_str())?;
+    for token_id in token_ids.iter() {
+        save_deposit(deps.storage, &contract_raw, token_id, &owner_raw)?;
+    }
+    Ok(
+        Response::new().set_data(to_binary(&ExecuteAnswer::BatchReceiveNft {
+            status: Success,
+        })?),
+    )
 }
 
 /// Returns StdResult<Response>
 ///
-/// transfer a token
+/// returns deposited tokens of another SNIP-721 contract to the address that deposited them
 ///
 /// # Arguments
 ///
 /// * `deps` - mutable reference to Extern containing all the contract's external dependencies
-/// * `env` - a reference to the Env of contract's environment
 /// * `sender` - a reference to the message sender address
-/// * `config` - a mutable reference to the Config
+/// * `config` - a reference to the Config
 /// * `priority` - u8 representation of highest ContractStatus level this action is permitted
-/// * `recipient` - the address receiving the token
-/// * `token_id` - token id String of token to be transferred
-/// * `memo` - optional memo for the mint tx
-#[allow(clippy::too_many_arguments)]
-pub fn transfer_nft(
+/// * `contract_address` - address of the SNIP-721 contract
+/// * `token_ids` - the tokens to withdraw
+pub fn withdraw_deposits(
     deps: DepsMut,
-    env: &Env,
     sender: &Addr,
-    config: &mut Config,
+    config: &Config,
     priority: u8,
-    recipient: String,
-    token_id: String,
-    memo: Option<String>,
+    contract_address: &str,
+    token_ids: Vec<String>,
 ) -> StdResult<Response> {
     check_status(config.status, priority)?;
-    let transfers = Some(vec![Transfer {
-        recipient,
-        token_ids: vec![token_id],
-        memo,
-    }]);
-    let _m = send_list(deps, env, sender, config, transfers, None)?;
-
-    let res = Response::new().set_data(to_binary(&ExecuteAnswer::TransferNft { status: Success })?);
-    Ok(res)
+    let sender_raw = deps.api.addr_canonicalize(sender.as_str())?;
+    let contract = deps.api.addr_validate(contract_address)?;
+    let contract_raw = deps.api.addr_canonicalize(contract.as_str())?;
+    let code_hash = swap_contract_hash(deps.storage, &contract_raw, contract.as_str())?;
+    for token_id in token_ids.iter() {
+        if depositor(deps.storage, &contract_raw, token_id)?.as_ref() != Some(&sender_raw) {
+            return Err(StdError::generic_err(format!(
+                "You have not deposited token {} of contract {}",
+                token_id, contract
+            )));
+        }
+    }
+    for token_id in token_ids.iter() {
+        remove_deposit(deps.storage, &contract_raw, token_id);
+    }
+    let transfer_msg = Snip721HandleMsg::BatchTransferNft {
+        transfers: vec![Transfer {
+            recipient: sender.to_string(),
+            token_ids,
+            memo: None,
+        }],
+        padding: None,
+    }
+    .to_cosmos_msg(code_hash, contract.to_string(), None)?;
+    Ok(Response::new()
+        .add_message(transfer_msg)
+        .set_data(to_binary(&ExecuteAnswer::WithdrawDeposits {
+            status: Success,
+        })?))
 }
 
 /// Returns StdResult<Response>
 ///
-/// sends tokens to contracts, and calls those contracts' ReceiveNft.  Will error if any
-/// contract has not registered its ReceiveNft
+/// proposes exchanging tokens with a counterparty, escrowing any coins sent as a sweetener
 ///
 /// # Arguments
 ///
 /// * `deps` - mutable reference to Extern containing all the contract's external dependencies
 /// * `env` - a reference to the Env of contract's environment
 /// * `sender` - a reference to the message sender address
-/// * `config` - a mutable reference to the Config
+/// * `funds` - the coins sent with the message
+/// * `config` - a reference to the Config
 /// * `priority` - u8 representation of highest ContractStatus level this action is permitted
-/// * `sends` - list of SendNfts to perform
-fn batch_send_nft(
+/// * `counterparty` - address that may accept the swap
+/// * `offered` - tokens the proposer gives
+/// * `requested` - tokens the counterparty gives
+/// * `expires` - expiration of the proposal
+#[allow(clippy::too_many_arguments)]
+pub fn propose_swap(
     deps: DepsMut,
     env: &Env,
     sender: &Addr,
-    config: &mut Config,
+    funds: &[Coin],
+    config: &Config,
     priority: u8,
-    sends: Vec<Send>,
+    counterparty: &str,
+    offered: Vec<SwapToken>,
+    requested: Vec<SwapToken>,
+    expires: Expiration,
 ) -> StdResult<Response> {
     check_status(config.status, priority)?;
-    let messages = send_list(deps, env, sender, config, None, Some(sends))?;
-
-    let res = Response::new()
-        .add_messages(messages)
-        .set_data(to_binary(&ExecuteAnswer::BatchSendNft { status: Success })?);
-    Ok(res)
+    let sender_raw = deps.api.addr_canonicalize(sender.as_str())?;
+    let counterparty_raw = deps
+        .api
+        .addr_canonicalize(deps.api.addr_validate(counterparty)?.as_str())?;
+    if counterparty_raw == sender_raw {
+        return Err(StdError::generic_err(
+            "You can not propose a swap with yourself",
+        ));
+    }
+    if requested.is_empty() {
+        return Err(StdError::generic_err(
+            "A swap must request at least one token",
+        ));
+    }
+    let sweetener: Vec<Coin> = funds
+        .iter()
+        .filter(|c| !c.amount.is_zero())
+        .cloned()
+        .collect();
+    if offered.is_empty() && sweetener.is_empty() {
+        return Err(StdError::generic_err(
+            "A swap must offer at least one token or a sweetener",
+        ));
+    }
+    if expires.is_expired(&env.block) {
+        return Err(StdError::generic_err(
+            "The swap expiration has already passed",
+        ));
+    }
+    let offered = stored_swap_tokens(deps.as_ref(), offered, Some(&sender_raw))?;
+    let requested = stored_swap_tokens(deps.as_ref(), requested, None)?;
+    let swap_id: u64 = may_load(deps.storage, SWAP_COUNT_KEY)?.unwrap_or(0);
+    save(
+        deps.storage,
+        SWAP_COUNT_KEY,
+        &swap_id
+            .checked_add(1)
+            .ok_or_else(|| StdError::generic_err("Reached the maximum number of swaps"))?,
+    )?;
+    let mut swap_store = PrefixedStorage::new(deps.storage, PREFIX_SWAPS);
+    json_save(
+        &mut swap_store,
+        &swap_id.to_le_bytes(),
+        &StoredSwap {
+            proposer: sender_raw,
+            counterparty: counterparty_raw,
+            offered,
+            requested,
+            sweetener,
+            expires,
+        },
+    )?;
+    Ok(Response::new().set_data(to_binary(&ExecuteAnswer::ProposeSwap { swap_id })?))
 }
 
 /// Returns StdResult<Response>
 ///
-/// sends a token to a contract, and calls that contract's ReceiveNft.  Will error if the
-/// contract has not registered its ReceiveNft
+/// accepts a swap, exchanging the offered tokens and sweetener for the requested tokens
 ///
 /// # Arguments
 ///
@@ -1331,44 +5121,125 @@ fn batch_send_nft(
 /// * `sender` - a reference to the message sender address
 /// * `config` - a mutable reference to the Config
 /// * `priority` - u8 representation of highest ContractStatus level this action is permitted
-/// * `contract` - the address of the contract receiving the token
-/// * `receiver_info` - optional code hash and BatchReceiveNft implementation status of
-///                     the recipient contract
-/// * `token_id` - ID String of the token that was sent
-/// * `msg` - optional msg used to control ReceiveNft logic
-/// * `memo` - optional memo for the mint tx
-#[allow(clippy::too_many_arguments)]
-fn send_nft(
-    deps: DepsMut,
+/// * `swap_id` - id of the swap
+pub fn accept_swap(
+    mut deps: DepsMut,
     env: &Env,
     sender: &Addr,
     config: &mut Config,
     priority: u8,
-    contract: String,
-    receiver_info: Option<ReceiverInfo>,
-    token_id: String,
-    msg: Option<Binary>,
-    memo: Option<String>,
+    swap_id: u64,
 ) -> StdResult<Response> {
     check_status(config.status, priority)?;
-    let sends = Some(vec![Send {
-        contract,
-        receiver_info,
-        token_ids: vec![token_id],
-        msg,
-        memo,
-    }]);
-    let messages = send_list(deps, env, sender, config, None, sends)?;
-
-    let res = Response::new()
+    let sender_raw = deps.api.addr_canonicalize(sender.as_str())?;
+    let mut swap_store = PrefixedStorage::new(deps.storage, PREFIX_SWAPS);
+    let swap: StoredSwap = json_may_load(&swap_store, &swap_id.to_le_bytes())?
+        .ok_or_else(|| StdError::generic_err(format!("Swap {} not found", swap_id)))?;
+    if swap.counterparty != sender_raw {
+        return Err(StdError::generic_err(format!(
+            "Only the counterparty may accept swap {}",
+            swap_id
+        )));
+    }
+    if swap.expires.is_expired(&env.block) {
+        return Err(StdError::generic_err(format!(
+            "Swap {} has expired",
+            swap_id
+        )));
+    }
+    remove(&mut swap_store, &swap_id.to_le_bytes());
+    let proposer = deps.api.addr_humanize(&swap.proposer)?;
+    let mut messages: Vec<CosmosMsg> = Vec::new();
+    let mut offered_ids: Vec<String> = Vec::new();
+    let mut requested_ids: Vec<String> = Vec::new();
+    for (tokens, giver, recipient, local_ids) in [
+        (swap.offered, &swap.proposer, sender, &mut offered_ids),
+        (
+            swap.requested,
+            &swap.counterparty,
+            &proposer,
+            &mut requested_ids,
+        ),
+    ] {
+        for token in tokens.into_iter() {
+            if let Some(contract_raw) = token.contract {
+                let contract = deps.api.addr_humanize(&contract_raw)?;
+                if depositor(deps.storage, &contract_raw, &token.token_id)?.as_ref() != Some(giver)
+                {
+                    return Err(StdError::generic_err(format!(
+                        "Token {} of contract {} is not deposited by {}",
+                        token.token_id,
+                        contract,
+                        deps.api.addr_humanize(giver)?
+                    )));
+                }
+                remove_deposit(deps.storage, &contract_raw, &token.token_id);
+                let code_hash = swap_contract_hash(deps.storage, &contract_raw, contract.as_str())?;
+                messages.push(
+                    Snip721HandleMsg::BatchTransferNft {
+                        transfers: vec![Transfer {
+                            recipient: recipient.to_string(),
+                            token_ids: vec![token.token_id],
+                            memo: None,
+                        }],
+                        padding: None,
+                    }
+                    .to_cosmos_msg(code_hash, contract.to_string(), None)?,
+                );
+            } else {
+                local_ids.push(token.token_id);
+            }
+        }
+    }
+    if !swap.sweetener.is_empty() {
+        messages.push(CosmosMsg::Bank(BankMsg::Send {
+            to_address: sender.to_string(),
+            amount: swap.sweetener,
+        }));
+    }
+    // each side must still own the tokens of this contract it gives.  The transfers only check
+    // transfer permission, which an operator of another owner would also have
+    for (owner_raw, token_ids, not_owned) in [
+        (
+            &swap.proposer,
+            &offered_ids,
+            format!("The proposer of swap {} no longer owns token", swap_id),
+        ),
+        (
+            &sender_raw,
+            &requested_ids,
+            "You do not own token".to_string(),
+        ),
+    ] {
+        for token_id in token_ids.iter() {
+            let err = format!("{} {}", not_owned, token_id);
+            let (token, _) = get_token(deps.storage, token_id, Some(&err))?;
+            if token.owner != *owner_raw {
+                return Err(StdError::generic_err(err));
+            }
+        }
+    }
+    for (owner, recipient, token_ids) in [
+        (&proposer, sender, offered_ids),
+        (sender, &proposer, requested_ids),
+    ] {
+        if !token_ids.is_empty() {
+            let transfers = Some(vec![Transfer {
+                recipient: recipient.to_string(),
+                token_ids,
+                memo: None,
+            }]);
+            send_list(deps.branch(), env, owner, config, transfers, None)?;
+        }
+    }
+    Ok(Response::new()
         .add_messages(messages)
-        .set_data(to_binary(&ExecuteAnswer::SendNft { status: Success })?);
-    Ok(res)
+        .set_data(to_binary(&ExecuteAnswer::AcceptSwap { status: Success })?))
 }
 
 /// Returns StdResult<Response>
 ///
-/// registers a contract's ReceiveNft
+/// cancels a swap and returns the sweetener to the proposer
 ///
 /// # Arguments
 ///
@@ -1376,65 +5247,119 @@ fn send_nft(
 /// * `sender` - a reference to the message sender address
 /// * `config` - a reference to the Config
 /// * `priority` - u8 representation of highest ContractStatus level this action is permitted
-/// * `code_hash` - code hash String of the registering contract
-/// * `impl_batch` - optionally true if the contract also implements BatchReceiveNft
-pub fn register_receive_nft(
+/// * `swap_id` - id of the swap
+pub fn cancel_swap(
     deps: DepsMut,
     sender: &Addr,
     config: &Config,
     priority: u8,
-    code_hash: String,
-    impl_batch: Option<bool>,
+    swap_id: u64,
 ) -> StdResult<Response> {
     check_status(config.status, priority)?;
     let sender_raw = deps.api.addr_canonicalize(sender.as_str())?;
-    let regrec = ReceiveRegistration {
-        code_hash,
-        impl_batch: impl_batch.unwrap_or(false),
-    };
-    let mut store = PrefixedStorage::new(deps.storage, PREFIX_RECEIVERS);
-    save(&mut store, sender_raw.as_slice(), &regrec)?;
-    let res = Response::new().set_data(to_binary(&ExecuteAnswer::RegisterReceiveNft {
-        status: Success,
-    })?);
-    Ok(res)
+    let mut swap_store = PrefixedStorage::new(deps.storage, PREFIX_SWAPS);
+    let swap: StoredSwap = json_may_load(&swap_store, &swap_id.to_le_bytes())?
+        .ok_or_else(|| StdError::generic_err(format!("Swap {} not found", swap_id)))?;
+    if swap.proposer != sender_raw && swap.counterparty != sender_raw {
+        return Err(StdError::generic_err(format!(
+            "Only the proposer or the counterparty may cancel swap {}",
+            swap_id
+        )));
+    }
+    remove(&mut swap_store, &swap_id.to_le_bytes());
+    let mut resp = Response::new();
+    if !swap.sweetener.is_empty() {
+        resp = resp.add_message(BankMsg::Send {
+            to_address: deps.api.addr_humanize(&swap.proposer)?.to_string(),
+            amount: swap.sweetener,
+        });
+    }
+    Ok(resp.set_data(to_binary(&ExecuteAnswer::CancelSwap { status: Success })?))
 }
 
-/// Returns StdResult<Response>
-///
-/// creates a viewing key
+/// Returns StdResult<String> of the code hash of a contract registered for swaps
 ///
 /// # Arguments
 ///
-/// * `deps` - mutable reference to Extern containing all the contract's external dependencies
-/// * `env` - a reference to the Env of contract's environment
-/// * `info` - contract execution info for authorization - identity of the call, and payment.
-/// * `config` - a reference to the Config
-/// * `priority` - u8 representation of highest ContractStatus level this action is permitted
-/// * `entropy` - string slice of the input String to be used as entropy in randomization
-pub fn create_key(
-    deps: DepsMut,
-    env: &Env,
-    info: &MessageInfo,
-    config: &Config,
-    priority: u8,
-    entropy: &str,
-) -> StdResult<Response> {
-    check_status(config.status, priority)?;
-    let key = ViewingKey::create(
-        deps.storage,
-        info,
-        env,
-        info.sender.as_str(),
-        entropy.as_ref(),
-    );
+/// * `storage` - a reference to the contract's storage
+/// * `contract` - a reference to the contract's canonical address
+/// * `contract_human` - string slice of the contract's address for the error message
+fn swap_contract_hash(
+    storage: &dyn Storage,
+    contract: &CanonicalAddr,
+    contract_human: &str,
+) -> StdResult<String> {
+    let hash_store = ReadonlyPrefixedStorage::new(storage, PREFIX_SWAP_CONTRACTS);
+    may_load(&hash_store, contract.as_slice())?.ok_or_else(|| {
+        StdError::generic_err(format!(
+            "Contract {} is not registered for swaps",
+            contract_human
+        ))
+    })
+}
 
-    Ok(Response::new().set_data(to_binary(&ExecuteAnswer::ViewingKey { key })?))
+/// Returns StdResult<Vec<StoredSwapToken>> from converting the tokens of a swap for storage,
+/// checking that other contracts are registered and, if an owner is given, that the owner
+/// owns or deposited each token
+///
+/// # Arguments
+///
+/// * `deps` - a reference to Extern containing all the contract's external dependencies
+/// * `tokens` - the tokens of the swap
+/// * `owner` - optional reference to the address that must own the tokens
+fn stored_swap_tokens(
+    deps: Deps,
+    tokens: Vec<SwapToken>,
+    owner: Option<&CanonicalAddr>,
+) -> StdResult<Vec<StoredSwapToken>> {
+    tokens
+        .into_iter()
+        .map(|token| {
+            let contract = token
+                .contract
+                .map(|c| deps.api.addr_validate(&c))
+                .transpose()?;
+            let contract_raw = contract
+                .as_ref()
+                .map(|c| deps.api.addr_canonicalize(c.as_str()))
+                .transpose()?;
+            if let (Some(c), Some(c_raw)) = (&contract, &contract_raw) {
+                swap_contract_hash(deps.storage, c_raw, c.as_str())?;
+            }
+            if let Some(owner) = owner {
+                match (&contract, &contract_raw) {
+                    (Some(c), Some(c_raw)) => {
+                        if depositor(deps.storage, c_raw, &token.token_id)?.as_ref() != Some(owner)
+                        {
+                            return Err(StdError::generic_err(format!(
+                                "You have not deposited token {} of contract {}",
+                                token.token_id, c
+                            )));
+                        }
+                    }
+                    _ => {
+                        // don't leak whether a token the owner does not own exists
+                        let custom_err = format!("You do not own token {}", token.token_id);
+                        let (stored, _) =
+                            get_token(deps.storage, &token.token_id, Some(&custom_err))?;
+                        if stored.owner != *owner {
+                            return Err(StdError::generic_err(custom_err));
+                        }
+                    }
+                }
+            }
+            Ok(StoredSwapToken {
+                contract: contract_raw,
+                token_id: token.token_id,
+            })
+        })
+        .collect()
 }
 
 /// Returns StdResult<Response>
 ///
-/// sets the viewing key to the input String
+/// sends all of the public sale's native and SNIP-20 proceeds to the admin or an optional
+/// recipient
 ///
 /// # Arguments
 ///
@@ -1442,22 +5367,62 @@ pub fn create_key(
 /// * `sender` - a reference to the message sender address
 /// * `config` - a reference to the Config
 /// * `priority` - u8 representation of highest ContractStatus level this action is permitted
-/// * `key` - String to be used as the viewing key
-pub fn set_key(
+/// * `recipient` - optional address to send the proceeds to
+pub fn withdraw_proceeds(
     deps: DepsMut,
     sender: &Addr,
     config: &Config,
     priority: u8,
-    key: String,
+    recipient: Option<&str>,
 ) -> StdResult<Response> {
     check_status(config.status, priority)?;
-    ViewingKey::set(deps.storage, sender.as_str(), &key);
-    Ok(Response::new().set_data(to_binary(&ExecuteAnswer::ViewingKey { key })?))
+    let sender_raw = deps.api.addr_canonicalize(sender.as_str())?;
+    if config.admin != sender_raw {
+        return Err(StdError::generic_err(
+            "This is an admin command and can only be run from the admin address",
+        ));
+    }
+    let to_address = recipient
+        .map(|r| deps.api.addr_validate(r))
+        .transpose()?
+        .unwrap_or_else(|| sender.clone());
+    let mut messages: Vec<CosmosMsg> = Vec::new();
+    let proceeds: Vec<Coin> = may_load(deps.storage, SALE_PROCEEDS_KEY)?.unwrap_or_default();
+    if !proceeds.is_empty() {
+        messages.push(CosmosMsg::Bank(BankMsg::Send {
+            to_address: to_address.to_string(),
+            amount: proceeds,
+        }));
+        remove(deps.storage, SALE_PROCEEDS_KEY);
+    }
+    let may_payments: Option<Vec<StoredSnip20Payment>> =
+        may_load(deps.storage, SNIP20_PAYMENTS_KEY)?;
+    if let Some(mut payments) = may_payments {
+        for payment in payments.iter_mut().filter(|p| !p.proceeds.is_zero()) {
+            messages.push(transfer_msg(
+                to_address.to_string(),
+                payment.proceeds,
+                None,
+                None,
+                BLOCK_SIZE,
+                payment.code_hash.clone(),
+                deps.api.addr_humanize(&payment.address)?.to_string(),
+            )?);
+            payment.proceeds = Uint128::zero();
+        }
+        save(deps.storage, SNIP20_PAYMENTS_KEY, &payments)?;
+    }
+    if messages.is_empty() {
+        return Err(StdError::generic_err("There are no proceeds to withdraw"));
+    }
+    Ok(Response::new().add_messages(messages).set_data(to_binary(
+        &ExecuteAnswer::WithdrawProceeds { status: Success },
+    )?))
 }
 
 /// Returns StdResult<Response>
 ///
-/// add a list of minters
+/// propose a new admin address, replacing any previous proposal
 ///
 /// # Arguments
 ///
@@ -1465,13 +5430,15 @@ pub fn set_key(
 /// * `sender` - a reference to the message sender address
 /// * `config` - a reference to the Config
 /// * `priority` - u8 representation of highest ContractStatus level this action is permitted
-/// * `new_minters` - list of minter addresses to add
-pub fn add_minters(
+/// * `address` - proposed admin address
+/// * `expiration` - optional Expiration for the proposal
+pub fn propose_admin(
     deps: DepsMut,
     sender: &Addr,
     config: &Config,
     priority: u8,
-    new_minters: &[String],
+    address: &str,
+    expiration: Option<Expiration>,
 ) -> StdResult<Response> {
     check_status(config.status, priority)?;
     let sender_raw = deps.api.addr_canonicalize(sender.as_str())?;
@@ -1480,79 +5447,52 @@ pub fn add_minters(
             "This is an admin command and can only be run from the admin address",
         ));
     }
-    let mut minters: Vec<CanonicalAddr> = may_load(deps.storage, MINTERS_KEY)?.unwrap_or_default();
-    let mut update = false;
-    for minter in new_minters {
-        let minter_raw = deps
+    let proposed = PendingAdmin {
+        address: deps
             .api
-            .addr_canonicalize(deps.api.addr_validate(minter)?.as_str())?;
-        if !minters.contains(&minter_raw) {
-            minters.push(minter_raw);
-            update = true;
-        }
-    }
-    // only save if the list changed
-    if update {
-        save(deps.storage, MINTERS_KEY, &minters)?;
-    }
-    Ok(Response::new().set_data(to_binary(&ExecuteAnswer::AddMinters { status: Success })?))
+            .addr_canonicalize(deps.api.addr_validate(address)?.as_str())?,
+        expiration: expiration.unwrap_or_default(),
+    };
+    json_save(deps.storage, PENDING_ADMIN_KEY, &proposed)?;
+    Ok(Response::new().set_data(to_binary(&ExecuteAnswer::ProposeAdmin { status: Success })?))
 }
 
 /// Returns StdResult<Response>
 ///
-/// remove a list of minters
+/// make the message sender the admin if they have an unexpired admin proposal
 ///
 /// # Arguments
 ///
 /// * `deps` - mutable reference to Extern containing all the contract's external dependencies
+/// * `env` - a reference to the Env of contract's environment
 /// * `sender` - a reference to the message sender address
-/// * `config` - a reference to the Config
+/// * `config` - a mutable reference to the Config
 /// * `priority` - u8 representation of highest ContractStatus level this action is permitted
-/// * `no_minters` - list of minter addresses to remove
-pub fn remove_minters(
+pub fn accept_admin(
     deps: DepsMut,
+    env: &Env,
     sender: &Addr,
-    config: &Config,
+    config: &mut Config,
     priority: u8,
-    no_minters: &[String],
 ) -> StdResult<Response> {
     check_status(config.status, priority)?;
     let sender_raw = deps.api.addr_canonicalize(sender.as_str())?;
-    if config.admin != sender_raw {
-        return Err(StdError::generic_err(
-            "This is an admin command and can only be run from the admin address",
-        ));
+    let pending: Option<PendingAdmin> = json_may_load(deps.storage, PENDING_ADMIN_KEY)?;
+    let proposal = pending
+        .filter(|p| p.address == sender_raw)
+        .ok_or_else(|| StdError::generic_err("You have not been proposed as the new admin"))?;
+    if proposal.expiration.is_expired(&env.block) {
+        return Err(StdError::generic_err("The admin proposal has expired"));
     }
-    let may_minters: Option<Vec<CanonicalAddr>> = may_load(deps.storage, MINTERS_KEY)?;
-    if let Some(mut minters) = may_minters {
-        let old_len = minters.len();
-        let no_raw: Vec<CanonicalAddr> = no_minters
-            .iter()
-            .map(|x| {
-                deps.api
-                    .addr_canonicalize(deps.api.addr_validate(x)?.as_str())
-            })
-            .collect::<StdResult<Vec<CanonicalAddr>>>()?;
-        minters.retain(|m| !no_raw.contains(m));
-        let new_len = minters.len();
-        if new_len > 0 {
-            if old_len != new_len {
-                save(deps.storage, MINTERS_KEY, &minters)?;
-            }
-        } else {
-            remove(deps.storage, MINTERS_KEY);
-        }
-    }
-    Ok(
-        Response::new().set_data(to_binary(&ExecuteAnswer::RemoveMinters {
-            status: Success,
-        })?),
-    )
+    config.admin = sender_raw;
+    save(deps.storage, CONFIG_KEY, &config)?;
+    remove(deps.storage, PENDING_ADMIN_KEY);
+    Ok(Response::new().set_data(to_binary(&ExecuteAnswer::AcceptAdmin { status: Success })?))
 }
 
 /// Returns StdResult<Response>
 ///
-/// define the exact list of minters
+/// cancel the pending admin proposal
 ///
 /// # Arguments
 ///
@@ -1560,13 +5500,11 @@ pub fn remove_minters(
 /// * `sender` - a reference to the message sender address
 /// * `config` - a reference to the Config
 /// * `priority` - u8 representation of highest ContractStatus level this action is permitted
-/// * `human_minters` - exact list of minter addresses
-pub fn set_minters(
+pub fn cancel_admin_proposal(
     deps: DepsMut,
     sender: &Addr,
     config: &Config,
     priority: u8,
-    human_minters: &[String],
 ) -> StdResult<Response> {
     check_status(config.status, priority)?;
     let sender_raw = deps.api.addr_canonicalize(sender.as_str())?;
@@ -1575,32 +5513,17 @@ pub fn set_minters(
             "This is an admin command and can only be run from the admin address",
         ));
     }
-    // remove duplicates from the minters list
-    let minters_raw: Vec<CanonicalAddr> = human_minters
-        .iter()
-        .map(|x| {
-            deps.api
-                .addr_canonicalize(deps.api.addr_validate(x)?.as_str())
-        })
-        .collect::<StdResult<Vec<CanonicalAddr>>>()?;
-    let mut sortable: Vec<&[u8]> = minters_raw.iter().map(|x| x.as_slice()).collect();
-    sortable.sort_unstable();
-    sortable.dedup();
-    let minters: Vec<CanonicalAddr> = sortable
-        .iter()
-        .map(|x| CanonicalAddr(Binary(x.to_vec())))
-        .collect();
-    if minters.is_empty() {
-        remove(deps.storage, MINTERS_KEY);
-    } else {
-        save(deps.storage, MINTERS_KEY, &minters)?;
-    }
-    Ok(Response::new().set_data(to_binary(&ExecuteAnswer::SetMinters { status: Success })?))
+    remove(deps.storage, PENDING_ADMIN_KEY);
+    Ok(
+        Response::new().set_data(to_binary(&ExecuteAnswer::CancelAdminProposal {
+            status: Success,
+        })?),
+    )
 }
 
 /// Returns StdResult<Response>
 ///
-/// change the admin address
+/// change the config flags, enforcing any one-way ratchets, and add new ratchets
 ///
 /// # Arguments
 ///
@@ -1608,13 +5531,15 @@ pub fn set_minters(
 /// * `sender` - a reference to the message sender address
 /// * `config` - a mutable reference to the Config
 /// * `priority` - u8 representation of highest ContractStatus level this action is permitted
-/// * `address` - new admin address
-pub fn change_admin(
+/// * `updates` - new values for the config flags
+/// * `new_ratchets` - optional ratchets to add
+pub fn update_config(
     deps: DepsMut,
     sender: &Addr,
     config: &mut Config,
     priority: u8,
-    address: &str,
+    updates: InstantiateConfig,
+    new_ratchets: Option<ConfigRatchets>,
 ) -> StdResult<Response> {
     check_status(config.status, priority)?;
     let sender_raw = deps.api.addr_canonicalize(sender.as_str())?;
@@ -1623,14 +5548,195 @@ pub fn change_admin(
             "This is an admin command and can only be run from the admin address",
         ));
     }
-    let new_admin = deps
-        .api
-        .addr_canonicalize(deps.api.addr_validate(address)?.as_str())?;
-    if new_admin != config.admin {
-        config.admin = new_admin;
+    let mut ratchets: ConfigRatchets =
+        json_may_load(deps.storage, RATCHETS_KEY)?.unwrap_or_default();
+    // sealed tokens could never be revealed if sealed metadata were disabled
+    if config.sealed_metadata_is_enabled && updates.enable_sealed_metadata == Some(false) {
+        return Err(StdError::generic_err(
+            "Sealed metadata can not be disabled once it has been enabled",
+        ));
+    }
+    let mut changed: Vec<String> = Vec::new();
+    // flags are checked against the ratchets in place before this message
+    update_flag(
+        "public_token_supply",
+        &mut config.token_supply_is_public,
+        updates.public_token_supply,
+        ratchets.public_token_supply,
+        &mut changed,
+    )?;
+    update_flag(
+        "public_owner",
+        &mut config.owner_is_public,
+        updates.public_owner,
+        ratchets.public_owner,
+        &mut changed,
+    )?;
+    update_flag(
+        "enable_sealed_metadata",
+        &mut config.sealed_metadata_is_enabled,
+        updates.enable_sealed_metadata,
+        ratchets.enable_sealed_metadata,
+        &mut changed,
+    )?;
+    update_flag(
+        "unwrapped_metadata_is_private",
+        &mut config.unwrap_to_private,
+        updates.unwrapped_metadata_is_private,
+        ratchets.unwrapped_metadata_is_private,
+        &mut changed,
+    )?;
+    update_flag(
+        "minter_may_update_metadata",
+        &mut config.minter_may_update_metadata,
+        updates.minter_may_update_metadata,
+        ratchets.minter_may_update_metadata,
+        &mut changed,
+    )?;
+    update_flag(
+        "owner_may_update_metadata",
+        &mut config.owner_may_update_metadata,
+        updates.owner_may_update_metadata,
+        ratchets.owner_may_update_metadata,
+        &mut changed,
+    )?;
+    update_flag(
+        "enable_burn",
+        &mut config.burn_is_enabled,
+        updates.enable_burn,
+        ratchets.enable_burn,
+        &mut changed,
+    )?;
+    let mut ratcheted: Vec<String> = Vec::new();
+    if let Some(new) = new_ratchets {
+        add_ratchet(
+            "public_token_supply",
+            &mut ratchets.public_token_supply,
+            new.public_token_supply,
+            &mut ratcheted,
+        )?;
+        add_ratchet(
+            "public_owner",
+            &mut ratchets.public_owner,
+            new.public_owner,
+            &mut ratcheted,
+        )?;
+        add_ratchet(
+            "enable_sealed_metadata",
+            &mut ratchets.enable_sealed_metadata,
+            new.enable_sealed_metadata,
+            &mut ratcheted,
+        )?;
+        add_ratchet(
+            "unwrapped_metadata_is_private",
+            &mut ratchets.unwrapped_metadata_is_private,
+            new.unwrapped_metadata_is_private,
+            &mut ratcheted,
+        )?;
+        add_ratchet(
+            "minter_may_update_metadata",
+            &mut ratchets.minter_may_update_metadata,
+            new.minter_may_update_metadata,
+            &mut ratcheted,
+        )?;
+        add_ratchet(
+            "owner_may_update_metadata",
+            &mut ratchets.owner_may_update_metadata,
+            new.owner_may_update_metadata,
+            &mut ratcheted,
+        )?;
+        add_ratchet(
+            "enable_burn",
+            &mut ratchets.enable_burn,
+            new.enable_burn,
+            &mut ratcheted,
+        )?;
+    }
+    if !changed.is_empty() {
         save(deps.storage, CONFIG_KEY, &config)?;
     }
-    Ok(Response::new().set_data(to_binary(&ExecuteAnswer::ChangeAdmin { status: Success })?))
+    if !ratcheted.is_empty() {
+        json_save(deps.storage, RATCHETS_KEY, &ratchets)?;
+    }
+    Ok(
+        Response::new().set_data(to_binary(&ExecuteAnswer::UpdateConfig {
+            status: Success,
+            changed,
+            ratcheted,
+        })?),
+    )
+}
+
+/// Returns StdResult<()>
+///
+/// sets a config flag to its new value if the flag's ratchet allows it
+///
+/// # Arguments
+///
+/// * `name` - name of the flag
+/// * `flag` - a mutable reference to the flag's current value
+/// * `new_value` - optional new value of the flag
+/// * `ratchet` - the flag's optional one-way restriction
+/// * `changed` - a mutable reference to the list of names of changed flags
+fn update_flag(
+    name: &str,
+    flag: &mut bool,
+    new_value: Option<bool>,
+    ratchet: Option<Ratchet>,
+    changed: &mut Vec<String>,
+) -> StdResult<()> {
+    if let Some(value) = new_value {
+        if value != *flag {
+            let allowed = match ratchet {
+                Some(Ratchet::OnlyEnable) => value,
+                Some(Ratchet::OnlyDisable) => !value,
+                None => true,
+            };
+            if !allowed {
+                return Err(StdError::generic_err(format!(
+                    "{} is one-way and can not be set to {}",
+                    name, value
+                )));
+            }
+            *flag = value;
+            changed.push(name.to_string());
+        }
+    }
+    Ok(())
+}
+
+/// Returns StdResult<()>
+///
+/// gives a config flag a one-way ratchet.  An existing ratchet can not be changed
+///
+/// # Arguments
+///
+/// * `name` - name of the flag
+/// * `ratchet` - a mutable reference to the flag's current ratchet
+/// * `new_ratchet` - optional ratchet to add
+/// * `ratcheted` - a mutable reference to the list of names of newly ratcheted flags
+fn add_ratchet(
+    name: &str,
+    ratchet: &mut Option<Ratchet>,
+    new_ratchet: Option<Ratchet>,
+    ratcheted: &mut Vec<String>,
+) -> StdResult<()> {
+    if let Some(new) = new_ratchet {
+        match ratchet {
+            None => {
+                *ratchet = Some(new);
+                ratcheted.push(name.to_string());
+            }
+            Some(old) if *old != new => {
+                return Err(StdError::generic_err(format!(
+                    "{} is already one-way and its ratchet can not be changed",
+                    name
+                )));
+            }
+            _ => (),
+        }
+    }
+    Ok(())
 }
 
 /// Returns StdResult<Response>
@@ -1650,12 +5756,20 @@ pub fn set_contract_status(
     level: ContractStatus,
 ) -> StdResult<Response> {
     let sender_raw = deps.api.addr_canonicalize(sender.as_str())?;
+    let new_status = level.to_u8();
     if config.admin != sender_raw {
-        return Err(StdError::generic_err(
-            "This is an admin command and can only be run from the admin address",
-        ));
+        // pausers may only make the status more restrictive
+        if !load_role(deps.storage, Role::Pauser)?.contains(&sender_raw) {
+            return Err(StdError::generic_err(
+                "This is an admin command and can only be run from the admin address",
+            ));
+        }
+        if new_status < config.status {
+            return Err(StdError::generic_err(
+                "Pausers may only make the contract status more restrictive",
+            ));
+        }
     }
-    let new_status = level.to_u8();
     if config.status != new_status {
         config.status = new_status;
         save(deps.storage, CONFIG_KEY, &config)?;
@@ -1709,7 +5823,42 @@ pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> StdResult<Binary> {
         }
         QueryMsg::ContractConfig {} => query_config(deps.storage),
         QueryMsg::Minters {} => query_minters(deps),
+        QueryMsg::Roles {} => query_roles(deps),
+        QueryMsg::MinterAllowance { minter } => query_minter_allowance(deps, &env.block, &minter),
+        QueryMsg::PendingAdmin {} => query_pending_admin(deps, &env.block),
+        QueryMsg::Sale {} => query_sale(deps.storage, deps.api),
+        QueryMsg::MintPhases {} => query_mint_phases(deps.storage),
+        QueryMsg::VoucherSigner {} => query_voucher_signer(deps.storage),
+        QueryMsg::RevealPool {} => query_reveal_pool(deps.storage),
+        QueryMsg::RevealSchedule {} => query_reveal_schedule(deps.storage, &env.block),
+        QueryMsg::Recipes {} => query_recipes(deps.storage),
         QueryMsg::NumTokens { viewer } => query_num_tokens(deps, viewer, None),
+        QueryMsg::NumBurned { viewer } => query_num_burned(deps, viewer, None),
+        QueryMsg::RemainingSupply {
+            mint_run_id,
+            viewer,
+        } => query_remaining_supply(deps, mint_run_id.as_deref(), viewer, None),
+        QueryMsg::MintRuns {
+            mint_run_id,
+            viewer,
+            start_after,
+            limit,
+        } => query_mint_runs(deps, &mint_run_id, viewer, start_after, limit, None),
+        QueryMsg::TokensInMintRun {
+            mint_run_id,
+            mint_run,
+            viewer,
+            start_after,
+            limit,
+        } => query_tokens_in_mint_run(
+            deps,
+            &mint_run_id,
+            mint_run,
+            viewer,
+            start_after.as_deref(),
+            limit,
+            None,
+        ),
         QueryMsg::AllTokens {
             viewer,
             start_after,
@@ -1720,7 +5869,7 @@ pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> StdResult<Binary> {
             viewer,
             include_expired,
         } => query_owner_of(deps, &env.block, &token_id, viewer, include_expired, None),
-        QueryMsg::NftInfo { token_id } => query_nft_info(deps.storage, &token_id),
+        QueryMsg::NftInfo { token_id } => query_nft_info(deps.storage, &env.block, &token_id),
         QueryMsg::PrivateMetadata { token_id, viewer } => {
             query_private_meta(deps, &env.block, &token_id, viewer, None)
         }
@@ -1813,14 +5962,51 @@ pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> StdResult<Binary> {
             });
             query_verify_approval(deps, &env.block, token_ids, viewer, None)
         }
-        QueryMsg::IsUnwrapped { token_id } => query_is_unwrapped(deps.storage, &token_id),
-        QueryMsg::IsTransferable { token_id } => query_is_transferable(deps.storage, &token_id),
+        QueryMsg::IsUnwrapped { token_id } => {
+            query_is_unwrapped(deps.storage, &env.block, &token_id)
+        }
+        QueryMsg::IsTransferable { token_id } => query_is_transferable(deps, &token_id),
+        QueryMsg::IsLocked { token_id } => query_is_locked(deps, &env.block, &token_id),
+        QueryMsg::Listings { start_after, limit } => {
+            query_listings(deps, start_after.as_deref(), limit)
+        }
+        QueryMsg::Auction { token_id } => query_auction(deps, &env.block, token_id),
+        QueryMsg::UserOf { token_id, viewer } => {
+            query_user_of(deps, &env.block, &token_id, viewer, None)
+        }
+        QueryMsg::LockInfo { token_id, viewer } => {
+            query_lock_info(deps, &env.block, &token_id, viewer, None)
+        }
+        QueryMsg::TokenOffers {
+            token_id,
+            viewer,
+            start_after,
+            limit,
+        } => query_token_offers(deps, &token_id, viewer, start_after, limit, None),
+        QueryMsg::BuyerOffers {
+            viewer,
+            start_after,
+            limit,
+        } => query_buyer_offers(deps, Some(viewer), start_after, limit, None),
+        QueryMsg::OfferSnip20s {} => query_offer_snip20s(deps),
+        QueryMsg::Swap { swap_id, viewer } => query_swap(deps, swap_id, Some(viewer), None),
         QueryMsg::ImplementsNonTransferableTokens {} => {
             to_binary(&QueryAnswer::ImplementsNonTransferableTokens { is_enabled: true })
         }
         QueryMsg::ImplementsTokenSubtype {} => {
             to_binary(&QueryAnswer::ImplementsTokenSubtype { is_enabled: true })
         }
+        QueryMsg::PhaseUsage {
+            phase,
+            address,
+            viewing_key,
+        } => {
+            let viewer = Some(ViewerInfo {
+                address,
+                viewing_key,
+            });
+            query_phase_usage(deps, &phase, viewer, None)
+        }
         QueryMsg::TransactionHistory {
             address,
             viewing_key,
@@ -1902,10 +6088,51 @@ pub fn permit_queries(
         QueryWithPermit::VerifyTransferApproval { token_ids } => {
             query_verify_approval(deps, block, token_ids, None, Some(querier))
         }
+        QueryWithPermit::PhaseUsage { phase } => {
+            query_phase_usage(deps, &phase, None, Some(querier))
+        }
         QueryWithPermit::TransactionHistory { page, page_size } => {
             query_transactions(deps, None, page, page_size, Some(querier))
         }
         QueryWithPermit::NumTokens {} => query_num_tokens(deps, None, Some(querier)),
+        QueryWithPermit::NumBurned {} => query_num_burned(deps, None, Some(querier)),
+        QueryWithPermit::LockInfo { token_id } => {
+            query_lock_info(deps, block, &token_id, None, Some(querier))
+        }
+        QueryWithPermit::UserOf { token_id } => {
+            query_user_of(deps, block, &token_id, None, Some(querier))
+        }
+        QueryWithPermit::TokenOffers {
+            token_id,
+            start_after,
+            limit,
+        } => query_token_offers(deps, &token_id, None, start_after, limit, Some(querier)),
+        QueryWithPermit::BuyerOffers { start_after, limit } => {
+            query_buyer_offers(deps, None, start_after, limit, Some(querier))
+        }
+        QueryWithPermit::Swap { swap_id } => query_swap(deps, swap_id, None, Some(querier)),
+        QueryWithPermit::RemainingSupply { mint_run_id } => {
+            query_remaining_supply(deps, mint_run_id.as_deref(), None, Some(querier))
+        }
+        QueryWithPermit::MintRuns {
+            mint_run_id,
+            start_after,
+            limit,
+        } => query_mint_runs(deps, &mint_run_id, None, start_after, limit, Some(querier)),
+        QueryWithPermit::TokensInMintRun {
+            mint_run_id,
+            mint_run,
+            start_after,
+            limit,
+        } => query_tokens_in_mint_run(
+            deps,
+            &mint_run_id,
+            mint_run,
+            None,
+            start_after.as_deref(),
+            limit,
+            Some(querier),
+        ),
         QueryWithPermit::AllTokens { start_after, limit } => {
             query_all_tokens(deps, None, start_after.as_deref(), limit, Some(querier))
         }
@@ -2004,11 +6231,13 @@ pub fn query_royalty(
             // token id not found
         } else {
             let config: Config = load(deps.storage, CONFIG_KEY)?;
-            let minters: Vec<CanonicalAddr> =
-                may_load(deps.storage, MINTERS_KEY)?.unwrap_or_default();
-            let is_minter = viewer_raw.map(|v| minters.contains(&v)).unwrap_or(false);
-            // if minter querying or the token supply is public, let them know the token does not exist
-            if config.token_supply_is_public || is_minter {
+            let may_view_supply = viewer_raw
+                .map(|v| holds_any_role(deps.storage, &v, &[Role::Minter, Role::Auditor]))
+                .transpose()?
+                .unwrap_or(false);
+            // if minter or auditor querying or the token supply is public, let them know the token
+            // does not exist
+            if config.token_supply_is_public || may_view_supply {
                 return Err(StdError::generic_err(format!("Token ID: {} not found", id)));
             }
             // token supply is private and querier is not a minter so just show the default without addresses
@@ -2019,11 +6248,20 @@ pub fn query_royalty(
         }
         // no id specified, so get the default
     } else {
-        let minters: Vec<CanonicalAddr> = may_load(deps.storage, MINTERS_KEY)?.unwrap_or_default();
-        // only let minters view default royalty addresses
+        // only let minters, royalty managers, and auditors view default royalty addresses
+        let may_view_addrs = viewer_raw
+            .map(|v| {
+                holds_any_role(
+                    deps.storage,
+                    &v,
+                    &[Role::Minter, Role::RoyaltyManager, Role::Auditor],
+                )
+            })
+            .transpose()?
+            .unwrap_or(false);
         (
             may_load::<StoredRoyaltyInfo>(deps.storage, DEFAULT_ROYALTY_KEY)?,
-            viewer_raw.map(|v| !minters.contains(&v)).unwrap_or(true),
+            !may_view_addrs,
         )
     };
     to_binary(&QueryAnswer::RoyaltyInfo {
@@ -2051,6 +6289,8 @@ pub fn query_config(storage: &dyn Storage) -> StdResult<Binary> {
         burn_is_enabled: config.burn_is_enabled,
         implements_non_transferable_tokens: true,
         implements_token_subtype: true,
+        ratchets: json_may_load(storage, RATCHETS_KEY)?.unwrap_or_default(),
+        token_id_scheme: json_may_load(storage, TOKEN_ID_SCHEME_KEY)?,
     })
 }
 
@@ -2060,7 +6300,7 @@ pub fn query_config(storage: &dyn Storage) -> StdResult<Binary> {
 ///
 /// * `deps` - a reference to Extern containing all the contract's external dependencies
 pub fn query_minters(deps: Deps) -> StdResult<Binary> {
-    let minters: Vec<CanonicalAddr> = may_load(deps.storage, MINTERS_KEY)?.unwrap_or_default();
+    let minters = load_role(deps.storage, Role::Minter)?;
 
     to_binary(&QueryAnswer::Minters {
         minters: minters
@@ -2070,6 +6310,213 @@ pub fn query_minters(deps: Deps) -> StdResult<Binary> {
     })
 }
 
+/// Returns StdResult<Binary> displaying the addresses holding each role
+///
+/// # Arguments
+///
+/// * `deps` - a reference to Extern containing all the contract's external dependencies
+pub fn query_roles(deps: Deps) -> StdResult<Binary> {
+    let roles = Role::ALL
+        .iter()
+        .map(|role| {
+            Ok(RoleHolders {
+                role: *role,
+                addresses: load_role(deps.storage, *role)?
+                    .iter()
+                    .map(|a| deps.api.addr_humanize(a))
+                    .collect::<StdResult<Vec<Addr>>>()?,
+            })
+        })
+        .collect::<StdResult<Vec<RoleHolders>>>()?;
+    to_binary(&QueryAnswer::Roles { roles })
+}
+
+/// Returns StdResult<Binary> displaying a minter's limits and how much of them remains
+///
+/// # Arguments
+///
+/// * `deps` - a reference to Extern containing all the contract's external dependencies
+/// * `block` - a reference to the current BlockInfo
+/// * `minter` - the minter being queried
+pub fn query_minter_allowance(deps: Deps, block: &BlockInfo, minter: &str) -> StdResult<Binary> {
+    let minter_raw = deps
+        .api
+        .addr_canonicalize(deps.api.addr_validate(minter)?.as_str())?;
+    let quota_store = ReadonlyPrefixedStorage::new(deps.storage, PREFIX_MINTER_QUOTA);
+    let quota: MinterQuota =
+        json_may_load(&quota_store, minter_raw.as_slice())?.unwrap_or_default();
+    let usage_store = ReadonlyPrefixedStorage::new(deps.storage, PREFIX_MINTER_USAGE);
+    let usage: MinterUsage = may_load(&usage_store, minter_raw.as_slice())?.unwrap_or_default();
+    let lifetime_remaining = quota.lifetime.map(|l| l.saturating_sub(usage.total));
+    let window_remaining = quota
+        .window
+        .as_ref()
+        .map(|w| usage.window_remaining(w, block));
+    let window_ends = quota
+        .window
+        .as_ref()
+        .filter(|w| usage.window_is_open(w, block))
+        .map(|w| w.length.end(usage.window_start));
+    to_binary(&QueryAnswer::MinterAllowance {
+        quota,
+        minted: usage.total,
+        lifetime_remaining,
+        window_remaining,
+        window_ends,
+    })
+}
+
+/// Returns StdResult<Binary> displaying the public sale and its progress
+///
+/// # Arguments
+///
+/// * `storage` - a reference to the contract's storage
+/// * `api` - a reference to the Api used to convert human and canonical addresses
+pub fn query_sale(storage: &dyn Storage, api: &dyn Api) -> StdResult<Binary> {
+    let sale: Option<Sale> = json_may_load(storage, SALE_KEY)?;
+    let counts: SaleCounts = may_load(storage, SALE_COUNTS_KEY)?.unwrap_or_default();
+    let payments: Vec<StoredSnip20Payment> =
+        may_load(storage, SNIP20_PAYMENTS_KEY)?.unwrap_or_default();
+    let snip20_prices = payments
+        .into_iter()
+        .filter_map(|p| {
+            p.price.map(|price| {
+                api.addr_humanize(&p.address).map(|address| Snip20Price {
+                    address,
+                    code_hash: p.code_hash,
+                    price,
+                })
+            })
+        })
+        .collect::<StdResult<Vec<Snip20Price>>>()?;
+    to_binary(&QueryAnswer::Sale {
+        sale,
+        sold: counts.sold,
+        pool_remaining: counts.pool_remaining(),
+        snip20_prices,
+    })
+}
+
+/// Returns StdResult<Binary> displaying the allowlist mint phases
+///
+/// # Arguments
+///
+/// * `storage` - a reference to the contract's storage
+pub fn query_mint_phases(storage: &dyn Storage) -> StdResult<Binary> {
+    let phases: Vec<MintPhase> = may_load(storage, MINT_PHASES_KEY)?.unwrap_or_default();
+    to_binary(&QueryAnswer::MintPhases { phases })
+}
+
+/// Returns StdResult<Binary> displaying the public key of the mint voucher signer
+///
+/// # Arguments
+///
+/// * `storage` - a reference to the contract's storage
+pub fn query_voucher_signer(storage: &dyn Storage) -> StdResult<Binary> {
+    let public_key: Option<Binary> = may_load(storage, VOUCHER_SIGNER_KEY)?;
+    to_binary(&QueryAnswer::VoucherSigner { public_key })
+}
+
+/// Returns StdResult<Binary> displaying the reveal pool's committed provenance hash and
+/// progress
+///
+/// # Arguments
+///
+/// * `storage` - a reference to the contract's storage
+pub fn query_reveal_pool(storage: &dyn Storage) -> StdResult<Binary> {
+    let may_pool: Option<RevealPool> = may_load(storage, REVEAL_POOL_KEY)?;
+    let answer = may_pool.map_or(
+        QueryAnswer::RevealPool {
+            provenance_hash: None,
+            size: 0,
+            loaded: 0,
+            remaining: 0,
+            seed_hash: None,
+            seed_revealed: false,
+        },
+        |pool| QueryAnswer::RevealPool {
+            provenance_hash: Some(Binary(pool.provenance_hash)),
+            size: pool.size,
+            loaded: pool.loaded,
+            remaining: pool.remaining,
+            seed_hash: Some(Binary(pool.seed_hash)),
+            seed_revealed: storage.get(REVEAL_SEED_KEY).is_some(),
+        },
+    );
+    to_binary(&answer)
+}
+
+/// Returns StdResult<Binary> displaying when every sealed token will be unwrapped
+///
+/// # Arguments
+///
+/// * `storage` - a reference to the contract's storage
+/// * `block` - a reference to the BlockInfo
+pub fn query_reveal_schedule(storage: &dyn Storage, block: &BlockInfo) -> StdResult<Binary> {
+    let at: Option<Expiration> = json_may_load(storage, REVEAL_SCHEDULE_KEY)?;
+    let revealed = at.as_ref().map_or(false, |e| e.is_expired(block));
+    to_binary(&QueryAnswer::RevealSchedule { at, revealed })
+}
+
+/// Returns StdResult<Binary> displaying the burn-to-redeem recipes without the private
+/// metadata of their outputs
+///
+/// # Arguments
+///
+/// * `storage` - a reference to the contract's storage
+pub fn query_recipes(storage: &dyn Storage) -> StdResult<Binary> {
+    let mut recipes: Vec<Recipe> = json_may_load(storage, RECIPES_KEY)?.unwrap_or_default();
+    for recipe in recipes.iter_mut() {
+        recipe.private_metadata = None;
+    }
+    to_binary(&QueryAnswer::Recipes { recipes })
+}
+
+/// Returns StdResult<Binary> displaying the number of tokens the querier minted in a mint
+/// phase
+///
+/// # Arguments
+///
+/// * `deps` - a reference to Extern containing all the contract's external dependencies
+/// * `phase` - name of the mint phase
+/// * `viewer` - optional address and key making an authenticated query request
+/// * `from_permit` - address derived from an Owner permit, if applicable
+pub fn query_phase_usage(
+    deps: Deps,
+    phase: &str,
+    viewer: Option<ViewerInfo>,
+    from_permit: Option<CanonicalAddr>,
+) -> StdResult<Binary> {
+    let address_raw = get_querier(deps, viewer, from_permit)?.ok_or_else(|| {
+        StdError::generic_err("This is being called incorrectly if there is no querier address")
+    })?;
+    let usage_store =
+        ReadonlyPrefixedStorage::multilevel(deps.storage, &[PREFIX_PHASE_USAGE, phase.as_bytes()]);
+    let minted: u32 = may_load(&usage_store, address_raw.as_slice())?.unwrap_or(0);
+    to_binary(&QueryAnswer::PhaseUsage { minted })
+}
+
+/// Returns StdResult<Binary> displaying the proposed admin, if the proposal has not expired
+///
+/// # Arguments
+///
+/// * `deps` - a reference to Extern containing all the contract's external dependencies
+/// * `block` - a reference to the current BlockInfo
+pub fn query_pending_admin(deps: Deps, block: &BlockInfo) -> StdResult<Binary> {
+    let pending: Option<PendingAdmin> = json_may_load(deps.storage, PENDING_ADMIN_KEY)?;
+    let (address, expiration) = match pending.filter(|p| !p.expiration.is_expired(block)) {
+        Some(p) => (
+            Some(deps.api.addr_humanize(&p.address)?),
+            Some(p.expiration),
+        ),
+        None => (None, None),
+    };
+    to_binary(&QueryAnswer::PendingAdmin {
+        address,
+        expiration,
+    })
+}
+
 /// Returns StdResult<Binary> displaying the number of tokens the contract controls
 ///
 /// # Arguments
@@ -2084,9 +6531,173 @@ pub fn query_num_tokens(
 ) -> StdResult<Binary> {
     // authenticate permission to view token supply
     check_view_supply(deps, viewer, from_permit)?;
-    let config: Config = load(deps.storage, CONFIG_KEY)?;
-    to_binary(&QueryAnswer::NumTokens {
-        count: config.token_cnt,
+    let config: Config = load(deps.storage, CONFIG_KEY)?;
+    to_binary(&QueryAnswer::NumTokens {
+        count: config.token_cnt,
+    })
+}
+
+/// Returns StdResult<Binary> displaying the number of tokens that have been burned
+///
+/// # Arguments
+///
+/// * `deps` - a reference to Extern containing all the contract's external dependencies
+/// * `viewer` - optional address and key making an authenticated query request
+/// * `from_permit` - address derived from an Owner permit, if applicable
+pub fn query_num_burned(
+    deps: Deps,
+    viewer: Option<ViewerInfo>,
+    from_permit: Option<CanonicalAddr>,
+) -> StdResult<Binary> {
+    // authenticate permission to view token supply
+    check_view_supply(deps, viewer, from_permit)?;
+    let config: Config = load(deps.storage, CONFIG_KEY)?;
+    // every minted token that is no longer counted was burned, including any burned before
+    // this query existed
+    to_binary(&QueryAnswer::NumBurned {
+        count: config.mint_cnt.saturating_sub(config.token_cnt),
+    })
+}
+
+/// Returns StdResult<Binary> displaying the max supply and how many more tokens may be
+/// minted for the collection or a mint run id
+///
+/// # Arguments
+///
+/// * `deps` - a reference to Extern containing all the contract's external dependencies
+/// * `mint_run_id` - optional mint run id
+/// * `viewer` - optional address and key making an authenticated query request
+/// * `from_permit` - address derived from an Owner permit, if applicable
+pub fn query_remaining_supply(
+    deps: Deps,
+    mint_run_id: Option<&str>,
+    viewer: Option<ViewerInfo>,
+    from_permit: Option<CanonicalAddr>,
+) -> StdResult<Binary> {
+    // authenticate permission to view token supply
+    check_view_supply(deps, viewer, from_permit)?;
+    let (max_supply, minted) = if let Some(id) = mint_run_id {
+        let cap_store = ReadonlyPrefixedStorage::new(deps.storage, PREFIX_MINT_RUN_CAP);
+        let supply_store = ReadonlyPrefixedStorage::new(deps.storage, PREFIX_MINT_RUN_SUPPLY);
+        (
+            may_load(&cap_store, id.as_bytes())?,
+            may_load(&supply_store, id.as_bytes())?.unwrap_or(0),
+        )
+    } else {
+        let config: Config = load(deps.storage, CONFIG_KEY)?;
+        (may_load(deps.storage, MAX_SUPPLY_KEY)?, config.mint_cnt)
+    };
+    to_binary(&QueryAnswer::RemainingSupply {
+        max_supply,
+        minted,
+        remaining: max_supply.map(|m| m.saturating_sub(minted)),
+    })
+}
+
+/// Returns StdResult<Binary> displaying the mint runs of a mint run id
+///
+/// # Arguments
+///
+/// * `deps` - a reference to Extern containing all the contract's external dependencies
+/// * `mint_run_id` - string slice of the mint run id
+/// * `viewer` - optional address and key making an authenticated query request
+/// * `start_after` - optionally only display mint runs that come after this one
+/// * `limit` - optional max number of mint runs to display
+/// * `from_permit` - address derived from an Owner permit, if applicable
+pub fn query_mint_runs(
+    deps: Deps,
+    mint_run_id: &str,
+    viewer: Option<ViewerInfo>,
+    start_after: Option<u32>,
+    limit: Option<u32>,
+    from_permit: Option<CanonicalAddr>,
+) -> StdResult<Binary> {
+    // authenticate permission to view token supply
+    check_view_supply(deps, viewer, from_permit)?;
+    let run_store = ReadonlyPrefixedStorage::new(deps.storage, PREFIX_MINT_RUN_NUM);
+    let last_run: u32 = may_load(&run_store, mint_run_id.as_bytes())?.unwrap_or(0);
+    let reg_store = ReadonlyPrefixedStorage::multilevel(
+        deps.storage,
+        &[PREFIX_MINT_RUN_REGISTRY, mint_run_id.as_bytes()],
+    );
+    let cut_off = limit.unwrap_or(30) as usize;
+    let mut mint_runs = Vec::new();
+    let mut run = start_after.unwrap_or(0).saturating_add(1);
+    while mint_runs.len() < cut_off && run <= last_run {
+        if let Some(stored) = may_load::<StoredMintRun>(&reg_store, &run.to_le_bytes())? {
+            mint_runs.push(stored.into_humanized(deps.api, run)?);
+        }
+        // run can't overflow if it was not greater than a u32
+        run += 1;
+    }
+    to_binary(&QueryAnswer::MintRuns { mint_runs })
+}
+
+/// Returns StdResult<Binary> displaying the existing tokens minted in a mint run
+///
+/// # Arguments
+///
+/// * `deps` - a reference to Extern containing all the contract's external dependencies
+/// * `mint_run_id` - string slice of the mint run id
+/// * `mint_run` - the number of the mint run
+/// * `viewer` - optional address and key making an authenticated query request
+/// * `start_after` - optionally only display token ids that come after this one
+/// * `limit` - optional max number of tokens to display
+/// * `from_permit` - address derived from an Owner permit, if applicable
+pub fn query_tokens_in_mint_run(
+    deps: Deps,
+    mint_run_id: &str,
+    mint_run: u32,
+    viewer: Option<ViewerInfo>,
+    start_after: Option<&str>,
+    limit: Option<u32>,
+    from_permit: Option<CanonicalAddr>,
+) -> StdResult<Binary> {
+    // authenticate permission to view token supply
+    check_view_supply(deps, viewer, from_permit)?;
+    let reg_store = ReadonlyPrefixedStorage::multilevel(
+        deps.storage,
+        &[PREFIX_MINT_RUN_REGISTRY, mint_run_id.as_bytes()],
+    );
+    let stored: StoredMintRun =
+        may_load(&reg_store, &mint_run.to_le_bytes())?.ok_or_else(|| {
+            StdError::generic_err(format!(
+                "Mint run {} of mint run ID {} not found",
+                mint_run, mint_run_id
+            ))
+        })?;
+    // the tokens of a mint run have consecutive indices
+    let end = stored.first_index.saturating_add(stored.quantity);
+    let mut i = start_after.map_or_else(
+        || Ok(stored.first_index),
+        |id| {
+            let map2idx = ReadonlyPrefixedStorage::new(deps.storage, PREFIX_MAP_TO_INDEX);
+            may_load::<u32>(&map2idx, id.as_bytes())?
+                .filter(|idx| *idx >= stored.first_index && *idx < end)
+                .map(|idx| idx + 1)
+                .ok_or_else(|| {
+                    StdError::generic_err(format!(
+                        "Token ID: {} is not in mint run {} of mint run ID {}",
+                        id, mint_run, mint_run_id
+                    ))
+                })
+        },
+    )?;
+    let cut_off = limit.unwrap_or(300);
+    let mut tokens = Vec::new();
+    let mut count = 0u32;
+    let map2id = ReadonlyPrefixedStorage::new(deps.storage, PREFIX_MAP_TO_ID);
+    while count < cut_off && i < end {
+        // burned tokens are skipped
+        if let Some(id) = may_load::<String>(&map2id, &i.to_le_bytes())? {
+            tokens.push(id);
+            count += 1;
+        }
+        i += 1;
+    }
+    to_binary(&QueryAnswer::TokenList {
+        tokens,
+        balances: None,
     })
 }
 
@@ -2133,7 +6744,10 @@ pub fn query_all_tokens(
         // i can't overflow if it was less than a u32
         i += 1;
     }
-    to_binary(&QueryAnswer::TokenList { tokens })
+    to_binary(&QueryAnswer::TokenList {
+        tokens,
+        balances: None,
+    })
 }
 
 /// Returns StdResult<Binary> displaying the owner of the input token if the requester is authorized
@@ -2155,6 +6769,9 @@ pub fn query_owner_of(
     include_expired: Option<bool>,
     from_permit: Option<CanonicalAddr>,
 ) -> StdResult<Binary> {
+    if let Some(burned) = query_burned(deps, token_id, viewer.clone(), from_permit.clone())? {
+        return Ok(burned);
+    }
     let (may_owner, approvals, _idx) =
         process_cw721_owner_of(deps, block, token_id, viewer, include_expired, from_permit)?;
     if let Some(owner) = may_owner {
@@ -2166,19 +6783,67 @@ pub fn query_owner_of(
     )))
 }
 
+/// Returns StdResult<Option<Binary>> displaying the tombstone of a burned token if the querier
+/// is its previous owner, its burner, a minter, or an auditor, or None otherwise
+///
+/// # Arguments
+///
+/// * `deps` - a reference to Extern containing all the contract's external dependencies
+/// * `token_id` - string slice of the token id
+/// * `viewer` - optional address and key making an authenticated query request
+/// * `from_permit` - address derived from an Owner permit, if applicable
+fn query_burned(
+    deps: Deps,
+    token_id: &str,
+    viewer: Option<ViewerInfo>,
+    from_permit: Option<CanonicalAddr>,
+) -> StdResult<Option<Binary>> {
+    if let Some(tomb) = tombstone(deps.storage, token_id)? {
+        if let Some(querier) = get_querier(deps, viewer, from_permit)? {
+            if tomb.owner == querier
+                || tomb.burner.as_ref() == Some(&querier)
+                || holds_any_role(deps.storage, &querier, &[Role::Minter, Role::Auditor])?
+            {
+                let burner = tomb
+                    .burner
+                    .map(|b| deps.api.addr_humanize(&b))
+                    .transpose()?;
+                return to_binary(&QueryAnswer::BurnedToken {
+                    token_id: token_id.to_string(),
+                    owner: deps.api.addr_humanize(&tomb.owner)?,
+                    burner,
+                    block_height: tomb.block_height,
+                    block_time: tomb.block_time,
+                })
+                .map(Some);
+            }
+        }
+    }
+    Ok(None)
+}
+
 /// Returns StdResult<Binary> displaying the public metadata of a token
 ///
 /// # Arguments
 ///
 /// * `storage` - a reference to the contract's storage
+/// * `block` - a reference to the BlockInfo
 /// * `token_id` - string slice of the token id
-pub fn query_nft_info(storage: &dyn Storage, token_id: &str) -> StdResult<Binary> {
+pub fn query_nft_info(
+    storage: &dyn Storage,
+    block: &BlockInfo,
+    token_id: &str,
+) -> StdResult<Binary> {
+    let config: Config = load(storage, CONFIG_KEY)?;
     let map2idx = ReadonlyPrefixedStorage::new(storage, PREFIX_MAP_TO_INDEX);
     let may_idx: Option<u32> = may_load(&map2idx, token_id.as_bytes())?;
     // if token id was found
     if let Some(idx) = may_idx {
-        let meta_store = ReadonlyPrefixedStorage::new(storage, PREFIX_PUB_META);
-        let meta: Metadata = may_load(&meta_store, &idx.to_le_bytes())?.unwrap_or(Metadata {
+        let info_store = ReadonlyPrefixedStorage::new(storage, PREFIX_INFOS);
+        let mut token: Token = json_load(&info_store, &idx.to_le_bytes())?;
+        let reveal_passed = scheduled_reveal_passed(storage, block)?;
+        let (public, _) = load_revealed_metadata(storage, &config, reveal_passed, &mut token, idx)?;
+        let meta = public.unwrap_or(Metadata {
             token_uri: None,
             extension: None,
         });
@@ -2187,7 +6852,6 @@ pub fn query_nft_info(storage: &dyn Storage, token_id: &str) -> StdResult<Binary
             extension: meta.extension,
         });
     }
-    let config: Config = load(storage, CONFIG_KEY)?;
     // token id wasn't found
     // if the token supply is public, let them know the token does not exist
     if config.token_supply_is_public {
@@ -2220,17 +6884,29 @@ pub fn query_private_meta(
     viewer: Option<ViewerInfo>,
     from_permit: Option<CanonicalAddr>,
 ) -> StdResult<Binary> {
-    let prep_info = query_token_prep(deps, token_id, viewer, from_permit)?;
-    check_perm_core(
-        deps,
-        block,
-        &prep_info.token,
-        token_id,
-        prep_info.viewer_raw.as_ref(),
-        prep_info.token.owner.as_slice(),
-        PermissionType::ViewMetadata.to_usize(),
-        &mut Vec::new(),
-        &prep_info.err_msg,
+    let mut prep_info = query_token_prep(deps, token_id, viewer, from_permit)?;
+    // holders of an edition may view its private metadata
+    if !holds_edition(deps.storage, prep_info.idx, prep_info.viewer_raw.as_ref())? {
+        check_perm_core(
+            deps,
+            block,
+            &prep_info.token,
+            token_id,
+            prep_info.viewer_raw.as_ref(),
+            prep_info.token.owner.as_slice(),
+            PermissionType::ViewMetadata.to_usize(),
+            &mut Vec::new(),
+            &prep_info.err_msg,
+        )?;
+    }
+    let config: Config = load(deps.storage, CONFIG_KEY)?;
+    let reveal_passed = scheduled_reveal_passed(deps.storage, block)?;
+    let (_, private) = load_revealed_metadata(
+        deps.storage,
+        &config,
+        reveal_passed,
+        &mut prep_info.token,
+        prep_info.idx,
     )?;
     // don't display if private metadata is sealed
     if !prep_info.token.unwrapped {
@@ -2238,8 +6914,7 @@ pub fn query_private_meta(
             "Sealed metadata must be unwrapped by calling Reveal before it can be viewed",
         ));
     }
-    let meta_store = ReadonlyPrefixedStorage::new(deps.storage, PREFIX_PRIV_META);
-    let meta: Metadata = may_load(&meta_store, &prep_info.idx.to_le_bytes())?.unwrap_or(Metadata {
+    let meta: Metadata = private.unwrap_or(Metadata {
         token_uri: None,
         extension: None,
     });
@@ -2269,8 +6944,11 @@ pub fn query_all_nft_info(
 ) -> StdResult<Binary> {
     let (owner, approvals, idx) =
         process_cw721_owner_of(deps, block, token_id, viewer, include_expired, from_permit)?;
-    let meta_store = ReadonlyPrefixedStorage::new(deps.storage, PREFIX_PUB_META);
-    let info: Option<Metadata> = may_load(&meta_store, &idx.to_le_bytes())?;
+    let config: Config = load(deps.storage, CONFIG_KEY)?;
+    let info_store = ReadonlyPrefixedStorage::new(deps.storage, PREFIX_INFOS);
+    let mut token: Token = json_load(&info_store, &idx.to_le_bytes())?;
+    let reveal_passed = scheduled_reveal_passed(deps.storage, block)?;
+    let (info, _) = load_revealed_metadata(deps.storage, &config, reveal_passed, &mut token, idx)?;
     let access = Cw721OwnerOfResponse { owner, approvals };
     to_binary(&QueryAnswer::AllNftInfo { access, info })
 }
@@ -2296,6 +6974,9 @@ pub fn query_nft_dossier(
     include_expired: Option<bool>,
     from_permit: Option<CanonicalAddr>,
 ) -> StdResult<Binary> {
+    if let Some(burned) = query_burned(deps, &token_id, viewer.clone(), from_permit.clone())? {
+        return Ok(burned);
+    }
     let dossier = dossier_list(
         deps,
         block,
@@ -2322,6 +7003,9 @@ pub fn query_nft_dossier(
         private_metadata_is_public_expiration: dossier.private_metadata_is_public_expiration,
         token_approvals: dossier.token_approvals,
         inventory_approvals: dossier.inventory_approvals,
+        edition: dossier.edition,
+        revocation: dossier.revocation,
+        user: dossier.user,
     })
 }
 
@@ -2555,8 +7239,8 @@ pub fn query_approved_for_all(
 }
 
 /// Returns StdResult<Binary> displaying an optionally paginated list of all tokens belonging to
-/// the owner address.  It will only display the tokens that the querier has view_owner
-/// approval
+/// the owner address, along with the owner's balance of each listed edition.  It will only
+/// display the tokens that the querier has view_owner approval
 ///
 /// # Arguments
 ///
@@ -2616,7 +7300,10 @@ pub fn query_tokens(
     };
     // exit early if the limit is 0
     if cut_off == 0 {
-        return to_binary(&QueryAnswer::TokenList { tokens: Vec::new() });
+        return to_binary(&QueryAnswer::TokenList {
+            tokens: Vec::new(),
+            balances: Some(Vec::new()),
+        });
     }
     // get list of owner's tokens
     let own_inv = Inventory::new(deps.storage, owner_raw)?;
@@ -2639,6 +7326,7 @@ pub fn query_tokens(
     let mut list_it: bool;
     let mut oper_for: Vec<CanonicalAddr> = Vec::new();
     let mut tokens: Vec<String> = Vec::new();
+    let mut balances: Vec<EditionBalance> = Vec::new();
     let map2id = ReadonlyPrefixedStorage::new(deps.storage, PREFIX_MAP_TO_ID);
     let mut inv_iter = if let Some(after) = start_after {
         // load the config if we haven't already
@@ -2719,6 +7407,12 @@ pub fn query_tokens(
                 }
             }
             if list_it {
+                if edition_supply(deps.storage, idx)?.is_some() {
+                    balances.push(EditionBalance {
+                        token_id: id.clone(),
+                        balance: Uint128::new(edition_balance(deps.storage, idx, &own_inv.owner)?),
+                    });
+                }
                 tokens.push(id);
                 // it'll hit the gas ceiling before overflowing the count
                 count += 1;
@@ -2729,7 +7423,10 @@ pub fn query_tokens(
             }
         }
     }
-    to_binary(&QueryAnswer::TokenList { tokens })
+    to_binary(&QueryAnswer::TokenList {
+        tokens,
+        balances: Some(balances),
+    })
 }
 
 /// Returns StdResult<Binary> displaying the number of tokens that the querier has permission to
@@ -2875,62 +7572,381 @@ pub fn query_num_owner_tokens(
         }
     }
 
-    to_binary(&QueryAnswer::NumTokens { count })
+    to_binary(&QueryAnswer::NumTokens { count })
+}
+
+/// Returns StdResult<Binary> displaying true if the token has been unwrapped.  If sealed metadata
+/// is not enabled, all tokens are considered unwrapped
+///
+/// # Arguments
+///
+/// * `storage` - a reference to the contract's storage
+/// * `block` - a reference to the BlockInfo
+/// * `token_id` - string slice of the token id
+pub fn query_is_unwrapped(
+    storage: &dyn Storage,
+    block: &BlockInfo,
+    token_id: &str,
+) -> StdResult<Binary> {
+    let config: Config = load(storage, CONFIG_KEY)?;
+    let get_token_res = get_token(storage, token_id, None);
+    match get_token_res {
+        Err(err) => match err {
+            // if the token id is not found, but token supply is private, just say
+            // the token's wrapped state is the same as a newly minted token
+            StdError::GenericErr { msg, .. }
+                if !config.token_supply_is_public && msg.contains("Token ID") =>
+            {
+                to_binary(&QueryAnswer::IsUnwrapped {
+                    token_is_unwrapped: !config.sealed_metadata_is_enabled,
+                })
+            }
+            _ => Err(err),
+        },
+        Ok((token, _idx)) => to_binary(&QueryAnswer::IsUnwrapped {
+            token_is_unwrapped: token.unwrapped || scheduled_reveal_passed(storage, block)?,
+        }),
+    }
+}
+
+/// Returns StdResult<Binary> displaying true if the token is transferable, and the token's
+/// revocation if it is a revoked non-transferable token
+///
+/// # Arguments
+///
+/// * `deps` - a reference to Extern containing all the contract's external dependencies
+/// * `token_id` - string slice of the token id
+pub fn query_is_transferable(deps: Deps, token_id: &str) -> StdResult<Binary> {
+    let config: Config = load(deps.storage, CONFIG_KEY)?;
+    let get_token_res = get_token(deps.storage, token_id, None);
+    match get_token_res {
+        Err(err) => match err {
+            // if the token id is not found, but token supply is private, just say
+            // the token is transferable
+            StdError::GenericErr { msg, .. }
+                if !config.token_supply_is_public && msg.contains("Token ID") =>
+            {
+                to_binary(&QueryAnswer::IsTransferable {
+                    token_is_transferable: true,
+                    revocation: None,
+                })
+            }
+            _ => Err(err),
+        },
+        Ok((token, idx)) => to_binary(&QueryAnswer::IsTransferable {
+            token_is_transferable: token.transferable,
+            revocation: revocation(deps.storage, deps.api, idx)?,
+        }),
+    }
+}
+
+/// Returns StdResult<Binary> displaying if a token is locked
+///
+/// # Arguments
+///
+/// * `deps` - a reference to Extern containing all the contract's external dependencies
+/// * `block` - a reference to the BlockInfo
+/// * `token_id` - string slice of the token id
+pub fn query_is_locked(deps: Deps, block: &BlockInfo, token_id: &str) -> StdResult<Binary> {
+    let config: Config = load(deps.storage, CONFIG_KEY)?;
+    let get_token_res = get_token(deps.storage, token_id, None);
+    match get_token_res {
+        Err(err) => match err {
+            // if the token id is not found, but token supply is private, just say
+            // the token is not locked
+            StdError::GenericErr { msg, .. }
+                if !config.token_supply_is_public && msg.contains("Token ID") =>
+            {
+                to_binary(&QueryAnswer::IsLocked {
+                    token_is_locked: false,
+                })
+            }
+            _ => Err(err),
+        },
+        Ok((_, idx)) => to_binary(&QueryAnswer::IsLocked {
+            token_is_locked: active_lock(deps.storage, block, idx)?.is_some(),
+        }),
+    }
+}
+
+/// Returns StdResult<Binary> displaying an optionally paginated list of the tokens listed for
+/// sale
+///
+/// # Arguments
+///
+/// * `deps` - a reference to Extern containing all the contract's external dependencies
+/// * `start_after` - optionally only display listings of tokens minted after this token id
+/// * `limit` - optional max number of listings to display
+pub fn query_listings(
+    deps: Deps,
+    start_after: Option<&str>,
+    limit: Option<u32>,
+) -> StdResult<Binary> {
+    let mut i = start_after.map_or_else(
+        || Ok(0),
+        |id| {
+            let map2idx = ReadonlyPrefixedStorage::new(deps.storage, PREFIX_MAP_TO_INDEX);
+            let idx: u32 = may_load(&map2idx, id.as_bytes())?
+                .ok_or_else(|| StdError::generic_err(format!("Token ID: {} not found", id)))?;
+            idx.checked_add(1).ok_or_else(|| {
+                StdError::generic_err("This token was the last one the contract could mint")
+            })
+        },
+    )?;
+    let cut_off = limit.unwrap_or(30);
+    let config: Config = load(deps.storage, CONFIG_KEY)?;
+    let mut listings = Vec::new();
+    let mut count = 0u32;
+    let list_store = ReadonlyPrefixedStorage::new(deps.storage, PREFIX_LISTINGS);
+    let map2id = ReadonlyPrefixedStorage::new(deps.storage, PREFIX_MAP_TO_ID);
+    while count < cut_off && i < config.mint_cnt {
+        if let Some(listing) = may_load::<StoredListing>(&list_store, &i.to_le_bytes())? {
+            let token_id: String = load(&map2id, &i.to_le_bytes())?;
+            listings.push(Listing {
+                token_id,
+                price: listing.price,
+            });
+            // will hit gas ceiling before the count overflows
+            count += 1;
+        }
+        // i can't overflow if it was less than a u32
+        i += 1;
+    }
+    to_binary(&QueryAnswer::Listings { listings })
+}
+
+/// Returns StdResult<Binary> displaying the auction of a token
+///
+/// # Arguments
+///
+/// * `deps` - a reference to Extern containing all the contract's external dependencies
+/// * `block` - a reference to the BlockInfo
+/// * `token_id` - the token id
+pub fn query_auction(deps: Deps, block: &BlockInfo, token_id: String) -> StdResult<Binary> {
+    // a token that does not exist is just not being auctioned, so the token supply is not leaked
+    let map2idx = ReadonlyPrefixedStorage::new(deps.storage, PREFIX_MAP_TO_INDEX);
+    let auction = may_load::<u32>(&map2idx, token_id.as_bytes())?
+        .map(|idx| {
+            let auction_store = ReadonlyPrefixedStorage::new(deps.storage, PREFIX_AUCTIONS);
+            json_may_load::<StoredAuction>(&auction_store, &idx.to_le_bytes())
+        })
+        .transpose()?
+        .flatten()
+        .map(|a| a.into_display(token_id, block.time.seconds()));
+    to_binary(&QueryAnswer::Auction { auction })
+}
+
+/// Returns StdResult<Binary> displaying the address renting a token if the querier is the
+/// user or may view the token's owner
+///
+/// # Arguments
+///
+/// * `deps` - a reference to Extern containing all the contract's external dependencies
+/// * `block` - a reference to the BlockInfo
+/// * `token_id` - string slice of the token id
+/// * `viewer` - optional address and key making an authenticated query request
+/// * `from_permit` - address derived from an Owner permit, if applicable
+pub fn query_user_of(
+    deps: Deps,
+    block: &BlockInfo,
+    token_id: &str,
+    viewer: Option<ViewerInfo>,
+    from_permit: Option<CanonicalAddr>,
+) -> StdResult<Binary> {
+    let prep_info = query_token_prep(deps, token_id, viewer, from_permit)?;
+    // the user may view the owner, so this also permits the user
+    check_permission(
+        deps,
+        block,
+        &prep_info.token,
+        token_id,
+        prep_info.viewer_raw.as_ref(),
+        PermissionType::ViewOwner,
+        &mut Vec::new(),
+        &format!(
+            "You are not authorized to view the user of token {}",
+            token_id
+        ),
+        prep_info.owner_is_public,
+    )?;
+    let (user, expires) = if let Some(usr) = active_user(deps.storage, block, token_id)? {
+        (Some(deps.api.addr_humanize(&usr.user)?), Some(usr.expires))
+    } else {
+        (None, None)
+    };
+    to_binary(&QueryAnswer::UserOf { user, expires })
+}
+
+/// Returns StdResult<Binary> displaying the offers on a token if the querier is the token's
+/// owner
+///
+/// # Arguments
+///
+/// * `deps` - a reference to Extern containing all the contract's external dependencies
+/// * `token_id` - string slice of the token id
+/// * `viewer` - optional address and key making an authenticated query request
+/// * `start_after` - optionally only display offers that come after this one
+/// * `limit` - optional max number of offers to display
+/// * `from_permit` - address derived from an Owner permit, if applicable
+pub fn query_token_offers(
+    deps: Deps,
+    token_id: &str,
+    viewer: Option<ViewerInfo>,
+    start_after: Option<u64>,
+    limit: Option<u32>,
+    from_permit: Option<CanonicalAddr>,
+) -> StdResult<Binary> {
+    let custom_err = format!(
+        "You are not authorized to view the offers on token {}",
+        token_id
+    );
+    let querier = get_querier(deps, viewer, from_permit)?
+        .ok_or_else(|| StdError::generic_err(&custom_err))?;
+    let (token, _) = get_token(deps.storage, token_id, Some(&custom_err))?;
+    if token.owner != querier {
+        return Err(StdError::generic_err(custom_err));
+    }
+    let ids = offer_ids(
+        deps.storage,
+        PREFIX_TOKEN_OFFERS,
+        token_id.as_bytes(),
+        start_after,
+        limit.unwrap_or(30),
+    )?;
+    to_binary(&QueryAnswer::Offers {
+        offers: display_offers(deps, ids)?,
+    })
+}
+
+/// Returns StdResult<Binary> displaying the offers made by the querier
+///
+/// # Arguments
+///
+/// * `deps` - a reference to Extern containing all the contract's external dependencies
+/// * `viewer` - optional address and key making an authenticated query request
+/// * `start_after` - optionally only display offers that come after this one
+/// * `limit` - optional max number of offers to display
+/// * `from_permit` - address derived from an Owner permit, if applicable
+pub fn query_buyer_offers(
+    deps: Deps,
+    viewer: Option<ViewerInfo>,
+    start_after: Option<u64>,
+    limit: Option<u32>,
+    from_permit: Option<CanonicalAddr>,
+) -> StdResult<Binary> {
+    let querier = get_querier(deps, viewer, from_permit)?
+        .ok_or_else(|| StdError::generic_err(VIEWING_KEY_ERR_MSG))?;
+    let ids = offer_ids(
+        deps.storage,
+        PREFIX_BUYER_OFFERS,
+        querier.as_slice(),
+        start_after,
+        limit.unwrap_or(30),
+    )?;
+    to_binary(&QueryAnswer::Offers {
+        offers: display_offers(deps, ids)?,
+    })
+}
+
+/// Returns StdResult<Binary> displaying the SNIP-20 contracts offers may be made in
+///
+/// # Arguments
+///
+/// * `deps` - a reference to Extern containing all the contract's external dependencies
+pub fn query_offer_snip20s(deps: Deps) -> StdResult<Binary> {
+    let snip20s: Vec<StoredOfferSnip20> =
+        may_load(deps.storage, OFFER_SNIP20S_KEY)?.unwrap_or_default();
+    let snip20s = snip20s
+        .into_iter()
+        .filter_map(|s| {
+            s.min_amount.map(|min_amount| {
+                deps.api
+                    .addr_humanize(&s.address)
+                    .map(|address| OfferSnip20 {
+                        address,
+                        code_hash: s.code_hash,
+                        min_amount,
+                    })
+            })
+        })
+        .collect::<StdResult<Vec<OfferSnip20>>>()?;
+    to_binary(&QueryAnswer::OfferSnip20s { snip20s })
 }
 
-/// Returns StdResult<Binary> displaying true if the token has been unwrapped.  If sealed metadata
-/// is not enabled, all tokens are considered unwrapped
+/// Returns StdResult<Binary> displaying a swap if the querier is its proposer or counterparty
 ///
 /// # Arguments
 ///
-/// * `storage` - a reference to the contract's storage
-pub fn query_is_unwrapped(storage: &dyn Storage, token_id: &str) -> StdResult<Binary> {
-    let config: Config = load(storage, CONFIG_KEY)?;
-    let get_token_res = get_token(storage, token_id, None);
-    match get_token_res {
-        Err(err) => match err {
-            // if the token id is not found, but token supply is private, just say
-            // the token's wrapped state is the same as a newly minted token
-            StdError::GenericErr { msg, .. }
-                if !config.token_supply_is_public && msg.contains("Token ID") =>
-            {
-                to_binary(&QueryAnswer::IsUnwrapped {
-                    token_is_unwrapped: !config.sealed_metadata_is_enabled,
-                })
-            }
-            _ => Err(err),
-        },
-        Ok((token, _idx)) => to_binary(&QueryAnswer::IsUnwrapped {
-            token_is_unwrapped: token.unwrapped,
-        }),
-    }
+/// * `deps` - a reference to Extern containing all the contract's external dependencies
+/// * `swap_id` - id of the swap
+/// * `viewer` - optional address and key making an authenticated query request
+/// * `from_permit` - address derived from an Owner permit, if applicable
+pub fn query_swap(
+    deps: Deps,
+    swap_id: u64,
+    viewer: Option<ViewerInfo>,
+    from_permit: Option<CanonicalAddr>,
+) -> StdResult<Binary> {
+    let querier = get_querier(deps, viewer, from_permit)?
+        .ok_or_else(|| StdError::generic_err(VIEWING_KEY_ERR_MSG))?;
+    let custom_err = format!("You are not authorized to view swap {}", swap_id);
+    let swap_store = ReadonlyPrefixedStorage::new(deps.storage, PREFIX_SWAPS);
+    // don't leak whether the swap exists
+    let swap: StoredSwap = json_may_load(&swap_store, &swap_id.to_le_bytes())?
+        .filter(|s: &StoredSwap| s.proposer == querier || s.counterparty == querier)
+        .ok_or_else(|| StdError::generic_err(custom_err))?;
+    to_binary(&QueryAnswer::Swap {
+        swap: swap.into_humanized(deps.api, swap_id)?,
+    })
 }
 
-/// Returns StdResult<Binary> displaying true if the token is transferable
+/// Returns StdResult<Vec<Offer>> of the displayable offers with the given ids
 ///
 /// # Arguments
 ///
-/// * `storage` - a reference to the contract's storage
-pub fn query_is_transferable(storage: &dyn Storage, token_id: &str) -> StdResult<Binary> {
-    let config: Config = load(storage, CONFIG_KEY)?;
-    let get_token_res = get_token(storage, token_id, None);
-    match get_token_res {
-        Err(err) => match err {
-            // if the token id is not found, but token supply is private, just say
-            // the token is transferable
-            StdError::GenericErr { msg, .. }
-                if !config.token_supply_is_public && msg.contains("Token ID") =>
-            {
-                to_binary(&QueryAnswer::IsTransferable {
-                    token_is_transferable: true,
-                })
-            }
-            _ => Err(err),
-        },
-        Ok((token, _idx)) => to_binary(&QueryAnswer::IsTransferable {
-            token_is_transferable: token.transferable,
-        }),
+/// * `deps` - a reference to Extern containing all the contract's external dependencies
+/// * `ids` - ids of the offers
+fn display_offers(deps: Deps, ids: Vec<u64>) -> StdResult<Vec<Offer>> {
+    ids.into_iter()
+        .map(|id| {
+            may_load_offer(deps.storage, id)?
+                .ok_or_else(|| StdError::generic_err(format!("Offer {} not found", id)))?
+                .into_humanized(deps.api, id)
+        })
+        .collect()
+}
+
+/// Returns StdResult<Binary> displaying the lock of a token if the querier is the token's
+/// owner or the address that locked it
+///
+/// # Arguments
+///
+/// * `deps` - a reference to Extern containing all the contract's external dependencies
+/// * `block` - a reference to the BlockInfo
+/// * `token_id` - string slice of the token id
+/// * `viewer` - optional address and key making an authenticated query request
+/// * `from_permit` - address derived from an Owner permit, if applicable
+pub fn query_lock_info(
+    deps: Deps,
+    block: &BlockInfo,
+    token_id: &str,
+    viewer: Option<ViewerInfo>,
+    from_permit: Option<CanonicalAddr>,
+) -> StdResult<Binary> {
+    let custom_err = format!(
+        "You are not authorized to view the lock of token {}",
+        token_id
+    );
+    let querier = get_querier(deps, viewer, from_permit)?
+        .ok_or_else(|| StdError::generic_err(&custom_err))?;
+    let (token, idx) = get_token(deps.storage, token_id, Some(&custom_err))?;
+    let lock = active_lock(deps.storage, block, idx)?;
+    if token.owner != querier && lock.as_ref().map(|l| &l.locker) != Some(&querier) {
+        return Err(StdError::generic_err(custom_err));
     }
+    to_binary(&QueryAnswer::LockInfo {
+        lock: lock.map(|l| l.into_humanized(deps.api)).transpose()?,
+    })
 }
 
 /// Returns StdResult<Binary> displaying an optionally paginated list of all transactions
@@ -3317,9 +8333,7 @@ fn check_view_supply(
     if !is_auth {
         let querier = get_querier(deps, viewer, from_permit)?;
         if let Some(viewer_raw) = querier {
-            let minters: Vec<CanonicalAddr> =
-                may_load(deps.storage, MINTERS_KEY)?.unwrap_or_default();
-            is_auth = minters.contains(&viewer_raw);
+            is_auth = holds_any_role(deps.storage, &viewer_raw, &[Role::Minter, Role::Auditor])?;
         }
         if !is_auth {
             return Err(StdError::generic_err(
@@ -3422,6 +8436,13 @@ fn check_perm_core(
         if token.owner == *sender {
             return Ok(());
         }
+        // the token's user may view the owner and private metadata, but may not transfer
+        if !only_public
+            && exp_idx != PermissionType::Transfer.to_usize()
+            && active_user(deps.storage, block, token_id)?.map_or(false, |u| u.user == *sender)
+        {
+            return Ok(());
+        }
         // check if the token is public or the sender has token permission.
         // Can't use find because even if the global or sender permission expired, you
         // still want to see if the other is still valid, but if we are only checking for public
@@ -3492,89 +8513,429 @@ fn check_perm_core(
                 }
             }
         }
-        return Err(StdError::generic_err(err_msg));
+        return Err(StdError::generic_err(err_msg));
+    }
+    Ok(())
+}
+
+/// Returns StdResult<(Token, u32)>
+///
+/// returns the token information if the sender has authorization
+///
+/// # Arguments
+///
+/// * `deps` - a reference to Extern containing all the contract's external dependencies
+/// * `block` - a reference to the current BlockInfo
+/// * `token_id` - token ID String slice
+/// * `sender` - a optional reference to the address trying to get access to the token
+/// * `perm_type` - PermissionType we are checking
+/// * `oper_for` - a mutable reference to a list of owners that gave the sender "all" permission
+/// * `config` - a reference to the Config
+fn get_token_if_permitted(
+    deps: Deps,
+    block: &BlockInfo,
+    token_id: &str,
+    sender: Option<&CanonicalAddr>,
+    perm_type: PermissionType,
+    oper_for: &mut Vec<CanonicalAddr>,
+    config: &Config,
+) -> StdResult<(Token, u32)> {
+    let custom_err = format!(
+        "You are not authorized to perform this action on token {}",
+        token_id
+    );
+    // if token supply is private, don't leak that the token id does not exist
+    // instead just say they are not authorized for that token
+    let opt_err = if config.token_supply_is_public {
+        None
+    } else {
+        Some(&*custom_err)
+    };
+    let (token, idx) = get_token(deps.storage, token_id, opt_err)?;
+    check_permission(
+        deps,
+        block,
+        &token,
+        token_id,
+        sender,
+        perm_type,
+        oper_for,
+        &custom_err,
+        config.owner_is_public,
+    )?;
+    Ok((token, idx))
+}
+
+/// Returns StdResult<(Token, u32)>
+///
+/// returns the specified token and its identifier index
+///
+/// # Arguments
+///
+/// * `storage` - a reference to contract's storage
+/// * `token_id` - token id string slice
+/// * `custom_err` - optional custom error message to use if don't want to reveal that a token
+///                  does not exist
+fn get_token(
+    storage: &dyn Storage,
+    token_id: &str,
+    custom_err: Option<&str>,
+) -> StdResult<(Token, u32)> {
+    let default_err: String;
+    let not_found = if let Some(err) = custom_err {
+        err
+    } else {
+        default_err = format!("Token ID: {} not found", token_id);
+        &*default_err
+    };
+    let map2idx = ReadonlyPrefixedStorage::new(storage, PREFIX_MAP_TO_INDEX);
+    let idx: u32 =
+        may_load(&map2idx, token_id.as_bytes())?.ok_or_else(|| StdError::generic_err(not_found))?;
+    let info_store = ReadonlyPrefixedStorage::new(storage, PREFIX_INFOS);
+    let token: Token = json_may_load(&info_store, &idx.to_le_bytes())?.ok_or_else(|| {
+        StdError::generic_err(format!("Unable to find token info for {}", token_id))
+    })?;
+    Ok((token, idx))
+}
+
+/// Returns StdResult<bool> true if the address holds prints of the edition
+///
+/// # Arguments
+///
+/// * `storage` - a reference to contract's storage
+/// * `idx` - the token's idx
+/// * `holder` - optional reference to the address in question
+fn holds_edition(
+    storage: &dyn Storage,
+    idx: u32,
+    holder: Option<&CanonicalAddr>,
+) -> StdResult<bool> {
+    if let Some(addr) = holder {
+        return Ok(edition_balance(storage, idx, addr)? > 0);
+    }
+    Ok(false)
+}
+
+/// Returns StdResult<()>
+///
+/// records the minting of `count` tokens by a minter, enforcing any quota it has
+///
+/// # Arguments
+///
+/// * `storage` - a mutable reference to the contract's storage
+/// * `block` - a reference to the current BlockInfo
+/// * `minter` - a reference to the minter's address
+/// * `count` - number of tokens being minted
+fn use_mint_quota(
+    storage: &mut dyn Storage,
+    block: &BlockInfo,
+    minter: &CanonicalAddr,
+    count: u32,
+) -> StdResult<()> {
+    let quota_store = ReadonlyPrefixedStorage::new(storage, PREFIX_MINTER_QUOTA);
+    let quota: MinterQuota = json_may_load(&quota_store, minter.as_slice())?.unwrap_or_default();
+    let mut usage_store = PrefixedStorage::new(storage, PREFIX_MINTER_USAGE);
+    let mut usage: MinterUsage = may_load(&usage_store, minter.as_slice())?.unwrap_or_default();
+    usage.consume(&quota, block, count)?;
+    save(&mut usage_store, minter.as_slice(), &usage)
+}
+
+/// Returns StdResult<()>
+///
+/// records the minting of `count` tokens, enforcing the collection's max supply and the
+/// mint run id's cap if there are any
+///
+/// # Arguments
+///
+/// * `storage` - a mutable reference to the contract's storage
+/// * `config` - a reference to the Config
+/// * `mint_run_id` - optional mint run id the tokens belong to
+/// * `count` - number of tokens being minted
+fn use_supply(
+    storage: &mut dyn Storage,
+    config: &Config,
+    mint_run_id: Option<&str>,
+    count: u32,
+) -> StdResult<()> {
+    let max_supply: Option<u32> = may_load(storage, MAX_SUPPLY_KEY)?;
+    if let Some(max) = max_supply {
+        let remaining = max.saturating_sub(config.mint_cnt);
+        if count > remaining {
+            return Err(StdError::generic_err(format!(
+                "Minting {} tokens exceeds the remaining supply of {}",
+                count, remaining
+            )));
+        }
+    }
+    if let Some(id) = mint_run_id {
+        let cap_store = ReadonlyPrefixedStorage::new(storage, PREFIX_MINT_RUN_CAP);
+        let cap: Option<u32> = may_load(&cap_store, id.as_bytes())?;
+        let mut supply_store = PrefixedStorage::new(storage, PREFIX_MINT_RUN_SUPPLY);
+        let minted: u32 = may_load(&supply_store, id.as_bytes())?.unwrap_or(0);
+        if let Some(cap) = cap {
+            let remaining = cap.saturating_sub(minted);
+            if count > remaining {
+                return Err(StdError::generic_err(format!(
+                    "Minting {} tokens exceeds the remaining supply of {} for mint run ID {}",
+                    count, remaining, id
+                )));
+            }
+        }
+        save(
+            &mut supply_store,
+            id.as_bytes(),
+            &minted.saturating_add(count),
+        )?;
+    }
+    Ok(())
+}
+
+/// Returns StdResult<Sale> of the public sale if it is open
+///
+/// # Arguments
+///
+/// * `storage` - a reference to the contract's storage
+/// * `block` - a reference to the current BlockInfo
+fn load_open_sale(storage: &dyn Storage, block: &BlockInfo) -> StdResult<Sale> {
+    let sale: Sale = json_may_load(storage, SALE_KEY)?
+        .ok_or_else(|| StdError::generic_err("There is no public sale"))?;
+    sale.check_open(block)?;
+    Ok(sale)
+}
+
+/// Returns StdResult<()>
+///
+/// records the number of tokens an address bought from the public sale, enforcing the
+/// sale's wallet limit if there is one
+///
+/// # Arguments
+///
+/// * `storage` - a mutable reference to the contract's storage
+/// * `sale` - a reference to the public sale
+/// * `buyer_raw` - a reference to the address of the buyer
+/// * `quantity` - number of tokens being bought
+fn use_wallet_limit(
+    storage: &mut dyn Storage,
+    sale: &Sale,
+    buyer_raw: &CanonicalAddr,
+    quantity: u32,
+) -> StdResult<()> {
+    if let Some(limit) = sale.wallet_limit {
+        let mut wallet_store = PrefixedStorage::new(storage, PREFIX_SALE_WALLET);
+        let bought: u32 = may_load(&wallet_store, buyer_raw.as_slice())?.unwrap_or(0);
+        if bought.saturating_add(quantity) > limit {
+            return Err(StdError::generic_err(format!(
+                "Each address may only buy {} tokens, and you have already bought {}",
+                limit, bought
+            )));
+        }
+        save(
+            &mut wallet_store,
+            buyer_raw.as_slice(),
+            &(bought + quantity),
+        )?;
+    }
+    Ok(())
+}
+
+/// Returns StdResult<Option<BankMsg>> of the message returning any overpayment
+///
+/// checks that the coins sent pay for the tokens being bought, and adds the cost to the
+/// sale proceeds
+///
+/// # Arguments
+///
+/// * `storage` - a mutable reference to the contract's storage
+/// * `sender` - a reference to the address that sent the coins
+/// * `funds` - the coins sent with the message
+/// * `price` - a reference to the price of one token
+/// * `quantity` - number of tokens being bought
+fn take_native_payment(
+    storage: &mut dyn Storage,
+    sender: &Addr,
+    funds: &[Coin],
+    price: &Coin,
+    quantity: u32,
+) -> StdResult<Option<BankMsg>> {
+    let cost = price.amount.checked_mul(Uint128::from(quantity))?;
+    let mut paid = Uint128::zero();
+    for coin in funds.iter() {
+        if coin.denom != price.denom {
+            return Err(StdError::generic_err(format!(
+                "The sale only accepts payment in {}",
+                price.denom
+            )));
+        }
+        paid = paid.checked_add(coin.amount)?;
+    }
+    if paid < cost {
+        return Err(StdError::generic_err(format!(
+            "Insufficient payment.  {} tokens cost {}{}",
+            quantity, cost, price.denom
+        )));
+    }
+    if !cost.is_zero() {
+        let mut proceeds: Vec<Coin> = may_load(storage, SALE_PROCEEDS_KEY)?.unwrap_or_default();
+        if let Some(coin) = proceeds.iter_mut().find(|c| c.denom == price.denom) {
+            coin.amount = coin.amount.checked_add(cost)?;
+        } else {
+            proceeds.push(Coin {
+                denom: price.denom.clone(),
+                amount: cost,
+            });
+        }
+        save(storage, SALE_PROCEEDS_KEY, &proceeds)?;
     }
-    Ok(())
+    let change = paid - cost;
+    Ok((!change.is_zero()).then(|| BankMsg::Send {
+        to_address: sender.to_string(),
+        amount: vec![Coin {
+            denom: price.denom.clone(),
+            amount: change,
+        }],
+    }))
 }
 
-/// Returns StdResult<(Token, u32)>
+/// Returns StdResult<Vec<String>> of the ids of the tokens sold
 ///
-/// returns the token information if the sender has authorization
+/// mints paid-for tokens from the public sale, enforcing the sale's limits
 ///
 /// # Arguments
 ///
-/// * `deps` - a reference to Extern containing all the contract's external dependencies
-/// * `block` - a reference to the current BlockInfo
-/// * `token_id` - token ID String slice
-/// * `sender` - a optional reference to the address trying to get access to the token
-/// * `perm_type` - PermissionType we are checking
-/// * `oper_for` - a mutable reference to a list of owners that gave the sender "all" permission
-/// * `config` - a reference to the Config
-fn get_token_if_permitted(
-    deps: Deps,
-    block: &BlockInfo,
-    token_id: &str,
-    sender: Option<&CanonicalAddr>,
-    perm_type: PermissionType,
-    oper_for: &mut Vec<CanonicalAddr>,
-    config: &Config,
-) -> StdResult<(Token, u32)> {
-    let custom_err = format!(
-        "You are not authorized to perform this action on token {}",
-        token_id
-    );
-    // if token supply is private, don't leak that the token id does not exist
-    // instead just say they are not authorized for that token
-    let opt_err = if config.token_supply_is_public {
-        None
-    } else {
-        Some(&*custom_err)
+/// * `deps` - a mutable reference to Extern containing all the contract's external dependencies
+/// * `env` - a reference to the Env of the contract's environment
+/// * `config` - a mutable reference to the Config
+/// * `sale` - a reference to the public sale
+/// * `wallet_limited` - true if the sale's wallet limit applies to this mint
+/// * `buyer_raw` - a reference to the address of the buyer
+/// * `quantity` - number of tokens to mint
+/// * `memo` - optional memo for the mint txs
+#[allow(clippy::too_many_arguments)]
+fn sale_mint(
+    deps: DepsMut,
+    env: &Env,
+    config: &mut Config,
+    sale: &Sale,
+    wallet_limited: bool,
+    buyer_raw: &CanonicalAddr,
+    quantity: u32,
+    memo: Option<String>,
+) -> StdResult<Vec<String>> {
+    if quantity == 0 {
+        return Err(StdError::generic_err("Quantity can not be zero"));
+    }
+    let mut counts: SaleCounts = may_load(deps.storage, SALE_COUNTS_KEY)?.unwrap_or_default();
+    // the sale is limited by its metadata pool and the collection's max supply
+    let mut available = match sale.metadata {
+        SaleMetadata::Pool {} => Some(counts.pool_remaining()),
+        SaleMetadata::Template { .. } => None,
     };
-    let (token, idx) = get_token(deps.storage, token_id, opt_err)?;
-    check_permission(
-        deps,
-        block,
-        &token,
-        token_id,
-        sender,
-        perm_type,
-        oper_for,
-        &custom_err,
-        config.owner_is_public,
-    )?;
-    Ok((token, idx))
+    let max_supply: Option<u32> = may_load(deps.storage, MAX_SUPPLY_KEY)?;
+    if let Some(max) = max_supply {
+        let left = max.saturating_sub(config.mint_cnt);
+        available = Some(available.map_or(left, |a| a.min(left)));
+    }
+    if let Some(avail) = available {
+        if avail == 0 {
+            return Err(StdError::generic_err("The sale is sold out"));
+        }
+        if quantity > avail {
+            return Err(StdError::generic_err(format!(
+                "Only {} tokens are left in the sale",
+                avail
+            )));
+        }
+    }
+    if wallet_limited {
+        use_wallet_limit(deps.storage, sale, buyer_raw, quantity)?;
+    }
+    // gather the metadata of the tokens being sold
+    let mut mints: Vec<Mint> = Vec::new();
+    for _ in 0..quantity {
+        let (public_metadata, private_metadata) = match &sale.metadata {
+            SaleMetadata::Template {
+                public_metadata,
+                private_metadata,
+            } => (public_metadata.clone(), private_metadata.clone()),
+            SaleMetadata::Pool {} => {
+                let mut pool_store = PrefixedStorage::new(deps.storage, PREFIX_SALE_POOL);
+                let key = counts.pool_taken.to_le_bytes();
+                let entry: PoolEntry = load(&pool_store, &key)?;
+                remove(&mut pool_store, &key);
+                counts.pool_taken += 1;
+                (entry.public_metadata, entry.private_metadata)
+            }
+        };
+        mints.push(Mint {
+            token_id: None,
+            owner: None,
+            public_metadata,
+            private_metadata,
+            serial_number: None,
+            royalty_info: None,
+            transferable: Some(true),
+            memo: memo.clone(),
+        });
+    }
+    counts.sold = counts.sold.saturating_add(quantity);
+    save(deps.storage, SALE_COUNTS_KEY, &counts)?;
+    mint_list(deps, env, config, buyer_raw, mints, None, None, false)
 }
 
-/// Returns StdResult<(Token, u32)>
+/// Returns StdResult<()>
 ///
-/// returns the specified token and its identifier index
+/// gives a token the item at its shuffled position in the reveal pool
 ///
 /// # Arguments
 ///
-/// * `storage` - a reference to contract's storage
-/// * `token_id` - token id string slice
-/// * `custom_err` - optional custom error message to use if don't want to reveal that a token
-///                  does not exist
-fn get_token(
+/// * `storage` - a mutable reference to the contract's storage
+/// * `token_key` - the storage key of the token
+/// * `position` - the token's shuffled position in the pool
+/// * `pool` - the reveal pool
+fn assign_reveal_item(
+    storage: &mut dyn Storage,
+    token_key: &[u8],
+    position: u32,
+    mut pool: RevealPool,
+) -> StdResult<()> {
+    // each position belongs to only one token, which can only be revealed once
+    let mut item_store = PrefixedStorage::new(storage, PREFIX_REVEAL_POOL);
+    let item: PoolEntry = load(&item_store, &position.to_le_bytes())?;
+    remove(&mut item_store, &position.to_le_bytes());
+    pool.remaining = pool.remaining.saturating_sub(1);
+    save(storage, REVEAL_POOL_KEY, &pool)?;
+    for (prefix, metadata) in [
+        (PREFIX_PUB_META, item.public_metadata),
+        (PREFIX_PRIV_META, item.private_metadata),
+    ] {
+        let mut meta_store = PrefixedStorage::new(storage, prefix);
+        if let Some(meta) = metadata {
+            save(&mut meta_store, token_key, &meta)?;
+        } else {
+            remove(&mut meta_store, token_key);
+        }
+    }
+    Ok(())
+}
+
+/// Returns StdResult<bool> which is true if the address holds any of the specified roles
+///
+/// # Arguments
+///
+/// * `storage` - a reference to the contract's storage
+/// * `address` - a reference to the address in question
+/// * `roles` - the roles to check
+fn holds_any_role(
     storage: &dyn Storage,
-    token_id: &str,
-    custom_err: Option<&str>,
-) -> StdResult<(Token, u32)> {
-    let default_err: String;
-    let not_found = if let Some(err) = custom_err {
-        err
-    } else {
-        default_err = format!("Token ID: {} not found", token_id);
-        &*default_err
-    };
-    let map2idx = ReadonlyPrefixedStorage::new(storage, PREFIX_MAP_TO_INDEX);
-    let idx: u32 =
-        may_load(&map2idx, token_id.as_bytes())?.ok_or_else(|| StdError::generic_err(not_found))?;
-    let info_store = ReadonlyPrefixedStorage::new(storage, PREFIX_INFOS);
-    let token: Token = json_may_load(&info_store, &idx.to_le_bytes())?.ok_or_else(|| {
-        StdError::generic_err(format!("Unable to find token info for {}", token_id))
-    })?;
-    Ok((token, idx))
+    address: &CanonicalAddr,
+    roles: &[Role],
+) -> StdResult<bool> {
+    for role in roles {
+        if load_role(storage, *role)?.contains(address) {
+            return Ok(true);
+        }
+    }
+    Ok(false)
 }
 
 /// Returns StdResult<()> that will error if the priority level of the action is not
@@ -3624,6 +8985,84 @@ fn set_metadata_impl(
     Ok(())
 }
 
+/// Returns StdResult<()>
+///
+/// marks a token as unwrapped and, unless unwrapped metadata stays private, moves its sealed
+/// private metadata to public
+///
+/// # Arguments
+///
+/// * `storage` - a mutable reference to the contract's storage
+/// * `config` - a reference to the Config
+/// * `token` - a mutable reference to the token being unwrapped
+/// * `idx` - the token identifier index
+fn unwrap_token(
+    storage: &mut dyn Storage,
+    config: &Config,
+    token: &mut Token,
+    idx: u32,
+) -> StdResult<()> {
+    token.unwrapped = true;
+    let token_key = idx.to_le_bytes();
+    let mut info_store = PrefixedStorage::new(storage, PREFIX_INFOS);
+    json_save(&mut info_store, &token_key, token)?;
+    if !config.unwrap_to_private {
+        let mut priv_store = PrefixedStorage::new(storage, PREFIX_PRIV_META);
+        let may_priv: Option<Metadata> = may_load(&priv_store, &token_key)?;
+        if let Some(metadata) = may_priv {
+            remove(&mut priv_store, &token_key);
+            let mut pub_store = PrefixedStorage::new(storage, PREFIX_PUB_META);
+            save(&mut pub_store, &token_key, &metadata)?;
+        }
+    }
+    Ok(())
+}
+
+/// Returns StdResult<bool> true if the collection-wide reveal was scheduled and its time has
+/// come
+///
+/// # Arguments
+///
+/// * `storage` - a reference to the contract's storage
+/// * `block` - a reference to the BlockInfo
+fn scheduled_reveal_passed(storage: &dyn Storage, block: &BlockInfo) -> StdResult<bool> {
+    let may_sched: Option<Expiration> = json_may_load(storage, REVEAL_SCHEDULE_KEY)?;
+    Ok(may_sched.map_or(false, |s| s.is_expired(block)))
+}
+
+/// Returns StdResult<(Option<Metadata>, Option<Metadata>)> of a token's public and private
+/// metadata.  If the scheduled reveal has passed but the token's metadata has not been moved
+/// yet, the token is marked as unwrapped and the metadata is displayed as it will be once
+/// the move is written
+///
+/// # Arguments
+///
+/// * `storage` - a reference to the contract's storage
+/// * `config` - a reference to the Config
+/// * `reveal_passed` - true if the scheduled reveal has passed
+/// * `token` - a mutable reference to the token
+/// * `idx` - the token identifier index
+fn load_revealed_metadata(
+    storage: &dyn Storage,
+    config: &Config,
+    reveal_passed: bool,
+    token: &mut Token,
+    idx: u32,
+) -> StdResult<(Option<Metadata>, Option<Metadata>)> {
+    let token_key = idx.to_le_bytes();
+    let pub_store = ReadonlyPrefixedStorage::new(storage, PREFIX_PUB_META);
+    let public: Option<Metadata> = may_load(&pub_store, &token_key)?;
+    let priv_store = ReadonlyPrefixedStorage::new(storage, PREFIX_PRIV_META);
+    let private: Option<Metadata> = may_load(&priv_store, &token_key)?;
+    if reveal_passed && !token.unwrapped {
+        token.unwrapped = true;
+        if !config.unwrap_to_private && private.is_some() {
+            return Ok((private, None));
+        }
+    }
+    Ok((public, private))
+}
+
 // enum used to return correct response from SetWhitelistedApproval
 pub enum SetAppResp {
     SetWhitelistedApproval,
@@ -4252,6 +9691,14 @@ fn transfer_impl(
             token_id
         )));
     }
+    check_unlocked(deps.storage, block, &token_id, idx)?;
+    check_not_auctioned(deps.storage, &token_id, idx)?;
+    if edition_supply(deps.storage, idx)?.is_some() {
+        return Err(StdError::generic_err(format!(
+            "Token ID: {} is an edition and must be transferred with TransferEdition",
+            token_id
+        )));
+    }
     let old_owner = token.owner;
     // throw error if ownership would not change
     if old_owner == recipient {
@@ -4262,6 +9709,10 @@ fn transfer_impl(
     }
     token.owner = recipient.clone();
     token.permissions.clear();
+    // the rental and the listing end when the token changes hands
+    remove_user(deps.storage, &token_id);
+    let mut list_store = PrefixedStorage::new(deps.storage, PREFIX_LISTINGS);
+    remove(&mut list_store, &idx.to_le_bytes());
 
     let update_addrs = vec![recipient.clone(), old_owner.clone()];
     // save updated token info
@@ -4412,9 +9863,9 @@ fn send_list(
     Ok(messages)
 }
 
-/// Returns StdResult<()>
+/// Returns StdResult<Vec<CosmosMsg>>
 ///
-/// burns a list of tokens
+/// burns a list of tokens and returns the messages refunding the offers made on them
 ///
 /// # Arguments
 ///
@@ -4423,32 +9874,50 @@ fn send_list(
 /// * `config` - a mutable reference to the Config
 /// * `sender` - a reference to the message sender address
 /// * `burns` - list of burns to perform
+/// * `redeeming` - true if the tokens are burned to redeem a recipe, which is allowed even if
+///   burn is not enabled
 fn burn_list(
-    deps: DepsMut,
+    mut deps: DepsMut,
     block: &BlockInfo,
     config: &mut Config,
     sender: &CanonicalAddr,
     burns: Vec<Burn>,
-) -> StdResult<()> {
+    redeeming: bool,
+) -> StdResult<Vec<CosmosMsg>> {
+    let mut refunds: Vec<CosmosMsg> = Vec::new();
     let mut oper_for: Vec<CanonicalAddr> = Vec::new();
     let mut inv_updates: Vec<InventoryUpdate> = Vec::new();
     let num_perm_types = PermissionType::ViewOwner.num_types();
+    // burners may burn any token
+    let is_burner = load_role(deps.storage, Role::Burner)?.contains(sender);
     for burn in burns.into_iter() {
         for token_id in burn.token_ids.into_iter() {
-            let (token, idx) = get_token_if_permitted(
-                deps.as_ref(),
-                block,
-                &token_id,
-                Some(sender),
-                PermissionType::Transfer,
-                &mut oper_for,
-                config,
-            )?;
-            if !config.burn_is_enabled && token.transferable {
+            let (token, idx) = if is_burner {
+                get_token(deps.storage, &token_id, None)?
+            } else {
+                get_token_if_permitted(
+                    deps.as_ref(),
+                    block,
+                    &token_id,
+                    Some(sender),
+                    PermissionType::Transfer,
+                    &mut oper_for,
+                    config,
+                )?
+            };
+            if !config.burn_is_enabled && token.transferable && !redeeming {
                 return Err(StdError::generic_err(
                     "Burn functionality is not enabled for this token",
                 ));
             }
+            if edition_supply(deps.storage, idx)?.is_some() {
+                return Err(StdError::generic_err(format!(
+                    "Token ID: {} is an edition and can not be burned",
+                    token_id
+                )));
+            }
+            check_unlocked(deps.storage, block, &token_id, idx)?;
+            check_not_auctioned(deps.storage, &token_id, idx)?;
             // log the inventory change
             let inv_upd = if let Some(inv) = inv_updates
                 .iter_mut()
@@ -4490,12 +9959,35 @@ fn burn_list(
             // remove royalty info if existent
             let mut roy_store = PrefixedStorage::new(deps.storage, PREFIX_ROYALTY_INFO);
             remove(&mut roy_store, &token_key);
+            // remove the revocation if existent
+            let mut revoke_store = PrefixedStorage::new(deps.storage, PREFIX_REVOKED_BADGES);
+            remove(&mut revoke_store, &token_key);
+            // remove the expired lock if existent
+            let mut lock_store = PrefixedStorage::new(deps.storage, PREFIX_LOCKS);
+            remove(&mut lock_store, &token_key);
+            // remove the user and the listing if existent
+            remove_user(deps.storage, &token_id);
+            let mut list_store = PrefixedStorage::new(deps.storage, PREFIX_LISTINGS);
+            remove(&mut list_store, &token_key);
+            // return the escrow of any offers on the token
+            refunds.extend(refund_token_offers(&mut deps, &token_id)?);
 
             let brnr = if token.owner == *sender {
                 None
             } else {
                 Some(sender.clone())
             };
+            // leave a tombstone so the id is known to have been burned
+            save_tombstone(
+                deps.storage,
+                &token_id,
+                &StoredTombstone {
+                    owner: token.owner.clone(),
+                    burner: brnr.clone(),
+                    block_height: block.height,
+                    block_time: block.time.seconds(),
+                },
+            )?;
             // store the tx
             store_burn(
                 deps.storage,
@@ -4510,7 +10002,7 @@ fn burn_list(
     }
     save(deps.storage, CONFIG_KEY, &config)?;
     update_owner_inventory(deps.storage, &inv_updates, num_perm_types)?;
-    Ok(())
+    Ok(refunds)
 }
 
 /// Returns <Vec<String>>
@@ -4524,18 +10016,92 @@ fn burn_list(
 /// * `config` - a mutable reference to the Config
 /// * `sender_raw` - a reference to the message sender address
 /// * `mints` - list of mints to perform
+/// * `mint_run_id` - optional mint run id the mints belong to
+/// * `edition_amount` - number of prints if the mints are editions
+/// * `fill_token_id` - true if `{token_id}` placeholders in the metadata should be filled in
+#[allow(clippy::too_many_arguments)]
 fn mint_list(
     deps: DepsMut,
     env: &Env,
     config: &mut Config,
     sender_raw: &CanonicalAddr,
     mints: Vec<Mint>,
+    mint_run_id: Option<&str>,
+    edition_amount: Option<u128>,
+    fill_token_id: bool,
 ) -> StdResult<Vec<String>> {
+    // the items new tokens would be revealed as could be predicted from the revealed seed
+    if may_load::<Vec<u8>>(deps.storage, REVEAL_SEED_KEY)?.is_some() {
+        return Err(StdError::generic_err(
+            "Minting has closed because the reveal pool's seed has been revealed",
+        ));
+    }
+    let id_scheme: Option<TokenIdScheme> = json_may_load(deps.storage, TOKEN_ID_SCHEME_KEY)?;
+    // number of tokens minted with the mint run id before these
+    let mut run_cnt: u32 = mint_run_id
+        .map(|id| {
+            let supply_store = ReadonlyPrefixedStorage::new(deps.storage, PREFIX_MINT_RUN_SUPPLY);
+            may_load(&supply_store, id.as_bytes())
+        })
+        .transpose()?
+        .flatten()
+        .unwrap_or(0);
+    let count = u32::try_from(mints.len()).map_err(|_| {
+        StdError::generic_err("Attempting to mint more tokens than the implementation limit")
+    })?;
+    use_supply(deps.storage, config, mint_run_id, count)?;
+    // serial numbers must be unique within a mint run, across every mint message.  Serials
+    // with neither a mint run id nor a mint run are standalone and may repeat
+    let run_id = mint_run_id.unwrap_or_default().as_bytes();
+    let mut serials: HashSet<(Option<u32>, u32)> = HashSet::new();
+    for ser in mints
+        .iter()
+        .filter_map(|m| m.serial_number.as_ref())
+        .filter(|s| mint_run_id.is_some() || s.mint_run.is_some())
+    {
+        let run_key: Vec<u8> = ser
+            .mint_run
+            .map_or_else(Vec::new, |r| r.to_le_bytes().to_vec());
+        let serial_store = ReadonlyPrefixedStorage::multilevel(
+            deps.storage,
+            &[PREFIX_USED_SERIALS, run_id, &run_key],
+        );
+        if !serials.insert((ser.mint_run, ser.serial_number))
+            || serial_store.get(&ser.serial_number.to_le_bytes()).is_some()
+        {
+            let run = ser
+                .mint_run
+                .map(|r| format!(" of mint run {}", r))
+                .unwrap_or_default();
+            return Err(StdError::generic_err(format!(
+                "Serial number {}{} is used more than once",
+                ser.serial_number, run
+            )));
+        }
+    }
+    for (mint_run, serial_number) in serials.into_iter() {
+        let run_key: Vec<u8> = mint_run.map_or_else(Vec::new, |r| r.to_le_bytes().to_vec());
+        let mut serial_store =
+            PrefixedStorage::multilevel(deps.storage, &[PREFIX_USED_SERIALS, run_id, &run_key]);
+        serial_store.set(&serial_number.to_le_bytes(), &[1]);
+    }
     let mut inventories: Vec<Inventory> = Vec::new();
     let mut minted: Vec<String> = Vec::new();
     let default_roy: Option<StoredRoyaltyInfo> = may_load(deps.storage, DEFAULT_ROYALTY_KEY)?;
-    for mint in mints.into_iter() {
-        let id = mint.token_id.unwrap_or(format!("{}", config.mint_cnt));
+    for mut mint in mints.into_iter() {
+        run_cnt = run_cnt.saturating_add(1);
+        let id = if let Some(id) = mint.token_id {
+            id
+        } else if let Some(scheme) = id_scheme.as_ref() {
+            scheme.generate(
+                config.mint_cnt,
+                mint_run_id.map(|r| (r, run_cnt)),
+                mint.public_metadata.as_ref(),
+                mint.private_metadata.as_ref(),
+            )?
+        } else {
+            format!("{}", config.mint_cnt)
+        };
         // check if id already exists
         let mut map2idx = PrefixedStorage::new(deps.storage, PREFIX_MAP_TO_INDEX);
         let may_exist: Option<u32> = may_load(&map2idx, id.as_bytes())?;
@@ -4551,6 +10117,8 @@ fn mint_list(
         })?;
         // map new token id to its index
         save(&mut map2idx, id.as_bytes(), &config.mint_cnt)?;
+        // a burned id that is minted again is no longer burned
+        remove_tombstone(deps.storage, &id);
         let recipient = if let Some(o) = mint.owner {
             deps.api
                 .addr_canonicalize(deps.api.addr_validate(&o)?.as_str())?
@@ -4558,8 +10126,14 @@ fn mint_list(
             sender_raw.clone()
         };
         let transferable = mint.transferable.unwrap_or(true);
+        // an edition is owned by its issuer, and its prints are held as balances
+        let owner = if edition_amount.is_some() {
+            sender_raw.clone()
+        } else {
+            recipient.clone()
+        };
         let token = Token {
-            owner: recipient.clone(),
+            owner,
             permissions: Vec::new(),
             unwrapped: !config.sealed_metadata_is_enabled,
             transferable,
@@ -4569,11 +10143,11 @@ fn mint_list(
         let token_key = config.mint_cnt.to_le_bytes();
         let mut info_store = PrefixedStorage::new(deps.storage, PREFIX_INFOS);
         json_save(&mut info_store, &token_key, &token)?;
-        // add token to owner's list
-        let inventory = if let Some(inv) = inventories.iter_mut().find(|i| i.owner == token.owner) {
+        // add token to the recipient's list
+        let inventory = if let Some(inv) = inventories.iter_mut().find(|i| i.owner == recipient) {
             inv
         } else {
-            let new_inv = Inventory::new(deps.storage, token.owner.clone())?;
+            let new_inv = Inventory::new(deps.storage, recipient.clone())?;
             inventories.push(new_inv);
             inventories.last_mut().ok_or_else(|| {
                 StdError::generic_err("Just pushed an Inventory so this can not happen")
@@ -4590,6 +10164,16 @@ fn mint_list(
         // a new prefix and store with the `token_key` like below
         //
         // save the metadata
+        if fill_token_id {
+            let values = [("{token_id}", id.as_str())];
+            for meta in mint
+                .public_metadata
+                .iter_mut()
+                .chain(mint.private_metadata.iter_mut())
+            {
+                meta.fill_placeholders(&values);
+            }
+        }
         if let Some(pub_meta) = mint.public_metadata {
             enforce_metadata_field_exclusion(&pub_meta)?;
             let mut pub_store = PrefixedStorage::new(deps.storage, PREFIX_PUB_META);
@@ -4635,15 +10219,30 @@ fn mint_list(
         //
 
         // store the tx
-        store_mint(
-            deps.storage,
-            config,
-            &env.block,
-            id.clone(),
-            sender_raw.clone(),
-            recipient,
-            mint.memo,
-        )?;
+        if let Some(amount) = edition_amount {
+            save_edition_supply(deps.storage, config.mint_cnt, amount)?;
+            save_edition_balance(deps.storage, config.mint_cnt, &recipient, amount)?;
+            store_edition_mint(
+                deps.storage,
+                config,
+                &env.block,
+                id.clone(),
+                sender_raw.clone(),
+                recipient,
+                amount,
+                mint.memo,
+            )?;
+        } else {
+            store_mint(
+                deps.storage,
+                config,
+                &env.block,
+                id.clone(),
+                sender_raw.clone(),
+                recipient,
+                mint.memo,
+            )?;
+        }
         minted.push(id);
         // increment index for next mint
         config.mint_cnt = config.mint_cnt.checked_add(1).ok_or_else(|| {
@@ -4805,11 +10404,10 @@ pub fn dossier_list(
     let mut dossiers: Vec<BatchNftDossierElement> = Vec::new();
     // set up all the immutable storage references
     let own_priv_store = ReadonlyPrefixedStorage::new(deps.storage, PREFIX_OWNER_PRIV);
-    let pub_store = ReadonlyPrefixedStorage::new(deps.storage, PREFIX_PUB_META);
-    let priv_store = ReadonlyPrefixedStorage::new(deps.storage, PREFIX_PRIV_META);
     let roy_store = ReadonlyPrefixedStorage::new(deps.storage, PREFIX_ROYALTY_INFO);
     let run_store = ReadonlyPrefixedStorage::new(deps.storage, PREFIX_MINT_RUN);
     let all_store = ReadonlyPrefixedStorage::new(deps.storage, PREFIX_ALL_PERMISSIONS);
+    let reveal_passed = scheduled_reveal_passed(deps.storage, block)?;
 
     for id in token_ids.into_iter() {
         let err_msg = format!(
@@ -4824,6 +10422,8 @@ pub fn dossier_list(
             Some(&*err_msg)
         };
         let (mut token, idx) = get_token(deps.storage, &id, opt_err)?;
+        let (public_metadata, stored_private) =
+            load_revealed_metadata(deps.storage, &config, reveal_passed, &mut token, idx)?;
         let owner_slice = token.owner.as_slice();
         // get the owner info either from the cache or storage
         let owner_inf = if let Some(inf) = owner_cache.iter().find(|o| o.owner == token.owner) {
@@ -4866,22 +10466,38 @@ pub fn dossier_list(
         } else {
             None
         };
-        // get the public metadata
         let token_key = idx.to_le_bytes();
-        let public_metadata: Option<Metadata> = may_load(&pub_store, &token_key)?;
+        // get the edition supply and the viewer's balance if this is an edition
+        let edition = edition_supply(deps.storage, idx)?
+            .map(|supply| {
+                let balance = opt_viewer
+                    .map(|v| edition_balance(deps.storage, idx, v))
+                    .transpose()?;
+                Ok::<EditionInfo, StdError>(EditionInfo {
+                    supply: Uint128::new(supply),
+                    balance: balance.map(Uint128::new),
+                })
+            })
+            .transpose()?;
+        // holders of an edition may view its private metadata
+        let meta_perm = if holds_edition(deps.storage, idx, opt_viewer)? {
+            Ok(())
+        } else {
+            check_perm_core(
+                deps,
+                block,
+                &token,
+                &id,
+                opt_viewer,
+                owner_slice,
+                perm_type_info.view_meta_idx,
+                &mut meta_oper_for,
+                &err_msg,
+            )
+        };
         // get the private metadata if it is not sealed and if the viewer is permitted
         let mut display_private_metadata_error = None;
-        let private_metadata = if let Err(err) = check_perm_core(
-            deps,
-            block,
-            &token,
-            &id,
-            opt_viewer,
-            owner_slice,
-            perm_type_info.view_meta_idx,
-            &mut meta_oper_for,
-            &err_msg,
-        ) {
+        let private_metadata = if let Err(err) = meta_perm {
             if let StdError::GenericErr { msg, .. } = err {
                 display_private_metadata_error = Some(msg);
             }
@@ -4892,8 +10508,7 @@ pub fn dossier_list(
             ));
             None
         } else {
-            let priv_meta: Option<Metadata> = may_load(&priv_store, &token_key)?;
-            priv_meta
+            stored_private
         };
         // get the royalty information if present
         let may_roy_inf: Option<StoredRoyaltyInfo> = may_load(&roy_store, &token_key)?;
@@ -4956,6 +10571,14 @@ pub fn dossier_list(
                 (None, None)
             }
         });
+        // display the user if the viewer may see the owner
+        let user = if owner.is_some() {
+            active_user(deps.storage, block, &id)?
+                .map(|u| u.into_humanized(deps.api))
+                .transpose()?
+        } else {
+            None
+        };
         dossiers.push(BatchNftDossierElement {
             token_id: id,
             owner,
@@ -4972,6 +10595,9 @@ pub fn dossier_list(
             private_metadata_is_public_expiration,
             token_approvals,
             inventory_approvals,
+            edition,
+            revocation: revocation(deps.storage, deps.api, idx)?,
+            user,
         });
     }
     Ok(dossiers)
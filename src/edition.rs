@@ -0,0 +1,88 @@
+use cosmwasm_std::{CanonicalAddr, StdResult, Storage, Uint128};
+use cosmwasm_storage::{PrefixedStorage, ReadonlyPrefixedStorage};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::state::{may_load, remove, save, PREFIX_EDITION_BALANCES, PREFIX_EDITION_SUPPLY};
+
+/// edition information for display
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
+pub struct EditionInfo {
+    /// number of prints of the edition
+    pub supply: Uint128,
+    /// number of prints held by the querier, if the querier is known
+    pub balance: Option<Uint128>,
+}
+
+/// an owner's balance of an edition in a token list
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
+pub struct EditionBalance {
+    /// token id of the edition
+    pub token_id: String,
+    /// number of prints the owner holds
+    pub balance: Uint128,
+}
+
+/// Returns StdResult<Option<u128>> of the number of prints of an edition, or None if the token
+/// is not an edition
+///
+/// # Arguments
+///
+/// * `storage` - a reference to the contract's storage
+/// * `idx` - the token's idx
+pub fn edition_supply(storage: &dyn Storage, idx: u32) -> StdResult<Option<u128>> {
+    let supply_store = ReadonlyPrefixedStorage::new(storage, PREFIX_EDITION_SUPPLY);
+    may_load(&supply_store, &idx.to_le_bytes())
+}
+
+/// Returns StdResult<()> after saving the number of prints of an edition
+///
+/// # Arguments
+///
+/// * `storage` - a mutable reference to the contract's storage
+/// * `idx` - the token's idx
+/// * `supply` - the number of prints
+pub fn save_edition_supply(storage: &mut dyn Storage, idx: u32, supply: u128) -> StdResult<()> {
+    let mut supply_store = PrefixedStorage::new(storage, PREFIX_EDITION_SUPPLY);
+    save(&mut supply_store, &idx.to_le_bytes(), &supply)
+}
+
+/// Returns StdResult<u128> of the number of prints of an edition an address holds
+///
+/// # Arguments
+///
+/// * `storage` - a reference to the contract's storage
+/// * `idx` - the token's idx
+/// * `holder` - a reference to the holder's address
+pub fn edition_balance(storage: &dyn Storage, idx: u32, holder: &CanonicalAddr) -> StdResult<u128> {
+    let bal_store = ReadonlyPrefixedStorage::multilevel(
+        storage,
+        &[PREFIX_EDITION_BALANCES, &idx.to_le_bytes()],
+    );
+    Ok(may_load(&bal_store, holder.as_slice())?.unwrap_or(0))
+}
+
+/// Returns StdResult<()> after saving the number of prints of an edition an address holds.  A
+/// zero balance is removed from storage
+///
+/// # Arguments
+///
+/// * `storage` - a mutable reference to the contract's storage
+/// * `idx` - the token's idx
+/// * `holder` - a reference to the holder's address
+/// * `balance` - the holder's new balance
+pub fn save_edition_balance(
+    storage: &mut dyn Storage,
+    idx: u32,
+    holder: &CanonicalAddr,
+    balance: u128,
+) -> StdResult<()> {
+    let mut bal_store =
+        PrefixedStorage::multilevel(storage, &[PREFIX_EDITION_BALANCES, &idx.to_le_bytes()]);
+    if balance == 0 {
+        remove(&mut bal_store, holder.as_slice());
+        Ok(())
+    } else {
+        save(&mut bal_store, holder.as_slice(), &balance)
+    }
+}
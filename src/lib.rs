@@ -1,16 +1,50 @@
 //#![allow(clippy::field_reassign_with_default)]
+pub mod auction;
+pub mod badge;
 pub mod contract;
+pub mod edition;
 pub mod expiration;
 mod inventory;
+pub mod lock;
+pub mod market;
+pub mod migrate;
+pub mod mint_phase;
 pub mod mint_run;
+pub mod minter_quota;
 pub mod msg;
+pub mod offer;
 pub mod receiver;
+pub mod recipe;
+pub mod rental;
+pub mod reveal_pool;
 pub mod royalties;
+pub mod sale;
 pub mod state;
+pub mod swap;
 pub mod token;
+pub mod token_id;
+pub mod tombstone;
+mod unittest_auction;
+mod unittest_edition;
 mod unittest_handles;
 mod unittest_inventory;
+mod unittest_lock;
+mod unittest_market;
+mod unittest_max_supply;
+mod unittest_migrate;
+mod unittest_mint_phase;
 mod unittest_mint_run;
+mod unittest_minter_quota;
 mod unittest_non_transferable;
+mod unittest_offer;
 mod unittest_queries;
+mod unittest_recipe;
+mod unittest_rental;
+mod unittest_reveal_pool;
+mod unittest_roles;
 mod unittest_royalties;
+mod unittest_sale;
+mod unittest_swap;
+mod unittest_token_id;
+mod unittest_voucher;
+pub mod voucher;
@@ -0,0 +1,105 @@
+use cosmwasm_std::{Addr, Api, BlockInfo, CanonicalAddr, StdError, StdResult, Storage};
+use cosmwasm_storage::ReadonlyPrefixedStorage;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::expiration::Expiration;
+use crate::state::{json_may_load, PREFIX_LOCKER_APPROVALS, PREFIX_LOCKS};
+
+/// lock preventing a token from being transferred, sent, or burned
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
+pub struct Lock {
+    /// address that locked the token and may unlock it
+    pub locker: Addr,
+    /// expiration of the lock
+    pub expires: Expiration,
+}
+
+/// stored lock of a token
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct StoredLock {
+    /// address that locked the token and may unlock it
+    pub locker: CanonicalAddr,
+    /// expiration of the lock
+    pub expires: Expiration,
+}
+
+impl StoredLock {
+    /// Returns StdResult<Lock> from converting a StoredLock to a displayable Lock
+    ///
+    /// # Arguments
+    ///
+    /// * `api` - a reference to the Api used to convert human and canonical addresses
+    pub fn into_humanized(self, api: &dyn Api) -> StdResult<Lock> {
+        Ok(Lock {
+            locker: api.addr_humanize(&self.locker)?,
+            expires: self.expires,
+        })
+    }
+}
+
+/// Returns StdResult<Option<StoredLock>> of the token's lock, or None if it is not locked or
+/// the lock has expired
+///
+/// # Arguments
+///
+/// * `storage` - a reference to the contract's storage
+/// * `block` - a reference to the current BlockInfo
+/// * `idx` - the token's idx
+pub fn active_lock(
+    storage: &dyn Storage,
+    block: &BlockInfo,
+    idx: u32,
+) -> StdResult<Option<StoredLock>> {
+    let lock_store = ReadonlyPrefixedStorage::new(storage, PREFIX_LOCKS);
+    Ok(
+        json_may_load::<StoredLock>(&lock_store, &idx.to_le_bytes())?
+            .filter(|l| !l.expires.is_expired(block)),
+    )
+}
+
+/// Returns StdResult<()> error if the token is locked
+///
+/// # Arguments
+///
+/// * `storage` - a reference to the contract's storage
+/// * `block` - a reference to the current BlockInfo
+/// * `token_id` - string slice of the token id
+/// * `idx` - the token's idx
+pub fn check_unlocked(
+    storage: &dyn Storage,
+    block: &BlockInfo,
+    token_id: &str,
+    idx: u32,
+) -> StdResult<()> {
+    if active_lock(storage, block, idx)?.is_some() {
+        return Err(StdError::generic_err(format!(
+            "Token ID: {} is locked",
+            token_id
+        )));
+    }
+    Ok(())
+}
+
+/// Returns StdResult<bool> true if the owner has approved the address to lock its tokens and
+/// the approval has not expired
+///
+/// # Arguments
+///
+/// * `storage` - a reference to the contract's storage
+/// * `block` - a reference to the current BlockInfo
+/// * `owner` - a reference to the token owner's address
+/// * `locker` - a reference to the address in question
+pub fn is_approved_locker(
+    storage: &dyn Storage,
+    block: &BlockInfo,
+    owner: &CanonicalAddr,
+    locker: &CanonicalAddr,
+) -> StdResult<bool> {
+    let approval_store =
+        ReadonlyPrefixedStorage::multilevel(storage, &[PREFIX_LOCKER_APPROVALS, owner.as_slice()]);
+    Ok(
+        json_may_load::<Expiration>(&approval_store, locker.as_slice())?
+            .map_or(false, |exp| !exp.is_expired(block)),
+    )
+}
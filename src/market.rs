@@ -0,0 +1,64 @@
+use cosmwasm_std::{Addr, Api, CanonicalAddr, Coin, StdError, StdResult, Uint128};
+use primitive_types::U256;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::royalties::StoredRoyaltyInfo;
+
+/// fixed-price listing of a token for sale
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq)]
+pub struct Listing {
+    /// id of the listed token
+    pub token_id: String,
+    /// price of the token
+    pub price: Coin,
+}
+
+/// stored listing of a token
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct StoredListing {
+    /// owner that listed the token
+    pub seller: CanonicalAddr,
+    /// price of the token
+    pub price: Coin,
+}
+
+/// Returns StdResult<Vec<(Addr, Uint128)>> of the payments that split a sale price between
+/// the royalty recipients and the seller.  Royalties are rounded down and the seller receives
+/// the remainder
+///
+/// # Arguments
+///
+/// * `api` - a reference to the Api used to convert human and canonical addresses
+/// * `royalty_info` - optional reference to the token's royalties
+/// * `seller` - a reference to the seller's address
+/// * `price` - the sale price
+pub fn split_payment(
+    api: &dyn Api,
+    royalty_info: Option<&StoredRoyaltyInfo>,
+    seller: &CanonicalAddr,
+    price: Uint128,
+) -> StdResult<Vec<(Addr, Uint128)>> {
+    let mut payments: Vec<(Addr, Uint128)> = Vec::new();
+    let mut remaining = price;
+    if let Some(royalties) = royalty_info {
+        // the number of decimal places was checked when the royalties were stored
+        let denominator = U256::from(10).pow(U256::from(royalties.decimal_places_in_rates));
+        for royalty in royalties.royalties.iter() {
+            let share = U256::from(price.u128()) * U256::from(royalty.rate) / denominator;
+            // a share can not exceed the price because the rates sum to at most 100%
+            let amount = Uint128::new(share.as_u128());
+            if amount.is_zero() {
+                continue;
+            }
+            remaining = remaining.checked_sub(amount).map_err(|_| {
+                StdError::generic_err("The sum of royalty rates must not exceed 100%")
+            })?;
+            payments.push((api.addr_humanize(&royalty.recipient)?, amount));
+        }
+    }
+    if !remaining.is_zero() {
+        payments.push((api.addr_humanize(seller)?, remaining));
+    }
+    Ok(payments)
+}
@@ -0,0 +1,71 @@
+use cosmwasm_std::{CanonicalAddr, StdError, StdResult, Storage};
+use cosmwasm_storage::PrefixedStorage;
+
+use crate::msg::Role;
+use crate::state::{
+    load_role, may_load, save, save_role, CONTRACT_VERSION_KEY, PREFIX_MINTER_GRANTS,
+};
+
+/// version of the storage layout used by this code
+pub const CONTRACT_VERSION: u32 = 2;
+
+/// an upgrade step that converts the storage layout of one version to the next
+type UpgradeStep = fn(&mut dyn Storage) -> StdResult<()>;
+
+/// ordered upgrade steps.  UPGRADES[n] converts storage of version n + 1 to version n + 2
+const UPGRADES: [UpgradeStep; CONTRACT_VERSION as usize - 1] = [upgrade_v1_to_v2];
+
+/// Returns StdResult<u32> of the storage version that was migrated from
+///
+/// runs every upgrade step needed to bring the contract's storage from its recorded version
+/// to the current version, and records the new version.  Storage written before versioning
+/// was introduced has the same Config, token info, tx, and inventory layouts as version 1, so
+/// it is treated as version 1
+///
+/// # Arguments
+///
+/// * `storage` - a mutable reference to the contract's storage
+pub fn migrate_storage(storage: &mut dyn Storage) -> StdResult<u32> {
+    let from: u32 = may_load(storage, CONTRACT_VERSION_KEY)?.unwrap_or(1);
+    if from > CONTRACT_VERSION {
+        return Err(StdError::generic_err(format!(
+            "Can not migrate from storage version {} to older version {}",
+            from, CONTRACT_VERSION
+        )));
+    }
+    for step in UPGRADES.iter().skip(from.saturating_sub(1) as usize) {
+        step(storage)?;
+    }
+    save(storage, CONTRACT_VERSION_KEY, &CONTRACT_VERSION)?;
+    Ok(from)
+}
+
+/// Returns StdResult<()>
+///
+/// upgrades v1 storage to v2.  Before roles existed, minters could also update metadata and
+/// set the default royalties, so existing minters are granted the metadata editor and royalty
+/// manager roles to keep those powers.  These grants are recorded so that removing a minter
+/// also removes the powers it only held because it was a minter
+///
+/// # Arguments
+///
+/// * `storage` - a mutable reference to the contract's storage
+fn upgrade_v1_to_v2(storage: &mut dyn Storage) -> StdResult<()> {
+    let minters = load_role(storage, Role::Minter)?;
+    for role in [Role::MetadataEditor, Role::RoyaltyManager] {
+        let mut holders = load_role(storage, role)?;
+        let mut granted: Vec<CanonicalAddr> = Vec::new();
+        for minter in minters.iter() {
+            if !holders.contains(minter) {
+                holders.push(minter.clone());
+                granted.push(minter.clone());
+            }
+        }
+        save_role(storage, role, &holders)?;
+        if !granted.is_empty() {
+            let mut grant_store = PrefixedStorage::new(storage, PREFIX_MINTER_GRANTS);
+            save(&mut grant_store, &[role.to_u8()], &granted)?;
+        }
+    }
+    Ok(())
+}
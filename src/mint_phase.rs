@@ -0,0 +1,92 @@
+use cosmwasm_std::{Binary, BlockInfo, Coin, StdError, StdResult};
+use schemars::JsonSchema;
+use secret_toolkit::crypto::sha_256;
+use serde::{Deserialize, Serialize};
+
+/// an allowlist mint phase.  Allowed addresses and their allocations are the leaves of a
+/// Merkle tree whose root is stored in the phase
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq)]
+pub struct MintPhase {
+    /// unique name of the phase
+    pub name: String,
+    /// root of the Merkle tree of allowed addresses and their allocations
+    pub merkle_root: Binary,
+    /// optional time the phase starts (in seconds since 01/01/1970)
+    pub start: Option<u64>,
+    /// optional time the phase ends (in seconds since 01/01/1970)
+    pub end: Option<u64>,
+    /// optional price of one token in native coins.  If omitted, minting in this phase is free
+    pub price: Option<Coin>,
+}
+
+impl MintPhase {
+    /// Returns StdResult<()>
+    ///
+    /// returns Ok if the phase is open at the current block time
+    ///
+    /// # Arguments
+    ///
+    /// * `block` - a reference to the current BlockInfo
+    pub fn check_open(&self, block: &BlockInfo) -> StdResult<()> {
+        let now = block.time.seconds();
+        if self.start.map_or(false, |s| now < s) {
+            return Err(StdError::generic_err(format!(
+                "Mint phase {} has not started yet",
+                self.name
+            )));
+        }
+        if self.end.map_or(false, |e| now >= e) {
+            return Err(StdError::generic_err(format!(
+                "Mint phase {} has ended",
+                self.name
+            )));
+        }
+        Ok(())
+    }
+
+    /// Returns StdResult<()>
+    ///
+    /// returns Ok if the proof shows the address was allowed the allocation in this phase
+    ///
+    /// # Arguments
+    ///
+    /// * `address` - the address minting
+    /// * `allocation` - the number of tokens the address claims it may mint
+    /// * `proof` - the sibling hashes from the address' leaf up to the root
+    pub fn verify(&self, address: &str, allocation: u32, proof: &[Binary]) -> StdResult<()> {
+        let mut hash = leaf_hash(address, allocation);
+        for sibling in proof.iter() {
+            hash = pair_hash(&hash, sibling.as_slice());
+        }
+        if hash.as_slice() != self.merkle_root.as_slice() {
+            return Err(StdError::generic_err(format!(
+                "The proof does not show an allocation of {} in mint phase {}",
+                allocation, self.name
+            )));
+        }
+        Ok(())
+    }
+}
+
+/// Returns [u8; 32] of the Merkle leaf of an address and its allocation.  The leaf is the
+/// sha256 hash of the string "<address>:<allocation>"
+///
+/// # Arguments
+///
+/// * `address` - the allowed address
+/// * `allocation` - the number of tokens the address may mint
+pub fn leaf_hash(address: &str, allocation: u32) -> [u8; 32] {
+    sha_256(format!("{}:{}", address, allocation).as_bytes())
+}
+
+/// Returns [u8; 32] of the hash of two Merkle nodes.  The nodes are sorted before being
+/// concatenated so proofs do not need to specify which side each sibling is on
+///
+/// # Arguments
+///
+/// * `a` - one node
+/// * `b` - the other node
+pub fn pair_hash(a: &[u8], b: &[u8]) -> [u8; 32] {
+    let (first, second) = if a <= b { (a, b) } else { (b, a) };
+    sha_256(&[first, second].concat())
+}
@@ -2,6 +2,8 @@ use cosmwasm_std::{Addr, Api, CanonicalAddr, StdResult};
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
+use crate::token::Metadata;
+
 /// information about the minting of the NFT
 #[derive(Serialize, Deserialize, Clone, PartialEq, Eq, JsonSchema, Debug)]
 pub struct MintRunInfo {
@@ -65,6 +67,60 @@ impl StoredMintRunInfo {
     }
 }
 
+/// information about one mint run of a mint run id
+#[derive(Serialize, Deserialize, Clone, PartialEq, Eq, JsonSchema, Debug)]
+pub struct MintRun {
+    /// number of this mint run
+    pub mint_run: u32,
+    /// address that minted this run
+    pub creator: Addr,
+    /// time of minting (in seconds since 01/01/1970)
+    pub time_of_minting: u64,
+    /// number of tokens minted in this run
+    pub quantity: u32,
+    /// id of the first token minted in this run
+    pub first_token_id: String,
+    /// id of the last token minted in this run
+    pub last_token_id: String,
+}
+
+/// stored information about one mint run of a mint run id
+#[derive(Serialize, Deserialize, Clone, PartialEq, Eq, JsonSchema, Debug)]
+pub struct StoredMintRun {
+    /// address that minted this run
+    pub creator: CanonicalAddr,
+    /// time of minting (in seconds since 01/01/1970)
+    pub time_of_minting: u64,
+    /// number of tokens minted in this run
+    pub quantity: u32,
+    /// index of the first token minted in this run.  The tokens of a run have consecutive
+    /// indices
+    pub first_index: u32,
+    /// id of the first token minted in this run
+    pub first_token_id: String,
+    /// id of the last token minted in this run
+    pub last_token_id: String,
+}
+
+impl StoredMintRun {
+    /// Returns StdResult<MintRun> from creating a MintRun from a StoredMintRun
+    ///
+    /// # Arguments
+    ///
+    /// * `api` - a reference to the Api used to convert human and canonical addresses
+    /// * `mint_run` - the number of this mint run
+    pub fn into_humanized(self, api: &dyn Api, mint_run: u32) -> StdResult<MintRun> {
+        Ok(MintRun {
+            mint_run,
+            creator: api.addr_humanize(&self.creator)?,
+            time_of_minting: self.time_of_minting,
+            quantity: self.quantity,
+            first_token_id: self.first_token_id,
+            last_token_id: self.last_token_id,
+        })
+    }
+}
+
 /// Serial number to give an NFT when minting
 #[derive(Serialize, Deserialize, Clone, PartialEq, Eq, JsonSchema, Debug)]
 pub struct SerialNumber {
@@ -81,3 +137,14 @@ pub struct SerialNumber {
     /// represent that this token is number m of n
     pub quantity_minted_this_run: Option<u32>,
 }
+
+/// metadata that replaces the template of MintNftClones for one serial number
+#[derive(Serialize, Deserialize, Clone, PartialEq, Eq, JsonSchema, Debug)]
+pub struct SerialOverride {
+    /// serial number of the clone whose metadata is replaced
+    pub serial_number: u32,
+    /// optional public metadata of this clone
+    pub public_metadata: Option<Metadata>,
+    /// optional private metadata of this clone
+    pub private_metadata: Option<Metadata>,
+}
@@ -0,0 +1,154 @@
+use cosmwasm_std::{BlockInfo, StdError, StdResult};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::expiration::Expiration;
+
+/// limits placed on a minter
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, Default, PartialEq, Eq)]
+pub struct MinterQuota {
+    /// optional maximum number of tokens the minter may ever mint
+    pub lifetime: Option<u32>,
+    /// optional maximum number of tokens the minter may mint per window
+    pub window: Option<QuotaWindow>,
+    /// optional maximum number of tokens the minter may mint in one message
+    pub max_batch: Option<u32>,
+}
+
+/// a limit on the number of tokens that may be minted within a window.  A window starts with
+/// the first mint after the previous window has ended
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
+pub struct QuotaWindow {
+    /// number of tokens that may be minted per window
+    pub limit: u32,
+    /// length of the window
+    pub length: WindowLength,
+}
+
+/// length of a quota window
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Copy, Debug, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum WindowLength {
+    /// window measured in seconds of block time
+    Seconds(u64),
+    /// window measured in block heights
+    Blocks(u64),
+}
+
+impl WindowLength {
+    /// Returns u64 of the current position of the block in this window's units
+    ///
+    /// # Arguments
+    ///
+    /// * `block` - a reference to the current BlockInfo
+    pub fn now(&self, block: &BlockInfo) -> u64 {
+        match self {
+            WindowLength::Seconds(_) => block.time.seconds(),
+            WindowLength::Blocks(_) => block.height,
+        }
+    }
+
+    /// Returns Expiration of when a window starting at `start` ends
+    ///
+    /// # Arguments
+    ///
+    /// * `start` - the start of the window in this window's units
+    pub fn end(&self, start: u64) -> Expiration {
+        match self {
+            WindowLength::Seconds(secs) => Expiration::AtTime(start.saturating_add(*secs)),
+            WindowLength::Blocks(blocks) => Expiration::AtHeight(start.saturating_add(*blocks)),
+        }
+    }
+
+    /// Returns bool, true if the length is zero
+    pub fn is_zero(&self) -> bool {
+        match self {
+            WindowLength::Seconds(len) | WindowLength::Blocks(len) => *len == 0,
+        }
+    }
+}
+
+/// a minter's mint counts
+#[derive(Serialize, Deserialize, Default)]
+pub struct MinterUsage {
+    /// number of tokens ever minted
+    pub total: u32,
+    /// start of the current window in the window's units
+    pub window_start: u64,
+    /// number of tokens minted in the current window
+    pub window_cnt: u32,
+}
+
+impl MinterUsage {
+    /// Returns bool, true if the current window is still open
+    ///
+    /// # Arguments
+    ///
+    /// * `window` - a reference to the quota window
+    /// * `block` - a reference to the current BlockInfo
+    pub fn window_is_open(&self, window: &QuotaWindow, block: &BlockInfo) -> bool {
+        self.window_cnt > 0 && !window.length.end(self.window_start).is_expired(block)
+    }
+
+    /// Returns u32 of the number of tokens that may still be minted in the current window
+    ///
+    /// # Arguments
+    ///
+    /// * `window` - a reference to the quota window
+    /// * `block` - a reference to the current BlockInfo
+    pub fn window_remaining(&self, window: &QuotaWindow, block: &BlockInfo) -> u32 {
+        if self.window_is_open(window, block) {
+            window.limit.saturating_sub(self.window_cnt)
+        } else {
+            window.limit
+        }
+    }
+
+    /// Returns StdResult<()>
+    ///
+    /// records the minting of `count` tokens if the quota allows it
+    ///
+    /// # Arguments
+    ///
+    /// * `quota` - a reference to the minter's quota
+    /// * `block` - a reference to the current BlockInfo
+    /// * `count` - number of tokens being minted
+    pub fn consume(&mut self, quota: &MinterQuota, block: &BlockInfo, count: u32) -> StdResult<()> {
+        if let Some(max) = quota.max_batch {
+            if count > max {
+                return Err(StdError::generic_err(format!(
+                    "Minting {} tokens exceeds the maximum batch size of {}",
+                    count, max
+                )));
+            }
+        }
+        let total = self.total.checked_add(count).ok_or_else(|| {
+            StdError::generic_err("Attempting to mint more tokens than the implementation limit")
+        })?;
+        if let Some(lifetime) = quota.lifetime {
+            if total > lifetime {
+                return Err(StdError::generic_err(format!(
+                    "Minting {} tokens exceeds the remaining lifetime quota of {}",
+                    count,
+                    lifetime.saturating_sub(self.total)
+                )));
+            }
+        }
+        if let Some(window) = quota.window.as_ref() {
+            let remaining = self.window_remaining(window, block);
+            if count > remaining {
+                return Err(StdError::generic_err(format!(
+                    "Minting {} tokens exceeds the {} remaining in the current quota window",
+                    count, remaining
+                )));
+            }
+            if !self.window_is_open(window, block) {
+                self.window_start = window.length.now(block);
+                self.window_cnt = 0;
+            }
+            self.window_cnt += count;
+        }
+        self.total = total;
+        Ok(())
+    }
+}
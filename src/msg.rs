@@ -1,14 +1,28 @@
 #![allow(clippy::large_enum_variant)]
 
-use cosmwasm_std::{Addr, Binary, Coin};
+use cosmwasm_std::{Addr, Binary, Coin, Uint128};
 use schemars::JsonSchema;
 use secret_toolkit::permit::Permit;
 use serde::{Deserialize, Serialize};
 
+use crate::auction::{Auction, AuctionKind};
+use crate::badge::Revocation;
+use crate::edition::{EditionBalance, EditionInfo};
 use crate::expiration::Expiration;
-use crate::mint_run::{MintRunInfo, SerialNumber};
+use crate::lock::Lock;
+use crate::market::Listing;
+use crate::mint_phase::MintPhase;
+use crate::mint_run::{MintRun, MintRunInfo, SerialNumber, SerialOverride};
+use crate::minter_quota::MinterQuota;
+use crate::offer::{Offer, OfferSnip20};
+use crate::recipe::Recipe;
+use crate::rental::TokenUser;
 use crate::royalties::{DisplayRoyaltyInfo, RoyaltyInfo};
+use crate::sale::{PoolEntry, Sale, Snip20Price};
+use crate::swap::{Swap, SwapToken};
 use crate::token::{Extension, Metadata};
+use crate::token_id::TokenIdScheme;
+use crate::voucher::{MintVoucher, VoucherMetadata};
 
 /// Instantiation message
 #[derive(Serialize, Deserialize, JsonSchema)]
@@ -33,6 +47,10 @@ pub struct InstantiateMsg {
     pub post_init_callback: Option<PostInstantiateCallback>,
 }
 
+/// Migration message
+#[derive(Serialize, Deserialize, JsonSchema)]
+pub struct MigrateMsg {}
+
 /// This type represents optional configuration values.
 /// All values are optional and have defaults which are more private by default,
 /// but can be overridden if necessary
@@ -86,6 +104,29 @@ impl Default for InstantiateConfig {
     }
 }
 
+/// the direction a config flag is restricted to once it has been made one-way
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Copy, Debug, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum Ratchet {
+    /// the flag may only be changed to true
+    OnlyEnable,
+    /// the flag may only be changed to false
+    OnlyDisable,
+}
+
+/// one-way restrictions on the config flags.  Once a flag has been given a ratchet, the
+/// ratchet can not be removed or reversed
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, Default, PartialEq, Eq)]
+pub struct ConfigRatchets {
+    pub public_token_supply: Option<Ratchet>,
+    pub public_owner: Option<Ratchet>,
+    pub enable_sealed_metadata: Option<Ratchet>,
+    pub unwrapped_metadata_is_private: Option<Ratchet>,
+    pub minter_may_update_metadata: Option<Ratchet>,
+    pub owner_may_update_metadata: Option<Ratchet>,
+    pub enable_burn: Option<Ratchet>,
+}
+
 /// info needed to perform a callback message after instantiation
 #[derive(Serialize, Deserialize, JsonSchema, Clone, Debug)]
 pub struct PostInstantiateCallback {
@@ -147,10 +188,14 @@ pub enum ExecuteMsg {
         quantity: u32,
         /// optional owner address. if omitted, owned by the message sender
         owner: Option<String>,
-        /// optional public metadata that can be seen by everyone
+        /// optional public metadata that can be seen by everyone.  The placeholders `{serial}`,
+        /// `{quantity}`, `{mint_run}`, and `{token_id}` are filled in for each clone
         public_metadata: Option<Metadata>,
-        /// optional private metadata that can only be seen by the owner and whitelist
+        /// optional private metadata that can only be seen by the owner and whitelist.  The
+        /// same placeholders as the public metadata are filled in
         private_metadata: Option<Metadata>,
+        /// optional list of serial numbers whose metadata replaces the template
+        serial_overrides: Option<Vec<SerialOverride>>,
         /// optional royalty information for these tokens
         royalty_info: Option<RoyaltyInfo>,
         /// optional memo for the mint txs
@@ -185,7 +230,8 @@ pub enum ExecuteMsg {
         /// optional message length padding
         padding: Option<String>,
     },
-    /// Reveal the private metadata of a sealed token and mark the token as having been unwrapped
+    /// Reveal the private metadata of a sealed token and mark the token as having been unwrapped.
+    /// If there is a reveal pool, the token is instead given a randomly chosen item of the pool
     Reveal {
         /// id of the token to unwrap
         token_id: String,
@@ -389,6 +435,486 @@ pub enum ExecuteMsg {
         /// optional message length padding
         padding: Option<String>,
     },
+    /// grant a role to a list of addresses
+    GrantRole {
+        /// role to grant
+        role: Role,
+        /// addresses receiving the role
+        addresses: Vec<String>,
+        /// optional message length padding
+        padding: Option<String>,
+    },
+    /// revoke a role from a list of addresses
+    RevokeRole {
+        /// role to revoke
+        role: Role,
+        /// addresses losing the role
+        addresses: Vec<String>,
+        /// optional message length padding
+        padding: Option<String>,
+    },
+    /// set or remove the limits on how many tokens a minter may mint
+    SetMinterQuota {
+        /// minter whose limits are being set
+        minter: String,
+        /// the new limits.  If omitted, the minter's limits are removed
+        quota: Option<MinterQuota>,
+        /// optional message length padding
+        padding: Option<String>,
+    },
+    /// set or remove the maximum number of tokens that may ever be minted, either for the
+    /// whole collection or for a mint run id.  Burned tokens still count toward the maximum
+    SetMaxSupply {
+        /// optional mint run id whose cap is being set.  If omitted, the collection-wide max
+        /// supply is set
+        mint_run_id: Option<String>,
+        /// the new maximum.  If omitted, the maximum is removed
+        max_supply: Option<u32>,
+        /// optional message length padding
+        padding: Option<String>,
+    },
+    /// set or remove the public sale
+    SetSale {
+        /// the new sale.  If omitted, the sale is closed
+        sale: Option<Sale>,
+        /// optional message length padding
+        padding: Option<String>,
+    },
+    /// add metadata to the end of the public sale's metadata pool
+    LoadSalePool {
+        /// metadata of the tokens to sell
+        metadata: Vec<PoolEntry>,
+        /// optional message length padding
+        padding: Option<String>,
+    },
+    /// buy tokens from the public sale by sending the price with the message.  Any amount
+    /// sent above the price is returned
+    PublicMint {
+        /// optional number of tokens to buy.  Defaults to 1
+        quantity: Option<u32>,
+        /// optional memo for the mint txs
+        memo: Option<String>,
+        /// optional message length padding
+        padding: Option<String>,
+    },
+    /// set or remove the price of a token in a SNIP-20.  The first time a SNIP-20 is given a
+    /// price, the contract registers with it to receive payments
+    SetSnip20Price {
+        /// address of the SNIP-20 contract
+        contract_address: String,
+        /// code hash of the SNIP-20 contract
+        code_hash: String,
+        /// the new price of one token.  If omitted, the SNIP-20 is no longer accepted
+        price: Option<Uint128>,
+        /// optional message length padding
+        padding: Option<String>,
+    },
+    /// buy tokens from the public sale with SNIP-20 tokens.  This is called by a SNIP-20
+    /// contract when tokens are sent to this contract with a ReceiveMsg
+    Receive {
+        /// address that called the SNIP-20 Send
+        sender: String,
+        /// owner of the SNIP-20 tokens that were sent
+        from: String,
+        /// amount of SNIP-20 tokens sent
+        amount: Uint128,
+        /// optional memo of the SNIP-20 transfer
+        memo: Option<String>,
+        /// the ReceiveMsg describing what to do with the payment
+        msg: Option<Binary>,
+    },
+    /// replace the allowlist mint phases
+    SetMintPhases {
+        /// the new mint phases.  An address' usage is kept for phases whose name is unchanged
+        phases: Vec<MintPhase>,
+        /// optional message length padding
+        padding: Option<String>,
+    },
+    /// mint tokens from the public sale's metadata during an allowlist mint phase by proving
+    /// the sender's allocation.  If the phase has a price, it must be sent with the message,
+    /// and any amount sent above the price is returned
+    AllowlistMint {
+        /// name of the mint phase
+        phase: String,
+        /// number of tokens the sender was allocated in the phase
+        allocation: u32,
+        /// the sibling hashes from the sender's Merkle leaf up to the phase's root
+        proof: Vec<Binary>,
+        /// optional number of tokens to mint.  Defaults to 1
+        quantity: Option<u32>,
+        /// optional memo for the mint txs
+        memo: Option<String>,
+        /// optional message length padding
+        padding: Option<String>,
+    },
+    /// set or remove the secp256k1 public key that signs mint vouchers
+    SetVoucherSigner {
+        /// optional compressed or uncompressed secp256k1 public key.  If omitted, vouchers can
+        /// no longer be redeemed
+        public_key: Option<Binary>,
+        /// optional message length padding
+        padding: Option<String>,
+    },
+    /// mint a token authorized by a voucher signed by the voucher signer
+    RedeemVoucher {
+        /// the mint voucher
+        voucher: MintVoucher,
+        /// the voucher signer's 64 byte secp256k1 signature of the voucher's message hash
+        signature: Binary,
+        /// the token's metadata, which must match the voucher's metadata hash
+        metadata: VoucherMetadata,
+        /// optional message length padding
+        padding: Option<String>,
+    },
+    /// commit to the provenance hash of the metadata pool that sealed tokens will be randomly
+    /// revealed from, and to the hash of the secret seed of the random assignment.  The
+    /// commitment can be replaced until the first item is loaded
+    CommitRevealPool {
+        /// provenance hash of the pool's items
+        provenance_hash: Binary,
+        /// number of items in the pool
+        size: u32,
+        /// sha256 hash of the secret seed that will be revealed with RevealSeed
+        seed_hash: Binary,
+        /// optional message length padding
+        padding: Option<String>,
+    },
+    /// reveal the secret seed of the reveal pool's random assignment.  No items are assigned
+    /// before the seed is revealed, and no tokens can be minted after
+    RevealSeed {
+        /// the secret seed whose sha256 hash was committed
+        seed: Binary,
+        /// optional message length padding
+        padding: Option<String>,
+    },
+    /// add items to the end of the reveal pool.  Loading the last item fails if the pool does
+    /// not match the committed provenance hash
+    LoadRevealPool {
+        /// the items to add
+        metadata: Vec<PoolEntry>,
+        /// optional message length padding
+        padding: Option<String>,
+    },
+    /// mint prints of an edition.  An edition is a single token id with one metadata record whose
+    /// prints are held as balances.  If the token id is already an edition, more prints are
+    /// added to it
+    MintEdition {
+        /// optional token id. if omitted, use current token index
+        token_id: Option<String>,
+        /// optional address to receive the prints. if omitted, held by the message sender
+        owner: Option<String>,
+        /// number of prints to mint
+        amount: Uint128,
+        /// optional public metadata that can be seen by everyone.  Only allowed when creating
+        /// the edition
+        public_metadata: Option<Metadata>,
+        /// optional private metadata that can only be seen by holders and the whitelist.  Only
+        /// allowed when creating the edition
+        private_metadata: Option<Metadata>,
+        /// optional royalty information for this edition.  Only used when creating the edition
+        royalty_info: Option<RoyaltyInfo>,
+        /// optional memo for the mint tx
+        memo: Option<String>,
+        /// optional message length padding
+        padding: Option<String>,
+    },
+    /// transfer some of the sender's prints of an edition
+    TransferEdition {
+        /// recipient of the transfer
+        recipient: String,
+        /// id of the edition
+        token_id: String,
+        /// number of prints to transfer
+        amount: Uint128,
+        /// optional memo for the tx
+        memo: Option<String>,
+        /// optional message length padding
+        padding: Option<String>,
+    },
+    /// schedule when every sealed token will be unwrapped.  The metadata of each token is moved
+    /// the first time the token's metadata is changed after the reveal
+    ScheduleReveal {
+        /// optional time or block height of the reveal.  If omitted, the scheduled reveal is
+        /// cancelled
+        at: Option<Expiration>,
+        /// optional message length padding
+        padding: Option<String>,
+    },
+    /// set or remove the scheme used to generate the ids of tokens minted without a token id
+    SetTokenIdScheme {
+        /// the new scheme.  If omitted, the mint index is used
+        scheme: Option<TokenIdScheme>,
+        /// optional message length padding
+        padding: Option<String>,
+    },
+    /// replace the burn-to-redeem recipes
+    SetRecipes {
+        /// the new recipes
+        recipes: Vec<Recipe>,
+        /// optional message length padding
+        padding: Option<String>,
+    },
+    /// burn tokens the sender owns to mint the output of a recipe to the sender
+    Redeem {
+        /// name of the recipe
+        recipe: String,
+        /// the tokens to burn, one for each of the recipe's inputs in the same order
+        token_ids: Vec<String>,
+        /// optional memo for the burn and mint txs
+        memo: Option<String>,
+        /// optional message length padding
+        padding: Option<String>,
+    },
+    /// revoke a non-transferable token.  Only the minter that issued the token may revoke it.
+    /// The token is kept, but is marked as revoked
+    RevokeBadge {
+        /// token to revoke
+        token_id: String,
+        /// why the token is revoked
+        reason: String,
+        /// optional message length padding
+        padding: Option<String>,
+    },
+    /// approve an address to lock any of the sender's tokens
+    ApproveLocker {
+        /// address being approved
+        locker: String,
+        /// optional expiration for the approval.  Defaults to never expiring
+        expires: Option<Expiration>,
+        /// optional message length padding
+        padding: Option<String>,
+    },
+    /// revoke an address' approval to lock the sender's tokens.  Existing locks are kept
+    RevokeLocker {
+        /// address whose approval is revoked
+        locker: String,
+        /// optional message length padding
+        padding: Option<String>,
+    },
+    /// lock a token so it can not be transferred, sent, or burned until it is unlocked or the
+    /// lock expires.  Only the token's owner or an address it approved as a locker may lock it
+    Lock {
+        /// token to lock
+        token_id: String,
+        /// expiration of the lock
+        expires: Expiration,
+        /// optional message length padding
+        padding: Option<String>,
+    },
+    /// unlock a token.  Only the address that locked the token may unlock it
+    Unlock {
+        /// token to unlock
+        token_id: String,
+        /// optional message length padding
+        padding: Option<String>,
+    },
+    /// rent a token to a user without transferring it.  Until the rental expires, the user may
+    /// view the token's owner and private metadata.  Only the token's owner or an address with
+    /// transfer approval may set the user.  The user is cleared when the token is transferred
+    SetUser {
+        /// token being rented
+        token_id: String,
+        /// address renting the token.  If omitted, the current user is removed
+        user: Option<String>,
+        /// optional expiration of the rental.  Defaults to never expiring
+        expires: Option<Expiration>,
+        /// optional message length padding
+        padding: Option<String>,
+    },
+    /// list a token for sale at a fixed price in native coins.  Listing a token that is already
+    /// listed changes its price.  The listing ends when the token is transferred or burned
+    List {
+        /// token to list
+        token_id: String,
+        /// price of the token
+        price: Coin,
+        /// optional message length padding
+        padding: Option<String>,
+    },
+    /// end the listing of a token
+    Delist {
+        /// token to delist
+        token_id: String,
+        /// optional message length padding
+        padding: Option<String>,
+    },
+    /// buy a listed token.  The listing price must be sent with the message.  The royalty
+    /// recipients are paid their share of the price and the seller receives the rest
+    Buy {
+        /// token to buy
+        token_id: String,
+        /// optional memo for the transfer tx
+        memo: Option<String>,
+        /// optional message length padding
+        padding: Option<String>,
+    },
+    /// start an auction of a token the sender owns.  The token can not be transferred, sent,
+    /// burned, listed, or locked until the auction is settled
+    StartAuction {
+        /// token to auction
+        token_id: String,
+        /// denomination bids are made in
+        denom: String,
+        /// the type of the auction
+        kind: AuctionKind,
+        /// number of seconds the auction runs for
+        duration: u64,
+        /// optional message length padding
+        padding: Option<String>,
+    },
+    /// bid on an auctioned token.  The bid must be sent with the message and is held in escrow.
+    /// A bid on an English auction refunds the previous highest bid, and a bid on a Dutch
+    /// auction buys the token at the current price and returns any excess
+    Bid {
+        /// token being auctioned
+        token_id: String,
+        /// optional message length padding
+        padding: Option<String>,
+    },
+    /// settle an auction that has ended.  If there is a winning bid, the token is transferred
+    /// to the bidder, the royalty recipients are paid their share of the bid, and the seller
+    /// receives the rest.  Anyone may settle an auction
+    SettleAuction {
+        /// token being auctioned
+        token_id: String,
+        /// optional message length padding
+        padding: Option<String>,
+    },
+    /// set or remove the minimum amount of an offer in a SNIP-20.  The first time a SNIP-20 is
+    /// given a minimum, the contract registers with it to receive offers
+    SetOfferSnip20 {
+        /// address of the SNIP-20 contract
+        contract_address: String,
+        /// code hash of the SNIP-20 contract
+        code_hash: String,
+        /// the new minimum amount of an offer.  If omitted, the SNIP-20 is no longer accepted
+        /// for new offers
+        min_amount: Option<Uint128>,
+        /// optional message length padding
+        padding: Option<String>,
+    },
+    /// accept an offer on a token the sender owns.  The token is transferred to the buyer, the
+    /// royalty recipients are paid their share of the escrowed SNIP-20 tokens, and the sender
+    /// receives the rest
+    AcceptOffer {
+        /// id of the offer to accept
+        offer_id: u64,
+        /// optional memo for the transfer tx
+        memo: Option<String>,
+        /// optional message length padding
+        padding: Option<String>,
+    },
+    /// withdraw an offer and return the escrowed SNIP-20 tokens to the buyer.  Only the buyer
+    /// may withdraw an offer, and may do so at any time before it is accepted
+    WithdrawOffer {
+        /// id of the offer to withdraw
+        offer_id: u64,
+        /// optional message length padding
+        padding: Option<String>,
+    },
+    /// register with another SNIP-721 contract so its tokens may be deposited for swaps.  Only
+    /// the admin may register a contract
+    RegisterSwapContract {
+        /// address of the SNIP-721 contract
+        contract_address: String,
+        /// code hash of the SNIP-721 contract
+        code_hash: String,
+        /// optional message length padding
+        padding: Option<String>,
+    },
+    /// record the tokens of a registered SNIP-721 contract that were sent to this contract as
+    /// deposits of their previous owner, so they can be swapped.  This is called by the
+    /// SNIP-721 contract when tokens are sent to this contract
+    BatchReceiveNft {
+        /// address that sent the tokens
+        sender: String,
+        /// previous owner of the sent tokens
+        from: String,
+        /// tokens that were sent
+        token_ids: Vec<String>,
+        /// optional message, which is ignored
+        msg: Option<Binary>,
+    },
+    /// return deposited tokens of another SNIP-721 contract to the sender that deposited them
+    WithdrawDeposits {
+        /// address of the SNIP-721 contract
+        contract_address: String,
+        /// tokens to withdraw
+        token_ids: Vec<String>,
+        /// optional message length padding
+        padding: Option<String>,
+    },
+    /// propose exchanging tokens with a counterparty.  Any coins sent with the message are
+    /// escrowed as a sweetener that is paid to the counterparty if the swap is accepted.  The
+    /// proposer must own the offered tokens of this contract and have deposited the offered
+    /// tokens of other contracts
+    ProposeSwap {
+        /// address that may accept the swap
+        counterparty: String,
+        /// tokens the proposer gives
+        offered: Vec<SwapToken>,
+        /// tokens the counterparty gives
+        requested: Vec<SwapToken>,
+        /// expiration of the proposal
+        expires: Expiration,
+        /// optional message length padding
+        padding: Option<String>,
+    },
+    /// accept a swap proposed to the sender.  The offered tokens and the sweetener go to the
+    /// sender and the requested tokens go to the proposer, or nothing happens at all.  The
+    /// sender must own the requested tokens of this contract and have deposited the requested
+    /// tokens of other contracts
+    AcceptSwap {
+        /// id of the swap
+        swap_id: u64,
+        /// optional message length padding
+        padding: Option<String>,
+    },
+    /// cancel a swap and return the sweetener to the proposer.  Either the proposer or the
+    /// counterparty may cancel a swap
+    CancelSwap {
+        /// id of the swap
+        swap_id: u64,
+        /// optional message length padding
+        padding: Option<String>,
+    },
+    /// send the public sale's proceeds to the admin or an optional recipient
+    WithdrawProceeds {
+        /// optional address to send the proceeds to.  Defaults to the admin
+        recipient: Option<String>,
+        /// optional message length padding
+        padding: Option<String>,
+    },
+    /// propose an address to become the new admin.  The handover only happens once the
+    /// proposed address accepts it
+    ProposeAdmin {
+        /// proposed admin address
+        address: String,
+        /// optional expiration for the proposal.  Defaults to never expiring
+        expiration: Option<Expiration>,
+        /// optional message length padding
+        padding: Option<String>,
+    },
+    /// accept a pending admin proposal made to the message sender
+    AcceptAdmin {
+        /// optional message length padding
+        padding: Option<String>,
+    },
+    /// cancel the pending admin proposal
+    CancelAdminProposal {
+        /// optional message length padding
+        padding: Option<String>,
+    },
+    /// change the contract's config flags after instantiation.  Any omitted flag keeps its
+    /// current value
+    UpdateConfig {
+        /// new values for the config flags
+        config: InstantiateConfig,
+        /// optional ratchets that make flags one-way from now on
+        ratchets: Option<ConfigRatchets>,
+        /// optional message length padding
+        padding: Option<String>,
+    },
     /// set contract status level to determine which functions are allowed.  StopTransactions
     /// status prevent mints, burns, sends, and transfers, but allows all other functions
     SetContractStatus {
@@ -406,6 +932,27 @@ pub enum ExecuteMsg {
     },
 }
 
+/// messages that can be embedded in a SNIP-20 Send to this contract
+#[derive(Serialize, Deserialize, JsonSchema, Debug)]
+#[serde(rename_all = "snake_case")]
+pub enum ReceiveMsg {
+    /// buy tokens from the public sale.  The tokens are owned by the SNIP-20 sender
+    PublicMint {
+        /// optional number of tokens to buy.  Defaults to 1
+        quantity: Option<u32>,
+        /// optional memo for the mint txs
+        memo: Option<String>,
+    },
+    /// escrow the sent tokens as an offer on a token.  The SNIP-20 contract must be one that
+    /// the contract has registered with SetSnip20Price
+    MakeOffer {
+        /// token the offer is for
+        token_id: String,
+        /// expiration of the offer
+        expires: Expiration,
+    },
+}
+
 /// permission access level
 #[derive(Serialize, Deserialize, JsonSchema, Debug)]
 #[serde(rename_all = "snake_case")]
@@ -567,6 +1114,154 @@ pub enum ExecuteAnswer {
     ChangeAdmin {
         status: ResponseStatus,
     },
+    GrantRole {
+        status: ResponseStatus,
+    },
+    RevokeRole {
+        status: ResponseStatus,
+    },
+    SetMinterQuota {
+        status: ResponseStatus,
+    },
+    SetMaxSupply {
+        status: ResponseStatus,
+    },
+    SetSale {
+        status: ResponseStatus,
+    },
+    LoadSalePool {
+        status: ResponseStatus,
+    },
+    PublicMint {
+        token_ids: Vec<String>,
+    },
+    SetSnip20Price {
+        status: ResponseStatus,
+    },
+    SetMintPhases {
+        status: ResponseStatus,
+    },
+    AllowlistMint {
+        token_ids: Vec<String>,
+    },
+    SetVoucherSigner {
+        status: ResponseStatus,
+    },
+    RedeemVoucher {
+        token_id: String,
+    },
+    CommitRevealPool {
+        status: ResponseStatus,
+    },
+    LoadRevealPool {
+        status: ResponseStatus,
+    },
+    RevealSeed {
+        status: ResponseStatus,
+    },
+    MintEdition {
+        token_id: String,
+    },
+    TransferEdition {
+        status: ResponseStatus,
+    },
+    ScheduleReveal {
+        status: ResponseStatus,
+    },
+    SetTokenIdScheme {
+        status: ResponseStatus,
+    },
+    SetRecipes {
+        status: ResponseStatus,
+    },
+    RevokeBadge {
+        status: ResponseStatus,
+    },
+    Redeem {
+        token_id: String,
+    },
+    ApproveLocker {
+        status: ResponseStatus,
+    },
+    RevokeLocker {
+        status: ResponseStatus,
+    },
+    Lock {
+        status: ResponseStatus,
+    },
+    Unlock {
+        status: ResponseStatus,
+    },
+    SetUser {
+        status: ResponseStatus,
+    },
+    List {
+        status: ResponseStatus,
+    },
+    Delist {
+        status: ResponseStatus,
+    },
+    Buy {
+        status: ResponseStatus,
+    },
+    StartAuction {
+        status: ResponseStatus,
+    },
+    Bid {
+        status: ResponseStatus,
+    },
+    SettleAuction {
+        status: ResponseStatus,
+    },
+    SetOfferSnip20 {
+        status: ResponseStatus,
+    },
+    MakeOffer {
+        offer_id: u64,
+    },
+    AcceptOffer {
+        status: ResponseStatus,
+    },
+    WithdrawOffer {
+        status: ResponseStatus,
+    },
+    RegisterSwapContract {
+        status: ResponseStatus,
+    },
+    BatchReceiveNft {
+        status: ResponseStatus,
+    },
+    WithdrawDeposits {
+        status: ResponseStatus,
+    },
+    ProposeSwap {
+        swap_id: u64,
+    },
+    AcceptSwap {
+        status: ResponseStatus,
+    },
+    CancelSwap {
+        status: ResponseStatus,
+    },
+    WithdrawProceeds {
+        status: ResponseStatus,
+    },
+    ProposeAdmin {
+        status: ResponseStatus,
+    },
+    AcceptAdmin {
+        status: ResponseStatus,
+    },
+    CancelAdminProposal {
+        status: ResponseStatus,
+    },
+    UpdateConfig {
+        status: ResponseStatus,
+        /// names of the flags whose values changed
+        changed: Vec<String>,
+        /// names of the flags that became one-way
+        ratcheted: Vec<String>,
+    },
     SetContractStatus {
         status: ResponseStatus,
     },
@@ -621,6 +1316,33 @@ pub enum TxAction {
         /// burner's address if not owner
         burner: Option<Addr>,
     },
+    /// minted prints of an edition
+    MintEdition {
+        /// minter's address
+        minter: Addr,
+        /// address receiving the prints
+        recipient: Addr,
+        /// number of prints minted
+        amount: Uint128,
+    },
+    /// transferred prints of an edition
+    TransferEdition {
+        /// previous holder
+        from: Addr,
+        /// new holder
+        recipient: Addr,
+        /// number of prints transferred
+        amount: Uint128,
+    },
+    /// revoked a non-transferable token
+    RevokeBadge {
+        /// issuer's address
+        issuer: Addr,
+        /// token's owner
+        owner: Addr,
+        /// why the token was revoked
+        reason: String,
+    },
 }
 
 /// tx for display
@@ -650,12 +1372,73 @@ pub enum QueryMsg {
     ContractConfig {},
     /// display the list of authorized minters
     Minters {},
+    /// display the addresses holding each role
+    Roles {},
+    /// display a minter's limits and how much of them remains
+    MinterAllowance {
+        /// the minter being queried
+        minter: String,
+    },
+    /// display the address that has been proposed as the new admin, if any
+    PendingAdmin {},
+    /// display the public sale and its progress
+    Sale {},
+    /// display the allowlist mint phases
+    MintPhases {},
+    /// display the public key of the mint voucher signer
+    VoucherSigner {},
+    /// display the reveal pool's committed provenance hash and progress
+    RevealPool {},
+    /// display when every sealed token will be unwrapped
+    RevealSchedule {},
+    /// display the burn-to-redeem recipes without the private metadata of their outputs
+    Recipes {},
     /// display the number of tokens controlled by the contract.  The token supply must
     /// either be public, or the querier must be an authenticated minter
     NumTokens {
         /// optional address and key requesting to view the number of tokens
         viewer: Option<ViewerInfo>,
     },
+    /// display the number of tokens that have been burned.  The token supply must either be
+    /// public, or the querier must be an authenticated minter
+    NumBurned {
+        /// optional address and key requesting to view the number of burned tokens
+        viewer: Option<ViewerInfo>,
+    },
+    /// display the max supply and how many more tokens may be minted, either for the whole
+    /// collection or for a mint run id.  The token supply must either be public, or the
+    /// querier must be an authenticated minter
+    RemainingSupply {
+        /// optional mint run id.  If omitted, the collection-wide supply is displayed
+        mint_run_id: Option<String>,
+        /// optional address and key requesting to view the remaining supply
+        viewer: Option<ViewerInfo>,
+    },
+    /// display an optionally paginated list of the mint runs of a mint run id.  The token
+    /// supply must either be public, or the querier must be an authenticated minter
+    MintRuns {
+        mint_run_id: String,
+        /// optional address and key requesting to view the mint runs
+        viewer: Option<ViewerInfo>,
+        /// paginate by providing the last mint run number received in the previous query
+        start_after: Option<u32>,
+        /// optional number of mint runs to display
+        limit: Option<u32>,
+    },
+    /// display an optionally paginated list of the existing tokens minted in a mint run.
+    /// The token supply must either be public, or the querier must be an authenticated
+    /// minter
+    TokensInMintRun {
+        mint_run_id: String,
+        /// number of the mint run
+        mint_run: u32,
+        /// optional address and key requesting to view the tokens
+        viewer: Option<ViewerInfo>,
+        /// paginate by providing the last token_id received in the previous query
+        start_after: Option<String>,
+        /// optional number of token ids to display
+        limit: Option<u32>,
+    },
     /// display an optionally paginated list of all the tokens controlled by the contract.
     /// The token supply must either be public, or the querier must be an authenticated
     /// minter
@@ -780,6 +1563,58 @@ pub enum QueryMsg {
     IsUnwrapped { token_id: String },
     /// display if a token is transferable
     IsTransferable { token_id: String },
+    /// display if a token is locked
+    IsLocked { token_id: String },
+    /// display an optionally paginated list of the tokens listed for sale
+    Listings {
+        /// paginate by providing the last token_id received in the previous query
+        start_after: Option<String>,
+        /// optional number of listings to display
+        limit: Option<u32>,
+    },
+    /// display the auction of a token
+    Auction { token_id: String },
+    /// display the address renting a token.  Only the user and addresses that may view the
+    /// token's owner may view the user
+    UserOf {
+        token_id: String,
+        /// optional address and key requesting to view the user
+        viewer: Option<ViewerInfo>,
+    },
+    /// display the lock of a token.  Only the token's owner and the address that locked it
+    /// may view the lock
+    LockInfo {
+        token_id: String,
+        /// optional address and key requesting to view the lock
+        viewer: Option<ViewerInfo>,
+    },
+    /// display the offers on a token.  Only the token's owner may view them
+    TokenOffers {
+        token_id: String,
+        /// optional address and key requesting to view the offers
+        viewer: Option<ViewerInfo>,
+        /// paginate by providing the last offer_id received in the previous query
+        start_after: Option<u64>,
+        /// optional number of offers to display
+        limit: Option<u32>,
+    },
+    /// display the offers made by the querier
+    BuyerOffers {
+        /// address and key of the buyer
+        viewer: ViewerInfo,
+        /// paginate by providing the last offer_id received in the previous query
+        start_after: Option<u64>,
+        /// optional number of offers to display
+        limit: Option<u32>,
+    },
+    /// display the SNIP-20 contracts offers may be made in
+    OfferSnip20s {},
+    /// display a swap proposal.  Only the proposer and the counterparty may view it
+    Swap {
+        swap_id: u64,
+        /// address and key requesting to view the swap
+        viewer: ViewerInfo,
+    },
     /// display that this contract implements non-transferable tokens
     ImplementsNonTransferableTokens {},
     /// display that this contract implements the use of the `token_subtype` metadata extension field
@@ -794,6 +1629,14 @@ pub enum QueryMsg {
         /// viewing key
         viewing_key: String,
     },
+    /// display the number of tokens the specified address minted in a mint phase
+    PhaseUsage {
+        /// name of the mint phase
+        phase: String,
+        address: String,
+        /// viewing key
+        viewing_key: String,
+    },
     /// display the transaction history for the specified address in reverse
     /// chronological order
     TransactionHistory {
@@ -882,6 +1725,12 @@ pub struct BatchNftDossierElement {
     pub private_metadata_is_public_expiration: Option<Expiration>,
     pub token_approvals: Option<Vec<Snip721Approval>>,
     pub inventory_approvals: Option<Vec<Snip721Approval>>,
+    /// edition supply and the querier's balance if this token is an edition
+    pub edition: Option<EditionInfo>,
+    /// the revocation of this token if it is a revoked non-transferable token
+    pub revocation: Option<Revocation>,
+    /// the address renting this token if the querier may view the owner
+    pub user: Option<TokenUser>,
 }
 
 #[derive(Serialize, Deserialize, JsonSchema, Debug)]
@@ -901,15 +1750,111 @@ pub enum QueryAnswer {
         burn_is_enabled: bool,
         implements_non_transferable_tokens: bool,
         implements_token_subtype: bool,
+        ratchets: ConfigRatchets,
+        token_id_scheme: Option<TokenIdScheme>,
     },
     Minters {
         minters: Vec<Addr>,
     },
+    Roles {
+        roles: Vec<RoleHolders>,
+    },
+    MinterAllowance {
+        /// the minter's limits
+        quota: MinterQuota,
+        /// number of tokens the minter has ever minted
+        minted: u32,
+        /// number of tokens the minter may still mint, if there is a lifetime quota
+        lifetime_remaining: Option<u32>,
+        /// number of tokens the minter may still mint in the current window, if there is a
+        /// windowed quota
+        window_remaining: Option<u32>,
+        /// when the current window ends, if one is open
+        window_ends: Option<Expiration>,
+    },
+    Sale {
+        /// the public sale, if there is one
+        sale: Option<Sale>,
+        /// number of tokens sold
+        sold: u32,
+        /// number of metadata pool entries that have not been sold
+        pool_remaining: u32,
+        /// the SNIP-20 contracts accepted as payment and their prices
+        snip20_prices: Vec<Snip20Price>,
+    },
+    MintPhases {
+        phases: Vec<MintPhase>,
+    },
+    PhaseUsage {
+        /// number of tokens minted in the phase
+        minted: u32,
+    },
+    VoucherSigner {
+        public_key: Option<Binary>,
+    },
+    RevealPool {
+        /// the committed provenance hash, or None if there is no reveal pool
+        provenance_hash: Option<Binary>,
+        /// number of items in the pool
+        size: u32,
+        /// number of items loaded so far
+        loaded: u32,
+        /// number of loaded items that have not been assigned to a token
+        remaining: u32,
+        /// the committed hash of the secret seed, or None if there is no reveal pool
+        seed_hash: Option<Binary>,
+        /// true if the secret seed has been revealed
+        seed_revealed: bool,
+    },
+    RevealSchedule {
+        /// when every sealed token will be unwrapped, or None if no reveal is scheduled
+        at: Option<Expiration>,
+        /// true if the scheduled reveal has happened
+        revealed: bool,
+    },
+    Recipes {
+        recipes: Vec<Recipe>,
+    },
+    PendingAdmin {
+        /// proposed admin address, or None if there is no unexpired proposal
+        address: Option<Addr>,
+        /// when the proposal expires
+        expiration: Option<Expiration>,
+    },
     NumTokens {
         count: u32,
     },
+    NumBurned {
+        count: u32,
+    },
+    /// displayed instead of the token's information when a token was burned
+    BurnedToken {
+        token_id: String,
+        /// the token's owner when it was burned
+        owner: Addr,
+        /// address that burned the token if it was not the owner
+        burner: Option<Addr>,
+        /// height of the block the token was burned in
+        block_height: u64,
+        /// time of the block the token was burned in (in seconds since 01/01/1970)
+        block_time: u64,
+    },
+    RemainingSupply {
+        /// the maximum number of tokens that may ever be minted, if there is one
+        max_supply: Option<u32>,
+        /// number of tokens minted so far, including any that were burned
+        minted: u32,
+        /// number of tokens that may still be minted, if there is a max supply
+        remaining: Option<u32>,
+    },
+    MintRuns {
+        mint_runs: Vec<MintRun>,
+    },
     TokenList {
         tokens: Vec<String>,
+        /// the owner's balance of each listed edition.  Only the Tokens query lists balances
+        #[serde(skip_serializing_if = "Option::is_none")]
+        balances: Option<Vec<EditionBalance>>,
     },
     OwnerOf {
         owner: Addr,
@@ -956,6 +1901,9 @@ pub enum QueryAnswer {
         private_metadata_is_public_expiration: Option<Expiration>,
         token_approvals: Option<Vec<Snip721Approval>>,
         inventory_approvals: Option<Vec<Snip721Approval>>,
+        edition: Option<EditionInfo>,
+        revocation: Option<Revocation>,
+        user: Option<TokenUser>,
     },
     BatchNftDossier {
         nft_dossiers: Vec<BatchNftDossierElement>,
@@ -968,6 +1916,37 @@ pub enum QueryAnswer {
     },
     IsTransferable {
         token_is_transferable: bool,
+        /// the revocation of the token if it is a revoked non-transferable token
+        revocation: Option<Revocation>,
+    },
+    IsLocked {
+        token_is_locked: bool,
+    },
+    Listings {
+        listings: Vec<Listing>,
+    },
+    Auction {
+        /// the token's auction, or None if it is not being auctioned
+        auction: Option<Auction>,
+    },
+    UserOf {
+        /// address renting the token, or None if it is not rented
+        user: Option<Addr>,
+        /// expiration of the rental
+        expires: Option<Expiration>,
+    },
+    LockInfo {
+        /// the token's lock, or None if it is not locked
+        lock: Option<Lock>,
+    },
+    Offers {
+        offers: Vec<Offer>,
+    },
+    OfferSnip20s {
+        snip20s: Vec<OfferSnip20>,
+    },
+    Swap {
+        swap: Swap,
     },
     ImplementsNonTransferableTokens {
         is_enabled: bool,
@@ -1022,6 +2001,57 @@ impl ContractStatus {
     }
 }
 
+/// named roles that can be granted to many addresses
+#[derive(Serialize, Deserialize, Clone, Copy, PartialEq, Eq, JsonSchema, Debug)]
+#[serde(rename_all = "snake_case")]
+pub enum Role {
+    /// may mint tokens
+    Minter,
+    /// may update the metadata of any token if minter_may_update_metadata is enabled
+    MetadataEditor,
+    /// may set the contract's default royalties
+    RoyaltyManager,
+    /// may make the contract status more restrictive
+    Pauser,
+    /// may burn any token
+    Burner,
+    /// may view the token supply even when it is private
+    Auditor,
+}
+
+impl Role {
+    /// every role, in display order
+    pub const ALL: [Role; 6] = [
+        Role::Minter,
+        Role::MetadataEditor,
+        Role::RoyaltyManager,
+        Role::Pauser,
+        Role::Burner,
+        Role::Auditor,
+    ];
+
+    /// Returns u8 representation of the Role
+    pub fn to_u8(&self) -> u8 {
+        match self {
+            Role::Minter => 0,
+            Role::MetadataEditor => 1,
+            Role::RoyaltyManager => 2,
+            Role::Pauser => 3,
+            Role::Burner => 4,
+            Role::Auditor => 5,
+        }
+    }
+}
+
+/// the addresses holding a role
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+pub struct RoleHolders {
+    /// the role
+    pub role: Role,
+    /// addresses holding the role
+    pub addresses: Vec<Addr>,
+}
+
 /// queries using permits instead of viewing keys
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
 #[serde(rename_all = "snake_case")]
@@ -1084,6 +2114,11 @@ pub enum QueryWithPermit {
         /// list of tokens to verify approval for
         token_ids: Vec<String>,
     },
+    /// display the number of tokens the permit creator minted in a mint phase
+    PhaseUsage {
+        /// name of the mint phase
+        phase: String,
+    },
     /// display the transaction history for the permit creator in reverse
     /// chronological order
     TransactionHistory {
@@ -1095,6 +2130,60 @@ pub enum QueryWithPermit {
     /// display the number of tokens controlled by the contract.  The token supply must
     /// either be public, or the querier must be an authenticated minter
     NumTokens {},
+    /// display the number of tokens that have been burned.  The token supply must either be
+    /// public, or the querier must be an authenticated minter
+    NumBurned {},
+    /// display the lock of a token.  Only the token's owner and the address that locked it
+    /// may view the lock
+    LockInfo { token_id: String },
+    /// display the address renting a token.  Only the user and addresses that may view the
+    /// token's owner may view the user
+    UserOf { token_id: String },
+    /// display the offers on a token.  Only the token's owner may view them
+    TokenOffers {
+        token_id: String,
+        /// paginate by providing the last offer_id received in the previous query
+        start_after: Option<u64>,
+        /// optional number of offers to display
+        limit: Option<u32>,
+    },
+    /// display the offers made by the permit signer
+    BuyerOffers {
+        /// paginate by providing the last offer_id received in the previous query
+        start_after: Option<u64>,
+        /// optional number of offers to display
+        limit: Option<u32>,
+    },
+    /// display a swap proposal.  Only the proposer and the counterparty may view it
+    Swap { swap_id: u64 },
+    /// display the max supply and how many more tokens may be minted, either for the whole
+    /// collection or for a mint run id.  The token supply must either be public, or the
+    /// querier must be an authenticated minter
+    RemainingSupply {
+        /// optional mint run id.  If omitted, the collection-wide supply is displayed
+        mint_run_id: Option<String>,
+    },
+    /// display an optionally paginated list of the mint runs of a mint run id.  The token
+    /// supply must either be public, or the querier must be an authenticated minter
+    MintRuns {
+        mint_run_id: String,
+        /// paginate by providing the last mint run number received in the previous query
+        start_after: Option<u32>,
+        /// optional number of mint runs to display
+        limit: Option<u32>,
+    },
+    /// display an optionally paginated list of the existing tokens minted in a mint run.
+    /// The token supply must either be public, or the querier must be an authenticated
+    /// minter
+    TokensInMintRun {
+        mint_run_id: String,
+        /// number of the mint run
+        mint_run: u32,
+        /// paginate by providing the last token_id received in the previous query
+        start_after: Option<String>,
+        /// optional number of token ids to display
+        limit: Option<u32>,
+    },
     /// display an optionally paginated list of all the tokens controlled by the contract.
     /// The token supply must either be public, or the querier must be an authenticated
     /// minter
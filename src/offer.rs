@@ -0,0 +1,244 @@
+use cosmwasm_std::{Addr, Api, CanonicalAddr, StdError, StdResult, Storage, Uint128};
+use cosmwasm_storage::{PrefixedStorage, ReadonlyPrefixedStorage};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::expiration::Expiration;
+use crate::state::{json_may_load, may_load, remove, save, PREFIX_OFFERS};
+
+/// offer of SNIP-20 tokens escrowed by a buyer for a token
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
+pub struct Offer {
+    /// id of the offer
+    pub offer_id: u64,
+    /// id of the token the offer is for
+    pub token_id: String,
+    /// address that made the offer
+    pub buyer: Addr,
+    /// address of the SNIP-20 contract the offer is paid in
+    pub snip20: Addr,
+    /// amount of SNIP-20 tokens escrowed
+    pub amount: Uint128,
+    /// expiration of the offer
+    pub expires: Expiration,
+}
+
+/// SNIP-20 contract that offers may be made in
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
+pub struct OfferSnip20 {
+    /// address of the SNIP-20 contract
+    pub address: Addr,
+    /// code hash of the SNIP-20 contract
+    pub code_hash: String,
+    /// minimum amount of an offer in the SNIP-20's smallest denomination
+    pub min_amount: Uint128,
+}
+
+/// stored information about a SNIP-20 contract the contract has accepted offers in
+#[derive(Serialize, Deserialize)]
+pub struct StoredOfferSnip20 {
+    /// address of the SNIP-20 contract
+    pub address: CanonicalAddr,
+    /// code hash of the SNIP-20 contract
+    pub code_hash: String,
+    /// minimum amount of an offer, or None if the SNIP-20 is no longer accepted
+    pub min_amount: Option<Uint128>,
+}
+
+/// stored offer
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct StoredOffer {
+    /// id of the token the offer is for
+    pub token_id: String,
+    /// address that made the offer
+    pub buyer: CanonicalAddr,
+    /// address of the SNIP-20 contract the offer is paid in
+    pub snip20: CanonicalAddr,
+    /// code hash of the SNIP-20 contract
+    pub code_hash: String,
+    /// amount of SNIP-20 tokens escrowed
+    pub amount: Uint128,
+    /// expiration of the offer
+    pub expires: Expiration,
+}
+
+impl StoredOffer {
+    /// Returns StdResult<Offer> from converting a StoredOffer to a displayable Offer
+    ///
+    /// # Arguments
+    ///
+    /// * `api` - a reference to the Api used to convert human and canonical addresses
+    /// * `offer_id` - id of the offer
+    pub fn into_humanized(self, api: &dyn Api, offer_id: u64) -> StdResult<Offer> {
+        Ok(Offer {
+            offer_id,
+            token_id: self.token_id,
+            buyer: api.addr_humanize(&self.buyer)?,
+            snip20: api.addr_humanize(&self.snip20)?,
+            amount: self.amount,
+            expires: self.expires,
+        })
+    }
+}
+
+/// Returns StdResult<Option<StoredOffer>> of the offer with the given id, or None if it does
+/// not exist
+///
+/// # Arguments
+///
+/// * `storage` - a reference to the contract's storage
+/// * `offer_id` - id of the offer
+pub fn may_load_offer(storage: &dyn Storage, offer_id: u64) -> StdResult<Option<StoredOffer>> {
+    let offer_store = ReadonlyPrefixedStorage::new(storage, PREFIX_OFFERS);
+    json_may_load(&offer_store, &offer_id.to_le_bytes())
+}
+
+/// the first and last offers of an index entry
+#[derive(Serialize, Deserialize, Default)]
+struct IndexEnds {
+    /// id of the oldest offer
+    head: Option<u64>,
+    /// id of the newest offer
+    tail: Option<u64>,
+}
+
+/// the offers before and after an offer in an index entry
+#[derive(Serialize, Deserialize)]
+struct IndexLinks {
+    /// id of the previous offer
+    prev: Option<u64>,
+    /// id of the next offer
+    next: Option<u64>,
+}
+
+/// namespace of the ends of each index entry
+const INDEX_ENDS: &[u8] = b"ends";
+/// namespace of the links of the offers in each index entry
+const INDEX_LINKS: &[u8] = b"links";
+
+/// Returns StdResult<Vec<u64>> of the ids of the offers in an index entry, oldest first
+///
+/// # Arguments
+///
+/// * `storage` - a reference to the contract's storage
+/// * `prefix` - prefix of the index
+/// * `key` - key of the index entry
+/// * `start_after` - optionally only return the offers that come after this one
+/// * `limit` - max number of offer ids to return
+pub fn offer_ids(
+    storage: &dyn Storage,
+    prefix: &[u8],
+    key: &[u8],
+    start_after: Option<u64>,
+    limit: u32,
+) -> StdResult<Vec<u64>> {
+    let links_store = ReadonlyPrefixedStorage::multilevel(storage, &[prefix, INDEX_LINKS, key]);
+    let mut id = match start_after {
+        Some(after) => {
+            may_load::<IndexLinks>(&links_store, &after.to_le_bytes())?
+                .ok_or_else(|| StdError::generic_err(format!("Offer {} not found", after)))?
+                .next
+        }
+        None => {
+            let ends_store = ReadonlyPrefixedStorage::multilevel(storage, &[prefix, INDEX_ENDS]);
+            may_load::<IndexEnds>(&ends_store, key)?
+                .unwrap_or_default()
+                .head
+        }
+    };
+    let mut ids = Vec::new();
+    while let Some(offer_id) = id.filter(|_| ids.len() < limit as usize) {
+        ids.push(offer_id);
+        id = may_load::<IndexLinks>(&links_store, &offer_id.to_le_bytes())?
+            .ok_or_else(|| StdError::generic_err("Offer index storage is corrupt"))?
+            .next;
+    }
+    Ok(ids)
+}
+
+/// Returns StdResult<()> after adding an offer id to the end of an index entry
+///
+/// # Arguments
+///
+/// * `storage` - a mutable reference to the contract's storage
+/// * `prefix` - prefix of the index
+/// * `key` - key of the index entry
+/// * `offer_id` - id of the offer
+pub fn add_offer_id(
+    storage: &mut dyn Storage,
+    prefix: &[u8],
+    key: &[u8],
+    offer_id: u64,
+) -> StdResult<()> {
+    let mut ends_store = PrefixedStorage::multilevel(storage, &[prefix, INDEX_ENDS]);
+    let mut ends: IndexEnds = may_load(&ends_store, key)?.unwrap_or_default();
+    let prev = ends.tail.replace(offer_id);
+    if ends.head.is_none() {
+        ends.head = Some(offer_id);
+    }
+    save(&mut ends_store, key, &ends)?;
+    let mut links_store = PrefixedStorage::multilevel(storage, &[prefix, INDEX_LINKS, key]);
+    if let Some(prev_id) = prev {
+        let mut prev_links: IndexLinks = may_load(&links_store, &prev_id.to_le_bytes())?
+            .ok_or_else(|| StdError::generic_err("Offer index storage is corrupt"))?;
+        prev_links.next = Some(offer_id);
+        save(&mut links_store, &prev_id.to_le_bytes(), &prev_links)?;
+    }
+    save(
+        &mut links_store,
+        &offer_id.to_le_bytes(),
+        &IndexLinks { prev, next: None },
+    )
+}
+
+/// Returns StdResult<()> after removing an offer id from an index entry
+///
+/// # Arguments
+///
+/// * `storage` - a mutable reference to the contract's storage
+/// * `prefix` - prefix of the index
+/// * `key` - key of the index entry
+/// * `offer_id` - id of the offer
+pub fn remove_offer_id(
+    storage: &mut dyn Storage,
+    prefix: &[u8],
+    key: &[u8],
+    offer_id: u64,
+) -> StdResult<()> {
+    let mut links_store = PrefixedStorage::multilevel(storage, &[prefix, INDEX_LINKS, key]);
+    let links: IndexLinks = match may_load(&links_store, &offer_id.to_le_bytes())? {
+        Some(l) => l,
+        None => return Ok(()),
+    };
+    remove(&mut links_store, &offer_id.to_le_bytes());
+    // point the neighbors at each other
+    for (neighbor, is_prev) in [(links.prev, true), (links.next, false)] {
+        if let Some(id) = neighbor {
+            let mut neighbor_links: IndexLinks = may_load(&links_store, &id.to_le_bytes())?
+                .ok_or_else(|| StdError::generic_err("Offer index storage is corrupt"))?;
+            if is_prev {
+                neighbor_links.next = links.next;
+            } else {
+                neighbor_links.prev = links.prev;
+            }
+            save(&mut links_store, &id.to_le_bytes(), &neighbor_links)?;
+        }
+    }
+    // update the ends if the offer was the first or last one
+    if links.prev.is_none() || links.next.is_none() {
+        let mut ends_store = PrefixedStorage::multilevel(storage, &[prefix, INDEX_ENDS]);
+        let mut ends: IndexEnds = may_load(&ends_store, key)?.unwrap_or_default();
+        if links.prev.is_none() {
+            ends.head = links.next;
+        }
+        if links.next.is_none() {
+            ends.tail = links.prev;
+        }
+        if ends.head.is_none() {
+            remove(&mut ends_store, key);
+        } else {
+            save(&mut ends_store, key, &ends)?;
+        }
+    }
+    Ok(())
+}
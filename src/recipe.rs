@@ -0,0 +1,95 @@
+use cosmwasm_std::{StdResult, Storage};
+use cosmwasm_storage::ReadonlyPrefixedStorage;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::mint_run::StoredMintRun;
+use crate::royalties::RoyaltyInfo;
+use crate::state::{may_load, PREFIX_MINT_RUN_NUM, PREFIX_MINT_RUN_REGISTRY};
+use crate::token::Metadata;
+
+/// a burn-to-redeem recipe.  Holders burn one token matching each input to receive a new token
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
+pub struct Recipe {
+    /// unique name of the recipe
+    pub name: String,
+    /// the tokens that must be burned, in order
+    pub inputs: Vec<RecipeInput>,
+    /// optional public metadata of the redeemed token
+    pub public_metadata: Option<Metadata>,
+    /// optional private metadata of the redeemed token
+    pub private_metadata: Option<Metadata>,
+    /// optional royalties of the redeemed token.  If omitted, the default royalties are used
+    pub royalty_info: Option<RoyaltyInfo>,
+}
+
+/// a constraint on a token burned to redeem a recipe
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum RecipeInput {
+    /// the token with this id
+    TokenId { token_id: String },
+    /// any token minted with this mint run id, optionally only in one mint run
+    MintRun {
+        mint_run_id: String,
+        mint_run: Option<u32>,
+    },
+    /// any token whose public metadata has this trait
+    Trait { trait_type: String, value: String },
+}
+
+impl RecipeInput {
+    /// Returns StdResult<bool> true if the token satisfies this input
+    ///
+    /// # Arguments
+    ///
+    /// * `storage` - a reference to the contract's storage
+    /// * `token_id` - string slice of the token id
+    /// * `idx` - the token's idx
+    /// * `public_metadata` - the token's public metadata as it is displayed
+    pub fn is_satisfied_by(
+        &self,
+        storage: &dyn Storage,
+        token_id: &str,
+        idx: u32,
+        public_metadata: Option<&Metadata>,
+    ) -> StdResult<bool> {
+        match self {
+            RecipeInput::TokenId { token_id: id } => Ok(id == token_id),
+            RecipeInput::MintRun {
+                mint_run_id,
+                mint_run,
+            } => {
+                let runs = if let Some(run) = mint_run {
+                    *run..=*run
+                } else {
+                    let run_store = ReadonlyPrefixedStorage::new(storage, PREFIX_MINT_RUN_NUM);
+                    let last: u32 = may_load(&run_store, mint_run_id.as_bytes())?.unwrap_or(0);
+                    1..=last
+                };
+                let reg_store = ReadonlyPrefixedStorage::multilevel(
+                    storage,
+                    &[PREFIX_MINT_RUN_REGISTRY, mint_run_id.as_bytes()],
+                );
+                for run in runs {
+                    if let Some(stored) = may_load::<StoredMintRun>(&reg_store, &run.to_le_bytes())?
+                    {
+                        // the tokens of a mint run have consecutive indices
+                        if idx >= stored.first_index && idx - stored.first_index < stored.quantity {
+                            return Ok(true);
+                        }
+                    }
+                }
+                Ok(false)
+            }
+            RecipeInput::Trait { trait_type, value } => Ok(public_metadata
+                .and_then(|m| m.extension.as_ref())
+                .and_then(|e| e.attributes.as_ref())
+                .map_or(false, |attrs| {
+                    attrs.iter().any(|a| {
+                        a.trait_type.as_deref() == Some(trait_type.as_str()) && a.value == *value
+                    })
+                })),
+        }
+    }
+}
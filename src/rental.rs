@@ -0,0 +1,72 @@
+use cosmwasm_std::{Addr, Api, BlockInfo, CanonicalAddr, StdResult, Storage};
+use cosmwasm_storage::{PrefixedStorage, ReadonlyPrefixedStorage};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::expiration::Expiration;
+use crate::state::{json_may_load, remove, PREFIX_TOKEN_USERS};
+
+/// address renting a token.  The user may view the token's owner and private metadata
+/// until the rental expires
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
+pub struct TokenUser {
+    /// address renting the token
+    pub user: Addr,
+    /// expiration of the rental
+    pub expires: Expiration,
+}
+
+/// stored user of a token
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct StoredTokenUser {
+    /// address renting the token
+    pub user: CanonicalAddr,
+    /// expiration of the rental
+    pub expires: Expiration,
+}
+
+impl StoredTokenUser {
+    /// Returns StdResult<TokenUser> from converting a StoredTokenUser to a displayable
+    /// TokenUser
+    ///
+    /// # Arguments
+    ///
+    /// * `api` - a reference to the Api used to convert human and canonical addresses
+    pub fn into_humanized(self, api: &dyn Api) -> StdResult<TokenUser> {
+        Ok(TokenUser {
+            user: api.addr_humanize(&self.user)?,
+            expires: self.expires,
+        })
+    }
+}
+
+/// Returns StdResult<Option<StoredTokenUser>> of the token's user, or None if it has no user
+/// or the rental has expired
+///
+/// # Arguments
+///
+/// * `storage` - a reference to the contract's storage
+/// * `block` - a reference to the current BlockInfo
+/// * `token_id` - string slice of the token id
+pub fn active_user(
+    storage: &dyn Storage,
+    block: &BlockInfo,
+    token_id: &str,
+) -> StdResult<Option<StoredTokenUser>> {
+    let user_store = ReadonlyPrefixedStorage::new(storage, PREFIX_TOKEN_USERS);
+    Ok(
+        json_may_load::<StoredTokenUser>(&user_store, token_id.as_bytes())?
+            .filter(|u| !u.expires.is_expired(block)),
+    )
+}
+
+/// removes the user of a token if existent
+///
+/// # Arguments
+///
+/// * `storage` - a mutable reference to the contract's storage
+/// * `token_id` - string slice of the token id
+pub fn remove_user(storage: &mut dyn Storage, token_id: &str) {
+    let mut user_store = PrefixedStorage::new(storage, PREFIX_TOKEN_USERS);
+    remove(&mut user_store, token_id.as_bytes());
+}
@@ -0,0 +1,76 @@
+use cosmwasm_std::{to_vec, StdResult};
+use secret_toolkit::crypto::sha_256;
+use serde::{Deserialize, Serialize};
+
+use crate::sale::PoolEntry;
+
+/// stored information about the metadata pool that sealed tokens are randomly revealed from
+#[derive(Serialize, Deserialize)]
+pub struct RevealPool {
+    /// the provenance hash committed before the pool was loaded
+    pub provenance_hash: Vec<u8>,
+    /// number of items in the pool
+    pub size: u32,
+    /// number of items loaded so far
+    pub loaded: u32,
+    /// provenance hash of the items loaded so far
+    pub loaded_hash: Vec<u8>,
+    /// number of items that have not been assigned to a token
+    pub remaining: u32,
+    /// sha256 hash of the secret seed committed before the pool was loaded
+    pub seed_hash: Vec<u8>,
+}
+
+impl RevealPool {
+    /// Returns bool true if every item of the pool has been loaded
+    pub fn is_loaded(&self) -> bool {
+        self.loaded == self.size
+    }
+}
+
+/// Returns StdResult<[u8; 32]> of the provenance hash after adding an item to the pool.  The
+/// provenance hash of a pool is found by starting with an empty hash and, for each item in the
+/// order it was loaded, taking the sha256 hash of the previous hash followed by the sha256
+/// hash of the item's JSON serialization
+///
+/// # Arguments
+///
+/// * `prev` - the provenance hash of the items before this one
+/// * `item` - a reference to the item being added
+pub fn chain_hash(prev: &[u8], item: &PoolEntry) -> StdResult<[u8; 32]> {
+    let item_hash = sha_256(&to_vec(item)?);
+    Ok(sha_256(&[prev, item_hash.as_slice()].concat()))
+}
+
+/// Returns u32 position of `index` in the seed-keyed shuffle of the numbers below `domain`.
+/// The shuffle is a four round Feistel network keyed with the seed, cycle-walked until the
+/// position falls below `domain`, so each index has its own position no matter the order
+/// positions are looked up in, and anyone holding the seed can recompute it.  Each round
+/// xors one half with the first 8 bytes (little-endian) of the sha256 hash of the seed, the
+/// round number, and the other half (as a little-endian u64), masked to the half's width
+///
+/// # Arguments
+///
+/// * `seed` - the revealed seed
+/// * `domain` - number of positions being shuffled, which must be greater than `index`
+/// * `index` - the number whose position is wanted
+pub fn shuffled_position(seed: &[u8], domain: u32, index: u32) -> u32 {
+    // width in bits of each half of a block large enough to hold every position
+    let bits = (u32::BITS - domain.saturating_sub(1).leading_zeros()).max(2);
+    let half = (bits + 1) / 2;
+    let mask = (1u64 << half) - 1;
+    let mut pos = index as u64;
+    loop {
+        let (mut left, mut right) = (pos >> half, pos & mask);
+        for round in 0u8..4 {
+            let hash = sha_256(&[seed, &[round], &right.to_le_bytes()].concat());
+            let mut rand_bytes = [0u8; 8];
+            rand_bytes.copy_from_slice(&hash[..8]);
+            (left, right) = (right, left ^ (u64::from_le_bytes(rand_bytes) & mask));
+        }
+        pos = (left << half) | right;
+        if pos < domain as u64 {
+            return pos as u32;
+        }
+    }
+}
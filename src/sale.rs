@@ -0,0 +1,108 @@
+use cosmwasm_std::{Addr, BlockInfo, CanonicalAddr, Coin, StdError, StdResult, Uint128};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::token::Metadata;
+
+/// a public sale that lets anyone mint by paying the price
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq)]
+pub struct Sale {
+    /// optional price of one token in native coins.  If omitted, tokens can only be bought
+    /// with SNIP-20 tokens
+    pub price: Option<Coin>,
+    /// optional time the sale starts (in seconds since 01/01/1970)
+    pub start: Option<u64>,
+    /// optional time the sale ends (in seconds since 01/01/1970)
+    pub end: Option<u64>,
+    /// optional maximum number of tokens one address may buy
+    pub wallet_limit: Option<u32>,
+    /// where the metadata of sold tokens comes from
+    pub metadata: SaleMetadata,
+}
+
+impl Sale {
+    /// Returns StdResult<()>
+    ///
+    /// returns Ok if the sale is open at the current block time
+    ///
+    /// # Arguments
+    ///
+    /// * `block` - a reference to the current BlockInfo
+    pub fn check_open(&self, block: &BlockInfo) -> StdResult<()> {
+        let now = block.time.seconds();
+        if self.start.map_or(false, |s| now < s) {
+            return Err(StdError::generic_err("The sale has not started yet"));
+        }
+        if self.end.map_or(false, |e| now >= e) {
+            return Err(StdError::generic_err("The sale has ended"));
+        }
+        Ok(())
+    }
+}
+
+/// source of the metadata of tokens minted by a sale
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+#[allow(clippy::large_enum_variant)]
+pub enum SaleMetadata {
+    /// every sold token gets a copy of the same metadata
+    Template {
+        /// optional public metadata
+        public_metadata: Option<Metadata>,
+        /// optional private metadata
+        private_metadata: Option<Metadata>,
+    },
+    /// sold tokens take the metadata of the pool entries in the order they were loaded
+    Pool {},
+}
+
+/// metadata of one token waiting in a metadata pool
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
+pub struct PoolEntry {
+    /// optional public metadata
+    pub public_metadata: Option<Metadata>,
+    /// optional private metadata
+    pub private_metadata: Option<Metadata>,
+}
+
+/// counts of the sale's progress
+#[derive(Serialize, Deserialize, Default)]
+pub struct SaleCounts {
+    /// number of tokens sold
+    pub sold: u32,
+    /// number of entries loaded into the metadata pool
+    pub pool_loaded: u32,
+    /// number of pool entries that have been minted
+    pub pool_taken: u32,
+}
+
+impl SaleCounts {
+    /// Returns u32 of the number of pool entries that have not been minted
+    pub fn pool_remaining(&self) -> u32 {
+        self.pool_loaded.saturating_sub(self.pool_taken)
+    }
+}
+
+/// a SNIP-20 contract accepted as payment by the public sale
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
+pub struct Snip20Price {
+    /// address of the SNIP-20 contract
+    pub address: Addr,
+    /// code hash of the SNIP-20 contract
+    pub code_hash: String,
+    /// price of one token in the SNIP-20's smallest denomination
+    pub price: Uint128,
+}
+
+/// stored information about a SNIP-20 contract the sale has accepted payment in
+#[derive(Serialize, Deserialize)]
+pub struct StoredSnip20Payment {
+    /// address of the SNIP-20 contract
+    pub address: CanonicalAddr,
+    /// code hash of the SNIP-20 contract
+    pub code_hash: String,
+    /// price of one token, or None if the SNIP-20 is no longer accepted
+    pub price: Option<Uint128>,
+    /// proceeds that have not been withdrawn
+    pub proceeds: Uint128,
+}
@@ -1,6 +1,6 @@
 use std::any::type_name;
 
-use cosmwasm_std::{Api, BlockInfo, CanonicalAddr, StdError, StdResult, Storage};
+use cosmwasm_std::{Api, BlockInfo, CanonicalAddr, StdError, StdResult, Storage, Uint128};
 use cosmwasm_storage::{PrefixedStorage, ReadonlyPrefixedStorage};
 use secret_toolkit::{
     serialization::{Bincode2, Json, Serde},
@@ -9,16 +9,52 @@ use secret_toolkit::{
 use serde::{de::DeserializeOwned, Deserialize, Serialize};
 
 use crate::expiration::Expiration;
-use crate::msg::{Tx, TxAction};
+use crate::msg::{Role, Tx, TxAction};
 
 /// storage key for config
 pub const CONFIG_KEY: &[u8] = b"config";
+/// storage key for the storage layout version
+pub const CONTRACT_VERSION_KEY: &[u8] = b"version";
+/// storage key for the one-way restrictions on config flags
+pub const RATCHETS_KEY: &[u8] = b"ratchets";
+/// storage key for a proposed admin that has not yet accepted
+pub const PENDING_ADMIN_KEY: &[u8] = b"pendingadmin";
 /// storage key for minters
 pub const MINTERS_KEY: &[u8] = b"minters";
 /// storage key for the contract instantiator
 pub const CREATOR_KEY: &[u8] = b"creator";
 /// storage key for the default RoyaltyInfo to use if none is supplied when minting
 pub const DEFAULT_ROYALTY_KEY: &[u8] = b"defaultroy";
+/// storage key for the maximum number of tokens that may ever be minted
+pub const MAX_SUPPLY_KEY: &[u8] = b"maxsupply";
+/// storage key for the public sale
+pub const SALE_KEY: &[u8] = b"sale";
+/// storage key for the counts of the public sale's progress
+pub const SALE_COUNTS_KEY: &[u8] = b"salecnts";
+/// storage key for the sale proceeds that have not been withdrawn
+pub const SALE_PROCEEDS_KEY: &[u8] = b"proceeds";
+/// storage key for the SNIP-20 contracts the public sale has accepted payment in
+pub const SNIP20_PAYMENTS_KEY: &[u8] = b"snip20pay";
+/// storage key for the allowlist mint phases
+pub const MINT_PHASES_KEY: &[u8] = b"mintphases";
+/// storage key for the public key of the mint voucher signer
+pub const VOUCHER_SIGNER_KEY: &[u8] = b"vchrsigner";
+/// storage key for the metadata pool sealed tokens are revealed from
+pub const REVEAL_POOL_KEY: &[u8] = b"revealpool";
+/// storage key for the revealed seed used to randomly assign reveal pool items
+pub const REVEAL_SEED_KEY: &[u8] = b"revealseed";
+/// storage key for the time of the collection-wide reveal
+pub const REVEAL_SCHEDULE_KEY: &[u8] = b"revealsched";
+/// storage key for the scheme used to generate token ids
+pub const TOKEN_ID_SCHEME_KEY: &[u8] = b"idscheme";
+/// storage key for the burn-to-redeem recipes
+pub const RECIPES_KEY: &[u8] = b"recipes";
+/// storage key for the number of offers made
+pub const OFFER_COUNT_KEY: &[u8] = b"offercnt";
+/// storage key for the SNIP-20 contracts offers may be made in
+pub const OFFER_SNIP20S_KEY: &[u8] = b"offersnip20s";
+/// storage key for the number of swaps proposed
+pub const SWAP_COUNT_KEY: &[u8] = b"swapcnt";
 /// prefix for storage that maps ids to indices
 pub const PREFIX_MAP_TO_INDEX: &[u8] = b"map2idx";
 /// prefix for storage that maps indices to ids
@@ -45,6 +81,63 @@ pub const PREFIX_OWNER_PRIV: &[u8] = b"ownerpriv";
 pub const PREFIX_RECEIVERS: &[u8] = b"receivers";
 /// prefix for the storage of mint run numbers
 pub const PREFIX_MINT_RUN_NUM: &[u8] = b"runnum";
+/// prefix for the storage of the maximum number of tokens that may be minted per mint run id
+pub const PREFIX_MINT_RUN_CAP: &[u8] = b"runcap";
+/// prefix for the storage of the number of tokens minted per mint run id
+pub const PREFIX_MINT_RUN_SUPPLY: &[u8] = b"runsupply";
+/// prefix for the storage of the registry of mint runs of each mint run id
+pub const PREFIX_MINT_RUN_REGISTRY: &[u8] = b"runregistry";
+/// prefix for the storage of the serial numbers used in each mint run
+pub const PREFIX_USED_SERIALS: &[u8] = b"usedserials";
+/// prefix for the storage of the metadata pool of the public sale
+pub const PREFIX_SALE_POOL: &[u8] = b"salepool";
+/// prefix for the storage of the number of tokens each address bought in the public sale
+pub const PREFIX_SALE_WALLET: &[u8] = b"salewallet";
+/// prefix for the storage of the number of tokens each address minted in each mint phase
+pub const PREFIX_PHASE_USAGE: &[u8] = b"phaseusage";
+/// prefix for storage of the nonces of redeemed mint vouchers
+pub const PREFIX_VOUCHER_NONCES: &[u8] = b"vchrnonce";
+/// prefix for storage of the reveal pool's unassigned items
+pub const PREFIX_REVEAL_POOL: &[u8] = b"revealitems";
+/// prefix for the storage of the total supply of each edition
+pub const PREFIX_EDITION_SUPPLY: &[u8] = b"edsupply";
+/// prefix for the storage of each address' balance of each edition
+pub const PREFIX_EDITION_BALANCES: &[u8] = b"edbalances";
+/// prefix for the storage of the revocations of non-transferable tokens
+pub const PREFIX_REVOKED_BADGES: &[u8] = b"revokedbadges";
+/// prefix for the storage of the tombstones of burned token ids
+pub const PREFIX_TOMBSTONES: &[u8] = b"tombstones";
+/// prefix for the storage of token locks
+pub const PREFIX_LOCKS: &[u8] = b"locks";
+/// prefix for the storage of the addresses each owner approved to lock its tokens
+pub const PREFIX_LOCKER_APPROVALS: &[u8] = b"lockerapprovals";
+/// prefix for the storage of the users renting tokens
+pub const PREFIX_TOKEN_USERS: &[u8] = b"tokenusers";
+/// prefix for the storage of fixed-price listings
+pub const PREFIX_LISTINGS: &[u8] = b"listings";
+/// prefix for the storage of auctions
+pub const PREFIX_AUCTIONS: &[u8] = b"auctions";
+/// prefix for the storage of swap proposals
+pub const PREFIX_SWAPS: &[u8] = b"swaps";
+/// prefix for the storage of the code hashes of the contracts whose tokens can be swapped
+pub const PREFIX_SWAP_CONTRACTS: &[u8] = b"swapcontracts";
+/// prefix for the storage of the depositors of other contracts' tokens
+pub const PREFIX_SWAP_DEPOSITS: &[u8] = b"swapdeposits";
+/// prefix for the storage of escrowed offers
+pub const PREFIX_OFFERS: &[u8] = b"offers";
+/// prefix for the storage of the ids of the offers on each token
+pub const PREFIX_TOKEN_OFFERS: &[u8] = b"tokenoffers";
+/// prefix for the storage of the ids of the offers made by each buyer
+pub const PREFIX_BUYER_OFFERS: &[u8] = b"buyeroffers";
+/// prefix for the storage of the addresses holding each role other than minter
+pub const PREFIX_ROLES: &[u8] = b"roles";
+/// prefix for the storage of the addresses granted a role by the v1 to v2 migration because
+/// they were minters
+pub const PREFIX_MINTER_GRANTS: &[u8] = b"mintergrants";
+/// prefix for the storage of minter quotas
+pub const PREFIX_MINTER_QUOTA: &[u8] = b"mintquota";
+/// prefix for the storage of minter usage counts
+pub const PREFIX_MINTER_USAGE: &[u8] = b"mintusage";
 /// prefix for the storage of revoked permits
 pub const PREFIX_REVOKED_PERMITS: &str = "revoke";
 
@@ -114,6 +207,33 @@ pub enum StoredTxAction {
         /// burner's address if not owner
         burner: Option<CanonicalAddr>,
     },
+    /// minted prints of an edition
+    MintEdition {
+        /// minter's address
+        minter: CanonicalAddr,
+        /// address receiving the prints
+        recipient: CanonicalAddr,
+        /// number of prints minted
+        amount: u128,
+    },
+    /// transferred prints of an edition
+    TransferEdition {
+        /// previous holder
+        from: CanonicalAddr,
+        /// new holder
+        recipient: CanonicalAddr,
+        /// number of prints transferred
+        amount: u128,
+    },
+    /// revoked a non-transferable token
+    RevokeBadge {
+        /// issuer's address
+        issuer: CanonicalAddr,
+        /// token's owner
+        owner: CanonicalAddr,
+        /// why the token was revoked
+        reason: String,
+    },
 }
 
 /// tx in storage
@@ -173,6 +293,33 @@ impl StoredTx {
                     burner: bnr,
                 }
             }
+            StoredTxAction::MintEdition {
+                minter,
+                recipient,
+                amount,
+            } => TxAction::MintEdition {
+                minter: api.addr_humanize(&minter)?,
+                recipient: api.addr_humanize(&recipient)?,
+                amount: Uint128::new(amount),
+            },
+            StoredTxAction::TransferEdition {
+                from,
+                recipient,
+                amount,
+            } => TxAction::TransferEdition {
+                from: api.addr_humanize(&from)?,
+                recipient: api.addr_humanize(&recipient)?,
+                amount: Uint128::new(amount),
+            },
+            StoredTxAction::RevokeBadge {
+                issuer,
+                owner,
+                reason,
+            } => TxAction::RevokeBadge {
+                issuer: api.addr_humanize(&issuer)?,
+                owner: api.addr_humanize(&owner)?,
+                reason,
+            },
         };
         let tx = Tx {
             tx_id: self.tx_id,
@@ -325,6 +472,138 @@ pub fn store_burn(
     Ok(())
 }
 
+/// Returns StdResult<()> after storing tx
+///
+/// # Arguments
+///
+/// * `storage` - a mutable reference to the storage this item should go to
+/// * `config` - a mutable reference to the contract Config
+/// * `block` - a reference to the current BlockInfo
+/// * `token_id` - id of the edition being minted
+/// * `minter` - the minter's address
+/// * `recipient` - the recipient's address
+/// * `amount` - number of prints minted
+/// * `memo` - optional memo for the tx
+#[allow(clippy::too_many_arguments)]
+pub fn store_edition_mint(
+    storage: &mut dyn Storage,
+    config: &mut Config,
+    block: &BlockInfo,
+    token_id: String,
+    minter: CanonicalAddr,
+    recipient: CanonicalAddr,
+    amount: u128,
+    memo: Option<String>,
+) -> StdResult<()> {
+    append_tx_for_addr(storage, config.tx_cnt, &recipient)?;
+    if recipient != minter {
+        append_tx_for_addr(storage, config.tx_cnt, &minter)?;
+    }
+    let action = StoredTxAction::MintEdition {
+        minter,
+        recipient,
+        amount,
+    };
+    save_tx(storage, config, block, token_id, action, memo)
+}
+
+/// Returns StdResult<()> after storing tx
+///
+/// # Arguments
+///
+/// * `storage` - a mutable reference to the storage this item should go to
+/// * `config` - a mutable reference to the contract Config
+/// * `block` - a reference to the current BlockInfo
+/// * `token_id` - id of the edition being transferred
+/// * `from` - the previous holder's address
+/// * `recipient` - the recipient's address
+/// * `amount` - number of prints transferred
+/// * `memo` - optional memo for the tx
+#[allow(clippy::too_many_arguments)]
+pub fn store_edition_transfer(
+    storage: &mut dyn Storage,
+    config: &mut Config,
+    block: &BlockInfo,
+    token_id: String,
+    from: CanonicalAddr,
+    recipient: CanonicalAddr,
+    amount: u128,
+    memo: Option<String>,
+) -> StdResult<()> {
+    append_tx_for_addr(storage, config.tx_cnt, &from)?;
+    append_tx_for_addr(storage, config.tx_cnt, &recipient)?;
+    let action = StoredTxAction::TransferEdition {
+        from,
+        recipient,
+        amount,
+    };
+    save_tx(storage, config, block, token_id, action, memo)
+}
+
+/// Returns StdResult<()> after storing tx
+///
+/// # Arguments
+///
+/// * `storage` - a mutable reference to the storage this item should go to
+/// * `config` - a mutable reference to the contract Config
+/// * `block` - a reference to the current BlockInfo
+/// * `token_id` - id of the token being revoked
+/// * `issuer` - the issuer's address
+/// * `owner` - the owner's address
+/// * `reason` - why the token was revoked
+pub fn store_badge_revocation(
+    storage: &mut dyn Storage,
+    config: &mut Config,
+    block: &BlockInfo,
+    token_id: String,
+    issuer: CanonicalAddr,
+    owner: CanonicalAddr,
+    reason: String,
+) -> StdResult<()> {
+    append_tx_for_addr(storage, config.tx_cnt, &owner)?;
+    if owner != issuer {
+        append_tx_for_addr(storage, config.tx_cnt, &issuer)?;
+    }
+    let action = StoredTxAction::RevokeBadge {
+        issuer,
+        owner,
+        reason,
+    };
+    save_tx(storage, config, block, token_id, action, None)
+}
+
+/// Returns StdResult<()> after saving a tx under the next tx id
+///
+/// # Arguments
+///
+/// * `storage` - a mutable reference to the storage this item should go to
+/// * `config` - a mutable reference to the contract Config
+/// * `block` - a reference to the current BlockInfo
+/// * `token_id` - token id of the tx
+/// * `action` - tx type and specifics
+/// * `memo` - optional memo for the tx
+fn save_tx(
+    storage: &mut dyn Storage,
+    config: &mut Config,
+    block: &BlockInfo,
+    token_id: String,
+    action: StoredTxAction,
+    memo: Option<String>,
+) -> StdResult<()> {
+    let tx = StoredTx {
+        tx_id: config.tx_cnt,
+        block_height: block.height,
+        block_time: block.time.seconds(),
+        token_id,
+        action,
+        memo,
+    };
+    let mut tx_store = PrefixedStorage::new(storage, PREFIX_TXS);
+    json_save(&mut tx_store, &config.tx_cnt.to_le_bytes(), &tx)?;
+    config.tx_cnt += 1;
+    Ok(())
+}
+
 /// Returns StdResult<()> after saving tx id
 ///
 /// # Arguments
@@ -422,6 +701,98 @@ pub struct AuthList {
     pub tokens: [Vec<u32>; 3],
 }
 
+/// Returns StdResult<Vec<CanonicalAddr>> of the addresses holding a role.  Minters keep
+/// their original storage key so the minter role is the same list as before roles existed
+///
+/// # Arguments
+///
+/// * `storage` - a reference to the contract's storage
+/// * `role` - the role whose holders should be loaded
+pub fn load_role(storage: &dyn Storage, role: Role) -> StdResult<Vec<CanonicalAddr>> {
+    let holders: Option<Vec<CanonicalAddr>> = if role == Role::Minter {
+        may_load(storage, MINTERS_KEY)?
+    } else {
+        let role_store = ReadonlyPrefixedStorage::new(storage, PREFIX_ROLES);
+        may_load(&role_store, &[role.to_u8()])?
+    };
+    Ok(holders.unwrap_or_default())
+}
+
+/// Returns StdResult<Vec<CanonicalAddr>> of the given addresses that were granted a role by
+/// the v1 to v2 migration, and forgets those grants
+///
+/// # Arguments
+///
+/// * `storage` - a mutable reference to the contract's storage
+/// * `role` - the role that may have been granted by the migration
+/// * `addresses` - the addresses whose migration grants should be removed
+pub fn take_minter_grants(
+    storage: &mut dyn Storage,
+    role: Role,
+    addresses: &[CanonicalAddr],
+) -> StdResult<Vec<CanonicalAddr>> {
+    let mut grant_store = PrefixedStorage::new(storage, PREFIX_MINTER_GRANTS);
+    let mut granted: Vec<CanonicalAddr> =
+        may_load(&grant_store, &[role.to_u8()])?.unwrap_or_default();
+    let old_len = granted.len();
+    let mut taken = Vec::new();
+    granted.retain(|g| {
+        if addresses.contains(g) {
+            taken.push(g.clone());
+            false
+        } else {
+            true
+        }
+    });
+    if granted.len() != old_len {
+        if granted.is_empty() {
+            remove(&mut grant_store, &[role.to_u8()]);
+        } else {
+            save(&mut grant_store, &[role.to_u8()], &granted)?;
+        }
+    }
+    Ok(taken)
+}
+
+/// Returns StdResult<()> after saving the addresses holding a role, removing the list if
+/// it is empty
+///
+/// # Arguments
+///
+/// * `storage` - a mutable reference to the contract's storage
+/// * `role` - the role whose holders should be saved
+/// * `holders` - the addresses holding the role
+pub fn save_role(
+    storage: &mut dyn Storage,
+    role: Role,
+    holders: &[CanonicalAddr],
+) -> StdResult<()> {
+    if role == Role::Minter {
+        if holders.is_empty() {
+            remove(storage, MINTERS_KEY);
+        } else {
+            save(storage, MINTERS_KEY, &holders)?;
+        }
+    } else {
+        let mut role_store = PrefixedStorage::new(storage, PREFIX_ROLES);
+        if holders.is_empty() {
+            remove(&mut role_store, &[role.to_u8()]);
+        } else {
+            save(&mut role_store, &[role.to_u8()], &holders)?;
+        }
+    }
+    Ok(())
+}
+
+/// an admin handover awaiting acceptance by the proposed admin
+#[derive(Serialize, Deserialize)]
+pub struct PendingAdmin {
+    /// proposed admin address
+    pub address: CanonicalAddr,
+    /// when the proposal can no longer be accepted
+    pub expiration: Expiration,
+}
+
 /// a contract's code hash and whether they implement BatchReceiveNft
 #[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct ReceiveRegistration {
@@ -0,0 +1,186 @@
+use cosmwasm_std::{Addr, Api, CanonicalAddr, Coin, StdResult, Storage};
+use cosmwasm_storage::{PrefixedStorage, ReadonlyPrefixedStorage};
+use schemars::JsonSchema;
+use secret_toolkit::utils::HandleCallback;
+use serde::{Deserialize, Serialize};
+
+use crate::contract::BLOCK_SIZE;
+use crate::expiration::Expiration;
+use crate::msg::Transfer;
+use crate::state::{may_load, remove, save, PREFIX_SWAP_DEPOSITS};
+
+/// token exchanged in a swap
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
+pub struct SwapToken {
+    /// address of the SNIP-721 contract of the token.  Omitted for tokens of this contract
+    pub contract: Option<String>,
+    /// id of the token
+    pub token_id: String,
+}
+
+/// stored token exchanged in a swap
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct StoredSwapToken {
+    /// address of the SNIP-721 contract of the token, or None for tokens of this contract
+    pub contract: Option<CanonicalAddr>,
+    /// id of the token
+    pub token_id: String,
+}
+
+impl StoredSwapToken {
+    /// Returns StdResult<SwapToken> from converting a StoredSwapToken to a displayable
+    /// SwapToken
+    ///
+    /// # Arguments
+    ///
+    /// * `api` - a reference to the Api used to convert human and canonical addresses
+    pub fn into_humanized(self, api: &dyn Api) -> StdResult<SwapToken> {
+        Ok(SwapToken {
+            contract: self
+                .contract
+                .map(|c| api.addr_humanize(&c).map(|a| a.to_string()))
+                .transpose()?,
+            token_id: self.token_id,
+        })
+    }
+}
+
+/// proposal to exchange tokens between two owners
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq)]
+pub struct Swap {
+    /// id of the swap
+    pub swap_id: u64,
+    /// address that proposed the swap
+    pub proposer: Addr,
+    /// address that may accept the swap
+    pub counterparty: Addr,
+    /// tokens the proposer gives
+    pub offered: Vec<SwapToken>,
+    /// tokens the counterparty gives
+    pub requested: Vec<SwapToken>,
+    /// coins escrowed by the proposer that are paid to the counterparty
+    pub sweetener: Vec<Coin>,
+    /// expiration of the proposal
+    pub expires: Expiration,
+}
+
+/// stored swap proposal
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct StoredSwap {
+    /// address that proposed the swap
+    pub proposer: CanonicalAddr,
+    /// address that may accept the swap
+    pub counterparty: CanonicalAddr,
+    /// tokens the proposer gives
+    pub offered: Vec<StoredSwapToken>,
+    /// tokens the counterparty gives
+    pub requested: Vec<StoredSwapToken>,
+    /// coins escrowed by the proposer that are paid to the counterparty
+    pub sweetener: Vec<Coin>,
+    /// expiration of the proposal
+    pub expires: Expiration,
+}
+
+impl StoredSwap {
+    /// Returns StdResult<Swap> from converting a StoredSwap to a displayable Swap
+    ///
+    /// # Arguments
+    ///
+    /// * `api` - a reference to the Api used to convert human and canonical addresses
+    /// * `swap_id` - id of the swap
+    pub fn into_humanized(self, api: &dyn Api, swap_id: u64) -> StdResult<Swap> {
+        Ok(Swap {
+            swap_id,
+            proposer: api.addr_humanize(&self.proposer)?,
+            counterparty: api.addr_humanize(&self.counterparty)?,
+            offered: self
+                .offered
+                .into_iter()
+                .map(|t| t.into_humanized(api))
+                .collect::<StdResult<Vec<SwapToken>>>()?,
+            requested: self
+                .requested
+                .into_iter()
+                .map(|t| t.into_humanized(api))
+                .collect::<StdResult<Vec<SwapToken>>>()?,
+            sweetener: self.sweetener,
+            expires: self.expires,
+        })
+    }
+}
+
+/// messages this contract sends to the SNIP-721 contracts whose tokens can be deposited
+#[derive(Serialize, Deserialize, Clone, JsonSchema, Debug)]
+#[serde(rename_all = "snake_case")]
+pub enum Snip721HandleMsg {
+    /// register this contract's BatchReceiveNft so deposits are recorded
+    RegisterReceiveNft {
+        /// code hash of this contract
+        code_hash: String,
+        /// true because this contract implements BatchReceiveNft
+        also_implements_batch_receive_nft: Option<bool>,
+        /// optional message length padding
+        padding: Option<String>,
+    },
+    /// transfer deposited tokens out of this contract
+    BatchTransferNft {
+        /// list of transfers to perform
+        transfers: Vec<Transfer>,
+        /// optional message length padding
+        padding: Option<String>,
+    },
+}
+
+impl HandleCallback for Snip721HandleMsg {
+    const BLOCK_SIZE: usize = BLOCK_SIZE;
+}
+
+/// Returns StdResult<Option<CanonicalAddr>> of the address that deposited a token of another
+/// contract, or None if the token is not deposited
+///
+/// # Arguments
+///
+/// * `storage` - a reference to the contract's storage
+/// * `contract` - a reference to the address of the token's contract
+/// * `token_id` - string slice of the token id
+pub fn depositor(
+    storage: &dyn Storage,
+    contract: &CanonicalAddr,
+    token_id: &str,
+) -> StdResult<Option<CanonicalAddr>> {
+    let deposit_store =
+        ReadonlyPrefixedStorage::multilevel(storage, &[PREFIX_SWAP_DEPOSITS, contract.as_slice()]);
+    may_load(&deposit_store, token_id.as_bytes())
+}
+
+/// Returns StdResult<()> after recording who deposited a token of another contract
+///
+/// # Arguments
+///
+/// * `storage` - a mutable reference to the contract's storage
+/// * `contract` - a reference to the address of the token's contract
+/// * `token_id` - string slice of the token id
+/// * `owner` - a reference to the address that deposited the token
+pub fn save_deposit(
+    storage: &mut dyn Storage,
+    contract: &CanonicalAddr,
+    token_id: &str,
+    owner: &CanonicalAddr,
+) -> StdResult<()> {
+    let mut deposit_store =
+        PrefixedStorage::multilevel(storage, &[PREFIX_SWAP_DEPOSITS, contract.as_slice()]);
+    save(&mut deposit_store, token_id.as_bytes(), owner)
+}
+
+/// removes the deposit record of a token of another contract
+///
+/// # Arguments
+///
+/// * `storage` - a mutable reference to the contract's storage
+/// * `contract` - a reference to the address of the token's contract
+/// * `token_id` - string slice of the token id
+pub fn remove_deposit(storage: &mut dyn Storage, contract: &CanonicalAddr, token_id: &str) {
+    let mut deposit_store =
+        PrefixedStorage::multilevel(storage, &[PREFIX_SWAP_DEPOSITS, contract.as_slice()]);
+    remove(&mut deposit_store, token_id.as_bytes());
+}
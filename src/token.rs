@@ -28,6 +28,38 @@ pub struct Metadata {
     pub extension: Option<Extension>,
 }
 
+impl Metadata {
+    /// replaces every occurrence of each placeholder in the token uri and in the extension's
+    /// name, description, and attribute values
+    ///
+    /// # Arguments
+    ///
+    /// * `values` - list of placeholders and the strings that replace them
+    pub fn fill_placeholders(&mut self, values: &[(&str, &str)]) {
+        let fill = |field: &mut String| {
+            for (placeholder, value) in values {
+                if field.contains(placeholder) {
+                    *field = field.replace(placeholder, value);
+                }
+            }
+        };
+        if let Some(uri) = self.token_uri.as_mut() {
+            fill(uri);
+        }
+        if let Some(ext) = self.extension.as_mut() {
+            if let Some(name) = ext.name.as_mut() {
+                fill(name);
+            }
+            if let Some(description) = ext.description.as_mut() {
+                fill(description);
+            }
+            for attribute in ext.attributes.iter_mut().flatten() {
+                fill(&mut attribute.value);
+            }
+        }
+    }
+}
+
 /// metadata extension
 /// You can add any metadata fields you need here.  These fields are based on
 /// https://docs.opensea.io/docs/metadata-standards and are the metadata fields that
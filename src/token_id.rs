@@ -0,0 +1,69 @@
+use cosmwasm_std::{to_vec, StdResult};
+use schemars::JsonSchema;
+use secret_toolkit::crypto::sha_256;
+use serde::{Deserialize, Serialize};
+
+use crate::token::Metadata;
+
+/// how the ids of tokens minted without a token id are generated
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
+pub struct TokenIdScheme {
+    /// optional string placed before every generated id
+    pub prefix: Option<String>,
+    /// optional string placed after every generated id
+    pub suffix: Option<String>,
+    /// what the generated ids are derived from
+    pub base: TokenIdBase,
+    /// optional number of digits counters are zero-padded to
+    pub pad_to: Option<u8>,
+}
+
+/// what generated token ids are derived from
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum TokenIdBase {
+    /// the contract's mint index
+    MintIndex,
+    /// the number of tokens minted with the token's mint run id, following the mint run id.
+    /// Tokens minted without a mint run id use the mint index
+    MintRunCounter,
+    /// the hex encoded sha256 hash of the token's mint index and its public and private metadata
+    MetadataHash,
+}
+
+impl TokenIdScheme {
+    /// Returns StdResult<String> of the generated token id
+    ///
+    /// # Arguments
+    ///
+    /// * `mint_index` - the contract's mint index of this token
+    /// * `mint_run` - optional mint run id and the token's number within that mint run id
+    /// * `public_metadata` - an optional reference to the token's public metadata
+    /// * `private_metadata` - an optional reference to the token's private metadata
+    pub fn generate(
+        &self,
+        mint_index: u32,
+        mint_run: Option<(&str, u32)>,
+        public_metadata: Option<&Metadata>,
+        private_metadata: Option<&Metadata>,
+    ) -> StdResult<String> {
+        let width = self.pad_to.unwrap_or(0) as usize;
+        let base = match (&self.base, mint_run) {
+            (TokenIdBase::MintRunCounter, Some((run_id, count))) => {
+                format!("{}-{:0width$}", run_id, count, width = width)
+            }
+            (TokenIdBase::MetadataHash, _) => {
+                // the mint index keeps tokens with identical metadata from colliding
+                let hash = sha_256(&to_vec(&(mint_index, public_metadata, private_metadata))?);
+                hash.iter().map(|b| format!("{:02x}", b)).collect()
+            }
+            _ => format!("{:0width$}", mint_index, width = width),
+        };
+        Ok(format!(
+            "{}{}{}",
+            self.prefix.as_deref().unwrap_or_default(),
+            base,
+            self.suffix.as_deref().unwrap_or_default()
+        ))
+    }
+}
@@ -0,0 +1,57 @@
+use cosmwasm_std::{CanonicalAddr, StdResult, Storage};
+use cosmwasm_storage::{PrefixedStorage, ReadonlyPrefixedStorage};
+use serde::{Deserialize, Serialize};
+
+use crate::state::{may_load, remove, save, PREFIX_TOMBSTONES};
+
+/// record of a burned token
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct StoredTombstone {
+    /// the token's owner when it was burned
+    pub owner: CanonicalAddr,
+    /// address that burned the token if it was not the owner
+    pub burner: Option<CanonicalAddr>,
+    /// height of the block the token was burned in
+    pub block_height: u64,
+    /// time of the block the token was burned in (in seconds since 01/01/1970)
+    pub block_time: u64,
+}
+
+/// Returns StdResult<Option<StoredTombstone>> of the tombstone of a burned token id, or None if
+/// no token with that id was burned
+///
+/// # Arguments
+///
+/// * `storage` - a reference to the contract's storage
+/// * `token_id` - string slice of the token id
+pub fn tombstone(storage: &dyn Storage, token_id: &str) -> StdResult<Option<StoredTombstone>> {
+    let tomb_store = ReadonlyPrefixedStorage::new(storage, PREFIX_TOMBSTONES);
+    may_load(&tomb_store, token_id.as_bytes())
+}
+
+/// Returns StdResult<()> after saving the tombstone of a burned token id
+///
+/// # Arguments
+///
+/// * `storage` - a mutable reference to the contract's storage
+/// * `token_id` - string slice of the token id
+/// * `tomb` - a reference to the tombstone
+pub fn save_tombstone(
+    storage: &mut dyn Storage,
+    token_id: &str,
+    tomb: &StoredTombstone,
+) -> StdResult<()> {
+    let mut tomb_store = PrefixedStorage::new(storage, PREFIX_TOMBSTONES);
+    save(&mut tomb_store, token_id.as_bytes(), tomb)
+}
+
+/// removes the tombstone of a token id that is minted again
+///
+/// # Arguments
+///
+/// * `storage` - a mutable reference to the contract's storage
+/// * `token_id` - string slice of the token id
+pub fn remove_tombstone(storage: &mut dyn Storage, token_id: &str) {
+    let mut tomb_store = PrefixedStorage::new(storage, PREFIX_TOMBSTONES);
+    remove(&mut tomb_store, token_id.as_bytes());
+}
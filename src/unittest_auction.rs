@@ -0,0 +1,375 @@
+#[cfg(test)]
+mod tests {
+    use std::any::Any;
+
+    use cosmwasm_std::testing::*;
+    use cosmwasm_std::{
+        coins, from_binary, Addr, BankMsg, Coin, CosmosMsg, Env, OwnedDeps, Response, StdError,
+        StdResult, Uint128,
+    };
+
+    use crate::auction::{Auction, AuctionKind};
+    use crate::contract::{execute, instantiate, query};
+    use crate::msg::{ExecuteMsg, InstantiateConfig, InstantiateMsg, QueryAnswer, QueryMsg};
+    use crate::royalties::{Royalty, RoyaltyInfo};
+
+    // Helper functions
+
+    fn init_helper_default() -> (
+        StdResult<Response>,
+        OwnedDeps<MockStorage, MockApi, MockQuerier>,
+    ) {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+        let info = mock_info("instantiator", &[]);
+        let init_msg = InstantiateMsg {
+            name: "sec721".to_string(),
+            symbol: "S721".to_string(),
+            admin: Some("admin".to_string()),
+            entropy: "We're going to need a bigger boat".to_string(),
+            royalty_info: Some(RoyaltyInfo {
+                decimal_places_in_rates: 2,
+                royalties: vec![
+                    Royalty {
+                        recipient: "artist".to_string(),
+                        rate: 5,
+                    },
+                    Royalty {
+                        recipient: "curator".to_string(),
+                        rate: 2,
+                    },
+                ],
+            }),
+            config: Some(InstantiateConfig {
+                public_owner: Some(true),
+                enable_burn: Some(true),
+                ..InstantiateConfig::default()
+            }),
+            post_init_callback: None,
+        };
+
+        (instantiate(deps.as_mut(), env, info, init_msg), deps)
+    }
+
+    fn extract_error_msg<T: Any>(error: StdResult<T>) -> String {
+        match error {
+            Ok(_response) => panic!("Expected error, but had Ok response"),
+            Err(err) => match err {
+                StdError::GenericErr { msg, .. } => msg,
+                _ => panic!("Unexpected error result {:?}", err),
+            },
+        }
+    }
+
+    fn bank_sends(response: &Response) -> Vec<(String, Vec<Coin>)> {
+        response
+            .messages
+            .iter()
+            .filter_map(|m| match &m.msg {
+                CosmosMsg::Bank(BankMsg::Send { to_address, amount }) => {
+                    Some((to_address.clone(), amount.clone()))
+                }
+                _ => None,
+            })
+            .collect()
+    }
+
+    fn mint(deps: &mut OwnedDeps<MockStorage, MockApi, MockQuerier>) {
+        let execute_msg = ExecuteMsg::MintNft {
+            token_id: Some("NFT1".to_string()),
+            owner: Some("alice".to_string()),
+            public_metadata: None,
+            private_metadata: None,
+            royalty_info: None,
+            serial_number: None,
+            transferable: None,
+            memo: None,
+            padding: None,
+        };
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("admin", &[]),
+            execute_msg,
+        )
+        .unwrap();
+    }
+
+    fn at(seconds_after_start: u64) -> Env {
+        let mut env = mock_env();
+        env.block.time = env.block.time.plus_seconds(seconds_after_start);
+        env
+    }
+
+    fn start_auction(
+        deps: &mut OwnedDeps<MockStorage, MockApi, MockQuerier>,
+        sender: &str,
+        kind: AuctionKind,
+        duration: u64,
+    ) -> StdResult<Response> {
+        let execute_msg = ExecuteMsg::StartAuction {
+            token_id: "NFT1".to_string(),
+            denom: "uscrt".to_string(),
+            kind,
+            duration,
+            padding: None,
+        };
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(sender, &[]),
+            execute_msg,
+        )
+    }
+
+    fn bid(
+        deps: &mut OwnedDeps<MockStorage, MockApi, MockQuerier>,
+        env: Env,
+        sender: &str,
+        funds: &[Coin],
+    ) -> StdResult<Response> {
+        let execute_msg = ExecuteMsg::Bid {
+            token_id: "NFT1".to_string(),
+            padding: None,
+        };
+        execute(deps.as_mut(), env, mock_info(sender, funds), execute_msg)
+    }
+
+    fn settle(
+        deps: &mut OwnedDeps<MockStorage, MockApi, MockQuerier>,
+        env: Env,
+    ) -> StdResult<Response> {
+        let execute_msg = ExecuteMsg::SettleAuction {
+            token_id: "NFT1".to_string(),
+            padding: None,
+        };
+        execute(deps.as_mut(), env, mock_info("dave", &[]), execute_msg)
+    }
+
+    fn auction(deps: &OwnedDeps<MockStorage, MockApi, MockQuerier>, env: Env) -> Option<Auction> {
+        let query_msg = QueryMsg::Auction {
+            token_id: "NFT1".to_string(),
+        };
+        let query_result = query(deps.as_ref(), env, query_msg).unwrap();
+        match from_binary(&query_result).unwrap() {
+            QueryAnswer::Auction { auction } => auction,
+            _ => panic!("unexpected"),
+        }
+    }
+
+    fn owner(deps: &OwnedDeps<MockStorage, MockApi, MockQuerier>) -> Addr {
+        let query_msg = QueryMsg::OwnerOf {
+            token_id: "NFT1".to_string(),
+            viewer: None,
+            include_expired: None,
+        };
+        let query_result = query(deps.as_ref(), mock_env(), query_msg).unwrap();
+        match from_binary(&query_result).unwrap() {
+            QueryAnswer::OwnerOf { owner, .. } => owner,
+            _ => panic!("unexpected"),
+        }
+    }
+
+    #[test]
+    fn test_english_auction() {
+        let (init_result, mut deps) = init_helper_default();
+        assert!(
+            init_result.is_ok(),
+            "Init failed: {}",
+            init_result.err().unwrap()
+        );
+        mint(&mut deps);
+        let english = |min_increment: u128| AuctionKind::English {
+            reserve_price: Uint128::new(100),
+            min_increment: Uint128::new(min_increment),
+            extension: 60,
+        };
+
+        // test bad auctions
+        let error = extract_error_msg(start_auction(&mut deps, "bob", english(10), 3600));
+        assert!(error.contains("You are not authorized to auction token NFT1"));
+        let error = extract_error_msg(start_auction(&mut deps, "alice", english(0), 3600));
+        assert!(error.contains("The minimum bid increment must be greater than zero"));
+        let error = extract_error_msg(start_auction(&mut deps, "alice", english(10), 0));
+        assert!(error.contains("The auction duration must be greater than zero"));
+
+        start_auction(&mut deps, "alice", english(10), 3600).unwrap();
+        let error = extract_error_msg(start_auction(&mut deps, "alice", english(10), 3600));
+        assert!(error.contains("Token ID: NFT1 is being auctioned"));
+
+        // the token can not be transferred, burned, or listed during the auction
+        let execute_msg = ExecuteMsg::TransferNft {
+            recipient: "bob".to_string(),
+            token_id: "NFT1".to_string(),
+            memo: None,
+            padding: None,
+        };
+        let handle_result = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("alice", &[]),
+            execute_msg,
+        );
+        let error = extract_error_msg(handle_result);
+        assert!(error.contains("Token ID: NFT1 is being auctioned"));
+        let execute_msg = ExecuteMsg::BurnNft {
+            token_id: "NFT1".to_string(),
+            memo: None,
+            padding: None,
+        };
+        let handle_result = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("alice", &[]),
+            execute_msg,
+        );
+        let error = extract_error_msg(handle_result);
+        assert!(error.contains("Token ID: NFT1 is being auctioned"));
+        let execute_msg = ExecuteMsg::List {
+            token_id: "NFT1".to_string(),
+            price: Coin::new(1000, "uscrt"),
+            padding: None,
+        };
+        let handle_result = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("alice", &[]),
+            execute_msg,
+        );
+        let error = extract_error_msg(handle_result);
+        assert!(error.contains("Token ID: NFT1 is being auctioned"));
+
+        // test bad bids
+        let error = extract_error_msg(bid(&mut deps, mock_env(), "alice", &coins(100, "uscrt")));
+        assert!(error.contains("You can not bid on your own auction of token NFT1"));
+        let error = extract_error_msg(bid(&mut deps, mock_env(), "bob", &coins(100, "uatom")));
+        assert!(error.contains("Bids on token NFT1 must be made in uscrt"));
+        let error = extract_error_msg(bid(&mut deps, mock_env(), "bob", &coins(99, "uscrt")));
+        assert!(error.contains("Bid too low.  The minimum bid on token NFT1 is 100uscrt"));
+
+        // outbid bidders are refunded
+        let response = bid(&mut deps, mock_env(), "bob", &coins(100, "uscrt")).unwrap();
+        assert!(bank_sends(&response).is_empty());
+        let error = extract_error_msg(bid(&mut deps, mock_env(), "carol", &coins(109, "uscrt")));
+        assert!(error.contains("Bid too low.  The minimum bid on token NFT1 is 110uscrt"));
+        let response = bid(&mut deps, mock_env(), "carol", &coins(200, "uscrt")).unwrap();
+        assert_eq!(
+            bank_sends(&response),
+            vec![("bob".to_string(), coins(100, "uscrt"))]
+        );
+        let start = mock_env().block.time.seconds();
+        assert_eq!(
+            auction(&deps, mock_env()),
+            Some(Auction {
+                token_id: "NFT1".to_string(),
+                denom: "uscrt".to_string(),
+                kind: english(10),
+                starts_at: start,
+                ends_at: start + 3600,
+                highest_bid: Some(Uint128::new(200)),
+                current_price: None,
+            })
+        );
+
+        // test settling early
+        let error = extract_error_msg(settle(&mut deps, at(3599)));
+        assert!(error.contains("The auction of token NFT1 has not ended"));
+
+        // a late bid extends the auction
+        let response = bid(&mut deps, at(3590), "bob", &coins(300, "uscrt")).unwrap();
+        assert_eq!(
+            bank_sends(&response),
+            vec![("carol".to_string(), coins(200, "uscrt"))]
+        );
+        assert_eq!(auction(&deps, mock_env()).unwrap().ends_at, start + 3650);
+        let error = extract_error_msg(settle(&mut deps, at(3600)));
+        assert!(error.contains("The auction of token NFT1 has not ended"));
+        let error = extract_error_msg(bid(&mut deps, at(3650), "carol", &coins(400, "uscrt")));
+        assert!(error.contains("The auction of token NFT1 has ended"));
+
+        // anyone may settle the auction, which pays the royalties and the seller
+        let response = settle(&mut deps, at(3650)).unwrap();
+        assert_eq!(
+            bank_sends(&response),
+            vec![
+                ("artist".to_string(), coins(15, "uscrt")),
+                ("curator".to_string(), coins(6, "uscrt")),
+                ("alice".to_string(), coins(279, "uscrt")),
+            ]
+        );
+        assert_eq!(owner(&deps), Addr::unchecked("bob"));
+        assert!(auction(&deps, mock_env()).is_none());
+        let error = extract_error_msg(settle(&mut deps, at(3650)));
+        assert!(error.contains("Token ID: NFT1 is not being auctioned"));
+    }
+
+    #[test]
+    fn test_dutch_auction() {
+        let (init_result, mut deps) = init_helper_default();
+        assert!(
+            init_result.is_ok(),
+            "Init failed: {}",
+            init_result.err().unwrap()
+        );
+        mint(&mut deps);
+        let dutch = |start_price: u128| AuctionKind::Dutch {
+            start_price: Uint128::new(start_price),
+            end_price: Uint128::new(100),
+        };
+
+        let error = extract_error_msg(start_auction(&mut deps, "alice", dutch(100), 1000));
+        assert!(
+            error.contains("The start price of a Dutch auction must be greater than its end price")
+        );
+        start_auction(&mut deps, "alice", dutch(1000), 1000).unwrap();
+
+        // the price decays linearly
+        assert_eq!(
+            auction(&deps, mock_env()).unwrap().current_price,
+            Some(Uint128::new(1000))
+        );
+        assert_eq!(
+            auction(&deps, at(500)).unwrap().current_price,
+            Some(Uint128::new(550))
+        );
+        assert_eq!(auction(&deps, at(1000)).unwrap().current_price, None);
+
+        // the first bid at the current price wins
+        let error = extract_error_msg(bid(&mut deps, at(500), "bob", &coins(549, "uscrt")));
+        assert!(error.contains("Bid too low.  The current price of token NFT1 is 550uscrt"));
+        let response = bid(&mut deps, at(500), "bob", &coins(600, "uscrt")).unwrap();
+        assert_eq!(
+            bank_sends(&response),
+            vec![
+                ("artist".to_string(), coins(27, "uscrt")),
+                ("curator".to_string(), coins(11, "uscrt")),
+                ("alice".to_string(), coins(512, "uscrt")),
+                ("bob".to_string(), coins(50, "uscrt")),
+            ]
+        );
+        assert_eq!(owner(&deps), Addr::unchecked("bob"));
+        assert!(auction(&deps, mock_env()).is_none());
+
+        // an auction without a winner leaves the token with the seller once it is settled
+        start_auction(&mut deps, "bob", dutch(1000), 1000).unwrap();
+        let error = extract_error_msg(bid(&mut deps, at(1000), "carol", &coins(100, "uscrt")));
+        assert!(error.contains("The auction of token NFT1 has ended"));
+        let response = settle(&mut deps, at(1000)).unwrap();
+        assert!(bank_sends(&response).is_empty());
+        assert_eq!(owner(&deps), Addr::unchecked("bob"));
+        let execute_msg = ExecuteMsg::TransferNft {
+            recipient: "carol".to_string(),
+            token_id: "NFT1".to_string(),
+            memo: None,
+            padding: None,
+        };
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("bob", &[]),
+            execute_msg,
+        )
+        .unwrap();
+        assert_eq!(owner(&deps), Addr::unchecked("carol"));
+    }
+}
@@ -0,0 +1,425 @@
+#[cfg(test)]
+mod tests {
+    use std::any::Any;
+
+    use cosmwasm_std::testing::*;
+    use cosmwasm_std::{from_binary, Addr, OwnedDeps, Response, StdError, StdResult, Uint128};
+
+    use crate::contract::{execute, instantiate, query};
+    use crate::edition::{EditionBalance, EditionInfo};
+    use crate::minter_quota::MinterQuota;
+    use crate::msg::{
+        ExecuteAnswer, ExecuteMsg, InstantiateConfig, InstantiateMsg, QueryAnswer, QueryMsg,
+        TxAction, ViewerInfo,
+    };
+    use crate::token::Metadata;
+
+    // Helper functions
+
+    fn init_helper_default() -> (
+        StdResult<Response>,
+        OwnedDeps<MockStorage, MockApi, MockQuerier>,
+    ) {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+        let info = mock_info("instantiator", &[]);
+        let init_msg = InstantiateMsg {
+            name: "sec721".to_string(),
+            symbol: "S721".to_string(),
+            admin: Some("admin".to_string()),
+            entropy: "We're going to need a bigger boat".to_string(),
+            royalty_info: None,
+            config: Some(InstantiateConfig {
+                enable_burn: Some(true),
+                ..InstantiateConfig::default()
+            }),
+            post_init_callback: None,
+        };
+
+        (instantiate(deps.as_mut(), env, info, init_msg), deps)
+    }
+
+    fn extract_error_msg<T: Any>(error: StdResult<T>) -> String {
+        match error {
+            Ok(_response) => panic!("Expected error, but had Ok response"),
+            Err(err) => match err {
+                StdError::GenericErr { msg, .. } => msg,
+                _ => panic!("Unexpected error result {:?}", err),
+            },
+        }
+    }
+
+    fn exec(
+        deps: &mut OwnedDeps<MockStorage, MockApi, MockQuerier>,
+        sender: &str,
+        msg: ExecuteMsg,
+    ) -> StdResult<Response> {
+        execute(deps.as_mut(), mock_env(), mock_info(sender, &[]), msg)
+    }
+
+    fn mint_edition(
+        deps: &mut OwnedDeps<MockStorage, MockApi, MockQuerier>,
+        sender: &str,
+        token_id: &str,
+        owner: &str,
+        amount: u128,
+        with_metadata: bool,
+    ) -> StdResult<Response> {
+        let (public_metadata, private_metadata) = if with_metadata {
+            (
+                Some(Metadata {
+                    token_uri: Some("public uri".to_string()),
+                    extension: None,
+                }),
+                Some(Metadata {
+                    token_uri: Some("private uri".to_string()),
+                    extension: None,
+                }),
+            )
+        } else {
+            (None, None)
+        };
+        let execute_msg = ExecuteMsg::MintEdition {
+            token_id: Some(token_id.to_string()),
+            owner: Some(owner.to_string()),
+            amount: Uint128::new(amount),
+            public_metadata,
+            private_metadata,
+            royalty_info: None,
+            memo: None,
+            padding: None,
+        };
+        exec(deps, sender, execute_msg)
+    }
+
+    fn transfer_edition(
+        deps: &mut OwnedDeps<MockStorage, MockApi, MockQuerier>,
+        sender: &str,
+        token_id: &str,
+        recipient: &str,
+        amount: u128,
+    ) -> StdResult<Response> {
+        let execute_msg = ExecuteMsg::TransferEdition {
+            recipient: recipient.to_string(),
+            token_id: token_id.to_string(),
+            amount: Uint128::new(amount),
+            memo: None,
+            padding: None,
+        };
+        exec(deps, sender, execute_msg)
+    }
+
+    fn set_key(deps: &mut OwnedDeps<MockStorage, MockApi, MockQuerier>, address: &str) {
+        let execute_msg = ExecuteMsg::SetViewingKey {
+            key: "key".to_string(),
+            padding: None,
+        };
+        exec(deps, address, execute_msg).unwrap();
+    }
+
+    fn tokens(deps: &OwnedDeps<MockStorage, MockApi, MockQuerier>, owner: &str) -> Vec<String> {
+        let query_msg = QueryMsg::Tokens {
+            owner: owner.to_string(),
+            viewer: None,
+            viewing_key: Some("key".to_string()),
+            start_after: None,
+            limit: None,
+        };
+        let query_result = query(deps.as_ref(), mock_env(), query_msg).unwrap();
+        match from_binary(&query_result).unwrap() {
+            QueryAnswer::TokenList { tokens, .. } => tokens,
+            _ => panic!("unexpected"),
+        }
+    }
+
+    fn balances(
+        deps: &OwnedDeps<MockStorage, MockApi, MockQuerier>,
+        owner: &str,
+    ) -> Vec<EditionBalance> {
+        let query_msg = QueryMsg::Tokens {
+            owner: owner.to_string(),
+            viewer: None,
+            viewing_key: Some("key".to_string()),
+            start_after: None,
+            limit: None,
+        };
+        let query_result = query(deps.as_ref(), mock_env(), query_msg).unwrap();
+        match from_binary(&query_result).unwrap() {
+            QueryAnswer::TokenList { balances, .. } => balances.unwrap(),
+            _ => panic!("unexpected"),
+        }
+    }
+
+    fn dossier(
+        deps: &OwnedDeps<MockStorage, MockApi, MockQuerier>,
+        token_id: &str,
+        viewer: &str,
+    ) -> (Option<Metadata>, Option<EditionInfo>) {
+        let query_msg = QueryMsg::NftDossier {
+            token_id: token_id.to_string(),
+            viewer: Some(ViewerInfo {
+                address: viewer.to_string(),
+                viewing_key: "key".to_string(),
+            }),
+            include_expired: None,
+        };
+        let query_result = query(deps.as_ref(), mock_env(), query_msg).unwrap();
+        match from_binary(&query_result).unwrap() {
+            QueryAnswer::NftDossier {
+                private_metadata,
+                edition,
+                ..
+            } => (private_metadata, edition),
+            _ => panic!("unexpected"),
+        }
+    }
+
+    #[test]
+    fn test_mint_edition() {
+        let (init_result, mut deps) = init_helper_default();
+        assert!(
+            init_result.is_ok(),
+            "Init failed: {}",
+            init_result.err().unwrap()
+        );
+        set_key(&mut deps, "alice");
+        set_key(&mut deps, "bob");
+
+        // test non minter
+        let error = extract_error_msg(mint_edition(&mut deps, "alice", "ED1", "alice", 5, true));
+        assert!(error.contains("Only designated minters are allowed to mint"));
+
+        // test zero amount
+        let error = extract_error_msg(mint_edition(&mut deps, "admin", "ED1", "alice", 0, true));
+        assert!(error.contains("Amount can not be zero"));
+
+        let response = mint_edition(&mut deps, "admin", "ED1", "alice", 500, true).unwrap();
+        let answer: ExecuteAnswer = from_binary(response.data.as_ref().unwrap()).unwrap();
+        match answer {
+            ExecuteAnswer::MintEdition { token_id } => assert_eq!(token_id, "ED1"),
+            _ => panic!("unexpected"),
+        }
+        assert_eq!(tokens(&deps, "alice"), vec!["ED1".to_string()]);
+        // holders may view the private metadata
+        let (private_metadata, edition) = dossier(&deps, "ED1", "alice");
+        assert_eq!(
+            private_metadata.and_then(|m| m.token_uri),
+            Some("private uri".to_string())
+        );
+        assert_eq!(
+            edition,
+            Some(EditionInfo {
+                supply: Uint128::new(500),
+                balance: Some(Uint128::new(500)),
+            })
+        );
+        let (private_metadata, edition) = dossier(&deps, "ED1", "bob");
+        assert!(private_metadata.is_none());
+        assert_eq!(edition.and_then(|e| e.balance), Some(Uint128::zero()));
+
+        // test adding prints with metadata
+        let error = extract_error_msg(mint_edition(&mut deps, "admin", "ED1", "bob", 100, true));
+        assert!(
+            error.contains("Metadata and royalties can only be set when edition ED1 is created")
+        );
+
+        // test a minter that did not issue the edition
+        let execute_msg = ExecuteMsg::AddMinters {
+            minters: vec!["charlie".to_string()],
+            padding: None,
+        };
+        exec(&mut deps, "admin", execute_msg).unwrap();
+        let error = extract_error_msg(mint_edition(&mut deps, "charlie", "ED1", "bob", 100, false));
+        assert!(error.contains("Only the issuer of edition ED1 may mint more of its prints"));
+
+        // test an id used by a regular token
+        let execute_msg = ExecuteMsg::MintNft {
+            token_id: Some("NFT1".to_string()),
+            owner: Some("alice".to_string()),
+            public_metadata: None,
+            private_metadata: None,
+            royalty_info: None,
+            serial_number: None,
+            transferable: None,
+            memo: None,
+            padding: None,
+        };
+        exec(&mut deps, "admin", execute_msg).unwrap();
+        let error = extract_error_msg(mint_edition(&mut deps, "admin", "NFT1", "bob", 1, false));
+        assert!(error.contains("Token ID NFT1 is already in use"));
+
+        mint_edition(&mut deps, "admin", "ED1", "bob", 100, false).unwrap();
+        assert_eq!(tokens(&deps, "bob"), vec!["ED1".to_string()]);
+        assert_eq!(
+            balances(&deps, "bob"),
+            vec![EditionBalance {
+                token_id: "ED1".to_string(),
+                balance: Uint128::new(100),
+            }]
+        );
+        // regular tokens have no edition balance
+        assert_eq!(
+            balances(&deps, "alice"),
+            vec![EditionBalance {
+                token_id: "ED1".to_string(),
+                balance: Uint128::new(500),
+            }]
+        );
+        assert_eq!(
+            tokens(&deps, "alice"),
+            vec!["ED1".to_string(), "NFT1".to_string()]
+        );
+        let (_, edition) = dossier(&deps, "ED1", "bob");
+        assert_eq!(
+            edition,
+            Some(EditionInfo {
+                supply: Uint128::new(600),
+                balance: Some(Uint128::new(100)),
+            })
+        );
+        let (_, edition) = dossier(&deps, "NFT1", "alice");
+        assert!(edition.is_none());
+    }
+
+    #[test]
+    fn test_mint_edition_quota() {
+        let (init_result, mut deps) = init_helper_default();
+        assert!(
+            init_result.is_ok(),
+            "Init failed: {}",
+            init_result.err().unwrap()
+        );
+        let execute_msg = ExecuteMsg::SetMinterQuota {
+            minter: "admin".to_string(),
+            quota: Some(MinterQuota {
+                lifetime: Some(150),
+                window: None,
+                max_batch: None,
+            }),
+            padding: None,
+        };
+        exec(&mut deps, "admin", execute_msg).unwrap();
+
+        // every print counts towards the quota, whether it creates the edition or adds to it
+        let error = extract_error_msg(mint_edition(&mut deps, "admin", "ED1", "alice", 200, true));
+        assert!(error.contains("Minting 200 tokens exceeds the remaining lifetime quota of 150"));
+        mint_edition(&mut deps, "admin", "ED1", "alice", 100, true).unwrap();
+        let error = extract_error_msg(mint_edition(&mut deps, "admin", "ED1", "bob", 60, false));
+        assert!(error.contains("Minting 60 tokens exceeds the remaining lifetime quota of 50"));
+        mint_edition(&mut deps, "admin", "ED1", "bob", 50, false).unwrap();
+        let error = extract_error_msg(mint_edition(&mut deps, "admin", "ED1", "bob", 1, false));
+        assert!(error.contains("Minting 1 tokens exceeds the remaining lifetime quota of 0"));
+
+        // test an amount too large to count
+        let error = extract_error_msg(mint_edition(
+            &mut deps,
+            "admin",
+            "ED1",
+            "bob",
+            u32::MAX as u128 + 1,
+            false,
+        ));
+        assert!(error.contains("Attempting to mint more prints than the implementation limit"));
+    }
+
+    #[test]
+    fn test_transfer_edition() {
+        let (init_result, mut deps) = init_helper_default();
+        assert!(
+            init_result.is_ok(),
+            "Init failed: {}",
+            init_result.err().unwrap()
+        );
+        set_key(&mut deps, "alice");
+        set_key(&mut deps, "bob");
+        mint_edition(&mut deps, "admin", "ED1", "alice", 500, true).unwrap();
+
+        // test transferring more than the balance
+        let error = extract_error_msg(transfer_edition(&mut deps, "alice", "ED1", "bob", 501));
+        assert!(error.contains("Your balance of token ID: ED1 is 500, which is less than 501"));
+        let error = extract_error_msg(transfer_edition(&mut deps, "bob", "ED1", "alice", 1));
+        assert!(error.contains("Your balance of token ID: ED1 is 0, which is less than 1"));
+
+        // test the NFT handles
+        let execute_msg = ExecuteMsg::TransferNft {
+            recipient: "bob".to_string(),
+            token_id: "ED1".to_string(),
+            memo: None,
+            padding: None,
+        };
+        let error = extract_error_msg(exec(&mut deps, "admin", execute_msg));
+        assert!(error
+            .contains("Token ID: ED1 is an edition and must be transferred with TransferEdition"));
+        let execute_msg = ExecuteMsg::Approve {
+            spender: "bob".to_string(),
+            token_id: "ED1".to_string(),
+            expires: None,
+            padding: None,
+        };
+        let error = extract_error_msg(exec(&mut deps, "admin", execute_msg));
+        assert!(error.contains("Token ID: ED1 is an edition and can not have token approvals"));
+        let execute_msg = ExecuteMsg::BurnNft {
+            token_id: "ED1".to_string(),
+            memo: None,
+            padding: None,
+        };
+        let error = extract_error_msg(exec(&mut deps, "admin", execute_msg));
+        assert!(error.contains("Token ID: ED1 is an edition and can not be burned"));
+
+        transfer_edition(&mut deps, "alice", "ED1", "bob", 200).unwrap();
+        assert_eq!(tokens(&deps, "alice"), vec!["ED1".to_string()]);
+        assert_eq!(tokens(&deps, "bob"), vec!["ED1".to_string()]);
+        let (_, edition) = dossier(&deps, "ED1", "bob");
+        assert_eq!(edition.and_then(|e| e.balance), Some(Uint128::new(200)));
+
+        // transferring the whole balance removes the edition from the inventory
+        transfer_edition(&mut deps, "alice", "ED1", "bob", 300).unwrap();
+        assert!(tokens(&deps, "alice").is_empty());
+        let (private_metadata, edition) = dossier(&deps, "ED1", "alice");
+        assert!(private_metadata.is_none());
+        assert_eq!(edition.and_then(|e| e.balance), Some(Uint128::zero()));
+
+        let query_msg = QueryMsg::TransactionHistory {
+            address: "bob".to_string(),
+            viewing_key: "key".to_string(),
+            page: None,
+            page_size: None,
+        };
+        let query_result = query(deps.as_ref(), mock_env(), query_msg).unwrap();
+        match from_binary(&query_result).unwrap() {
+            QueryAnswer::TransactionHistory { total, txs } => {
+                assert_eq!(total, 2);
+                assert_eq!(
+                    txs[0].action,
+                    TxAction::TransferEdition {
+                        from: Addr::unchecked("alice"),
+                        recipient: Addr::unchecked("bob"),
+                        amount: Uint128::new(300),
+                    }
+                );
+            }
+            _ => panic!("unexpected"),
+        }
+        let query_msg = QueryMsg::TransactionHistory {
+            address: "alice".to_string(),
+            viewing_key: "key".to_string(),
+            page: None,
+            page_size: None,
+        };
+        let query_result = query(deps.as_ref(), mock_env(), query_msg).unwrap();
+        match from_binary(&query_result).unwrap() {
+            QueryAnswer::TransactionHistory { total, txs } => {
+                assert_eq!(total, 3);
+                assert_eq!(
+                    txs[2].action,
+                    TxAction::MintEdition {
+                        minter: Addr::unchecked("admin"),
+                        recipient: Addr::unchecked("alice"),
+                        amount: Uint128::new(500),
+                    }
+                );
+            }
+            _ => panic!("unexpected"),
+        }
+    }
+}
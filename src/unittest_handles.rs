@@ -17,16 +17,17 @@ mod tests {
     use crate::expiration::Expiration;
     use crate::inventory::Inventory;
     use crate::msg::{
-        AccessLevel, Burn, ContractStatus, ExecuteAnswer, ExecuteMsg, InstantiateConfig,
-        InstantiateMsg, Mint, PostInstantiateCallback, QueryAnswer, QueryMsg, ReceiverInfo, Send,
-        Transfer, Tx, TxAction,
+        AccessLevel, Burn, ConfigRatchets, ContractStatus, ExecuteAnswer, ExecuteMsg,
+        InstantiateConfig, InstantiateMsg, Mint, PostInstantiateCallback, QueryAnswer, QueryMsg,
+        Ratchet, ReceiverInfo, Send, Transfer, Tx, TxAction,
     };
     use crate::receiver::Snip721ReceiveMsg;
     use crate::state::{
-        get_txs, json_load, json_may_load, load, may_load, AuthList, Config, Permission,
-        PermissionType, CONFIG_KEY, MINTERS_KEY, PREFIX_ALL_PERMISSIONS, PREFIX_AUTHLIST,
-        PREFIX_INFOS, PREFIX_MAP_TO_ID, PREFIX_MAP_TO_INDEX, PREFIX_OWNER_PRIV, PREFIX_PRIV_META,
-        PREFIX_PUB_META, PREFIX_RECEIVERS,
+        get_txs, json_load, json_may_load, load, may_load, AuthList, Config, PendingAdmin,
+        Permission, PermissionType, CONFIG_KEY, MINTERS_KEY, PENDING_ADMIN_KEY,
+        PREFIX_ALL_PERMISSIONS, PREFIX_AUTHLIST, PREFIX_INFOS, PREFIX_MAP_TO_ID,
+        PREFIX_MAP_TO_INDEX, PREFIX_OWNER_PRIV, PREFIX_PRIV_META, PREFIX_PUB_META,
+        PREFIX_RECEIVERS, RATCHETS_KEY,
     };
     use crate::token::{Extension, Metadata, Token};
 
@@ -8354,7 +8355,7 @@ mod tests {
         let query_result = query(deps.as_ref(), mock_env(), query_msg);
         let query_answer: QueryAnswer = from_binary(&query_result.unwrap()).unwrap();
         match query_answer {
-            QueryAnswer::TokenList { tokens } => {
+            QueryAnswer::TokenList { tokens, .. } => {
                 let expected = vec![
                     "NFT6".to_string(),
                     "NFT2".to_string(),
@@ -8427,7 +8428,7 @@ mod tests {
         let query_result = query(deps.as_ref(), mock_env(), query_msg);
         let query_answer: QueryAnswer = from_binary(&query_result.unwrap()).unwrap();
         match query_answer {
-            QueryAnswer::TokenList { tokens } => {
+            QueryAnswer::TokenList { tokens, .. } => {
                 assert!(tokens.is_empty());
             }
             _ => panic!("unexpected"),
@@ -10026,7 +10027,7 @@ mod tests {
         let query_result = query(deps.as_ref(), mock_env(), query_msg);
         let query_answer: QueryAnswer = from_binary(&query_result.unwrap()).unwrap();
         match query_answer {
-            QueryAnswer::TokenList { tokens } => {
+            QueryAnswer::TokenList { tokens, .. } => {
                 let expected = vec![
                     "NFT1".to_string(),
                     "NFT3".to_string(),
@@ -10087,7 +10088,7 @@ mod tests {
         let query_result = query(deps.as_ref(), mock_env(), query_msg);
         let query_answer: QueryAnswer = from_binary(&query_result.unwrap()).unwrap();
         match query_answer {
-            QueryAnswer::TokenList { tokens } => {
+            QueryAnswer::TokenList { tokens, .. } => {
                 let expected = vec!["NFT2".to_string()];
                 assert_eq!(tokens, expected);
             }
@@ -10747,6 +10748,324 @@ mod tests {
         assert_eq!(config.admin, alice_raw);
     }
 
+    // test the two-step admin handover
+    #[test]
+    fn test_admin_handover() {
+        let (init_result, mut deps) = init_helper_default();
+        assert!(
+            init_result.is_ok(),
+            "Init failed: {}",
+            init_result.err().unwrap()
+        );
+        let admin_raw = deps.api.addr_canonicalize("admin").unwrap();
+        let alice_raw = deps.api.addr_canonicalize("alice").unwrap();
+
+        // test non admin trying to propose
+        let execute_msg = ExecuteMsg::ProposeAdmin {
+            address: "alice".to_string(),
+            expiration: None,
+            padding: None,
+        };
+        let handle_result = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("alice", &[]),
+            execute_msg,
+        );
+        let error = extract_error_msg(handle_result);
+        assert!(
+            error.contains("This is an admin command and can only be run from the admin address")
+        );
+
+        // test accepting without a proposal
+        let execute_msg = ExecuteMsg::AcceptAdmin { padding: None };
+        let handle_result = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("alice", &[]),
+            execute_msg,
+        );
+        let error = extract_error_msg(handle_result);
+        assert!(error.contains("You have not been proposed as the new admin"));
+
+        // propose alice with an expiration
+        let execute_msg = ExecuteMsg::ProposeAdmin {
+            address: "alice".to_string(),
+            expiration: Some(Expiration::AtHeight(12400)),
+            padding: None,
+        };
+        let handle_result = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("admin", &[]),
+            execute_msg,
+        );
+        assert!(handle_result.is_ok());
+        // the admin does not change until the proposal is accepted
+        let config: Config = load(&deps.storage, CONFIG_KEY).unwrap();
+        assert_eq!(config.admin, admin_raw);
+
+        // test someone else trying to accept
+        let execute_msg = ExecuteMsg::AcceptAdmin { padding: None };
+        let handle_result = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("bob", &[]),
+            execute_msg,
+        );
+        let error = extract_error_msg(handle_result);
+        assert!(error.contains("You have not been proposed as the new admin"));
+
+        // test accepting after the proposal expired
+        let mut env = mock_env();
+        env.block.height = 12400;
+        let execute_msg = ExecuteMsg::AcceptAdmin { padding: None };
+        let handle_result = execute(deps.as_mut(), env, mock_info("alice", &[]), execute_msg);
+        let error = extract_error_msg(handle_result);
+        assert!(error.contains("The admin proposal has expired"));
+
+        // test cancelling the proposal
+        let execute_msg = ExecuteMsg::CancelAdminProposal { padding: None };
+        let handle_result = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("admin", &[]),
+            execute_msg,
+        );
+        assert!(handle_result.is_ok());
+        let execute_msg = ExecuteMsg::AcceptAdmin { padding: None };
+        let handle_result = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("alice", &[]),
+            execute_msg,
+        );
+        let error = extract_error_msg(handle_result);
+        assert!(error.contains("You have not been proposed as the new admin"));
+
+        // propose again and accept
+        let execute_msg = ExecuteMsg::ProposeAdmin {
+            address: "alice".to_string(),
+            expiration: None,
+            padding: None,
+        };
+        let _handle_result = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("admin", &[]),
+            execute_msg,
+        );
+        let execute_msg = ExecuteMsg::AcceptAdmin { padding: None };
+        let handle_result = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("alice", &[]),
+            execute_msg,
+        );
+        let handle_answer: ExecuteAnswer =
+            from_binary(&handle_result.unwrap().data.unwrap()).unwrap();
+        assert!(matches!(handle_answer, ExecuteAnswer::AcceptAdmin { .. }));
+        let config: Config = load(&deps.storage, CONFIG_KEY).unwrap();
+        assert_eq!(config.admin, alice_raw);
+        // the proposal is used up
+        let pending: Option<PendingAdmin> =
+            json_may_load(&deps.storage, PENDING_ADMIN_KEY).unwrap();
+        assert!(pending.is_none());
+
+        // the old admin no longer has authority
+        let execute_msg = ExecuteMsg::CancelAdminProposal { padding: None };
+        let handle_result = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("admin", &[]),
+            execute_msg,
+        );
+        let error = extract_error_msg(handle_result);
+        assert!(
+            error.contains("This is an admin command and can only be run from the admin address")
+        );
+    }
+
+    // test update config
+    #[test]
+    fn test_update_config() {
+        let (init_result, mut deps) =
+            init_helper_with_config(true, true, false, false, true, false, true);
+        assert!(
+            init_result.is_ok(),
+            "Init failed: {}",
+            init_result.err().unwrap()
+        );
+        let no_change: InstantiateConfig = from_binary(&Binary::from(b"{}")).unwrap();
+
+        // test non admin trying to update the config
+        let execute_msg = ExecuteMsg::UpdateConfig {
+            config: no_change.clone(),
+            ratchets: None,
+            padding: None,
+        };
+        let handle_result = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("alice", &[]),
+            execute_msg,
+        );
+        let error = extract_error_msg(handle_result);
+        assert!(
+            error.contains("This is an admin command and can only be run from the admin address")
+        );
+
+        // change some flags, including setting one to its current value, and ratchet them
+        let execute_msg = ExecuteMsg::UpdateConfig {
+            config: InstantiateConfig {
+                public_token_supply: Some(false),
+                public_owner: Some(true),
+                enable_sealed_metadata: None,
+                unwrapped_metadata_is_private: None,
+                minter_may_update_metadata: None,
+                owner_may_update_metadata: Some(true),
+                enable_burn: Some(false),
+            },
+            ratchets: Some(ConfigRatchets {
+                public_token_supply: Some(Ratchet::OnlyDisable),
+                enable_burn: Some(Ratchet::OnlyDisable),
+                ..Default::default()
+            }),
+            padding: None,
+        };
+        let handle_result = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("admin", &[]),
+            execute_msg,
+        );
+        let handle_answer: ExecuteAnswer =
+            from_binary(&handle_result.unwrap().data.unwrap()).unwrap();
+        match handle_answer {
+            ExecuteAnswer::UpdateConfig {
+                changed, ratcheted, ..
+            } => {
+                assert_eq!(
+                    changed,
+                    vec![
+                        "public_token_supply".to_string(),
+                        "owner_may_update_metadata".to_string(),
+                        "enable_burn".to_string(),
+                    ]
+                );
+                assert_eq!(
+                    ratcheted,
+                    vec!["public_token_supply".to_string(), "enable_burn".to_string()]
+                );
+            }
+            _ => panic!("unexpected"),
+        }
+        let config: Config = load(&deps.storage, CONFIG_KEY).unwrap();
+        assert!(!config.token_supply_is_public);
+        assert!(config.owner_is_public);
+        assert!(config.owner_may_update_metadata);
+        assert!(!config.burn_is_enabled);
+
+        // test trying to move a ratcheted flag the wrong way
+        let mut enable_burn = no_change.clone();
+        enable_burn.enable_burn = Some(true);
+        let execute_msg = ExecuteMsg::UpdateConfig {
+            config: enable_burn,
+            ratchets: None,
+            padding: None,
+        };
+        let handle_result = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("admin", &[]),
+            execute_msg,
+        );
+        let error = extract_error_msg(handle_result);
+        assert!(error.contains("enable_burn is one-way and can not be set to true"));
+
+        // test trying to reverse a ratchet
+        let execute_msg = ExecuteMsg::UpdateConfig {
+            config: no_change.clone(),
+            ratchets: Some(ConfigRatchets {
+                public_token_supply: Some(Ratchet::OnlyEnable),
+                ..Default::default()
+            }),
+            padding: None,
+        };
+        let handle_result = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("admin", &[]),
+            execute_msg,
+        );
+        let error = extract_error_msg(handle_result);
+        assert!(error
+            .contains("public_token_supply is already one-way and its ratchet can not be changed"));
+
+        // sealed metadata can be enabled, but never disabled again
+        let mut sealed = no_change.clone();
+        sealed.enable_sealed_metadata = Some(true);
+        let execute_msg = ExecuteMsg::UpdateConfig {
+            config: sealed.clone(),
+            ratchets: None,
+            padding: None,
+        };
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("admin", &[]),
+            execute_msg,
+        )
+        .unwrap();
+        sealed.enable_sealed_metadata = Some(false);
+        let execute_msg = ExecuteMsg::UpdateConfig {
+            config: sealed,
+            ratchets: None,
+            padding: None,
+        };
+        let handle_result = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("admin", &[]),
+            execute_msg,
+        );
+        let error = extract_error_msg(handle_result);
+        assert!(error.contains("Sealed metadata can not be disabled once it has been enabled"));
+
+        // unratcheted flags can still go back and forth
+        let mut owner_update = no_change;
+        owner_update.owner_may_update_metadata = Some(false);
+        let execute_msg = ExecuteMsg::UpdateConfig {
+            config: owner_update,
+            ratchets: Some(ConfigRatchets {
+                public_token_supply: Some(Ratchet::OnlyDisable),
+                ..Default::default()
+            }),
+            padding: None,
+        };
+        let handle_result = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("admin", &[]),
+            execute_msg,
+        );
+        let handle_answer: ExecuteAnswer =
+            from_binary(&handle_result.unwrap().data.unwrap()).unwrap();
+        match handle_answer {
+            ExecuteAnswer::UpdateConfig {
+                changed, ratcheted, ..
+            } => {
+                assert_eq!(changed, vec!["owner_may_update_metadata".to_string()]);
+                assert!(ratcheted.is_empty());
+            }
+            _ => panic!("unexpected"),
+        }
+        let ratchets: ConfigRatchets = json_load(&deps.storage, RATCHETS_KEY).unwrap();
+        assert_eq!(ratchets.public_token_supply, Some(Ratchet::OnlyDisable));
+        assert_eq!(ratchets.enable_burn, Some(Ratchet::OnlyDisable));
+        assert_eq!(ratchets.public_owner, None);
+    }
+
     // test set contract status
     #[test]
     fn test_set_contract_status() {
@@ -0,0 +1,282 @@
+#[cfg(test)]
+mod tests {
+    use std::any::Any;
+
+    use cosmwasm_std::testing::*;
+    use cosmwasm_std::{from_binary, Addr, Env, OwnedDeps, Response, StdError, StdResult};
+
+    use crate::contract::{execute, instantiate, query};
+    use crate::expiration::Expiration;
+    use crate::lock::Lock;
+    use crate::msg::{
+        ExecuteMsg, InstantiateConfig, InstantiateMsg, QueryAnswer, QueryMsg, ViewerInfo,
+    };
+
+    // Helper functions
+
+    fn init_helper_default() -> (
+        StdResult<Response>,
+        OwnedDeps<MockStorage, MockApi, MockQuerier>,
+    ) {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+        let info = mock_info("instantiator", &[]);
+        let init_msg = InstantiateMsg {
+            name: "sec721".to_string(),
+            symbol: "S721".to_string(),
+            admin: Some("admin".to_string()),
+            entropy: "We're going to need a bigger boat".to_string(),
+            royalty_info: None,
+            config: Some(InstantiateConfig {
+                enable_burn: Some(true),
+                ..InstantiateConfig::default()
+            }),
+            post_init_callback: None,
+        };
+
+        (instantiate(deps.as_mut(), env, info, init_msg), deps)
+    }
+
+    fn extract_error_msg<T: Any>(error: StdResult<T>) -> String {
+        match error {
+            Ok(_response) => panic!("Expected error, but had Ok response"),
+            Err(err) => match err {
+                StdError::GenericErr { msg, .. } => msg,
+                _ => panic!("Unexpected error result {:?}", err),
+            },
+        }
+    }
+
+    fn mint(deps: &mut OwnedDeps<MockStorage, MockApi, MockQuerier>, token_id: &str) {
+        let execute_msg = ExecuteMsg::MintNft {
+            token_id: Some(token_id.to_string()),
+            owner: Some("alice".to_string()),
+            public_metadata: None,
+            private_metadata: None,
+            royalty_info: None,
+            serial_number: None,
+            transferable: None,
+            memo: None,
+            padding: None,
+        };
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("admin", &[]),
+            execute_msg,
+        )
+        .unwrap();
+    }
+
+    fn lock(
+        deps: &mut OwnedDeps<MockStorage, MockApi, MockQuerier>,
+        sender: &str,
+        token_id: &str,
+        expires: Expiration,
+    ) -> StdResult<Response> {
+        let execute_msg = ExecuteMsg::Lock {
+            token_id: token_id.to_string(),
+            expires,
+            padding: None,
+        };
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(sender, &[]),
+            execute_msg,
+        )
+    }
+
+    fn transfer(
+        deps: &mut OwnedDeps<MockStorage, MockApi, MockQuerier>,
+        env: Env,
+        token_id: &str,
+    ) -> StdResult<Response> {
+        let execute_msg = ExecuteMsg::TransferNft {
+            recipient: "bob".to_string(),
+            token_id: token_id.to_string(),
+            memo: None,
+            padding: None,
+        };
+        execute(deps.as_mut(), env, mock_info("alice", &[]), execute_msg)
+    }
+
+    fn is_locked(deps: &OwnedDeps<MockStorage, MockApi, MockQuerier>, token_id: &str) -> bool {
+        let query_msg = QueryMsg::IsLocked {
+            token_id: token_id.to_string(),
+        };
+        let query_result = query(deps.as_ref(), mock_env(), query_msg).unwrap();
+        match from_binary(&query_result).unwrap() {
+            QueryAnswer::IsLocked { token_is_locked } => token_is_locked,
+            _ => panic!("unexpected"),
+        }
+    }
+
+    #[test]
+    fn test_lock_token() {
+        let (init_result, mut deps) = init_helper_default();
+        assert!(
+            init_result.is_ok(),
+            "Init failed: {}",
+            init_result.err().unwrap()
+        );
+        mint(&mut deps, "NFT1");
+        mint(&mut deps, "NFT2");
+        let height = mock_env().block.height;
+        let expires = Expiration::AtHeight(height + 100);
+
+        // test an address that is neither the owner nor an approved locker
+        let error = extract_error_msg(lock(&mut deps, "staking", "NFT1", expires));
+        assert!(error.contains("You are not authorized to lock token NFT1"));
+        let error = extract_error_msg(lock(&mut deps, "staking", "NFT9", expires));
+        assert!(error.contains("You are not authorized to lock token NFT9"));
+
+        let execute_msg = ExecuteMsg::ApproveLocker {
+            locker: "staking".to_string(),
+            expires: None,
+            padding: None,
+        };
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("alice", &[]),
+            execute_msg,
+        )
+        .unwrap();
+
+        // test an expiration that already passed
+        let error = extract_error_msg(lock(&mut deps, "staking", "NFT1", Expiration::AtHeight(1)));
+        assert!(error.contains("The lock expiration has already passed"));
+
+        lock(&mut deps, "staking", "NFT1", expires).unwrap();
+        let error = extract_error_msg(lock(&mut deps, "alice", "NFT1", expires));
+        assert!(error.contains("Token ID: NFT1 is already locked"));
+        assert!(is_locked(&deps, "NFT1"));
+        assert!(!is_locked(&deps, "NFT2"));
+        assert!(!is_locked(&deps, "NFT9"));
+
+        // locked tokens can not be transferred, sent, or burned
+        let error = extract_error_msg(transfer(&mut deps, mock_env(), "NFT1"));
+        assert!(error.contains("Token ID: NFT1 is locked"));
+        let execute_msg = ExecuteMsg::SendNft {
+            contract: "market".to_string(),
+            receiver_info: None,
+            token_id: "NFT1".to_string(),
+            msg: None,
+            memo: None,
+            padding: None,
+        };
+        let handle_result = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("alice", &[]),
+            execute_msg,
+        );
+        let error = extract_error_msg(handle_result);
+        assert!(error.contains("Token ID: NFT1 is locked"));
+        let execute_msg = ExecuteMsg::BurnNft {
+            token_id: "NFT1".to_string(),
+            memo: None,
+            padding: None,
+        };
+        let handle_result = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("alice", &[]),
+            execute_msg,
+        );
+        let error = extract_error_msg(handle_result);
+        assert!(error.contains("Token ID: NFT1 is locked"));
+
+        // only the owner and the locker may view the lock
+        for addr in ["alice", "bob", "staking"] {
+            let execute_msg = ExecuteMsg::SetViewingKey {
+                key: "key".to_string(),
+                padding: None,
+            };
+            execute(deps.as_mut(), mock_env(), mock_info(addr, &[]), execute_msg).unwrap();
+        }
+        let lock_info = |addr: &str| QueryMsg::LockInfo {
+            token_id: "NFT1".to_string(),
+            viewer: Some(ViewerInfo {
+                address: addr.to_string(),
+                viewing_key: "key".to_string(),
+            }),
+        };
+        let error = extract_error_msg(query(deps.as_ref(), mock_env(), lock_info("bob")));
+        assert!(error.contains("You are not authorized to view the lock of token NFT1"));
+        for addr in ["alice", "staking"] {
+            let query_result = query(deps.as_ref(), mock_env(), lock_info(addr)).unwrap();
+            match from_binary(&query_result).unwrap() {
+                QueryAnswer::LockInfo { lock } => assert_eq!(
+                    lock,
+                    Some(Lock {
+                        locker: Addr::unchecked("staking"),
+                        expires,
+                    })
+                ),
+                _ => panic!("unexpected"),
+            }
+        }
+
+        // only the locker may unlock
+        let unlock = |token_id: &str| ExecuteMsg::Unlock {
+            token_id: token_id.to_string(),
+            padding: None,
+        };
+        let handle_result = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("alice", &[]),
+            unlock("NFT1"),
+        );
+        let error = extract_error_msg(handle_result);
+        assert!(error.contains("Only the address that locked token NFT1 may unlock it"));
+        let handle_result = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("staking", &[]),
+            unlock("NFT2"),
+        );
+        let error = extract_error_msg(handle_result);
+        assert!(error.contains("Token ID: NFT2 is not locked"));
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("staking", &[]),
+            unlock("NFT1"),
+        )
+        .unwrap();
+        assert!(!is_locked(&deps, "NFT1"));
+        transfer(&mut deps, mock_env(), "NFT1").unwrap();
+
+        // revoked lockers may no longer lock
+        let execute_msg = ExecuteMsg::RevokeLocker {
+            locker: "staking".to_string(),
+            padding: None,
+        };
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("alice", &[]),
+            execute_msg,
+        )
+        .unwrap();
+        let error = extract_error_msg(lock(&mut deps, "staking", "NFT2", expires));
+        assert!(error.contains("You are not authorized to lock token NFT2"));
+
+        // expired locks no longer apply
+        lock(
+            &mut deps,
+            "alice",
+            "NFT2",
+            Expiration::AtHeight(height + 10),
+        )
+        .unwrap();
+        let error = extract_error_msg(transfer(&mut deps, mock_env(), "NFT2"));
+        assert!(error.contains("Token ID: NFT2 is locked"));
+        let mut env = mock_env();
+        env.block.height = height + 10;
+        transfer(&mut deps, env, "NFT2").unwrap();
+    }
+}
@@ -0,0 +1,353 @@
+#[cfg(test)]
+mod tests {
+    use std::any::Any;
+
+    use cosmwasm_std::testing::*;
+    use cosmwasm_std::{
+        coins, from_binary, Addr, BankMsg, Coin, CosmosMsg, OwnedDeps, Response, StdError,
+        StdResult,
+    };
+
+    use crate::contract::{execute, instantiate, query};
+    use crate::expiration::Expiration;
+    use crate::market::Listing;
+    use crate::msg::{ExecuteMsg, InstantiateConfig, InstantiateMsg, QueryAnswer, QueryMsg};
+    use crate::royalties::{Royalty, RoyaltyInfo};
+
+    // Helper functions
+
+    fn init_helper_default() -> (
+        StdResult<Response>,
+        OwnedDeps<MockStorage, MockApi, MockQuerier>,
+    ) {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+        let info = mock_info("instantiator", &[]);
+        let init_msg = InstantiateMsg {
+            name: "sec721".to_string(),
+            symbol: "S721".to_string(),
+            admin: Some("admin".to_string()),
+            entropy: "We're going to need a bigger boat".to_string(),
+            royalty_info: Some(RoyaltyInfo {
+                decimal_places_in_rates: 2,
+                royalties: vec![
+                    Royalty {
+                        recipient: "artist".to_string(),
+                        rate: 5,
+                    },
+                    Royalty {
+                        recipient: "curator".to_string(),
+                        rate: 2,
+                    },
+                ],
+            }),
+            config: Some(InstantiateConfig {
+                public_owner: Some(true),
+                enable_burn: Some(true),
+                ..InstantiateConfig::default()
+            }),
+            post_init_callback: None,
+        };
+
+        (instantiate(deps.as_mut(), env, info, init_msg), deps)
+    }
+
+    fn extract_error_msg<T: Any>(error: StdResult<T>) -> String {
+        match error {
+            Ok(_response) => panic!("Expected error, but had Ok response"),
+            Err(err) => match err {
+                StdError::GenericErr { msg, .. } => msg,
+                _ => panic!("Unexpected error result {:?}", err),
+            },
+        }
+    }
+
+    fn bank_sends(response: &Response) -> Vec<(String, Vec<Coin>)> {
+        response
+            .messages
+            .iter()
+            .filter_map(|m| match &m.msg {
+                CosmosMsg::Bank(BankMsg::Send { to_address, amount }) => {
+                    Some((to_address.clone(), amount.clone()))
+                }
+                _ => None,
+            })
+            .collect()
+    }
+
+    fn mint(deps: &mut OwnedDeps<MockStorage, MockApi, MockQuerier>, token_id: &str) {
+        let execute_msg = ExecuteMsg::MintNft {
+            token_id: Some(token_id.to_string()),
+            owner: Some("alice".to_string()),
+            public_metadata: None,
+            private_metadata: None,
+            royalty_info: None,
+            serial_number: None,
+            transferable: None,
+            memo: None,
+            padding: None,
+        };
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("admin", &[]),
+            execute_msg,
+        )
+        .unwrap();
+    }
+
+    fn list(
+        deps: &mut OwnedDeps<MockStorage, MockApi, MockQuerier>,
+        sender: &str,
+        token_id: &str,
+        amount: u128,
+    ) -> StdResult<Response> {
+        let execute_msg = ExecuteMsg::List {
+            token_id: token_id.to_string(),
+            price: Coin::new(amount, "uscrt"),
+            padding: None,
+        };
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(sender, &[]),
+            execute_msg,
+        )
+    }
+
+    fn buy(
+        deps: &mut OwnedDeps<MockStorage, MockApi, MockQuerier>,
+        sender: &str,
+        token_id: &str,
+        funds: &[Coin],
+    ) -> StdResult<Response> {
+        let execute_msg = ExecuteMsg::Buy {
+            token_id: token_id.to_string(),
+            memo: None,
+            padding: None,
+        };
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(sender, funds),
+            execute_msg,
+        )
+    }
+
+    fn listings(
+        deps: &OwnedDeps<MockStorage, MockApi, MockQuerier>,
+        start_after: Option<&str>,
+        limit: Option<u32>,
+    ) -> Vec<String> {
+        let query_msg = QueryMsg::Listings {
+            start_after: start_after.map(|s| s.to_string()),
+            limit,
+        };
+        let query_result = query(deps.as_ref(), mock_env(), query_msg).unwrap();
+        match from_binary(&query_result).unwrap() {
+            QueryAnswer::Listings { listings } => {
+                listings.into_iter().map(|l| l.token_id).collect()
+            }
+            _ => panic!("unexpected"),
+        }
+    }
+
+    fn owner(deps: &OwnedDeps<MockStorage, MockApi, MockQuerier>, token_id: &str) -> Addr {
+        let query_msg = QueryMsg::OwnerOf {
+            token_id: token_id.to_string(),
+            viewer: None,
+            include_expired: None,
+        };
+        let query_result = query(deps.as_ref(), mock_env(), query_msg).unwrap();
+        match from_binary(&query_result).unwrap() {
+            QueryAnswer::OwnerOf { owner, .. } => owner,
+            _ => panic!("unexpected"),
+        }
+    }
+
+    #[test]
+    fn test_list_and_delist() {
+        let (init_result, mut deps) = init_helper_default();
+        assert!(
+            init_result.is_ok(),
+            "Init failed: {}",
+            init_result.err().unwrap()
+        );
+        for id in ["NFT1", "NFT2", "NFT3", "NFT4"] {
+            mint(&mut deps, id);
+        }
+
+        // test listing a token the sender does not own
+        let error = extract_error_msg(list(&mut deps, "bob", "NFT1", 1000));
+        assert!(error.contains("You are not authorized to list token NFT1"));
+        let error = extract_error_msg(list(&mut deps, "bob", "NFT9", 1000));
+        assert!(error.contains("You are not authorized to list token NFT9"));
+
+        // test a free listing
+        let error = extract_error_msg(list(&mut deps, "alice", "NFT1", 0));
+        assert!(error.contains("The listing price must be greater than zero"));
+
+        for id in ["NFT1", "NFT2", "NFT3", "NFT4"] {
+            list(&mut deps, "alice", id, 1000).unwrap();
+        }
+        // relisting changes the price
+        list(&mut deps, "alice", "NFT2", 2500).unwrap();
+        let query_msg = QueryMsg::Listings {
+            start_after: None,
+            limit: Some(2),
+        };
+        let query_result = query(deps.as_ref(), mock_env(), query_msg).unwrap();
+        match from_binary(&query_result).unwrap() {
+            QueryAnswer::Listings { listings } => assert_eq!(
+                listings,
+                vec![
+                    Listing {
+                        token_id: "NFT1".to_string(),
+                        price: Coin::new(1000, "uscrt"),
+                    },
+                    Listing {
+                        token_id: "NFT2".to_string(),
+                        price: Coin::new(2500, "uscrt"),
+                    },
+                ]
+            ),
+            _ => panic!("unexpected"),
+        }
+        assert_eq!(
+            listings(&deps, Some("NFT2"), None),
+            vec!["NFT3".to_string(), "NFT4".to_string()]
+        );
+
+        // test delisting
+        let delist = |token_id: &str| ExecuteMsg::Delist {
+            token_id: token_id.to_string(),
+            padding: None,
+        };
+        let handle_result = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("bob", &[]),
+            delist("NFT1"),
+        );
+        let error = extract_error_msg(handle_result);
+        assert!(error.contains("Only the seller may delist token NFT1"));
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("alice", &[]),
+            delist("NFT1"),
+        )
+        .unwrap();
+        let handle_result = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("alice", &[]),
+            delist("NFT1"),
+        );
+        let error = extract_error_msg(handle_result);
+        assert!(error.contains("Token ID: NFT1 is not listed"));
+
+        // listings end on transfer and burn
+        let execute_msg = ExecuteMsg::TransferNft {
+            recipient: "carol".to_string(),
+            token_id: "NFT2".to_string(),
+            memo: None,
+            padding: None,
+        };
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("alice", &[]),
+            execute_msg,
+        )
+        .unwrap();
+        let execute_msg = ExecuteMsg::BurnNft {
+            token_id: "NFT3".to_string(),
+            memo: None,
+            padding: None,
+        };
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("alice", &[]),
+            execute_msg,
+        )
+        .unwrap();
+        assert_eq!(listings(&deps, None, None), vec!["NFT4".to_string()]);
+        let error = extract_error_msg(buy(&mut deps, "bob", "NFT2", &coins(2500, "uscrt")));
+        assert!(error.contains("Token ID: NFT2 is not listed"));
+    }
+
+    #[test]
+    fn test_buy() {
+        let (init_result, mut deps) = init_helper_default();
+        assert!(
+            init_result.is_ok(),
+            "Init failed: {}",
+            init_result.err().unwrap()
+        );
+        mint(&mut deps, "NFT1");
+        list(&mut deps, "alice", "NFT1", 1000).unwrap();
+
+        // test buying your own listing
+        let error = extract_error_msg(buy(&mut deps, "alice", "NFT1", &coins(1000, "uscrt")));
+        assert!(error.contains("You already own token NFT1"));
+
+        // test bad payments
+        let error = extract_error_msg(buy(&mut deps, "bob", "NFT1", &coins(1000, "uatom")));
+        assert!(error.contains("Token ID: NFT1 is only sold for uscrt"));
+        let error = extract_error_msg(buy(&mut deps, "bob", "NFT1", &coins(999, "uscrt")));
+        assert!(error.contains("Insufficient payment.  Token ID: NFT1 costs 1000uscrt"));
+
+        // test a locked token
+        let execute_msg = ExecuteMsg::Lock {
+            token_id: "NFT1".to_string(),
+            expires: Expiration::AtHeight(mock_env().block.height + 1),
+            padding: None,
+        };
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("alice", &[]),
+            execute_msg,
+        )
+        .unwrap();
+        let error = extract_error_msg(buy(&mut deps, "bob", "NFT1", &coins(1000, "uscrt")));
+        assert!(error.contains("Token ID: NFT1 is locked"));
+        let execute_msg = ExecuteMsg::Unlock {
+            token_id: "NFT1".to_string(),
+            padding: None,
+        };
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("alice", &[]),
+            execute_msg,
+        )
+        .unwrap();
+
+        // the royalties are paid, the seller gets the rest, and the buyer gets the change
+        let response = buy(&mut deps, "bob", "NFT1", &coins(1200, "uscrt")).unwrap();
+        assert_eq!(
+            bank_sends(&response),
+            vec![
+                ("artist".to_string(), coins(50, "uscrt")),
+                ("curator".to_string(), coins(20, "uscrt")),
+                ("alice".to_string(), coins(930, "uscrt")),
+                ("bob".to_string(), coins(200, "uscrt")),
+            ]
+        );
+        assert_eq!(owner(&deps, "NFT1"), Addr::unchecked("bob"));
+        assert!(listings(&deps, None, None).is_empty());
+
+        // the new owner may list the token again
+        list(&mut deps, "bob", "NFT1", 5).unwrap();
+        let response = buy(&mut deps, "carol", "NFT1", &coins(5, "uscrt")).unwrap();
+        // royalties that round down to zero are skipped
+        assert_eq!(
+            bank_sends(&response),
+            vec![("bob".to_string(), coins(5, "uscrt"))]
+        );
+        assert_eq!(owner(&deps, "NFT1"), Addr::unchecked("carol"));
+    }
+}
@@ -0,0 +1,334 @@
+#[cfg(test)]
+mod tests {
+    use std::any::Any;
+
+    use cosmwasm_std::testing::*;
+    use cosmwasm_std::{from_binary, OwnedDeps, Response, StdError, StdResult};
+
+    use crate::contract::{execute, instantiate, query};
+    use crate::msg::{
+        ExecuteMsg, InstantiateConfig, InstantiateMsg, Mint, QueryAnswer, QueryMsg, ViewerInfo,
+    };
+
+    // Helper functions
+
+    fn init_helper_with_config(
+        public_token_supply: bool,
+    ) -> (
+        StdResult<Response>,
+        OwnedDeps<MockStorage, MockApi, MockQuerier>,
+    ) {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+        let info = mock_info("instantiator", &[]);
+        let init_msg = InstantiateMsg {
+            name: "sec721".to_string(),
+            symbol: "S721".to_string(),
+            admin: Some("admin".to_string()),
+            entropy: "We're going to need a bigger boat".to_string(),
+            royalty_info: None,
+            config: Some(InstantiateConfig {
+                public_token_supply: Some(public_token_supply),
+                enable_burn: Some(true),
+                ..InstantiateConfig::default()
+            }),
+            post_init_callback: None,
+        };
+
+        (instantiate(deps.as_mut(), env, info, init_msg), deps)
+    }
+
+    fn extract_error_msg<T: Any>(error: StdResult<T>) -> String {
+        match error {
+            Ok(_response) => panic!("Expected error, but had Ok response"),
+            Err(err) => match err {
+                StdError::GenericErr { msg, .. } => msg,
+                _ => panic!("Unexpected error result {:?}", err),
+            },
+        }
+    }
+
+    fn set_max_supply(
+        deps: &mut OwnedDeps<MockStorage, MockApi, MockQuerier>,
+        mint_run_id: Option<&str>,
+        max_supply: Option<u32>,
+    ) -> StdResult<Response> {
+        let execute_msg = ExecuteMsg::SetMaxSupply {
+            mint_run_id: mint_run_id.map(|i| i.to_string()),
+            max_supply,
+            padding: None,
+        };
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("admin", &[]),
+            execute_msg,
+        )
+    }
+
+    fn batch_mint(
+        deps: &mut OwnedDeps<MockStorage, MockApi, MockQuerier>,
+        token_ids: &[&str],
+    ) -> StdResult<Response> {
+        let mints = token_ids
+            .iter()
+            .map(|id| Mint {
+                token_id: Some(id.to_string()),
+                owner: None,
+                public_metadata: None,
+                private_metadata: None,
+                serial_number: None,
+                royalty_info: None,
+                transferable: None,
+                memo: None,
+            })
+            .collect();
+        let execute_msg = ExecuteMsg::BatchMintNft {
+            mints,
+            padding: None,
+        };
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("admin", &[]),
+            execute_msg,
+        )
+    }
+
+    fn mint_clones(
+        deps: &mut OwnedDeps<MockStorage, MockApi, MockQuerier>,
+        mint_run_id: &str,
+        quantity: u32,
+    ) -> StdResult<Response> {
+        let execute_msg = ExecuteMsg::MintNftClones {
+            mint_run_id: Some(mint_run_id.to_string()),
+            quantity,
+            owner: None,
+            public_metadata: None,
+            private_metadata: None,
+            serial_overrides: None,
+            royalty_info: None,
+            memo: None,
+            padding: None,
+        };
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("admin", &[]),
+            execute_msg,
+        )
+    }
+
+    fn remaining_supply(
+        deps: &OwnedDeps<MockStorage, MockApi, MockQuerier>,
+        mint_run_id: Option<&str>,
+        viewer: Option<ViewerInfo>,
+    ) -> StdResult<(Option<u32>, u32, Option<u32>)> {
+        let query_msg = QueryMsg::RemainingSupply {
+            mint_run_id: mint_run_id.map(|i| i.to_string()),
+            viewer,
+        };
+        let query_result = query(deps.as_ref(), mock_env(), query_msg)?;
+        let query_answer: QueryAnswer = from_binary(&query_result)?;
+        match query_answer {
+            QueryAnswer::RemainingSupply {
+                max_supply,
+                minted,
+                remaining,
+            } => Ok((max_supply, minted, remaining)),
+            _ => panic!("unexpected"),
+        }
+    }
+
+    #[test]
+    fn test_collection_max_supply() {
+        let (init_result, mut deps) = init_helper_with_config(true);
+        assert!(
+            init_result.is_ok(),
+            "Init failed: {}",
+            init_result.err().unwrap()
+        );
+        assert_eq!(
+            remaining_supply(&deps, None, None).unwrap(),
+            (None, 0, None)
+        );
+
+        // test non admin trying to set the max supply
+        let execute_msg = ExecuteMsg::SetMaxSupply {
+            mint_run_id: None,
+            max_supply: Some(3),
+            padding: None,
+        };
+        let handle_result = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("alice", &[]),
+            execute_msg,
+        );
+        let error = extract_error_msg(handle_result);
+        assert!(
+            error.contains("This is an admin command and can only be run from the admin address")
+        );
+
+        batch_mint(&mut deps, &["NFT1"]).unwrap();
+        set_max_supply(&mut deps, None, Some(3)).unwrap();
+        assert_eq!(
+            remaining_supply(&deps, None, None).unwrap(),
+            (Some(3), 1, Some(2))
+        );
+        let error = extract_error_msg(batch_mint(&mut deps, &["NFT2", "NFT3", "NFT4"]));
+        assert!(error.contains("Minting 3 tokens exceeds the remaining supply of 2"));
+        // the failed batch did not count toward the supply
+        batch_mint(&mut deps, &["NFT2"]).unwrap();
+
+        // burned tokens still count toward the max supply
+        let execute_msg = ExecuteMsg::BurnNft {
+            token_id: "NFT2".to_string(),
+            memo: None,
+            padding: None,
+        };
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("admin", &[]),
+            execute_msg,
+        )
+        .unwrap();
+        assert_eq!(
+            remaining_supply(&deps, None, None).unwrap(),
+            (Some(3), 2, Some(1))
+        );
+        let error = extract_error_msg(mint_clones(&mut deps, "run", 2));
+        assert!(error.contains("Minting 2 tokens exceeds the remaining supply of 1"));
+        let execute_msg = ExecuteMsg::MintNft {
+            token_id: Some("NFT3".to_string()),
+            owner: None,
+            public_metadata: None,
+            private_metadata: None,
+            serial_number: None,
+            royalty_info: None,
+            transferable: None,
+            memo: None,
+            padding: None,
+        };
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("admin", &[]),
+            execute_msg,
+        )
+        .unwrap();
+        let error = extract_error_msg(batch_mint(&mut deps, &["NFT4"]));
+        assert!(error.contains("Minting 1 tokens exceeds the remaining supply of 0"));
+
+        // test lowering the max supply below what has been minted
+        let error = extract_error_msg(set_max_supply(&mut deps, None, Some(2)));
+        assert!(error.contains("The contract has already minted 3 tokens"));
+
+        // removing the max supply lifts the limit
+        set_max_supply(&mut deps, None, None).unwrap();
+        batch_mint(&mut deps, &["NFT4"]).unwrap();
+        assert_eq!(
+            remaining_supply(&deps, None, None).unwrap(),
+            (None, 4, None)
+        );
+    }
+
+    #[test]
+    fn test_mint_run_cap() {
+        let (init_result, mut deps) = init_helper_with_config(true);
+        assert!(
+            init_result.is_ok(),
+            "Init failed: {}",
+            init_result.err().unwrap()
+        );
+
+        // mints are counted even before a cap is set
+        mint_clones(&mut deps, "run", 2).unwrap();
+        assert_eq!(
+            remaining_supply(&deps, Some("run"), None).unwrap(),
+            (None, 2, None)
+        );
+        let error = extract_error_msg(set_max_supply(&mut deps, Some("run"), Some(1)));
+        assert!(error.contains("Mint run ID run has already minted 2 tokens"));
+        set_max_supply(&mut deps, Some("run"), Some(5)).unwrap();
+
+        // the cap spans every run with the same mint run id
+        let error = extract_error_msg(mint_clones(&mut deps, "run", 4));
+        assert!(error
+            .contains("Minting 4 tokens exceeds the remaining supply of 3 for mint run ID run"));
+        mint_clones(&mut deps, "run", 3).unwrap();
+        assert_eq!(
+            remaining_supply(&deps, Some("run"), None).unwrap(),
+            (Some(5), 5, Some(0))
+        );
+
+        // other mint run ids and the collection are not limited by it
+        mint_clones(&mut deps, "other", 4).unwrap();
+        batch_mint(&mut deps, &["NFT1"]).unwrap();
+        assert_eq!(
+            remaining_supply(&deps, Some("other"), None).unwrap(),
+            (None, 4, None)
+        );
+        assert_eq!(
+            remaining_supply(&deps, None, None).unwrap(),
+            (None, 10, None)
+        );
+
+        // a collection max supply also applies to mint runs
+        set_max_supply(&mut deps, None, Some(11)).unwrap();
+        let error = extract_error_msg(mint_clones(&mut deps, "other", 2));
+        assert!(error.contains("Minting 2 tokens exceeds the remaining supply of 1"));
+        // the failed mint was not counted for the mint run id
+        assert_eq!(
+            remaining_supply(&deps, Some("other"), None).unwrap(),
+            (None, 4, None)
+        );
+    }
+
+    #[test]
+    fn test_query_remaining_supply_private() {
+        let (init_result, mut deps) = init_helper_with_config(false);
+        assert!(
+            init_result.is_ok(),
+            "Init failed: {}",
+            init_result.err().unwrap()
+        );
+        set_max_supply(&mut deps, None, Some(10)).unwrap();
+        for (address, key) in [("admin", "akey"), ("alice", "key")] {
+            let execute_msg = ExecuteMsg::SetViewingKey {
+                key: key.to_string(),
+                padding: None,
+            };
+            execute(
+                deps.as_mut(),
+                mock_env(),
+                mock_info(address, &[]),
+                execute_msg,
+            )
+            .unwrap();
+        }
+
+        // test no viewer
+        let error = extract_error_msg(remaining_supply(&deps, None, None));
+        assert!(error.contains("The token supply of this contract is private"));
+
+        // test non minter
+        let viewer = ViewerInfo {
+            address: "alice".to_string(),
+            viewing_key: "key".to_string(),
+        };
+        let error = extract_error_msg(remaining_supply(&deps, None, Some(viewer)));
+        assert!(error.contains("The token supply of this contract is private"));
+
+        // test minter
+        let viewer = ViewerInfo {
+            address: "admin".to_string(),
+            viewing_key: "akey".to_string(),
+        };
+        assert_eq!(
+            remaining_supply(&deps, None, Some(viewer)).unwrap(),
+            (Some(10), 0, Some(10))
+        );
+    }
+}
@@ -0,0 +1,323 @@
+#[cfg(test)]
+mod tests {
+    use std::any::Any;
+
+    use cosmwasm_std::testing::*;
+    use cosmwasm_std::{Api, Response, StdError, StdResult};
+    use cosmwasm_storage::{PrefixedStorage, ReadonlyPrefixedStorage};
+
+    use crate::contract::{execute, instantiate, migrate};
+    use crate::inventory::Inventory;
+    use crate::migrate::CONTRACT_VERSION;
+    use crate::msg::{ContractStatus, ExecuteMsg, InstantiateMsg, MigrateMsg, Role};
+    use crate::state::{
+        get_txs, json_load, json_save, load, load_role, may_load, remove, save, save_role,
+        store_mint, Config, StoredTx, StoredTxAction, CONFIG_KEY, CONTRACT_VERSION_KEY,
+        MINTERS_KEY, PREFIX_INFOS, PREFIX_MAP_TO_ID, PREFIX_MAP_TO_INDEX, PREFIX_TXS,
+    };
+    use crate::token::Token;
+
+    fn extract_error_msg<T: Any>(error: StdResult<T>) -> String {
+        match error {
+            Ok(_response) => panic!("Expected error, but had Ok response"),
+            Err(err) => match err {
+                StdError::GenericErr { msg, .. } => msg,
+                _ => panic!("Unexpected error result {:?}", err),
+            },
+        }
+    }
+
+    fn attr_value(resp: &Response, key: &str) -> String {
+        resp.attributes
+            .iter()
+            .find(|a| a.key == key)
+            .map(|a| a.value.clone())
+            .unwrap()
+    }
+
+    #[test]
+    fn test_instantiate_records_version() {
+        let mut deps = mock_dependencies();
+        let init_msg = InstantiateMsg {
+            name: "sec721".to_string(),
+            symbol: "S721".to_string(),
+            admin: Some("admin".to_string()),
+            entropy: "We're going to need a bigger boat".to_string(),
+            royalty_info: None,
+            config: None,
+            post_init_callback: None,
+        };
+        instantiate(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("instantiator", &[]),
+            init_msg,
+        )
+        .unwrap();
+        let version: Option<u32> = may_load(&deps.storage, CONTRACT_VERSION_KEY).unwrap();
+        assert_eq!(version, Some(CONTRACT_VERSION));
+
+        // migrating an up to date contract is a no-op
+        let resp = migrate(deps.as_mut(), mock_env(), MigrateMsg {}).unwrap();
+        assert_eq!(
+            attr_value(&resp, "migrated_from"),
+            CONTRACT_VERSION.to_string()
+        );
+        assert_eq!(
+            attr_value(&resp, "migrated_to"),
+            CONTRACT_VERSION.to_string()
+        );
+    }
+
+    #[test]
+    fn test_migrate_from_unversioned_storage() {
+        let mut deps = mock_dependencies();
+        let admin_raw = deps.api.addr_canonicalize("admin").unwrap();
+        let alice_raw = deps.api.addr_canonicalize("alice").unwrap();
+        let block = mock_env().block;
+
+        // write storage the way an unversioned contract laid it out, which is the v1 layout
+        let mut old_config = Config {
+            name: "sec721".to_string(),
+            symbol: "S721".to_string(),
+            admin: admin_raw.clone(),
+            mint_cnt: 1,
+            tx_cnt: 0,
+            token_cnt: 1,
+            status: ContractStatus::Normal.to_u8(),
+            token_supply_is_public: true,
+            owner_is_public: false,
+            sealed_metadata_is_enabled: false,
+            unwrap_to_private: false,
+            minter_may_update_metadata: true,
+            owner_may_update_metadata: false,
+            burn_is_enabled: true,
+        };
+        let token = Token {
+            owner: alice_raw.clone(),
+            permissions: Vec::new(),
+            unwrapped: true,
+            transferable: true,
+        };
+        let mut info_store = PrefixedStorage::new(&mut deps.storage, PREFIX_INFOS);
+        json_save(&mut info_store, &0u32.to_le_bytes(), &token).unwrap();
+        let mut map2idx = PrefixedStorage::new(&mut deps.storage, PREFIX_MAP_TO_INDEX);
+        save(&mut map2idx, "0".as_bytes(), &0u32).unwrap();
+        let mut map2id = PrefixedStorage::new(&mut deps.storage, PREFIX_MAP_TO_ID);
+        save(&mut map2id, &0u32.to_le_bytes(), &"0".to_string()).unwrap();
+        let mut inventory = Inventory::new(&deps.storage, alice_raw.clone()).unwrap();
+        inventory.insert(&mut deps.storage, 0, true).unwrap();
+        let mut expected = Config {
+            name: old_config.name.clone(),
+            symbol: old_config.symbol.clone(),
+            admin: admin_raw.clone(),
+            mint_cnt: 1,
+            tx_cnt: 0,
+            token_cnt: 1,
+            status: old_config.status,
+            token_supply_is_public: true,
+            owner_is_public: false,
+            sealed_metadata_is_enabled: false,
+            unwrap_to_private: false,
+            minter_may_update_metadata: true,
+            owner_may_update_metadata: false,
+            burn_is_enabled: true,
+        };
+        store_mint(
+            &mut deps.storage,
+            &mut expected,
+            &block,
+            "0".to_string(),
+            admin_raw.clone(),
+            alice_raw.clone(),
+            Some("first".to_string()),
+        )
+        .unwrap();
+        old_config.tx_cnt = expected.tx_cnt;
+        save(&mut deps.storage, CONFIG_KEY, &old_config).unwrap();
+        remove(&mut deps.storage, CONTRACT_VERSION_KEY);
+
+        let resp = migrate(deps.as_mut(), mock_env(), MigrateMsg {}).unwrap();
+        assert_eq!(attr_value(&resp, "migrated_from"), "1");
+        assert_eq!(
+            attr_value(&resp, "migrated_to"),
+            CONTRACT_VERSION.to_string()
+        );
+        let version: u32 = load(&deps.storage, CONTRACT_VERSION_KEY).unwrap();
+        assert_eq!(version, CONTRACT_VERSION);
+
+        // the unversioned layouts load with the current types
+        let config: Config = load(&deps.storage, CONFIG_KEY).unwrap();
+        assert_eq!(config, expected);
+        let info_store = ReadonlyPrefixedStorage::new(&deps.storage, PREFIX_INFOS);
+        let loaded: Token = json_load(&info_store, &0u32.to_le_bytes()).unwrap();
+        assert_eq!(loaded.owner, alice_raw);
+        assert!(loaded.unwrapped);
+        let tx_store = ReadonlyPrefixedStorage::new(&deps.storage, PREFIX_TXS);
+        let tx: StoredTx = json_load(&tx_store, &0u64.to_le_bytes()).unwrap();
+        assert!(matches!(tx.action, StoredTxAction::Mint { .. }));
+        let (txs, total) = get_txs(&deps.api, &deps.storage, &alice_raw, 0, 10).unwrap();
+        assert_eq!(total, 1);
+        assert_eq!(txs[0].memo, Some("first".to_string()));
+        assert!(Inventory::owns(&deps.storage, &alice_raw, 0).unwrap());
+        assert_eq!(Inventory::new(&deps.storage, alice_raw).unwrap().cnt, 1);
+
+        // the migrated contract keeps working
+        let execute_msg = ExecuteMsg::TransferNft {
+            recipient: "bob".to_string(),
+            token_id: "0".to_string(),
+            memo: None,
+            padding: None,
+        };
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("alice", &[]),
+            execute_msg,
+        )
+        .unwrap();
+        let bob_raw = deps.api.addr_canonicalize("bob").unwrap();
+        assert!(Inventory::owns(&deps.storage, &bob_raw, 0).unwrap());
+        let config: Config = load(&deps.storage, CONFIG_KEY).unwrap();
+        assert_eq!(config.tx_cnt, 2);
+    }
+
+    #[test]
+    fn test_migrate_v1_grants_minters_creator_roles() {
+        let mut deps = mock_dependencies();
+        let admin_raw = deps.api.addr_canonicalize("admin").unwrap();
+        let alice_raw = deps.api.addr_canonicalize("alice").unwrap();
+        // v1 storage only knew about minters
+        save(
+            &mut deps.storage,
+            MINTERS_KEY,
+            &vec![admin_raw.clone(), alice_raw.clone()],
+        )
+        .unwrap();
+        save(&mut deps.storage, CONTRACT_VERSION_KEY, &1u32).unwrap();
+
+        let resp = migrate(deps.as_mut(), mock_env(), MigrateMsg {}).unwrap();
+        assert_eq!(attr_value(&resp, "migrated_from"), "1");
+        let expected = vec![admin_raw, alice_raw];
+        assert_eq!(load_role(&deps.storage, Role::Minter).unwrap(), expected);
+        assert_eq!(
+            load_role(&deps.storage, Role::MetadataEditor).unwrap(),
+            expected
+        );
+        assert_eq!(
+            load_role(&deps.storage, Role::RoyaltyManager).unwrap(),
+            expected
+        );
+        assert!(load_role(&deps.storage, Role::Burner).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_removing_migrated_minters_revokes_their_creator_roles() {
+        let mut deps = mock_dependencies();
+        let init_msg = InstantiateMsg {
+            name: "sec721".to_string(),
+            symbol: "S721".to_string(),
+            admin: Some("admin".to_string()),
+            entropy: "We're going to need a bigger boat".to_string(),
+            royalty_info: None,
+            config: None,
+            post_init_callback: None,
+        };
+        instantiate(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("instantiator", &[]),
+            init_msg,
+        )
+        .unwrap();
+        let admin_raw = deps.api.addr_canonicalize("admin").unwrap();
+        let alice_raw = deps.api.addr_canonicalize("alice").unwrap();
+        let bob_raw = deps.api.addr_canonicalize("bob").unwrap();
+        // rewind to v1 storage, which only knew about minters
+        save(
+            &mut deps.storage,
+            MINTERS_KEY,
+            &vec![admin_raw.clone(), alice_raw.clone(), bob_raw.clone()],
+        )
+        .unwrap();
+        save_role(&mut deps.storage, Role::MetadataEditor, &[]).unwrap();
+        save_role(&mut deps.storage, Role::RoyaltyManager, &[]).unwrap();
+        save(&mut deps.storage, CONTRACT_VERSION_KEY, &1u32).unwrap();
+        migrate(deps.as_mut(), mock_env(), MigrateMsg {}).unwrap();
+
+        // bob is explicitly made a metadata editor after the migration
+        let execute_msg = ExecuteMsg::GrantRole {
+            role: Role::MetadataEditor,
+            addresses: vec!["bob".to_string()],
+            padding: None,
+        };
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("admin", &[]),
+            execute_msg,
+        )
+        .unwrap();
+
+        // removing alice as a minter removes the powers the migration gave alice
+        let execute_msg = ExecuteMsg::RemoveMinters {
+            minters: vec!["alice".to_string()],
+            padding: None,
+        };
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("admin", &[]),
+            execute_msg,
+        )
+        .unwrap();
+        assert_eq!(
+            load_role(&deps.storage, Role::MetadataEditor).unwrap(),
+            vec![admin_raw.clone(), bob_raw.clone()]
+        );
+        assert_eq!(
+            load_role(&deps.storage, Role::RoyaltyManager).unwrap(),
+            vec![admin_raw.clone(), bob_raw.clone()]
+        );
+
+        // dropping bob from the minters keeps the role explicitly granted to bob
+        let execute_msg = ExecuteMsg::SetMinters {
+            minters: vec!["admin".to_string()],
+            padding: None,
+        };
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("admin", &[]),
+            execute_msg,
+        )
+        .unwrap();
+        assert_eq!(
+            load_role(&deps.storage, Role::Minter).unwrap(),
+            vec![admin_raw.clone()]
+        );
+        assert_eq!(
+            load_role(&deps.storage, Role::MetadataEditor).unwrap(),
+            vec![admin_raw.clone(), bob_raw]
+        );
+        assert_eq!(
+            load_role(&deps.storage, Role::RoyaltyManager).unwrap(),
+            vec![admin_raw]
+        );
+    }
+
+    #[test]
+    fn test_migrate_rejects_newer_storage() {
+        let mut deps = mock_dependencies();
+        save(
+            &mut deps.storage,
+            CONTRACT_VERSION_KEY,
+            &(CONTRACT_VERSION + 1),
+        )
+        .unwrap();
+        let error = extract_error_msg(migrate(deps.as_mut(), mock_env(), MigrateMsg {}));
+        assert!(error.contains("Can not migrate from storage version"));
+        let version: u32 = load(&deps.storage, CONTRACT_VERSION_KEY).unwrap();
+        assert_eq!(version, CONTRACT_VERSION + 1);
+    }
+}
@@ -0,0 +1,472 @@
+#[cfg(test)]
+mod tests {
+    use std::any::Any;
+
+    use cosmwasm_std::testing::*;
+    use cosmwasm_std::{
+        coin, coins, from_binary, BankMsg, Binary, CosmosMsg, OwnedDeps, Response, StdError,
+        StdResult,
+    };
+
+    use crate::contract::{execute, instantiate, query};
+    use crate::mint_phase::{leaf_hash, pair_hash, MintPhase};
+    use crate::msg::{
+        ExecuteAnswer, ExecuteMsg, InstantiateConfig, InstantiateMsg, QueryAnswer, QueryMsg,
+    };
+    use crate::sale::{Sale, SaleMetadata};
+    use crate::token::Metadata;
+
+    // Helper functions
+
+    fn init_helper_default() -> (
+        StdResult<Response>,
+        OwnedDeps<MockStorage, MockApi, MockQuerier>,
+    ) {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+        let info = mock_info("instantiator", &[]);
+        let init_msg = InstantiateMsg {
+            name: "sec721".to_string(),
+            symbol: "S721".to_string(),
+            admin: Some("admin".to_string()),
+            entropy: "We're going to need a bigger boat".to_string(),
+            royalty_info: None,
+            config: Some(InstantiateConfig {
+                public_owner: Some(true),
+                ..InstantiateConfig::default()
+            }),
+            post_init_callback: None,
+        };
+
+        (instantiate(deps.as_mut(), env, info, init_msg), deps)
+    }
+
+    fn extract_error_msg<T: Any>(error: StdResult<T>) -> String {
+        match error {
+            Ok(_response) => panic!("Expected error, but had Ok response"),
+            Err(err) => match err {
+                StdError::GenericErr { msg, .. } => msg,
+                _ => panic!("Unexpected error result {:?}", err),
+            },
+        }
+    }
+
+    // builds the tree of alice:2, bob:1, carol:3, dave:1 and returns the root and the proofs
+    // of alice and carol
+    fn allowlist() -> (Binary, Vec<Binary>, Vec<Binary>) {
+        let alice = leaf_hash("alice", 2);
+        let bob = leaf_hash("bob", 1);
+        let carol = leaf_hash("carol", 3);
+        let dave = leaf_hash("dave", 1);
+        let left = pair_hash(&alice, &bob);
+        let right = pair_hash(&carol, &dave);
+        let root = pair_hash(&left, &right);
+        (
+            Binary::from(root.to_vec()),
+            vec![Binary::from(bob.to_vec()), Binary::from(right.to_vec())],
+            vec![Binary::from(dave.to_vec()), Binary::from(left.to_vec())],
+        )
+    }
+
+    fn phase(name: &str, merkle_root: &Binary) -> MintPhase {
+        MintPhase {
+            name: name.to_string(),
+            merkle_root: merkle_root.clone(),
+            start: None,
+            end: None,
+            price: None,
+        }
+    }
+
+    fn set_up_sale(deps: &mut OwnedDeps<MockStorage, MockApi, MockQuerier>) {
+        let execute_msg = ExecuteMsg::SetSale {
+            sale: Some(Sale {
+                price: Some(coin(100, "uscrt")),
+                start: None,
+                end: None,
+                wallet_limit: Some(1),
+                metadata: SaleMetadata::Template {
+                    public_metadata: Some(Metadata {
+                        token_uri: Some("uri".to_string()),
+                        extension: None,
+                    }),
+                    private_metadata: None,
+                },
+            }),
+            padding: None,
+        };
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("admin", &[]),
+            execute_msg,
+        )
+        .unwrap();
+    }
+
+    fn set_phases(
+        deps: &mut OwnedDeps<MockStorage, MockApi, MockQuerier>,
+        sender: &str,
+        phases: Vec<MintPhase>,
+    ) -> StdResult<Response> {
+        let execute_msg = ExecuteMsg::SetMintPhases {
+            phases,
+            padding: None,
+        };
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(sender, &[]),
+            execute_msg,
+        )
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn allowlist_mint(
+        deps: &mut OwnedDeps<MockStorage, MockApi, MockQuerier>,
+        minter: &str,
+        phase: &str,
+        allocation: u32,
+        proof: &[Binary],
+        quantity: Option<u32>,
+        funds: &[cosmwasm_std::Coin],
+        time: Option<u64>,
+    ) -> StdResult<Response> {
+        let execute_msg = ExecuteMsg::AllowlistMint {
+            phase: phase.to_string(),
+            allocation,
+            proof: proof.to_vec(),
+            quantity,
+            memo: None,
+            padding: None,
+        };
+        let mut env = mock_env();
+        if let Some(time) = time {
+            env.block.time = cosmwasm_std::Timestamp::from_seconds(time);
+        }
+        execute(deps.as_mut(), env, mock_info(minter, funds), execute_msg)
+    }
+
+    fn minted_ids(response: &Response) -> Vec<String> {
+        let answer: ExecuteAnswer = from_binary(response.data.as_ref().unwrap()).unwrap();
+        match answer {
+            ExecuteAnswer::AllowlistMint { token_ids } => token_ids,
+            _ => panic!("unexpected"),
+        }
+    }
+
+    fn phase_usage(
+        deps: &OwnedDeps<MockStorage, MockApi, MockQuerier>,
+        phase: &str,
+        address: &str,
+        viewing_key: &str,
+    ) -> StdResult<u32> {
+        let query_msg = QueryMsg::PhaseUsage {
+            phase: phase.to_string(),
+            address: address.to_string(),
+            viewing_key: viewing_key.to_string(),
+        };
+        let query_result = query(deps.as_ref(), mock_env(), query_msg)?;
+        match from_binary(&query_result)? {
+            QueryAnswer::PhaseUsage { minted } => Ok(minted),
+            _ => panic!("unexpected"),
+        }
+    }
+
+    #[test]
+    fn test_set_mint_phases() {
+        let (init_result, mut deps) = init_helper_default();
+        assert!(
+            init_result.is_ok(),
+            "Init failed: {}",
+            init_result.err().unwrap()
+        );
+        let (root, _, _) = allowlist();
+
+        // test non admin
+        let error = extract_error_msg(set_phases(&mut deps, "alice", vec![phase("og", &root)]));
+        assert!(
+            error.contains("This is an admin command and can only be run from the admin address")
+        );
+
+        // test duplicate names
+        let error = extract_error_msg(set_phases(
+            &mut deps,
+            "admin",
+            vec![phase("og", &root), phase("og", &root)],
+        ));
+        assert!(error.contains("Mint phase name og is used more than once"));
+
+        // test a root that is not a hash
+        let error = extract_error_msg(set_phases(
+            &mut deps,
+            "admin",
+            vec![phase("og", &Binary::from(b"short".to_vec()))],
+        ));
+        assert!(error.contains("The Merkle root of mint phase og must be a 32 byte sha256 hash"));
+
+        // test a phase that ends before it starts
+        let mut bad = phase("og", &root);
+        bad.start = Some(100);
+        bad.end = Some(100);
+        let error = extract_error_msg(set_phases(&mut deps, "admin", vec![bad]));
+        assert!(error.contains("Mint phase og must end after it starts"));
+
+        let phases = vec![phase("og", &root), phase("wl", &root)];
+        set_phases(&mut deps, "admin", phases.clone()).unwrap();
+        let query_result = query(deps.as_ref(), mock_env(), QueryMsg::MintPhases {}).unwrap();
+        match from_binary(&query_result).unwrap() {
+            QueryAnswer::MintPhases { phases: queried } => assert_eq!(queried, phases),
+            _ => panic!("unexpected"),
+        }
+    }
+
+    #[test]
+    fn test_allowlist_mint() {
+        let (init_result, mut deps) = init_helper_default();
+        assert!(
+            init_result.is_ok(),
+            "Init failed: {}",
+            init_result.err().unwrap()
+        );
+        let (root, alice_proof, carol_proof) = allowlist();
+        set_phases(&mut deps, "admin", vec![phase("og", &root)]).unwrap();
+
+        // test minting without a sale
+        let error = extract_error_msg(allowlist_mint(
+            &mut deps,
+            "alice",
+            "og",
+            2,
+            &alice_proof,
+            None,
+            &[],
+            None,
+        ));
+        assert!(error.contains("There is no public sale to mint from"));
+        set_up_sale(&mut deps);
+
+        // test an unknown phase
+        let error = extract_error_msg(allowlist_mint(
+            &mut deps,
+            "alice",
+            "wl",
+            2,
+            &alice_proof,
+            None,
+            &[],
+            None,
+        ));
+        assert!(error.contains("There is no mint phase named wl"));
+
+        // test claiming a larger allocation
+        let error = extract_error_msg(allowlist_mint(
+            &mut deps,
+            "alice",
+            "og",
+            3,
+            &alice_proof,
+            None,
+            &[],
+            None,
+        ));
+        assert!(error.contains("The proof does not show an allocation of 3 in mint phase og"));
+
+        // test using someone else's proof
+        let error = extract_error_msg(allowlist_mint(
+            &mut deps,
+            "bob",
+            "og",
+            2,
+            &alice_proof,
+            None,
+            &[],
+            None,
+        ));
+        assert!(error.contains("The proof does not show an allocation of 2 in mint phase og"));
+
+        // test sending funds to a free phase
+        let error = extract_error_msg(allowlist_mint(
+            &mut deps,
+            "alice",
+            "og",
+            2,
+            &alice_proof,
+            None,
+            &[coin(100, "uscrt")],
+            None,
+        ));
+        assert!(error.contains("Mint phase og is free, so no funds should be sent"));
+
+        // free phase mints ignore the sale's price and wallet limit
+        let response = allowlist_mint(
+            &mut deps,
+            "alice",
+            "og",
+            2,
+            &alice_proof,
+            Some(2),
+            &[],
+            None,
+        )
+        .unwrap();
+        assert_eq!(minted_ids(&response), vec!["0", "1"]);
+        assert!(response.messages.is_empty());
+
+        // test exceeding the allocation
+        let error = extract_error_msg(allowlist_mint(
+            &mut deps,
+            "alice",
+            "og",
+            2,
+            &alice_proof,
+            None,
+            &[],
+            None,
+        ));
+        assert!(
+            error.contains("Your allocation in mint phase og is 2, and you have already minted 2")
+        );
+
+        // allocations are used up across mints
+        allowlist_mint(&mut deps, "carol", "og", 3, &carol_proof, None, &[], None).unwrap();
+        let error = extract_error_msg(allowlist_mint(
+            &mut deps,
+            "carol",
+            "og",
+            3,
+            &carol_proof,
+            Some(3),
+            &[],
+            None,
+        ));
+        assert!(
+            error.contains("Your allocation in mint phase og is 3, and you have already minted 1")
+        );
+        allowlist_mint(
+            &mut deps,
+            "carol",
+            "og",
+            3,
+            &carol_proof,
+            Some(2),
+            &[],
+            None,
+        )
+        .unwrap();
+
+        // check the minted tokens' owner and metadata
+        let query_msg = QueryMsg::NftDossier {
+            token_id: "2".to_string(),
+            viewer: None,
+            include_expired: None,
+        };
+        let query_result = query(deps.as_ref(), mock_env(), query_msg).unwrap();
+        match from_binary(&query_result).unwrap() {
+            QueryAnswer::NftDossier {
+                owner,
+                public_metadata,
+                ..
+            } => {
+                assert_eq!(owner, Some(cosmwasm_std::Addr::unchecked("carol")));
+                assert_eq!(public_metadata.unwrap().token_uri, Some("uri".to_string()));
+            }
+            _ => panic!("unexpected"),
+        }
+    }
+
+    #[test]
+    fn test_paid_phase_window() {
+        let (init_result, mut deps) = init_helper_default();
+        assert!(
+            init_result.is_ok(),
+            "Init failed: {}",
+            init_result.err().unwrap()
+        );
+        set_up_sale(&mut deps);
+        let (root, alice_proof, _) = allowlist();
+        let mut paid = phase("presale", &root);
+        paid.start = Some(1000);
+        paid.end = Some(2000);
+        paid.price = Some(coin(50, "uscrt"));
+        set_phases(&mut deps, "admin", vec![paid]).unwrap();
+
+        let error = extract_error_msg(allowlist_mint(
+            &mut deps,
+            "alice",
+            "presale",
+            2,
+            &alice_proof,
+            None,
+            &coins(50, "uscrt"),
+            Some(999),
+        ));
+        assert!(error.contains("Mint phase presale has not started yet"));
+        let error = extract_error_msg(allowlist_mint(
+            &mut deps,
+            "alice",
+            "presale",
+            2,
+            &alice_proof,
+            None,
+            &coins(50, "uscrt"),
+            Some(2000),
+        ));
+        assert!(error.contains("Mint phase presale has ended"));
+
+        // test paying too little
+        let error = extract_error_msg(allowlist_mint(
+            &mut deps,
+            "alice",
+            "presale",
+            2,
+            &alice_proof,
+            Some(2),
+            &coins(99, "uscrt"),
+            Some(1500),
+        ));
+        assert!(error.contains("Insufficient payment.  2 tokens cost 100uscrt"));
+
+        // overpayment is refunded
+        let response = allowlist_mint(
+            &mut deps,
+            "alice",
+            "presale",
+            2,
+            &alice_proof,
+            Some(2),
+            &coins(130, "uscrt"),
+            Some(1500),
+        )
+        .unwrap();
+        assert_eq!(minted_ids(&response).len(), 2);
+        let refunds: Vec<_> = response
+            .messages
+            .iter()
+            .map(|m| match &m.msg {
+                CosmosMsg::Bank(BankMsg::Send { to_address, amount }) => {
+                    (to_address.clone(), amount.clone())
+                }
+                _ => panic!("unexpected"),
+            })
+            .collect();
+        assert_eq!(refunds, vec![("alice".to_string(), coins(30, "uscrt"))]);
+
+        // test the usage query
+        let execute_msg = ExecuteMsg::SetViewingKey {
+            key: "key".to_string(),
+            padding: None,
+        };
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("alice", &[]),
+            execute_msg,
+        )
+        .unwrap();
+        let error = extract_error_msg(phase_usage(&deps, "presale", "alice", "wrong"));
+        assert!(error.contains("Wrong viewing key"));
+        assert_eq!(phase_usage(&deps, "presale", "alice", "key").unwrap(), 2);
+        assert_eq!(phase_usage(&deps, "og", "alice", "key").unwrap(), 0);
+    }
+}
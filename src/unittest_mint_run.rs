@@ -6,8 +6,11 @@ mod tests {
     use cosmwasm_std::{from_binary, Addr, OwnedDeps, Response, StdError, StdResult};
 
     use crate::contract::{execute, instantiate, query};
-    use crate::mint_run::MintRunInfo;
-    use crate::msg::{ExecuteMsg, InstantiateMsg, QueryAnswer, QueryMsg};
+    use crate::mint_run::{MintRunInfo, SerialNumber, SerialOverride};
+    use crate::msg::{
+        ExecuteMsg, InstantiateConfig, InstantiateMsg, Mint, QueryAnswer, QueryMsg, ViewerInfo,
+    };
+    use crate::token::{Extension, Metadata, Trait};
 
     // Helper functions
 
@@ -24,7 +27,10 @@ mod tests {
             admin: Some("admin".to_string()),
             entropy: "We're going to need a bigger boat".to_string(),
             royalty_info: None,
-            config: None,
+            config: Some(InstantiateConfig {
+                enable_burn: Some(true),
+                ..InstantiateConfig::default()
+            }),
             post_init_callback: None,
         };
 
@@ -58,6 +64,7 @@ mod tests {
             owner: None,
             public_metadata: None,
             private_metadata: None,
+            serial_overrides: None,
             royalty_info: None,
             memo: None,
             padding: None,
@@ -78,6 +85,7 @@ mod tests {
             owner: None,
             public_metadata: None,
             private_metadata: None,
+            serial_overrides: None,
             royalty_info: None,
             memo: None,
             padding: None,
@@ -98,6 +106,7 @@ mod tests {
             owner: None,
             public_metadata: None,
             private_metadata: None,
+            serial_overrides: None,
             royalty_info: None,
             memo: None,
             padding: None,
@@ -182,6 +191,7 @@ mod tests {
             owner: None,
             public_metadata: None,
             private_metadata: None,
+            serial_overrides: None,
             royalty_info: None,
             memo: None,
             padding: None,
@@ -223,6 +233,7 @@ mod tests {
             owner: None,
             public_metadata: None,
             private_metadata: None,
+            serial_overrides: None,
             royalty_info: None,
             memo: None,
             padding: None,
@@ -278,4 +289,353 @@ mod tests {
             _ => panic!("unexpected"),
         }
     }
+
+    // test filling in the metadata templates of clones
+    #[test]
+    fn test_clone_templates() {
+        let (init_result, mut deps) = init_helper();
+        assert!(
+            init_result.is_ok(),
+            "Init failed: {}",
+            init_result.err().unwrap()
+        );
+        let template = |name: &str, value: &str| Metadata {
+            token_uri: None,
+            extension: Some(Extension {
+                name: Some(name.to_string()),
+                description: Some("Token {token_id} of run {mint_run}".to_string()),
+                attributes: Some(vec![Trait {
+                    display_type: None,
+                    trait_type: Some("Edition".to_string()),
+                    value: value.to_string(),
+                    max_value: None,
+                }]),
+                ..Extension::default()
+            }),
+        };
+        let mint_msg = |serial_overrides: Option<Vec<SerialOverride>>| ExecuteMsg::MintNftClones {
+            mint_run_id: Some("prints".to_string()),
+            quantity: 3,
+            owner: None,
+            public_metadata: Some(template(
+                "Print #{serial} of {quantity}",
+                "{serial}/{quantity}",
+            )),
+            private_metadata: None,
+            serial_overrides,
+            royalty_info: None,
+            memo: None,
+            padding: None,
+        };
+
+        // test an override outside of the run
+        let overrides = vec![SerialOverride {
+            serial_number: 4,
+            public_metadata: None,
+            private_metadata: None,
+        }];
+        let handle_result = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("admin", &[]),
+            mint_msg(Some(overrides)),
+        );
+        let error = extract_error_msg(handle_result);
+        assert!(error.contains("Serial number 4 is not one of the 3 clones being minted"));
+
+        // test overriding a serial number twice
+        let ovr = SerialOverride {
+            serial_number: 2,
+            public_metadata: Some(template("Artist Proof", "AP")),
+            private_metadata: None,
+        };
+        let handle_result = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("admin", &[]),
+            mint_msg(Some(vec![ovr.clone(), ovr.clone()])),
+        );
+        let error = extract_error_msg(handle_result);
+        assert!(error.contains("Serial number 2 is overridden more than once"));
+
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("admin", &[]),
+            mint_msg(Some(vec![ovr])),
+        )
+        .unwrap();
+        let expected = [
+            ("0", "Print #1 of 3", "1/3"),
+            ("1", "Artist Proof", "AP"),
+            ("2", "Print #3 of 3", "3/3"),
+        ];
+        for (token_id, name, value) in expected {
+            let query_msg = QueryMsg::NftInfo {
+                token_id: token_id.to_string(),
+            };
+            let query_result = query(deps.as_ref(), mock_env(), query_msg).unwrap();
+            match from_binary(&query_result).unwrap() {
+                QueryAnswer::NftInfo { extension, .. } => {
+                    let ext = extension.unwrap();
+                    assert_eq!(ext.name, Some(name.to_string()));
+                    assert_eq!(
+                        ext.description,
+                        Some(format!("Token {} of run 1", token_id))
+                    );
+                    assert_eq!(ext.attributes.unwrap()[0].value, value);
+                }
+                _ => panic!("unexpected"),
+            }
+        }
+    }
+
+    // test the mint run registry queries
+    #[test]
+    fn test_mint_run_registry() {
+        let (init_result, mut deps) = init_helper();
+        assert!(
+            init_result.is_ok(),
+            "Init failed: {}",
+            init_result.err().unwrap()
+        );
+        for quantity in [3, 2] {
+            let execute_msg = ExecuteMsg::MintNftClones {
+                mint_run_id: Some("series".to_string()),
+                quantity,
+                owner: Some("alice".to_string()),
+                public_metadata: None,
+                private_metadata: None,
+                serial_overrides: None,
+                royalty_info: None,
+                memo: None,
+                padding: None,
+            };
+            execute(
+                deps.as_mut(),
+                mock_env(),
+                mock_info("admin", &[]),
+                execute_msg,
+            )
+            .unwrap();
+        }
+        let execute_msg = ExecuteMsg::BurnNft {
+            token_id: "4".to_string(),
+            memo: None,
+            padding: None,
+        };
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("alice", &[]),
+            execute_msg,
+        )
+        .unwrap();
+
+        // test private supply
+        let query_msg = QueryMsg::MintRuns {
+            mint_run_id: "series".to_string(),
+            viewer: None,
+            start_after: None,
+            limit: None,
+        };
+        let error = extract_error_msg(query(deps.as_ref(), mock_env(), query_msg));
+        assert!(error.contains("The token supply of this contract is private"));
+
+        let execute_msg = ExecuteMsg::SetViewingKey {
+            key: "key".to_string(),
+            padding: None,
+        };
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("admin", &[]),
+            execute_msg,
+        )
+        .unwrap();
+        let viewer = ViewerInfo {
+            address: "admin".to_string(),
+            viewing_key: "key".to_string(),
+        };
+        let query_msg = QueryMsg::MintRuns {
+            mint_run_id: "series".to_string(),
+            viewer: Some(viewer.clone()),
+            start_after: None,
+            limit: None,
+        };
+        let query_result = query(deps.as_ref(), mock_env(), query_msg).unwrap();
+        match from_binary(&query_result).unwrap() {
+            QueryAnswer::MintRuns { mint_runs } => {
+                assert_eq!(mint_runs.len(), 2);
+                assert_eq!(mint_runs[0].mint_run, 1);
+                assert_eq!(mint_runs[0].creator, Addr::unchecked("admin"));
+                assert_eq!(mint_runs[0].quantity, 3);
+                assert_eq!(mint_runs[0].first_token_id, "0".to_string());
+                assert_eq!(mint_runs[0].last_token_id, "2".to_string());
+                assert_eq!(mint_runs[1].mint_run, 2);
+                assert_eq!(mint_runs[1].first_token_id, "3".to_string());
+                assert_eq!(mint_runs[1].last_token_id, "4".to_string());
+            }
+            _ => panic!("unexpected"),
+        }
+        let query_msg = QueryMsg::MintRuns {
+            mint_run_id: "series".to_string(),
+            viewer: Some(viewer.clone()),
+            start_after: Some(1),
+            limit: None,
+        };
+        let query_result = query(deps.as_ref(), mock_env(), query_msg).unwrap();
+        match from_binary(&query_result).unwrap() {
+            QueryAnswer::MintRuns { mint_runs } => {
+                assert_eq!(mint_runs.len(), 1);
+                assert_eq!(mint_runs[0].mint_run, 2);
+            }
+            _ => panic!("unexpected"),
+        }
+
+        // burned tokens are not listed
+        let query_msg = QueryMsg::TokensInMintRun {
+            mint_run_id: "series".to_string(),
+            mint_run: 2,
+            viewer: Some(viewer.clone()),
+            start_after: None,
+            limit: None,
+        };
+        let query_result = query(deps.as_ref(), mock_env(), query_msg).unwrap();
+        match from_binary(&query_result).unwrap() {
+            QueryAnswer::TokenList { tokens, .. } => assert_eq!(tokens, vec!["3".to_string()]),
+            _ => panic!("unexpected"),
+        }
+        let query_msg = QueryMsg::TokensInMintRun {
+            mint_run_id: "series".to_string(),
+            mint_run: 1,
+            viewer: Some(viewer.clone()),
+            start_after: Some("0".to_string()),
+            limit: Some(1),
+        };
+        let query_result = query(deps.as_ref(), mock_env(), query_msg).unwrap();
+        match from_binary(&query_result).unwrap() {
+            QueryAnswer::TokenList { tokens, .. } => assert_eq!(tokens, vec!["1".to_string()]),
+            _ => panic!("unexpected"),
+        }
+        // test a token from another run
+        let query_msg = QueryMsg::TokensInMintRun {
+            mint_run_id: "series".to_string(),
+            mint_run: 1,
+            viewer: Some(viewer.clone()),
+            start_after: Some("3".to_string()),
+            limit: None,
+        };
+        let error = extract_error_msg(query(deps.as_ref(), mock_env(), query_msg));
+        assert!(error.contains("Token ID: 3 is not in mint run 1 of mint run ID series"));
+        // test a run that does not exist
+        let query_msg = QueryMsg::TokensInMintRun {
+            mint_run_id: "series".to_string(),
+            mint_run: 3,
+            viewer: Some(viewer),
+            start_after: None,
+            limit: None,
+        };
+        let error = extract_error_msg(query(deps.as_ref(), mock_env(), query_msg));
+        assert!(error.contains("Mint run 3 of mint run ID series not found"));
+    }
+
+    // test that batch mints can not reuse a serial number within a mint run
+    #[test]
+    fn test_batch_mint_unique_serials() {
+        let (init_result, mut deps) = init_helper();
+        assert!(
+            init_result.is_ok(),
+            "Init failed: {}",
+            init_result.err().unwrap()
+        );
+        let mint = |mint_run: Option<u32>, serial_number: u32| Mint {
+            token_id: None,
+            owner: None,
+            public_metadata: None,
+            private_metadata: None,
+            serial_number: Some(SerialNumber {
+                mint_run,
+                serial_number,
+                quantity_minted_this_run: None,
+            }),
+            royalty_info: None,
+            transferable: None,
+            memo: None,
+        };
+        let execute_msg = ExecuteMsg::BatchMintNft {
+            mints: vec![mint(Some(1), 1), mint(Some(1), 2), mint(Some(1), 1)],
+            padding: None,
+        };
+        let handle_result = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("admin", &[]),
+            execute_msg,
+        );
+        let error = extract_error_msg(handle_result);
+        assert!(error.contains("Serial number 1 of mint run 1 is used more than once"));
+
+        // the same serial number may be used in different mint runs
+        let execute_msg = ExecuteMsg::BatchMintNft {
+            mints: vec![mint(Some(1), 1), mint(Some(2), 1), mint(None, 1)],
+            padding: None,
+        };
+        let handle_result = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("admin", &[]),
+            execute_msg,
+        );
+        assert!(handle_result.is_ok());
+
+        // test reusing a serial number of an earlier mint
+        let execute_msg = ExecuteMsg::BatchMintNft {
+            mints: vec![mint(Some(3), 1), mint(Some(2), 1)],
+            padding: None,
+        };
+        let handle_result = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("admin", &[]),
+            execute_msg,
+        );
+        let error = extract_error_msg(handle_result);
+        assert!(error.contains("Serial number 1 of mint run 2 is used more than once"));
+
+        // serial numbers without a mint run are standalone and may repeat
+        let execute_msg = ExecuteMsg::BatchMintNft {
+            mints: vec![mint(None, 1), mint(None, 1)],
+            padding: None,
+        };
+        let handle_result = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("admin", &[]),
+            execute_msg,
+        );
+        assert!(handle_result.is_ok());
+        let execute_msg = ExecuteMsg::MintNft {
+            token_id: None,
+            owner: None,
+            public_metadata: None,
+            private_metadata: None,
+            serial_number: Some(SerialNumber {
+                mint_run: None,
+                serial_number: 1,
+                quantity_minted_this_run: None,
+            }),
+            royalty_info: None,
+            transferable: None,
+            memo: None,
+            padding: None,
+        };
+        let handle_result = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("admin", &[]),
+            execute_msg,
+        );
+        assert!(handle_result.is_ok());
+    }
 }
@@ -0,0 +1,296 @@
+#[cfg(test)]
+mod tests {
+    use std::any::Any;
+
+    use cosmwasm_std::testing::*;
+    use cosmwasm_std::{from_binary, OwnedDeps, Response, StdError, StdResult, Timestamp};
+
+    use crate::contract::{execute, instantiate, query};
+    use crate::expiration::Expiration;
+    use crate::minter_quota::{MinterQuota, QuotaWindow, WindowLength};
+    use crate::msg::{ExecuteMsg, InstantiateMsg, Mint, QueryAnswer, QueryMsg};
+
+    // Helper functions
+
+    fn init_helper_default() -> (
+        StdResult<Response>,
+        OwnedDeps<MockStorage, MockApi, MockQuerier>,
+    ) {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+        let info = mock_info("instantiator", &[]);
+        let init_msg = InstantiateMsg {
+            name: "sec721".to_string(),
+            symbol: "S721".to_string(),
+            admin: Some("admin".to_string()),
+            entropy: "We're going to need a bigger boat".to_string(),
+            royalty_info: None,
+            config: None,
+            post_init_callback: None,
+        };
+
+        (instantiate(deps.as_mut(), env, info, init_msg), deps)
+    }
+
+    fn extract_error_msg<T: Any>(error: StdResult<T>) -> String {
+        match error {
+            Ok(_response) => panic!("Expected error, but had Ok response"),
+            Err(err) => match err {
+                StdError::GenericErr { msg, .. } => msg,
+                _ => panic!("Unexpected error result {:?}", err),
+            },
+        }
+    }
+
+    fn set_quota(
+        deps: &mut OwnedDeps<MockStorage, MockApi, MockQuerier>,
+        quota: Option<MinterQuota>,
+    ) -> StdResult<Response> {
+        let execute_msg = ExecuteMsg::SetMinterQuota {
+            minter: "admin".to_string(),
+            quota,
+            padding: None,
+        };
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("admin", &[]),
+            execute_msg,
+        )
+    }
+
+    fn batch_mint(
+        deps: &mut OwnedDeps<MockStorage, MockApi, MockQuerier>,
+        env: cosmwasm_std::Env,
+        count: usize,
+    ) -> StdResult<Response> {
+        let mints = (0..count)
+            .map(|_| Mint {
+                token_id: None,
+                owner: None,
+                public_metadata: None,
+                private_metadata: None,
+                serial_number: None,
+                royalty_info: None,
+                transferable: None,
+                memo: None,
+            })
+            .collect();
+        let execute_msg = ExecuteMsg::BatchMintNft {
+            mints,
+            padding: None,
+        };
+        execute(deps.as_mut(), env, mock_info("admin", &[]), execute_msg)
+    }
+
+    fn allowance(
+        deps: &OwnedDeps<MockStorage, MockApi, MockQuerier>,
+        env: cosmwasm_std::Env,
+    ) -> (u32, Option<u32>, Option<u32>, Option<Expiration>) {
+        let query_msg = QueryMsg::MinterAllowance {
+            minter: "admin".to_string(),
+        };
+        let query_result = query(deps.as_ref(), env, query_msg);
+        let query_answer: QueryAnswer = from_binary(&query_result.unwrap()).unwrap();
+        match query_answer {
+            QueryAnswer::MinterAllowance {
+                minted,
+                lifetime_remaining,
+                window_remaining,
+                window_ends,
+                ..
+            } => (minted, lifetime_remaining, window_remaining, window_ends),
+            _ => panic!("unexpected"),
+        }
+    }
+
+    #[test]
+    fn test_set_minter_quota() {
+        let (init_result, mut deps) = init_helper_default();
+        assert!(
+            init_result.is_ok(),
+            "Init failed: {}",
+            init_result.err().unwrap()
+        );
+
+        // test non admin trying to set a quota
+        let execute_msg = ExecuteMsg::SetMinterQuota {
+            minter: "admin".to_string(),
+            quota: None,
+            padding: None,
+        };
+        let handle_result = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("alice", &[]),
+            execute_msg,
+        );
+        let error = extract_error_msg(handle_result);
+        assert!(
+            error.contains("This is an admin command and can only be run from the admin address")
+        );
+
+        // test a zero length window
+        let handle_result = set_quota(
+            &mut deps,
+            Some(MinterQuota {
+                window: Some(QuotaWindow {
+                    limit: 5,
+                    length: WindowLength::Blocks(0),
+                }),
+                ..MinterQuota::default()
+            }),
+        );
+        let error = extract_error_msg(handle_result);
+        assert!(error.contains("A quota window's length can not be zero"));
+
+        // no quota means no limits, but mints are still counted
+        batch_mint(&mut deps, mock_env(), 3).unwrap();
+        assert_eq!(allowance(&deps, mock_env()), (3, None, None, None));
+    }
+
+    #[test]
+    fn test_lifetime_quota_and_batch_size() {
+        let (init_result, mut deps) = init_helper_default();
+        assert!(
+            init_result.is_ok(),
+            "Init failed: {}",
+            init_result.err().unwrap()
+        );
+        set_quota(
+            &mut deps,
+            Some(MinterQuota {
+                lifetime: Some(5),
+                window: None,
+                max_batch: Some(3),
+            }),
+        )
+        .unwrap();
+
+        let error = extract_error_msg(batch_mint(&mut deps, mock_env(), 4));
+        assert!(error.contains("Minting 4 tokens exceeds the maximum batch size of 3"));
+        batch_mint(&mut deps, mock_env(), 3).unwrap();
+        assert_eq!(allowance(&deps, mock_env()), (3, Some(2), None, None));
+
+        // mint_clones is also limited
+        let execute_msg = ExecuteMsg::MintNftClones {
+            mint_run_id: None,
+            quantity: 3,
+            owner: None,
+            public_metadata: None,
+            private_metadata: None,
+            serial_overrides: None,
+            royalty_info: None,
+            memo: None,
+            padding: None,
+        };
+        let handle_result = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("admin", &[]),
+            execute_msg,
+        );
+        let error = extract_error_msg(handle_result);
+        assert!(error.contains("Minting 3 tokens exceeds the remaining lifetime quota of 2"));
+
+        batch_mint(&mut deps, mock_env(), 2).unwrap();
+        // single mints are limited too
+        let execute_msg = ExecuteMsg::MintNft {
+            token_id: None,
+            owner: None,
+            public_metadata: None,
+            private_metadata: None,
+            serial_number: None,
+            royalty_info: None,
+            transferable: None,
+            memo: None,
+            padding: None,
+        };
+        let handle_result = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("admin", &[]),
+            execute_msg,
+        );
+        let error = extract_error_msg(handle_result);
+        assert!(error.contains("Minting 1 tokens exceeds the remaining lifetime quota of 0"));
+        assert_eq!(allowance(&deps, mock_env()), (5, Some(0), None, None));
+
+        // removing the quota lifts the limits
+        set_quota(&mut deps, None).unwrap();
+        batch_mint(&mut deps, mock_env(), 4).unwrap();
+        assert_eq!(allowance(&deps, mock_env()), (9, None, None, None));
+    }
+
+    #[test]
+    fn test_window_quota() {
+        let (init_result, mut deps) = init_helper_default();
+        assert!(
+            init_result.is_ok(),
+            "Init failed: {}",
+            init_result.err().unwrap()
+        );
+        set_quota(
+            &mut deps,
+            Some(MinterQuota {
+                lifetime: None,
+                window: Some(QuotaWindow {
+                    limit: 4,
+                    length: WindowLength::Seconds(100),
+                }),
+                max_batch: None,
+            }),
+        )
+        .unwrap();
+        let start = mock_env().block.time.seconds();
+        assert_eq!(allowance(&deps, mock_env()), (0, None, Some(4), None));
+
+        batch_mint(&mut deps, mock_env(), 3).unwrap();
+        assert_eq!(
+            allowance(&deps, mock_env()),
+            (3, None, Some(1), Some(Expiration::AtTime(start + 100)))
+        );
+        let mut env = mock_env();
+        env.block.time = Timestamp::from_seconds(start + 99);
+        let error = extract_error_msg(batch_mint(&mut deps, env.clone(), 2));
+        assert!(
+            error.contains("Minting 2 tokens exceeds the 1 remaining in the current quota window")
+        );
+        batch_mint(&mut deps, env, 1).unwrap();
+
+        // the next window opens once the current one ends
+        let mut env = mock_env();
+        env.block.time = Timestamp::from_seconds(start + 100);
+        assert_eq!(allowance(&deps, env.clone()), (4, None, Some(4), None));
+        batch_mint(&mut deps, env.clone(), 4).unwrap();
+        assert_eq!(
+            allowance(&deps, env),
+            (8, None, Some(0), Some(Expiration::AtTime(start + 200)))
+        );
+
+        // a block height window
+        set_quota(
+            &mut deps,
+            Some(MinterQuota {
+                lifetime: None,
+                window: Some(QuotaWindow {
+                    limit: 2,
+                    length: WindowLength::Blocks(10),
+                }),
+                max_batch: None,
+            }),
+        )
+        .unwrap();
+        let height = mock_env().block.height;
+        batch_mint(&mut deps, mock_env(), 2).unwrap();
+        let mut env = mock_env();
+        env.block.height = height + 9;
+        let error = extract_error_msg(batch_mint(&mut deps, env, 1));
+        assert!(
+            error.contains("Minting 1 tokens exceeds the 0 remaining in the current quota window")
+        );
+        let mut env = mock_env();
+        env.block.height = height + 10;
+        batch_mint(&mut deps, env, 2).unwrap();
+    }
+}
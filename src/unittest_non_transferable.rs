@@ -14,7 +14,7 @@ mod tests {
     use crate::inventory::Inventory;
     use crate::msg::{
         Burn, ContractStatus, ExecuteMsg, InstantiateConfig, InstantiateMsg, Mint,
-        PostInstantiateCallback, QueryAnswer, QueryMsg, Send, Transfer,
+        PostInstantiateCallback, QueryAnswer, QueryMsg, Send, Transfer, TxAction,
     };
     use crate::royalties::{DisplayRoyalty, DisplayRoyaltyInfo, Royalty, RoyaltyInfo};
     use crate::state::{
@@ -778,6 +778,9 @@ mod tests {
                 private_metadata_is_public_expiration,
                 token_approvals,
                 inventory_approvals,
+                edition: _,
+                revocation: _,
+                user: _,
             } => {
                 assert_eq!(owner, Some(Addr::unchecked(alice)));
                 assert_eq!(public_metadata, Some(public_meta));
@@ -835,6 +838,7 @@ mod tests {
         match query_answer {
             QueryAnswer::IsTransferable {
                 token_is_transferable,
+                revocation: _,
             } => {
                 assert!(token_is_transferable);
             }
@@ -893,6 +897,7 @@ mod tests {
         match query_answer {
             QueryAnswer::IsTransferable {
                 token_is_transferable,
+                revocation: _,
             } => {
                 assert!(!token_is_transferable);
             }
@@ -908,6 +913,7 @@ mod tests {
         match query_answer {
             QueryAnswer::IsTransferable {
                 token_is_transferable,
+                revocation: _,
             } => {
                 assert!(token_is_transferable);
             }
@@ -1010,4 +1016,162 @@ mod tests {
             _ => panic!("unexpected"),
         }
     }
+
+    // test revoking badges
+    #[test]
+    fn test_revoke_badge() {
+        let (init_result, mut deps) = init_helper_default();
+        assert!(
+            init_result.is_ok(),
+            "Init failed: {}",
+            init_result.err().unwrap()
+        );
+        let execute_msg = ExecuteMsg::AddMinters {
+            minters: vec!["issuer".to_string()],
+            padding: None,
+        };
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("admin", &[]),
+            execute_msg,
+        )
+        .unwrap();
+        for (token_id, transferable) in [("badge", false), ("NFT", true)] {
+            let execute_msg = ExecuteMsg::MintNft {
+                token_id: Some(token_id.to_string()),
+                owner: Some("alice".to_string()),
+                public_metadata: None,
+                private_metadata: None,
+                royalty_info: None,
+                serial_number: None,
+                transferable: Some(transferable),
+                memo: None,
+                padding: None,
+            };
+            execute(
+                deps.as_mut(),
+                mock_env(),
+                mock_info("issuer", &[]),
+                execute_msg,
+            )
+            .unwrap();
+        }
+        let revoke = |token_id: &str| ExecuteMsg::RevokeBadge {
+            token_id: token_id.to_string(),
+            reason: "issued by mistake".to_string(),
+            padding: None,
+        };
+
+        // test non-minter
+        let handle_result = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("alice", &[]),
+            revoke("badge"),
+        );
+        let error = extract_error_msg(handle_result);
+        assert!(error.contains("Only designated minters are allowed to revoke badges"));
+
+        // test a minter that did not issue the badge
+        let handle_result = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("admin", &[]),
+            revoke("badge"),
+        );
+        let error = extract_error_msg(handle_result);
+        assert!(error.contains("Only the minter that issued token badge may revoke it"));
+
+        // test transferable token
+        let handle_result = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("issuer", &[]),
+            revoke("NFT"),
+        );
+        let error = extract_error_msg(handle_result);
+        assert!(error.contains("Token ID: NFT is transferable and can not be revoked"));
+
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("issuer", &[]),
+            revoke("badge"),
+        )
+        .unwrap();
+        let handle_result = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("issuer", &[]),
+            revoke("badge"),
+        );
+        let error = extract_error_msg(handle_result);
+        assert!(error.contains("Token ID: badge has already been revoked"));
+
+        let query_msg = QueryMsg::IsTransferable {
+            token_id: "badge".to_string(),
+        };
+        let query_result = query(deps.as_ref(), mock_env(), query_msg).unwrap();
+        match from_binary(&query_result).unwrap() {
+            QueryAnswer::IsTransferable {
+                token_is_transferable,
+                revocation,
+            } => {
+                assert!(!token_is_transferable);
+                let revocation = revocation.unwrap();
+                assert_eq!(revocation.issuer, Addr::unchecked("issuer"));
+                assert_eq!(revocation.reason, "issued by mistake".to_string());
+                assert_eq!(revocation.time, mock_env().block.time.seconds());
+            }
+            _ => panic!("unexpected"),
+        }
+        let query_msg = QueryMsg::NftDossier {
+            token_id: "badge".to_string(),
+            viewer: None,
+            include_expired: None,
+        };
+        let query_result = query(deps.as_ref(), mock_env(), query_msg).unwrap();
+        match from_binary(&query_result).unwrap() {
+            QueryAnswer::NftDossier { revocation, .. } => {
+                assert_eq!(revocation.unwrap().reason, "issued by mistake".to_string())
+            }
+            _ => panic!("unexpected"),
+        }
+
+        // the revocation is in the owner's history
+        let execute_msg = ExecuteMsg::SetViewingKey {
+            key: "akey".to_string(),
+            padding: None,
+        };
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("alice", &[]),
+            execute_msg,
+        )
+        .unwrap();
+        let query_msg = QueryMsg::TransactionHistory {
+            address: "alice".to_string(),
+            viewing_key: "akey".to_string(),
+            page: None,
+            page_size: None,
+        };
+        let query_result = query(deps.as_ref(), mock_env(), query_msg).unwrap();
+        match from_binary(&query_result).unwrap() {
+            QueryAnswer::TransactionHistory { total, txs } => {
+                assert_eq!(total, 3);
+                assert_eq!(txs[0].token_id, "badge".to_string());
+                assert_eq!(
+                    txs[0].action,
+                    TxAction::RevokeBadge {
+                        issuer: Addr::unchecked("issuer"),
+                        owner: Addr::unchecked("alice"),
+                        reason: "issued by mistake".to_string(),
+                    }
+                );
+            }
+            _ => panic!("unexpected"),
+        }
+    }
 }
@@ -0,0 +1,489 @@
+#[cfg(test)]
+mod tests {
+    use std::any::Any;
+
+    use cosmwasm_std::testing::*;
+    use cosmwasm_std::{
+        from_binary, to_binary, Addr, CosmosMsg, Env, OwnedDeps, Response, StdError, StdResult,
+        Uint128, WasmMsg,
+    };
+    use serde::Deserialize;
+
+    use crate::contract::{execute, instantiate, query};
+    use crate::expiration::Expiration;
+    use crate::msg::{
+        ExecuteAnswer, ExecuteMsg, InstantiateConfig, InstantiateMsg, QueryAnswer, QueryMsg,
+        ReceiveMsg, ViewerInfo,
+    };
+    use crate::offer::{Offer, OfferSnip20};
+    use crate::royalties::{Royalty, RoyaltyInfo};
+
+    // Helper functions
+
+    fn init_helper_default() -> (
+        StdResult<Response>,
+        OwnedDeps<MockStorage, MockApi, MockQuerier>,
+    ) {
+        init_helper_with_config(InstantiateConfig {
+            public_owner: Some(true),
+            ..InstantiateConfig::default()
+        })
+    }
+
+    fn init_helper_with_config(
+        config: InstantiateConfig,
+    ) -> (
+        StdResult<Response>,
+        OwnedDeps<MockStorage, MockApi, MockQuerier>,
+    ) {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+        let info = mock_info("instantiator", &[]);
+        let init_msg = InstantiateMsg {
+            name: "sec721".to_string(),
+            symbol: "S721".to_string(),
+            admin: Some("admin".to_string()),
+            entropy: "We're going to need a bigger boat".to_string(),
+            royalty_info: Some(RoyaltyInfo {
+                decimal_places_in_rates: 2,
+                royalties: vec![
+                    Royalty {
+                        recipient: "artist".to_string(),
+                        rate: 5,
+                    },
+                    Royalty {
+                        recipient: "curator".to_string(),
+                        rate: 2,
+                    },
+                ],
+            }),
+            config: Some(config),
+            post_init_callback: None,
+        };
+
+        (instantiate(deps.as_mut(), env, info, init_msg), deps)
+    }
+
+    fn extract_error_msg<T: Any>(error: StdResult<T>) -> String {
+        match error {
+            Ok(_response) => panic!("Expected error, but had Ok response"),
+            Err(err) => match err {
+                StdError::GenericErr { msg, .. } => msg,
+                _ => panic!("Unexpected error result {:?}", err),
+            },
+        }
+    }
+
+    // the SNIP-20 transfers this contract sends
+    #[derive(Deserialize, Debug, PartialEq)]
+    #[serde(rename_all = "snake_case")]
+    enum MockSnip20Msg {
+        Transfer {
+            recipient: String,
+            amount: Uint128,
+            memo: Option<String>,
+            padding: Option<String>,
+        },
+    }
+
+    fn snip20_transfers(response: &Response) -> Vec<(String, u128)> {
+        response
+            .messages
+            .iter()
+            .filter_map(|m| match &m.msg {
+                CosmosMsg::Wasm(WasmMsg::Execute {
+                    contract_addr,
+                    code_hash,
+                    msg,
+                    ..
+                }) => {
+                    assert_eq!(contract_addr, "snip20");
+                    assert_eq!(code_hash, "snip20 hash");
+                    match from_binary(msg).unwrap() {
+                        MockSnip20Msg::Transfer {
+                            recipient, amount, ..
+                        } => Some((recipient, amount.u128())),
+                    }
+                }
+                _ => None,
+            })
+            .collect()
+    }
+
+    fn make_offer(
+        deps: &mut OwnedDeps<MockStorage, MockApi, MockQuerier>,
+        snip20: &str,
+        buyer: &str,
+        token_id: &str,
+        amount: u128,
+        expires: Expiration,
+    ) -> StdResult<Response> {
+        let execute_msg = ExecuteMsg::Receive {
+            sender: buyer.to_string(),
+            from: buyer.to_string(),
+            amount: Uint128::new(amount),
+            memo: None,
+            msg: Some(
+                to_binary(&ReceiveMsg::MakeOffer {
+                    token_id: token_id.to_string(),
+                    expires,
+                })
+                .unwrap(),
+            ),
+        };
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(snip20, &[]),
+            execute_msg,
+        )
+    }
+
+    fn accept_offer(
+        deps: &mut OwnedDeps<MockStorage, MockApi, MockQuerier>,
+        env: Env,
+        sender: &str,
+        offer_id: u64,
+    ) -> StdResult<Response> {
+        let execute_msg = ExecuteMsg::AcceptOffer {
+            offer_id,
+            memo: None,
+            padding: None,
+        };
+        execute(deps.as_mut(), env, mock_info(sender, &[]), execute_msg)
+    }
+
+    fn withdraw_offer(
+        deps: &mut OwnedDeps<MockStorage, MockApi, MockQuerier>,
+        sender: &str,
+        offer_id: u64,
+    ) -> StdResult<Response> {
+        let execute_msg = ExecuteMsg::WithdrawOffer {
+            offer_id,
+            padding: None,
+        };
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(sender, &[]),
+            execute_msg,
+        )
+    }
+
+    fn viewer(address: &str) -> ViewerInfo {
+        ViewerInfo {
+            address: address.to_string(),
+            viewing_key: "key".to_string(),
+        }
+    }
+
+    fn token_offers(
+        deps: &OwnedDeps<MockStorage, MockApi, MockQuerier>,
+        address: &str,
+        start_after: Option<u64>,
+        limit: Option<u32>,
+    ) -> StdResult<Vec<u64>> {
+        let query_msg = QueryMsg::TokenOffers {
+            token_id: "NFT1".to_string(),
+            viewer: Some(viewer(address)),
+            start_after,
+            limit,
+        };
+        let query_result = query(deps.as_ref(), mock_env(), query_msg)?;
+        match from_binary(&query_result).unwrap() {
+            QueryAnswer::Offers { offers } => Ok(offers.into_iter().map(|o| o.offer_id).collect()),
+            _ => panic!("unexpected"),
+        }
+    }
+
+    fn buyer_offers(
+        deps: &OwnedDeps<MockStorage, MockApi, MockQuerier>,
+        address: &str,
+    ) -> Vec<Offer> {
+        let query_msg = QueryMsg::BuyerOffers {
+            viewer: viewer(address),
+            start_after: None,
+            limit: None,
+        };
+        let query_result = query(deps.as_ref(), mock_env(), query_msg).unwrap();
+        match from_binary(&query_result).unwrap() {
+            QueryAnswer::Offers { offers } => offers,
+            _ => panic!("unexpected"),
+        }
+    }
+
+    fn set_offer_snip20(
+        deps: &mut OwnedDeps<MockStorage, MockApi, MockQuerier>,
+        sender: &str,
+        min_amount: Option<u128>,
+    ) -> StdResult<Response> {
+        let execute_msg = ExecuteMsg::SetOfferSnip20 {
+            contract_address: "snip20".to_string(),
+            code_hash: "snip20 hash".to_string(),
+            min_amount: min_amount.map(Uint128::new),
+            padding: None,
+        };
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(sender, &[]),
+            execute_msg,
+        )
+    }
+
+    fn offer_snip20s(deps: &OwnedDeps<MockStorage, MockApi, MockQuerier>) -> Vec<OfferSnip20> {
+        let query_result = query(deps.as_ref(), mock_env(), QueryMsg::OfferSnip20s {}).unwrap();
+        match from_binary(&query_result).unwrap() {
+            QueryAnswer::OfferSnip20s { snip20s } => snip20s,
+            _ => panic!("unexpected"),
+        }
+    }
+
+    #[test]
+    fn test_offers() {
+        let (init_result, mut deps) = init_helper_default();
+        assert!(
+            init_result.is_ok(),
+            "Init failed: {}",
+            init_result.err().unwrap()
+        );
+        let execute_msg = ExecuteMsg::MintNft {
+            token_id: Some("NFT1".to_string()),
+            owner: Some("alice".to_string()),
+            public_metadata: None,
+            private_metadata: None,
+            royalty_info: None,
+            serial_number: None,
+            transferable: None,
+            memo: None,
+            padding: None,
+        };
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("admin", &[]),
+            execute_msg,
+        )
+        .unwrap();
+        for addr in ["alice", "bob", "carol", "dave"] {
+            let execute_msg = ExecuteMsg::SetViewingKey {
+                key: "key".to_string(),
+                padding: None,
+            };
+            execute(deps.as_mut(), mock_env(), mock_info(addr, &[]), execute_msg).unwrap();
+        }
+        let height = mock_env().block.height;
+        let expires = Expiration::AtHeight(height + 100);
+
+        // test a SNIP-20 the contract has not registered with
+        let error = extract_error_msg(make_offer(
+            &mut deps, "snip20", "bob", "NFT1", 1000, expires,
+        ));
+        assert!(error.contains("Offers can not be made in snip20"));
+        // test a non-admin accepting a SNIP-20
+        let error = extract_error_msg(set_offer_snip20(&mut deps, "alice", Some(100)));
+        assert!(
+            error.contains("This is an admin command and can only be run from the admin address")
+        );
+        // offers do not depend on the SNIP-20s the sale accepts
+        let response = set_offer_snip20(&mut deps, "admin", Some(100)).unwrap();
+        assert_eq!(response.messages.len(), 1);
+        assert_eq!(
+            offer_snip20s(&deps),
+            vec![OfferSnip20 {
+                address: Addr::unchecked("snip20"),
+                code_hash: "snip20 hash".to_string(),
+                min_amount: Uint128::new(100),
+            }]
+        );
+
+        // test bad offers
+        let error = extract_error_msg(make_offer(&mut deps, "snip20", "bob", "NFT1", 0, expires));
+        assert!(error.contains("The offer amount must be greater than zero"));
+        let error = extract_error_msg(make_offer(&mut deps, "snip20", "bob", "NFT1", 99, expires));
+        assert!(error.contains("Offers in snip20 must be at least 100"));
+        let error = extract_error_msg(make_offer(
+            &mut deps,
+            "snip20",
+            "bob",
+            "NFT1",
+            1000,
+            Expiration::AtHeight(1),
+        ));
+        assert!(error.contains("The offer expiration has already passed"));
+        let error = extract_error_msg(make_offer(
+            &mut deps, "snip20", "bob", "NFT9", 1000, expires,
+        ));
+        assert!(error.contains("You can not make an offer on token NFT9"));
+        let error = extract_error_msg(make_offer(
+            &mut deps, "snip20", "alice", "NFT1", 1000, expires,
+        ));
+        assert!(error.contains("You already own token NFT1"));
+
+        let response = make_offer(&mut deps, "snip20", "bob", "NFT1", 1000, expires).unwrap();
+        match from_binary(&response.data.unwrap()).unwrap() {
+            ExecuteAnswer::MakeOffer { offer_id } => assert_eq!(offer_id, 0),
+            _ => panic!("unexpected"),
+        }
+        make_offer(&mut deps, "snip20", "carol", "NFT1", 500, expires).unwrap();
+        make_offer(&mut deps, "snip20", "dave", "NFT1", 200, expires).unwrap();
+
+        // only the owner may view the offers on a token
+        assert_eq!(
+            token_offers(&deps, "alice", None, None).unwrap(),
+            vec![0, 1, 2]
+        );
+        assert_eq!(
+            token_offers(&deps, "alice", None, Some(2)).unwrap(),
+            vec![0, 1]
+        );
+        assert_eq!(
+            token_offers(&deps, "alice", Some(1), Some(2)).unwrap(),
+            vec![2]
+        );
+        let error = extract_error_msg(token_offers(&deps, "bob", None, None));
+        assert!(error.contains("You are not authorized to view the offers on token NFT1"));
+        assert_eq!(
+            buyer_offers(&deps, "bob"),
+            vec![Offer {
+                offer_id: 0,
+                token_id: "NFT1".to_string(),
+                buyer: Addr::unchecked("bob"),
+                snip20: Addr::unchecked("snip20"),
+                amount: Uint128::new(1000),
+                expires,
+            }]
+        );
+
+        // test withdrawing
+        let error = extract_error_msg(withdraw_offer(&mut deps, "carol", 0));
+        assert!(error.contains("Only the buyer may withdraw offer 0"));
+        let response = withdraw_offer(&mut deps, "carol", 1).unwrap();
+        assert_eq!(
+            snip20_transfers(&response),
+            vec![("carol".to_string(), 500)]
+        );
+        assert!(buyer_offers(&deps, "carol").is_empty());
+        let error = extract_error_msg(withdraw_offer(&mut deps, "carol", 1));
+        assert!(error.contains("Offer 1 not found"));
+        assert_eq!(
+            token_offers(&deps, "alice", None, None).unwrap(),
+            vec![0, 2]
+        );
+        assert_eq!(
+            token_offers(&deps, "alice", Some(0), None).unwrap(),
+            vec![2]
+        );
+        let error = extract_error_msg(token_offers(&deps, "alice", Some(1), None));
+        assert!(error.contains("Offer 1 not found"));
+
+        // test accepting
+        let error = extract_error_msg(accept_offer(&mut deps, mock_env(), "bob", 0));
+        assert!(error.contains("Only the owner of token NFT1 may accept offer 0"));
+        let mut env = mock_env();
+        env.block.height = height + 100;
+        let error = extract_error_msg(accept_offer(&mut deps, env, "alice", 0));
+        assert!(error.contains("Offer 0 has expired"));
+        let response = accept_offer(&mut deps, mock_env(), "alice", 0).unwrap();
+        assert_eq!(
+            snip20_transfers(&response),
+            vec![
+                ("artist".to_string(), 50),
+                ("curator".to_string(), 20),
+                ("alice".to_string(), 930),
+            ]
+        );
+        let query_msg = QueryMsg::OwnerOf {
+            token_id: "NFT1".to_string(),
+            viewer: None,
+            include_expired: None,
+        };
+        let query_result = query(deps.as_ref(), mock_env(), query_msg).unwrap();
+        match from_binary(&query_result).unwrap() {
+            QueryAnswer::OwnerOf { owner, .. } => assert_eq!(owner, Addr::unchecked("bob")),
+            _ => panic!("unexpected"),
+        }
+        assert!(buyer_offers(&deps, "bob").is_empty());
+        // the other offers stay open for the new owner
+        assert_eq!(token_offers(&deps, "bob", None, None).unwrap(), vec![2]);
+        let error = extract_error_msg(accept_offer(&mut deps, mock_env(), "bob", 0));
+        assert!(error.contains("Offer 0 not found"));
+
+        // a SNIP-20 that is no longer accepted can not be used for new offers, but its open
+        // offers can still be withdrawn
+        let response = set_offer_snip20(&mut deps, "admin", None).unwrap();
+        assert!(response.messages.is_empty());
+        assert!(offer_snip20s(&deps).is_empty());
+        let error = extract_error_msg(make_offer(
+            &mut deps, "snip20", "carol", "NFT1", 1000, expires,
+        ));
+        assert!(error.contains("Offers can not be made in snip20"));
+        let response = withdraw_offer(&mut deps, "dave", 2).unwrap();
+        assert_eq!(snip20_transfers(&response), vec![("dave".to_string(), 200)]);
+        assert!(token_offers(&deps, "bob", None, None).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_burn_refunds_offers() {
+        let (init_result, mut deps) = init_helper_with_config(InstantiateConfig {
+            public_owner: Some(true),
+            enable_burn: Some(true),
+            ..InstantiateConfig::default()
+        });
+        assert!(
+            init_result.is_ok(),
+            "Init failed: {}",
+            init_result.err().unwrap()
+        );
+        let execute_msg = ExecuteMsg::MintNft {
+            token_id: Some("NFT1".to_string()),
+            owner: Some("alice".to_string()),
+            public_metadata: None,
+            private_metadata: None,
+            royalty_info: None,
+            serial_number: None,
+            transferable: None,
+            memo: None,
+            padding: None,
+        };
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("admin", &[]),
+            execute_msg,
+        )
+        .unwrap();
+        for addr in ["alice", "bob"] {
+            let execute_msg = ExecuteMsg::SetViewingKey {
+                key: "key".to_string(),
+                padding: None,
+            };
+            execute(deps.as_mut(), mock_env(), mock_info(addr, &[]), execute_msg).unwrap();
+        }
+        set_offer_snip20(&mut deps, "admin", Some(100)).unwrap();
+        let expires = Expiration::AtHeight(mock_env().block.height + 100);
+        make_offer(&mut deps, "snip20", "bob", "NFT1", 1000, expires).unwrap();
+        make_offer(&mut deps, "snip20", "carol", "NFT1", 500, expires).unwrap();
+
+        // burning the token returns the escrow of every offer on it
+        let execute_msg = ExecuteMsg::BurnNft {
+            token_id: "NFT1".to_string(),
+            memo: None,
+            padding: None,
+        };
+        let response = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("alice", &[]),
+            execute_msg,
+        )
+        .unwrap();
+        assert_eq!(
+            snip20_transfers(&response),
+            vec![("bob".to_string(), 1000), ("carol".to_string(), 500)]
+        );
+        assert!(buyer_offers(&deps, "bob").is_empty());
+        let error = extract_error_msg(withdraw_offer(&mut deps, "bob", 0));
+        assert!(error.contains("Offer 0 not found"));
+    }
+}
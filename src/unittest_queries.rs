@@ -12,9 +12,11 @@ mod tests {
     use crate::expiration::Expiration;
     use crate::mint_run::MintRunInfo;
     use crate::msg::{
-        AccessLevel, BatchNftDossierElement, Cw721Approval, ExecuteMsg, InstantiateConfig,
-        InstantiateMsg, Mint, QueryAnswer, QueryMsg, Snip721Approval, Tx, TxAction, ViewerInfo,
+        AccessLevel, BatchNftDossierElement, ConfigRatchets, Cw721Approval, ExecuteMsg,
+        InstantiateConfig, InstantiateMsg, Mint, QueryAnswer, QueryMsg, Snip721Approval, Tx,
+        TxAction, ViewerInfo,
     };
+    use crate::state::{load, save, Config, CONFIG_KEY};
     use crate::token::{Extension, Metadata};
 
     // Helper functions
@@ -153,6 +155,8 @@ mod tests {
                 burn_is_enabled,
                 implements_non_transferable_tokens,
                 implements_token_subtype,
+                ratchets,
+                token_id_scheme,
             } => {
                 assert!(!token_supply_is_public);
                 assert!(owner_is_public);
@@ -163,6 +167,70 @@ mod tests {
                 assert!(burn_is_enabled);
                 assert!(implements_non_transferable_tokens);
                 assert!(implements_token_subtype);
+                assert_eq!(ratchets, ConfigRatchets::default());
+                assert!(token_id_scheme.is_none());
+            }
+            _ => panic!("unexpected"),
+        }
+    }
+
+    // test pending admin query
+    #[test]
+    fn test_query_pending_admin() {
+        let (init_result, mut deps) = init_helper_default();
+        assert!(
+            init_result.is_ok(),
+            "Init failed: {}",
+            init_result.err().unwrap()
+        );
+        let query_msg = QueryMsg::PendingAdmin {};
+        let query_result = query(deps.as_ref(), mock_env(), query_msg);
+        let query_answer: QueryAnswer = from_binary(&query_result.unwrap()).unwrap();
+        match query_answer {
+            QueryAnswer::PendingAdmin {
+                address,
+                expiration,
+            } => {
+                assert!(address.is_none());
+                assert!(expiration.is_none());
+            }
+            _ => panic!("unexpected"),
+        }
+
+        let execute_msg = ExecuteMsg::ProposeAdmin {
+            address: "alice".to_string(),
+            expiration: Some(Expiration::AtTime(1571797500)),
+            padding: None,
+        };
+        let _handle_result = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("admin", &[]),
+            execute_msg,
+        );
+        let query_msg = QueryMsg::PendingAdmin {};
+        let query_result = query(deps.as_ref(), mock_env(), query_msg);
+        let query_answer: QueryAnswer = from_binary(&query_result.unwrap()).unwrap();
+        match query_answer {
+            QueryAnswer::PendingAdmin {
+                address,
+                expiration,
+            } => {
+                assert_eq!(address, Some(Addr::unchecked("alice")));
+                assert_eq!(expiration, Some(Expiration::AtTime(1571797500)));
+            }
+            _ => panic!("unexpected"),
+        }
+
+        // an expired proposal is not displayed
+        let mut env = mock_env();
+        env.block.time = Timestamp::from_seconds(1571797500);
+        let query_msg = QueryMsg::PendingAdmin {};
+        let query_result = query(deps.as_ref(), env, query_msg);
+        let query_answer: QueryAnswer = from_binary(&query_result.unwrap()).unwrap();
+        match query_answer {
+            QueryAnswer::PendingAdmin { address, .. } => {
+                assert!(address.is_none());
             }
             _ => panic!("unexpected"),
         }
@@ -574,7 +642,7 @@ mod tests {
         );
         let query_answer: QueryAnswer = from_binary(&query_result.unwrap()).unwrap();
         match query_answer {
-            QueryAnswer::TokenList { tokens } => {
+            QueryAnswer::TokenList { tokens, .. } => {
                 let expected = vec!["NFT1".to_string(), "NFT2".to_string()];
                 assert_eq!(tokens, expected);
             }
@@ -732,7 +800,7 @@ mod tests {
         );
         let query_answer: QueryAnswer = from_binary(&query_result.unwrap()).unwrap();
         match query_answer {
-            QueryAnswer::TokenList { tokens } => {
+            QueryAnswer::TokenList { tokens, .. } => {
                 let expected = vec!["NFT3".to_string(), "NFT5".to_string(), "NFT4".to_string()];
                 assert_eq!(tokens, expected);
             }
@@ -775,7 +843,7 @@ mod tests {
         );
         let query_answer: QueryAnswer = from_binary(&query_result.unwrap()).unwrap();
         match query_answer {
-            QueryAnswer::TokenList { tokens } => {
+            QueryAnswer::TokenList { tokens, .. } => {
                 let expected = vec![
                     "NFT1".to_string(),
                     "NFT2".to_string(),
@@ -937,6 +1005,9 @@ mod tests {
                 private_metadata_is_public_expiration,
                 token_approvals,
                 inventory_approvals,
+                edition: _,
+                revocation: _,
+                user: _,
             } => {
                 assert_eq!(owner, Some(Addr::unchecked(alice.clone())));
                 assert_eq!(public_metadata, Some(public_meta.clone()));
@@ -1052,6 +1123,9 @@ mod tests {
                 private_metadata_is_public_expiration,
                 token_approvals,
                 inventory_approvals,
+                edition: _,
+                revocation: _,
+                user: _,
             } => {
                 assert_eq!(owner, Some(Addr::unchecked(alice.clone())));
                 assert_eq!(public_metadata, Some(public_meta.clone()));
@@ -1169,6 +1243,9 @@ mod tests {
                 private_metadata_is_public_expiration,
                 token_approvals,
                 inventory_approvals,
+                edition: _,
+                revocation: _,
+                user: _,
             } => {
                 assert_eq!(owner, Some(Addr::unchecked(alice.clone())));
                 assert_eq!(public_metadata, Some(public_meta.clone()));
@@ -1279,6 +1356,9 @@ mod tests {
                 private_metadata_is_public_expiration,
                 token_approvals,
                 inventory_approvals,
+                edition: _,
+                revocation: _,
+                user: _,
             } => {
                 assert_eq!(owner, Some(Addr::unchecked(alice.clone())));
                 assert_eq!(public_metadata, Some(public_meta.clone()));
@@ -1325,6 +1405,9 @@ mod tests {
                 private_metadata_is_public_expiration,
                 token_approvals,
                 inventory_approvals,
+                edition: _,
+                revocation: _,
+                user: _,
             } => {
                 assert_eq!(owner, Some(Addr::unchecked(alice.clone())));
                 assert_eq!(public_metadata, Some(public_meta.clone()));
@@ -1479,6 +1562,9 @@ mod tests {
                 private_metadata_is_public_expiration,
                 token_approvals,
                 inventory_approvals,
+                edition: _,
+                revocation: _,
+                user: _,
             } => {
                 assert_eq!(owner, Some(Addr::unchecked(alice)));
                 assert_eq!(public_metadata, Some(public_meta.clone()));
@@ -1539,6 +1625,9 @@ mod tests {
                 private_metadata_is_public_expiration,
                 token_approvals,
                 inventory_approvals,
+                edition: _,
+                revocation: _,
+                user: _,
             } => {
                 assert!(owner.is_none());
                 assert_eq!(public_metadata, Some(public_meta));
@@ -1741,7 +1830,7 @@ mod tests {
         let query_result = query(deps.as_ref(), mock_env(), query_msg);
         let query_answer: QueryAnswer = from_binary(&query_result.unwrap()).unwrap();
         match query_answer {
-            QueryAnswer::TokenList { tokens } => {
+            QueryAnswer::TokenList { tokens, .. } => {
                 let expected = vec![
                     "NFT1".to_string(),
                     "NFT2".to_string(),
@@ -1790,7 +1879,7 @@ mod tests {
         let query_result = query(deps.as_ref(), mock_env(), query_msg);
         let query_answer: QueryAnswer = from_binary(&query_result.unwrap()).unwrap();
         match query_answer {
-            QueryAnswer::TokenList { tokens } => {
+            QueryAnswer::TokenList { tokens, .. } => {
                 assert!(tokens.is_empty());
             }
             _ => panic!("unexpected"),
@@ -1807,7 +1896,7 @@ mod tests {
         let query_result = query(deps.as_ref(), mock_env(), query_msg);
         let query_answer: QueryAnswer = from_binary(&query_result.unwrap()).unwrap();
         match query_answer {
-            QueryAnswer::TokenList { tokens } => {
+            QueryAnswer::TokenList { tokens, .. } => {
                 let expected = vec!["NFT4".to_string()];
                 assert_eq!(tokens, expected);
             }
@@ -1874,7 +1963,7 @@ mod tests {
         let query_result = query(deps.as_ref(), mock_env(), query_msg);
         let query_answer: QueryAnswer = from_binary(&query_result.unwrap()).unwrap();
         match query_answer {
-            QueryAnswer::TokenList { tokens } => {
+            QueryAnswer::TokenList { tokens, .. } => {
                 let expected = vec!["NFT1".to_string(), "NFT3".to_string()];
                 assert_eq!(tokens, expected);
             }
@@ -1892,7 +1981,7 @@ mod tests {
         let query_result = query(deps.as_ref(), mock_env(), query_msg);
         let query_answer: QueryAnswer = from_binary(&query_result.unwrap()).unwrap();
         match query_answer {
-            QueryAnswer::TokenList { tokens } => {
+            QueryAnswer::TokenList { tokens, .. } => {
                 let expected = vec!["NFT1".to_string(), "NFT3".to_string(), "NFT5".to_string()];
                 assert_eq!(tokens, expected);
             }
@@ -1910,7 +1999,7 @@ mod tests {
         let query_result = query(deps.as_ref(), mock_env(), query_msg);
         let query_answer: QueryAnswer = from_binary(&query_result.unwrap()).unwrap();
         match query_answer {
-            QueryAnswer::TokenList { tokens } => {
+            QueryAnswer::TokenList { tokens, .. } => {
                 let expected = vec!["NFT1".to_string(), "NFT2".to_string(), "NFT3".to_string()];
                 assert_eq!(tokens, expected);
             }
@@ -1951,7 +2040,7 @@ mod tests {
         let query_result = query(deps.as_ref(), mock_env(), query_msg);
         let query_answer: QueryAnswer = from_binary(&query_result.unwrap()).unwrap();
         match query_answer {
-            QueryAnswer::TokenList { tokens } => {
+            QueryAnswer::TokenList { tokens, .. } => {
                 let expected = vec![
                     "NFT1".to_string(),
                     "NFT2".to_string(),
@@ -2241,7 +2330,7 @@ mod tests {
         let query_result = query(deps.as_ref(), mock_env(), query_msg);
         let query_answer: QueryAnswer = from_binary(&query_result.unwrap()).unwrap();
         match query_answer {
-            QueryAnswer::TokenList { tokens } => {
+            QueryAnswer::TokenList { tokens, .. } => {
                 assert!(tokens.is_empty());
             }
             _ => panic!("unexpected"),
@@ -2258,7 +2347,7 @@ mod tests {
         let query_result = query(deps.as_ref(), mock_env(), query_msg);
         let query_answer: QueryAnswer = from_binary(&query_result.unwrap()).unwrap();
         match query_answer {
-            QueryAnswer::TokenList { tokens } => {
+            QueryAnswer::TokenList { tokens, .. } => {
                 let expected = vec!["NFT9".to_string()];
                 assert_eq!(tokens, expected);
             }
@@ -5036,6 +5125,9 @@ mod tests {
                 private_metadata_is_public_expiration: None,
                 token_approvals: Some(Vec::new()),
                 inventory_approvals: Some(Vec::new()),
+                edition: None,
+                revocation: None,
+                user: None,
             },
             BatchNftDossierElement {
                 token_id: "NFT2".to_string(),
@@ -5053,6 +5145,9 @@ mod tests {
                 private_metadata_is_public_expiration: None,
                 token_approvals: Some(Vec::new()),
                 inventory_approvals: Some(Vec::new()),
+                edition: None,
+                revocation: None,
+                user: None,
             },
             // last one belongs to bob, so you can only see public info
             BatchNftDossierElement {
@@ -5073,6 +5168,9 @@ mod tests {
                 private_metadata_is_public_expiration: None,
                 token_approvals: None,
                 inventory_approvals: None,
+                edition: None,
+                revocation: None,
+                user: None,
             },
         ];
         let query_result = query(deps.as_ref(), mock_env(), query_msg);
@@ -5084,4 +5182,161 @@ mod tests {
             _ => panic!("unexpected"),
         }
     }
+
+    // test burn tombstones and the burned count
+    #[test]
+    fn test_burned_tokens() {
+        let (init_result, mut deps) =
+            init_helper_with_config(false, false, false, false, false, false, true);
+        assert!(
+            init_result.is_ok(),
+            "Init failed: {}",
+            init_result.err().unwrap()
+        );
+        let mint = |token_id: &str| ExecuteMsg::MintNft {
+            token_id: Some(token_id.to_string()),
+            owner: Some("alice".to_string()),
+            public_metadata: None,
+            private_metadata: None,
+            royalty_info: None,
+            serial_number: None,
+            transferable: None,
+            memo: None,
+            padding: None,
+        };
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("admin", &[]),
+            mint("NFT1"),
+        )
+        .unwrap();
+        let execute_msg = ExecuteMsg::BurnNft {
+            token_id: "NFT1".to_string(),
+            memo: None,
+            padding: None,
+        };
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("alice", &[]),
+            execute_msg,
+        )
+        .unwrap();
+        for addr in ["alice", "bob", "admin"] {
+            let execute_msg = ExecuteMsg::SetViewingKey {
+                key: "key".to_string(),
+                padding: None,
+            };
+            execute(deps.as_mut(), mock_env(), mock_info(addr, &[]), execute_msg).unwrap();
+        }
+        let viewer = |address: &str| ViewerInfo {
+            address: address.to_string(),
+            viewing_key: "key".to_string(),
+        };
+
+        // test the burned count
+        let query_msg = QueryMsg::NumBurned {
+            viewer: Some(viewer("bob")),
+        };
+        let error = extract_error_msg(query(deps.as_ref(), mock_env(), query_msg));
+        assert!(error.contains("The token supply of this contract is private"));
+        let query_msg = QueryMsg::NumBurned {
+            viewer: Some(viewer("admin")),
+        };
+        let query_result = query(deps.as_ref(), mock_env(), query_msg).unwrap();
+        match from_binary(&query_result).unwrap() {
+            QueryAnswer::NumBurned { count } => assert_eq!(count, 1),
+            _ => panic!("unexpected"),
+        }
+        // tokens burned before the contract was upgraded are counted too
+        let mut config: Config = load(&deps.storage, CONFIG_KEY).unwrap();
+        config.mint_cnt += 2;
+        save(&mut deps.storage, CONFIG_KEY, &config).unwrap();
+        let query_msg = QueryMsg::NumBurned {
+            viewer: Some(viewer("admin")),
+        };
+        let query_result = query(deps.as_ref(), mock_env(), query_msg).unwrap();
+        match from_binary(&query_result).unwrap() {
+            QueryAnswer::NumBurned { count } => assert_eq!(count, 3),
+            _ => panic!("unexpected"),
+        }
+
+        // the previous owner and minters see the tombstone
+        for addr in ["alice", "admin"] {
+            let query_msg = QueryMsg::OwnerOf {
+                token_id: "NFT1".to_string(),
+                viewer: Some(viewer(addr)),
+                include_expired: None,
+            };
+            let query_result = query(deps.as_ref(), mock_env(), query_msg).unwrap();
+            match from_binary(&query_result).unwrap() {
+                QueryAnswer::BurnedToken {
+                    token_id,
+                    owner,
+                    burner,
+                    block_height,
+                    block_time,
+                } => {
+                    assert_eq!(token_id, "NFT1".to_string());
+                    assert_eq!(owner, Addr::unchecked("alice"));
+                    assert_eq!(burner, None);
+                    assert_eq!(block_height, mock_env().block.height);
+                    assert_eq!(block_time, mock_env().block.time.seconds());
+                }
+                _ => panic!("unexpected"),
+            }
+        }
+        let query_msg = QueryMsg::NftDossier {
+            token_id: "NFT1".to_string(),
+            viewer: Some(viewer("alice")),
+            include_expired: None,
+        };
+        let query_result = query(deps.as_ref(), mock_env(), query_msg).unwrap();
+        assert!(matches!(
+            from_binary(&query_result).unwrap(),
+            QueryAnswer::BurnedToken { .. }
+        ));
+        // other addresses can not tell a burned token from one that never existed
+        let errors: Vec<(String, String)> = ["NFT1", "NFT9"]
+            .iter()
+            .map(|token_id| {
+                let query_msg = QueryMsg::NftDossier {
+                    token_id: token_id.to_string(),
+                    viewer: Some(viewer("bob")),
+                    include_expired: None,
+                };
+                let dossier = extract_error_msg(query(deps.as_ref(), mock_env(), query_msg))
+                    .replace(token_id, "");
+                let query_msg = QueryMsg::OwnerOf {
+                    token_id: token_id.to_string(),
+                    viewer: None,
+                    include_expired: None,
+                };
+                let owner = extract_error_msg(query(deps.as_ref(), mock_env(), query_msg))
+                    .replace(token_id, "");
+                (dossier, owner)
+            })
+            .collect();
+        assert_eq!(errors[0], errors[1]);
+
+        // minting the id again removes the tombstone
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("admin", &[]),
+            mint("NFT1"),
+        )
+        .unwrap();
+        let query_msg = QueryMsg::OwnerOf {
+            token_id: "NFT1".to_string(),
+            viewer: Some(viewer("alice")),
+            include_expired: None,
+        };
+        let query_result = query(deps.as_ref(), mock_env(), query_msg).unwrap();
+        match from_binary(&query_result).unwrap() {
+            QueryAnswer::OwnerOf { owner, .. } => assert_eq!(owner, Addr::unchecked("alice")),
+            _ => panic!("unexpected"),
+        }
+    }
 }
@@ -0,0 +1,471 @@
+#[cfg(test)]
+mod tests {
+    use std::any::Any;
+
+    use cosmwasm_std::testing::*;
+    use cosmwasm_std::{from_binary, Addr, OwnedDeps, Response, StdError, StdResult};
+
+    use crate::contract::{execute, instantiate, query};
+    use crate::expiration::Expiration;
+    use crate::msg::{
+        ExecuteAnswer, ExecuteMsg, InstantiateConfig, InstantiateMsg, QueryAnswer, QueryMsg,
+        TxAction, ViewerInfo,
+    };
+    use crate::recipe::{Recipe, RecipeInput};
+    use crate::token::{Extension, Metadata, Trait};
+
+    // Helper functions
+
+    fn init_helper_default() -> (
+        StdResult<Response>,
+        OwnedDeps<MockStorage, MockApi, MockQuerier>,
+    ) {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+        let info = mock_info("instantiator", &[]);
+        let init_msg = InstantiateMsg {
+            name: "sec721".to_string(),
+            symbol: "S721".to_string(),
+            admin: Some("admin".to_string()),
+            entropy: "We're going to need a bigger boat".to_string(),
+            royalty_info: None,
+            config: Some(InstantiateConfig {
+                enable_burn: Some(true),
+                ..InstantiateConfig::default()
+            }),
+            post_init_callback: None,
+        };
+
+        (instantiate(deps.as_mut(), env, info, init_msg), deps)
+    }
+
+    fn extract_error_msg<T: Any>(error: StdResult<T>) -> String {
+        match error {
+            Ok(_response) => panic!("Expected error, but had Ok response"),
+            Err(err) => match err {
+                StdError::GenericErr { msg, .. } => msg,
+                _ => panic!("Unexpected error result {:?}", err),
+            },
+        }
+    }
+
+    fn named(name: &str) -> Metadata {
+        Metadata {
+            token_uri: None,
+            extension: Some(Extension {
+                name: Some(name.to_string()),
+                attributes: Some(vec![Trait {
+                    display_type: None,
+                    trait_type: Some("element".to_string()),
+                    value: name.to_string(),
+                    max_value: None,
+                }]),
+                ..Extension::default()
+            }),
+        }
+    }
+
+    fn mint(
+        deps: &mut OwnedDeps<MockStorage, MockApi, MockQuerier>,
+        token_id: &str,
+        owner: &str,
+        public_metadata: Option<Metadata>,
+    ) {
+        let execute_msg = ExecuteMsg::MintNft {
+            token_id: Some(token_id.to_string()),
+            owner: Some(owner.to_string()),
+            public_metadata,
+            private_metadata: None,
+            royalty_info: None,
+            serial_number: None,
+            transferable: None,
+            memo: None,
+            padding: None,
+        };
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("admin", &[]),
+            execute_msg,
+        )
+        .unwrap();
+    }
+
+    fn redeem(
+        deps: &mut OwnedDeps<MockStorage, MockApi, MockQuerier>,
+        sender: &str,
+        token_ids: &[&str],
+    ) -> StdResult<Response> {
+        let execute_msg = ExecuteMsg::Redeem {
+            recipe: "potion".to_string(),
+            token_ids: token_ids.iter().map(|t| t.to_string()).collect(),
+            memo: Some("brew".to_string()),
+            padding: None,
+        };
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(sender, &[]),
+            execute_msg,
+        )
+    }
+
+    fn potion() -> Recipe {
+        Recipe {
+            name: "potion".to_string(),
+            inputs: vec![
+                RecipeInput::TokenId {
+                    token_id: "cauldron".to_string(),
+                },
+                RecipeInput::MintRun {
+                    mint_run_id: "herbs".to_string(),
+                    mint_run: None,
+                },
+                RecipeInput::Trait {
+                    trait_type: "element".to_string(),
+                    value: "fire".to_string(),
+                },
+            ],
+            public_metadata: Some(named("potion")),
+            private_metadata: Some(named("secret formula")),
+            royalty_info: None,
+        }
+    }
+
+    #[test]
+    fn test_set_recipes() {
+        let (init_result, mut deps) = init_helper_default();
+        assert!(
+            init_result.is_ok(),
+            "Init failed: {}",
+            init_result.err().unwrap()
+        );
+
+        // test non admin
+        let execute_msg = ExecuteMsg::SetRecipes {
+            recipes: vec![potion()],
+            padding: None,
+        };
+        let handle_result = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("alice", &[]),
+            execute_msg,
+        );
+        let error = extract_error_msg(handle_result);
+        assert!(
+            error.contains("This is an admin command and can only be run from the admin address")
+        );
+
+        // test duplicate names
+        let execute_msg = ExecuteMsg::SetRecipes {
+            recipes: vec![potion(), potion()],
+            padding: None,
+        };
+        let handle_result = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("admin", &[]),
+            execute_msg,
+        );
+        let error = extract_error_msg(handle_result);
+        assert!(error.contains("Recipe name potion is used more than once"));
+
+        // test no inputs
+        let mut empty = potion();
+        empty.inputs.clear();
+        let execute_msg = ExecuteMsg::SetRecipes {
+            recipes: vec![empty],
+            padding: None,
+        };
+        let handle_result = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("admin", &[]),
+            execute_msg,
+        );
+        let error = extract_error_msg(handle_result);
+        assert!(error.contains("Recipe potion must have at least one input"));
+
+        let execute_msg = ExecuteMsg::SetRecipes {
+            recipes: vec![potion()],
+            padding: None,
+        };
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("admin", &[]),
+            execute_msg,
+        )
+        .unwrap();
+
+        // the private metadata of the output is not displayed
+        let query_result = query(deps.as_ref(), mock_env(), QueryMsg::Recipes {}).unwrap();
+        let mut expected = potion();
+        expected.private_metadata = None;
+        match from_binary(&query_result).unwrap() {
+            QueryAnswer::Recipes { recipes } => assert_eq!(recipes, vec![expected]),
+            _ => panic!("unexpected"),
+        }
+    }
+
+    #[test]
+    fn test_redeem() {
+        let (init_result, mut deps) = init_helper_default();
+        assert!(
+            init_result.is_ok(),
+            "Init failed: {}",
+            init_result.err().unwrap()
+        );
+        let execute_msg = ExecuteMsg::SetRecipes {
+            recipes: vec![potion()],
+            padding: None,
+        };
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("admin", &[]),
+            execute_msg,
+        )
+        .unwrap();
+        mint(&mut deps, "cauldron", "alice", None);
+        let execute_msg = ExecuteMsg::MintNftClones {
+            mint_run_id: Some("herbs".to_string()),
+            quantity: 2,
+            owner: Some("alice".to_string()),
+            public_metadata: None,
+            private_metadata: None,
+            serial_overrides: None,
+            royalty_info: None,
+            memo: None,
+            padding: None,
+        };
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("admin", &[]),
+            execute_msg,
+        )
+        .unwrap();
+        mint(&mut deps, "ember", "alice", Some(named("fire")));
+        mint(&mut deps, "puddle", "alice", Some(named("water")));
+        mint(&mut deps, "spark", "bob", Some(named("fire")));
+
+        // test unknown recipe
+        let execute_msg = ExecuteMsg::Redeem {
+            recipe: "elixir".to_string(),
+            token_ids: vec![],
+            memo: None,
+            padding: None,
+        };
+        let handle_result = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("alice", &[]),
+            execute_msg,
+        );
+        let error = extract_error_msg(handle_result);
+        assert!(error.contains("There is no recipe named elixir"));
+
+        // test wrong number of tokens
+        let error = extract_error_msg(redeem(&mut deps, "alice", &["cauldron", "1"]));
+        assert!(error.contains("Recipe potion requires 3 tokens"));
+
+        // test a token the sender does not own
+        let error = extract_error_msg(redeem(&mut deps, "alice", &["cauldron", "1", "spark"]));
+        assert!(error.contains("You do not own token spark"));
+        let error = extract_error_msg(redeem(&mut deps, "alice", &["cauldron", "1", "nothing"]));
+        assert!(error.contains("You do not own token nothing"));
+
+        // test unsatisfied inputs
+        let error = extract_error_msg(redeem(&mut deps, "alice", &["cauldron", "ember", "ember"]));
+        assert!(error.contains("Token ID: ember does not satisfy input 2 of recipe potion"));
+        let error = extract_error_msg(redeem(&mut deps, "alice", &["cauldron", "2", "puddle"]));
+        assert!(error.contains("Token ID: puddle does not satisfy input 3 of recipe potion"));
+        let error = extract_error_msg(redeem(&mut deps, "alice", &["cauldron", "1", "cauldron"]));
+        assert!(error.contains("Token ID: cauldron is listed more than once"));
+
+        let handle_result = redeem(&mut deps, "alice", &["cauldron", "2", "ember"]).unwrap();
+        let token_id = match from_binary(handle_result.data.as_ref().unwrap()).unwrap() {
+            ExecuteAnswer::Redeem { token_id } => token_id,
+            _ => panic!("unexpected"),
+        };
+        assert_eq!(token_id, "6");
+
+        // the inputs are burned
+        let error = extract_error_msg(redeem(&mut deps, "alice", &["cauldron", "1", "spark"]));
+        assert!(error.contains("You do not own token cauldron"));
+
+        // the output is minted to the sender
+        let query_msg = QueryMsg::OwnerOf {
+            token_id: "6".to_string(),
+            viewer: None,
+            include_expired: None,
+        };
+        let error = extract_error_msg(query(deps.as_ref(), mock_env(), query_msg));
+        assert!(error.contains("You are not authorized to view the owner of token 6"));
+        let execute_msg = ExecuteMsg::SetViewingKey {
+            key: "key".to_string(),
+            padding: None,
+        };
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("alice", &[]),
+            execute_msg,
+        )
+        .unwrap();
+        let query_msg = QueryMsg::PrivateMetadata {
+            token_id: "6".to_string(),
+            viewer: Some(ViewerInfo {
+                address: "alice".to_string(),
+                viewing_key: "key".to_string(),
+            }),
+        };
+        let query_result = query(deps.as_ref(), mock_env(), query_msg).unwrap();
+        match from_binary(&query_result).unwrap() {
+            QueryAnswer::PrivateMetadata { extension, .. } => {
+                assert_eq!(extension.unwrap().name, Some("secret formula".to_string()))
+            }
+            _ => panic!("unexpected"),
+        }
+
+        // both the burns and the mint are recorded
+        let query_msg = QueryMsg::TransactionHistory {
+            address: "alice".to_string(),
+            viewing_key: "key".to_string(),
+            page: None,
+            page_size: None,
+        };
+        let query_result = query(deps.as_ref(), mock_env(), query_msg).unwrap();
+        match from_binary(&query_result).unwrap() {
+            QueryAnswer::TransactionHistory { txs, .. } => {
+                assert_eq!(txs[0].token_id, "6".to_string());
+                assert_eq!(
+                    txs[0].action,
+                    TxAction::Mint {
+                        minter: Addr::unchecked("alice"),
+                        recipient: Addr::unchecked("alice"),
+                    }
+                );
+                assert_eq!(txs[0].memo, Some("brew".to_string()));
+                let burned: Vec<&str> = txs[1..4].iter().map(|t| t.token_id.as_str()).collect();
+                assert_eq!(burned, vec!["ember", "2", "cauldron"]);
+                for tx in txs[1..4].iter() {
+                    assert_eq!(
+                        tx.action,
+                        TxAction::Burn {
+                            owner: Addr::unchecked("alice"),
+                            burner: None,
+                        }
+                    );
+                }
+            }
+            _ => panic!("unexpected"),
+        }
+    }
+
+    #[test]
+    fn test_redeem_sealed_without_burn() {
+        let mut deps = mock_dependencies();
+        let init_msg = InstantiateMsg {
+            name: "sec721".to_string(),
+            symbol: "S721".to_string(),
+            admin: Some("admin".to_string()),
+            entropy: "We're going to need a bigger boat".to_string(),
+            royalty_info: None,
+            config: Some(InstantiateConfig {
+                enable_sealed_metadata: Some(true),
+                ..InstantiateConfig::default()
+            }),
+            post_init_callback: None,
+        };
+        instantiate(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("instantiator", &[]),
+            init_msg,
+        )
+        .unwrap();
+        let recipe = Recipe {
+            name: "potion".to_string(),
+            inputs: vec![RecipeInput::Trait {
+                trait_type: "element".to_string(),
+                value: "fire".to_string(),
+            }],
+            public_metadata: Some(named("potion")),
+            private_metadata: None,
+            royalty_info: None,
+        };
+        let execute_msg = ExecuteMsg::SetRecipes {
+            recipes: vec![recipe],
+            padding: None,
+        };
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("admin", &[]),
+            execute_msg,
+        )
+        .unwrap();
+        let execute_msg = ExecuteMsg::MintNft {
+            token_id: Some("box".to_string()),
+            owner: Some("alice".to_string()),
+            public_metadata: Some(named("mystery")),
+            private_metadata: Some(named("fire")),
+            royalty_info: None,
+            serial_number: None,
+            transferable: None,
+            memo: None,
+            padding: None,
+        };
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("admin", &[]),
+            execute_msg,
+        )
+        .unwrap();
+
+        // the sealed trait does not count until it is revealed
+        let error = extract_error_msg(redeem(&mut deps, "alice", &["box"]));
+        assert!(error.contains("Token ID: box does not satisfy input 1 of recipe potion"));
+
+        // a scheduled reveal displays the sealed metadata without rewriting the token
+        let execute_msg = ExecuteMsg::ScheduleReveal {
+            at: Some(Expiration::AtHeight(1)),
+            padding: None,
+        };
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("admin", &[]),
+            execute_msg,
+        )
+        .unwrap();
+
+        // redeeming burns the inputs even though burn is not enabled
+        let handle_result = redeem(&mut deps, "alice", &["box"]).unwrap();
+        let token_id = match from_binary(handle_result.data.as_ref().unwrap()).unwrap() {
+            ExecuteAnswer::Redeem { token_id } => token_id,
+            _ => panic!("unexpected"),
+        };
+        assert_eq!(token_id, "1");
+        let error = extract_error_msg(redeem(&mut deps, "alice", &["box"]));
+        assert!(error.contains("You do not own token box"));
+
+        // burn messages are still disabled
+        let execute_msg = ExecuteMsg::BurnNft {
+            token_id: token_id.clone(),
+            memo: None,
+            padding: None,
+        };
+        let error = extract_error_msg(execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("alice", &[]),
+            execute_msg,
+        ));
+        assert!(error.contains("Burn functionality is not enabled for this token"));
+    }
+}
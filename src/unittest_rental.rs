@@ -0,0 +1,271 @@
+#[cfg(test)]
+mod tests {
+    use std::any::Any;
+
+    use cosmwasm_std::testing::*;
+    use cosmwasm_std::{from_binary, Addr, Binary, Env, OwnedDeps, Response, StdError, StdResult};
+
+    use crate::contract::{execute, instantiate, query};
+    use crate::expiration::Expiration;
+    use crate::msg::{ExecuteMsg, InstantiateMsg, QueryAnswer, QueryMsg, ViewerInfo};
+    use crate::rental::TokenUser;
+    use crate::token::{Extension, Metadata};
+
+    // Helper functions
+
+    fn init_helper_default() -> (
+        StdResult<Response>,
+        OwnedDeps<MockStorage, MockApi, MockQuerier>,
+    ) {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+        let info = mock_info("instantiator", &[]);
+        let init_msg = InstantiateMsg {
+            name: "sec721".to_string(),
+            symbol: "S721".to_string(),
+            admin: Some("admin".to_string()),
+            entropy: "We're going to need a bigger boat".to_string(),
+            royalty_info: None,
+            config: None,
+            post_init_callback: None,
+        };
+
+        (instantiate(deps.as_mut(), env, info, init_msg), deps)
+    }
+
+    fn extract_error_msg<T: Any>(error: StdResult<T>) -> String {
+        match error {
+            Ok(_response) => panic!("Expected error, but had Ok response"),
+            Err(err) => match err {
+                StdError::GenericErr { msg, .. } => msg,
+                _ => panic!("Unexpected error result {:?}", err),
+            },
+        }
+    }
+
+    fn set_user(
+        deps: &mut OwnedDeps<MockStorage, MockApi, MockQuerier>,
+        sender: &str,
+        user: Option<&str>,
+        expires: Option<Expiration>,
+    ) -> StdResult<Response> {
+        let execute_msg = ExecuteMsg::SetUser {
+            token_id: "NFT1".to_string(),
+            user: user.map(|u| u.to_string()),
+            expires,
+            padding: None,
+        };
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(sender, &[]),
+            execute_msg,
+        )
+    }
+
+    fn viewer(address: &str) -> Option<ViewerInfo> {
+        Some(ViewerInfo {
+            address: address.to_string(),
+            viewing_key: "key".to_string(),
+        })
+    }
+
+    fn user_of(
+        deps: &OwnedDeps<MockStorage, MockApi, MockQuerier>,
+        address: &str,
+    ) -> StdResult<(Option<Addr>, Option<Expiration>)> {
+        let query_msg = QueryMsg::UserOf {
+            token_id: "NFT1".to_string(),
+            viewer: viewer(address),
+        };
+        let query_result = query(deps.as_ref(), mock_env(), query_msg)?;
+        match from_binary(&query_result).unwrap() {
+            QueryAnswer::UserOf { user, expires } => Ok((user, expires)),
+            _ => panic!("unexpected"),
+        }
+    }
+
+    fn owner_of(
+        deps: &OwnedDeps<MockStorage, MockApi, MockQuerier>,
+        env: Env,
+        address: &str,
+    ) -> StdResult<Binary> {
+        let query_msg = QueryMsg::OwnerOf {
+            token_id: "NFT1".to_string(),
+            viewer: viewer(address),
+            include_expired: None,
+        };
+        query(deps.as_ref(), env, query_msg)
+    }
+
+    #[test]
+    fn test_set_user() {
+        let (init_result, mut deps) = init_helper_default();
+        assert!(
+            init_result.is_ok(),
+            "Init failed: {}",
+            init_result.err().unwrap()
+        );
+        let execute_msg = ExecuteMsg::MintNft {
+            token_id: Some("NFT1".to_string()),
+            owner: Some("alice".to_string()),
+            public_metadata: None,
+            private_metadata: Some(Metadata {
+                token_uri: None,
+                extension: Some(Extension {
+                    name: Some("secret".to_string()),
+                    ..Extension::default()
+                }),
+            }),
+            royalty_info: None,
+            serial_number: None,
+            transferable: None,
+            memo: None,
+            padding: None,
+        };
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("admin", &[]),
+            execute_msg,
+        )
+        .unwrap();
+        for addr in ["alice", "bob", "carol", "dave"] {
+            let execute_msg = ExecuteMsg::SetViewingKey {
+                key: "key".to_string(),
+                padding: None,
+            };
+            execute(deps.as_mut(), mock_env(), mock_info(addr, &[]), execute_msg).unwrap();
+        }
+        let height = mock_env().block.height;
+        let expires = Expiration::AtHeight(height + 100);
+
+        // test an address without transfer approval
+        let error = extract_error_msg(set_user(&mut deps, "bob", Some("carol"), Some(expires)));
+        assert!(error.contains("You are not authorized to perform this action on token NFT1"));
+
+        // test an expiration that already passed
+        let error = extract_error_msg(set_user(
+            &mut deps,
+            "alice",
+            Some("carol"),
+            Some(Expiration::AtHeight(1)),
+        ));
+        assert!(error.contains("The rental expiration has already passed"));
+
+        // transfer-approved operators may rent out the token
+        let execute_msg = ExecuteMsg::Approve {
+            spender: "bob".to_string(),
+            token_id: "NFT1".to_string(),
+            expires: None,
+            padding: None,
+        };
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("alice", &[]),
+            execute_msg,
+        )
+        .unwrap();
+        set_user(&mut deps, "bob", Some("carol"), Some(expires)).unwrap();
+
+        // the user may view the owner, the private metadata, and the user
+        assert_eq!(
+            user_of(&deps, "carol").unwrap(),
+            (Some(Addr::unchecked("carol")), Some(expires))
+        );
+        assert_eq!(
+            user_of(&deps, "alice").unwrap(),
+            (Some(Addr::unchecked("carol")), Some(expires))
+        );
+        let error = extract_error_msg(user_of(&deps, "dave"));
+        assert!(error.contains("You are not authorized to view the user of token NFT1"));
+        let query_result = owner_of(&deps, mock_env(), "carol").unwrap();
+        match from_binary(&query_result).unwrap() {
+            QueryAnswer::OwnerOf { owner, .. } => assert_eq!(owner, Addr::unchecked("alice")),
+            _ => panic!("unexpected"),
+        }
+        let query_msg = QueryMsg::PrivateMetadata {
+            token_id: "NFT1".to_string(),
+            viewer: viewer("carol"),
+        };
+        let query_result = query(deps.as_ref(), mock_env(), query_msg).unwrap();
+        match from_binary(&query_result).unwrap() {
+            QueryAnswer::PrivateMetadata { extension, .. } => {
+                assert_eq!(extension.unwrap().name, Some("secret".to_string()))
+            }
+            _ => panic!("unexpected"),
+        }
+        let query_msg = QueryMsg::NftDossier {
+            token_id: "NFT1".to_string(),
+            viewer: viewer("carol"),
+            include_expired: None,
+        };
+        let query_result = query(deps.as_ref(), mock_env(), query_msg).unwrap();
+        match from_binary(&query_result).unwrap() {
+            QueryAnswer::NftDossier {
+                user,
+                token_approvals,
+                ..
+            } => {
+                assert_eq!(
+                    user,
+                    Some(TokenUser {
+                        user: Addr::unchecked("carol"),
+                        expires,
+                    })
+                );
+                // the user does not see the owner's approvals
+                assert!(token_approvals.is_none());
+            }
+            _ => panic!("unexpected"),
+        }
+
+        // the user may not transfer the token
+        let execute_msg = ExecuteMsg::TransferNft {
+            recipient: "carol".to_string(),
+            token_id: "NFT1".to_string(),
+            memo: None,
+            padding: None,
+        };
+        let handle_result = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("carol", &[]),
+            execute_msg,
+        );
+        let error = extract_error_msg(handle_result);
+        assert!(error.contains("You are not authorized to perform this action on token NFT1"));
+
+        // the access ends when the rental expires
+        let mut env = mock_env();
+        env.block.height = height + 100;
+        let error = extract_error_msg(owner_of(&deps, env, "carol"));
+        assert!(error.contains("You are not authorized to view the owner of token NFT1"));
+
+        // removing the user
+        set_user(&mut deps, "alice", None, None).unwrap();
+        assert_eq!(user_of(&deps, "alice").unwrap(), (None, None));
+        let error = extract_error_msg(owner_of(&deps, mock_env(), "carol"));
+        assert!(error.contains("You are not authorized to view the owner of token NFT1"));
+
+        // the user is cleared on transfer
+        set_user(&mut deps, "alice", Some("carol"), None).unwrap();
+        let execute_msg = ExecuteMsg::TransferNft {
+            recipient: "dave".to_string(),
+            token_id: "NFT1".to_string(),
+            memo: None,
+            padding: None,
+        };
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("alice", &[]),
+            execute_msg,
+        )
+        .unwrap();
+        assert_eq!(user_of(&deps, "dave").unwrap(), (None, None));
+        let error = extract_error_msg(owner_of(&deps, mock_env(), "carol"));
+        assert!(error.contains("You are not authorized to view the owner of token NFT1"));
+    }
+}
@@ -0,0 +1,516 @@
+#[cfg(test)]
+mod tests {
+    use std::any::Any;
+
+    use cosmwasm_std::testing::*;
+    use cosmwasm_std::{
+        from_binary, Binary, Env, OwnedDeps, Response, StdError, StdResult, Timestamp,
+    };
+
+    use crate::contract::{execute, instantiate, query};
+    use crate::expiration::Expiration;
+    use crate::msg::{ExecuteMsg, InstantiateConfig, InstantiateMsg, Mint, QueryAnswer, QueryMsg};
+    use crate::reveal_pool::{chain_hash, shuffled_position};
+    use crate::sale::PoolEntry;
+    use crate::token::Metadata;
+    use secret_toolkit::crypto::sha_256;
+
+    const SEED: &[u8] = b"shuffle";
+
+    // Helper functions
+
+    fn init_helper_sealed() -> (
+        StdResult<Response>,
+        OwnedDeps<MockStorage, MockApi, MockQuerier>,
+    ) {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+        let info = mock_info("instantiator", &[]);
+        let init_msg = InstantiateMsg {
+            name: "sec721".to_string(),
+            symbol: "S721".to_string(),
+            admin: Some("admin".to_string()),
+            entropy: "We're going to need a bigger boat".to_string(),
+            royalty_info: None,
+            config: Some(InstantiateConfig {
+                public_token_supply: Some(true),
+                public_owner: Some(true),
+                enable_sealed_metadata: Some(true),
+                ..InstantiateConfig::default()
+            }),
+            post_init_callback: None,
+        };
+
+        (instantiate(deps.as_mut(), env, info, init_msg), deps)
+    }
+
+    fn extract_error_msg<T: Any>(error: StdResult<T>) -> String {
+        match error {
+            Ok(_response) => panic!("Expected error, but had Ok response"),
+            Err(err) => match err {
+                StdError::GenericErr { msg, .. } => msg,
+                _ => panic!("Unexpected error result {:?}", err),
+            },
+        }
+    }
+
+    fn uri_meta(uri: &str) -> Option<Metadata> {
+        Some(Metadata {
+            token_uri: Some(uri.to_string()),
+            extension: None,
+        })
+    }
+
+    fn items() -> Vec<PoolEntry> {
+        ["dragon", "knight", "wizard"]
+            .iter()
+            .map(|name| PoolEntry {
+                public_metadata: uri_meta(name),
+                private_metadata: uri_meta(&format!("{} secret", name)),
+            })
+            .collect()
+    }
+
+    fn provenance(items: &[PoolEntry]) -> Binary {
+        let mut hash: Vec<u8> = Vec::new();
+        for item in items.iter() {
+            hash = chain_hash(&hash, item).unwrap().to_vec();
+        }
+        Binary(hash)
+    }
+
+    fn commit(
+        deps: &mut OwnedDeps<MockStorage, MockApi, MockQuerier>,
+        provenance_hash: Binary,
+        size: u32,
+    ) -> StdResult<Response> {
+        let execute_msg = ExecuteMsg::CommitRevealPool {
+            provenance_hash,
+            size,
+            seed_hash: Binary(sha_256(SEED).to_vec()),
+            padding: None,
+        };
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("admin", &[]),
+            execute_msg,
+        )
+    }
+
+    fn load_pool(
+        deps: &mut OwnedDeps<MockStorage, MockApi, MockQuerier>,
+        metadata: Vec<PoolEntry>,
+    ) -> StdResult<Response> {
+        let execute_msg = ExecuteMsg::LoadRevealPool {
+            metadata,
+            padding: None,
+        };
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("admin", &[]),
+            execute_msg,
+        )
+    }
+
+    fn reveal_seed(
+        deps: &mut OwnedDeps<MockStorage, MockApi, MockQuerier>,
+        sender: &str,
+        seed: &[u8],
+    ) -> StdResult<Response> {
+        let execute_msg = ExecuteMsg::RevealSeed {
+            seed: Binary(seed.to_vec()),
+            padding: None,
+        };
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(sender, &[]),
+            execute_msg,
+        )
+    }
+
+    fn reveal(
+        deps: &mut OwnedDeps<MockStorage, MockApi, MockQuerier>,
+        token_id: &str,
+    ) -> StdResult<Response> {
+        let execute_msg = ExecuteMsg::Reveal {
+            token_id: token_id.to_string(),
+            padding: None,
+        };
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("alice", &[]),
+            execute_msg,
+        )
+    }
+
+    fn reveal_pool(
+        deps: &OwnedDeps<MockStorage, MockApi, MockQuerier>,
+    ) -> (Option<Binary>, u32, u32, u32) {
+        let query_result = query(deps.as_ref(), mock_env(), QueryMsg::RevealPool {}).unwrap();
+        match from_binary(&query_result).unwrap() {
+            QueryAnswer::RevealPool {
+                provenance_hash,
+                size,
+                loaded,
+                remaining,
+                ..
+            } => (provenance_hash, size, loaded, remaining),
+            _ => panic!("unexpected"),
+        }
+    }
+
+    fn seed_status(deps: &OwnedDeps<MockStorage, MockApi, MockQuerier>) -> (Option<Binary>, bool) {
+        let query_result = query(deps.as_ref(), mock_env(), QueryMsg::RevealPool {}).unwrap();
+        match from_binary(&query_result).unwrap() {
+            QueryAnswer::RevealPool {
+                seed_hash,
+                seed_revealed,
+                ..
+            } => (seed_hash, seed_revealed),
+            _ => panic!("unexpected"),
+        }
+    }
+
+    fn env_at(seconds: u64) -> Env {
+        let mut env = mock_env();
+        env.block.time = Timestamp::from_seconds(seconds);
+        env
+    }
+
+    fn schedule_reveal(
+        deps: &mut OwnedDeps<MockStorage, MockApi, MockQuerier>,
+        sender: &str,
+        at: Option<Expiration>,
+        env: Env,
+    ) -> StdResult<Response> {
+        let execute_msg = ExecuteMsg::ScheduleReveal { at, padding: None };
+        execute(deps.as_mut(), env, mock_info(sender, &[]), execute_msg)
+    }
+
+    fn public_uri(deps: &OwnedDeps<MockStorage, MockApi, MockQuerier>, env: Env) -> String {
+        let query_msg = QueryMsg::NftInfo {
+            token_id: "BOX1".to_string(),
+        };
+        let query_result = query(deps.as_ref(), env, query_msg).unwrap();
+        match from_binary(&query_result).unwrap() {
+            QueryAnswer::NftInfo { token_uri, .. } => token_uri.unwrap(),
+            _ => panic!("unexpected"),
+        }
+    }
+
+    fn is_unwrapped(deps: &OwnedDeps<MockStorage, MockApi, MockQuerier>, env: Env) -> bool {
+        let query_msg = QueryMsg::IsUnwrapped {
+            token_id: "BOX1".to_string(),
+        };
+        let query_result = query(deps.as_ref(), env, query_msg).unwrap();
+        match from_binary(&query_result).unwrap() {
+            QueryAnswer::IsUnwrapped { token_is_unwrapped } => token_is_unwrapped,
+            _ => panic!("unexpected"),
+        }
+    }
+
+    #[test]
+    fn test_reveal_pool() {
+        let (init_result, mut deps) = init_helper_sealed();
+        assert!(
+            init_result.is_ok(),
+            "Init failed: {}",
+            init_result.err().unwrap()
+        );
+        assert_eq!(reveal_pool(&deps), (None, 0, 0, 0));
+        let mints = ["BOX1", "BOX2", "BOX3", "BOX4"]
+            .iter()
+            .map(|id| Mint {
+                token_id: Some(id.to_string()),
+                owner: Some("alice".to_string()),
+                public_metadata: uri_meta("mystery box"),
+                private_metadata: uri_meta("placeholder"),
+                serial_number: None,
+                royalty_info: None,
+                transferable: None,
+                memo: None,
+            })
+            .collect();
+        let execute_msg = ExecuteMsg::BatchMintNft {
+            mints,
+            padding: None,
+        };
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("admin", &[]),
+            execute_msg,
+        )
+        .unwrap();
+
+        // test non admin commit
+        let execute_msg = ExecuteMsg::CommitRevealPool {
+            provenance_hash: provenance(&items()),
+            size: 3,
+            seed_hash: Binary(sha_256(SEED).to_vec()),
+            padding: None,
+        };
+        let error = extract_error_msg(execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("alice", &[]),
+            execute_msg,
+        ));
+        assert!(
+            error.contains("This is an admin command and can only be run from the admin address")
+        );
+        let error = extract_error_msg(load_pool(&mut deps, items()));
+        assert!(error.contains("There is no committed reveal pool"));
+        let error = extract_error_msg(reveal_seed(&mut deps, "admin", SEED));
+        assert!(error.contains("There is no committed reveal pool"));
+
+        // test a seed hash that is not a sha256 hash
+        let execute_msg = ExecuteMsg::CommitRevealPool {
+            provenance_hash: provenance(&items()),
+            size: 3,
+            seed_hash: Binary(SEED.to_vec()),
+            padding: None,
+        };
+        let error = extract_error_msg(execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("admin", &[]),
+            execute_msg,
+        ));
+        assert!(error.contains("The seed hash must be a 32 byte sha256 hash"));
+
+        // the commitment may be replaced before loading starts
+        commit(&mut deps, Binary(vec![0u8; 32]), 2).unwrap();
+        commit(&mut deps, provenance(&items()), 3).unwrap();
+        assert_eq!(reveal_pool(&deps), (Some(provenance(&items())), 3, 0, 0));
+
+        let mut pool = items();
+        load_pool(&mut deps, pool.drain(..2).collect()).unwrap();
+        let error = extract_error_msg(commit(&mut deps, provenance(&items()), 3));
+        assert!(error.contains("The reveal pool can not be changed once loading has started"));
+        let error = extract_error_msg(reveal(&mut deps, "BOX1"));
+        assert!(error.contains("The reveal pool has not been fully loaded"));
+        let error = extract_error_msg(reveal_seed(&mut deps, "admin", SEED));
+        assert!(error.contains("The reveal pool must be fully loaded before its seed is revealed"));
+        let error = extract_error_msg(load_pool(&mut deps, items()));
+        assert!(error.contains("The reveal pool only has room for 1 more items"));
+
+        // test loading items that do not match the commitment
+        let error = extract_error_msg(load_pool(&mut deps, vec![items()[0].clone()]));
+        assert!(error.contains("The reveal pool does not match the committed provenance hash"));
+        load_pool(&mut deps, pool).unwrap();
+        assert_eq!(reveal_pool(&deps), (Some(provenance(&items())), 3, 3, 3));
+
+        // no item is assigned until the committed seed is revealed
+        let error = extract_error_msg(reveal(&mut deps, "BOX1"));
+        assert!(error.contains("The reveal pool's seed has not been revealed"));
+        let error = extract_error_msg(reveal_seed(&mut deps, "alice", SEED));
+        assert!(
+            error.contains("This is an admin command and can only be run from the admin address")
+        );
+        let error = extract_error_msg(reveal_seed(&mut deps, "admin", b"guess"));
+        assert!(error.contains("The seed does not match the committed seed hash"));
+        assert_eq!(
+            seed_status(&deps),
+            (Some(Binary(sha_256(SEED).to_vec())), false)
+        );
+        reveal_seed(&mut deps, "admin", SEED).unwrap();
+        assert!(seed_status(&deps).1);
+        let error = extract_error_msg(reveal_seed(&mut deps, "admin", SEED));
+        assert!(error.contains("The reveal pool's seed has already been revealed"));
+
+        // minting closes once the seed is revealed
+        let execute_msg = ExecuteMsg::MintNft {
+            token_id: Some("BOX5".to_string()),
+            owner: Some("alice".to_string()),
+            public_metadata: uri_meta("mystery box"),
+            private_metadata: uri_meta("placeholder"),
+            serial_number: None,
+            royalty_info: None,
+            transferable: None,
+            memo: None,
+            padding: None,
+        };
+        let error = extract_error_msg(execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("admin", &[]),
+            execute_msg,
+        ));
+        assert!(
+            error.contains("Minting has closed because the reveal pool's seed has been revealed")
+        );
+
+        // each token gets the item at its shuffled position, no matter the order tokens are
+        // revealed in.  With 4 tokens and 3 items, one token is not given an item
+        let mut assigned: Vec<Option<Metadata>> = Vec::new();
+        for (idx, id) in ["BOX1", "BOX2", "BOX3", "BOX4"].iter().enumerate().rev() {
+            let position = shuffled_position(SEED, 4, idx as u32) as usize;
+            if position >= 3 {
+                let error = extract_error_msg(reveal(&mut deps, id));
+                assert!(error.contains(&format!(
+                    "Token {} is not given an item because the reveal pool has fewer items than tokens",
+                    id
+                )));
+                continue;
+            }
+            reveal(&mut deps, id).unwrap();
+            let query_msg = QueryMsg::NftDossier {
+                token_id: id.to_string(),
+                viewer: None,
+                include_expired: None,
+            };
+            let query_result = query(deps.as_ref(), mock_env(), query_msg).unwrap();
+            match from_binary(&query_result).unwrap() {
+                QueryAnswer::NftDossier {
+                    public_metadata,
+                    unwrapped,
+                    ..
+                } => {
+                    assert!(unwrapped);
+                    assert_eq!(public_metadata, items()[position].public_metadata);
+                    assert!(!assigned.contains(&public_metadata));
+                    assigned.push(public_metadata);
+                }
+                _ => panic!("unexpected"),
+            }
+        }
+        assert_eq!(assigned.len(), 3);
+        assert_eq!(reveal_pool(&deps).3, 0);
+        let error = extract_error_msg(reveal(&mut deps, "BOX1"));
+        assert!(error.contains("This token has already been unwrapped"));
+    }
+
+    #[test]
+    fn test_shuffled_position() {
+        // every domain size is shuffled into a permutation of its positions
+        for domain in 1..=70u32 {
+            let mut positions: Vec<u32> = (0..domain)
+                .map(|i| shuffled_position(SEED, domain, i))
+                .collect();
+            positions.sort_unstable();
+            assert_eq!(positions, (0..domain).collect::<Vec<u32>>());
+        }
+        // the shuffle depends on the seed
+        let shuffle =
+            |seed: &[u8]| -> Vec<u32> { (0..64).map(|i| shuffled_position(seed, 64, i)).collect() };
+        assert_ne!(shuffle(SEED), shuffle(b"another seed"));
+        assert_ne!(shuffle(SEED), (0..64).collect::<Vec<u32>>());
+    }
+
+    #[test]
+    fn test_scheduled_reveal() {
+        let (init_result, mut deps) = init_helper_sealed();
+        assert!(
+            init_result.is_ok(),
+            "Init failed: {}",
+            init_result.err().unwrap()
+        );
+        for (id, secret) in [("BOX1", "secret1"), ("BOX2", "secret2")] {
+            let execute_msg = ExecuteMsg::MintNft {
+                token_id: Some(id.to_string()),
+                owner: Some("alice".to_string()),
+                public_metadata: uri_meta("mystery box"),
+                private_metadata: uri_meta(secret),
+                serial_number: None,
+                royalty_info: None,
+                transferable: None,
+                memo: None,
+                padding: None,
+            };
+            execute(
+                deps.as_mut(),
+                mock_env(),
+                mock_info("admin", &[]),
+                execute_msg,
+            )
+            .unwrap();
+        }
+        let now = mock_env().block.time.seconds();
+        let at = Some(Expiration::AtTime(now + 100));
+
+        // test non admin
+        let error = extract_error_msg(schedule_reveal(&mut deps, "alice", at, mock_env()));
+        assert!(
+            error.contains("This is an admin command and can only be run from the admin address")
+        );
+        schedule_reveal(&mut deps, "admin", at, mock_env()).unwrap();
+        let error = extract_error_msg(commit(&mut deps, provenance(&items()), 3));
+        assert!(error.contains(
+            "A reveal pool can not be committed when a collection-wide reveal is scheduled"
+        ));
+        let query_result = query(deps.as_ref(), mock_env(), QueryMsg::RevealSchedule {}).unwrap();
+        match from_binary(&query_result).unwrap() {
+            QueryAnswer::RevealSchedule {
+                at: queried,
+                revealed,
+            } => {
+                assert_eq!(queried, at);
+                assert!(!revealed);
+            }
+            _ => panic!("unexpected"),
+        }
+
+        // nothing is revealed before the scheduled time
+        assert!(!is_unwrapped(&deps, env_at(now + 99)));
+        assert_eq!(public_uri(&deps, env_at(now + 99)), "mystery box");
+
+        // every token counts as unwrapped once the time has come
+        assert!(is_unwrapped(&deps, env_at(now + 100)));
+        assert_eq!(public_uri(&deps, env_at(now + 100)), "secret1");
+        let query_msg = QueryMsg::NftDossier {
+            token_id: "BOX2".to_string(),
+            viewer: None,
+            include_expired: None,
+        };
+        let query_result = query(deps.as_ref(), env_at(now + 100), query_msg).unwrap();
+        match from_binary(&query_result).unwrap() {
+            QueryAnswer::NftDossier {
+                public_metadata,
+                unwrapped,
+                ..
+            } => {
+                assert!(unwrapped);
+                assert_eq!(public_metadata, uri_meta("secret2"));
+            }
+            _ => panic!("unexpected"),
+        }
+        let error = extract_error_msg(schedule_reveal(&mut deps, "admin", None, env_at(now + 100)));
+        assert!(error.contains("The scheduled reveal has already happened"));
+
+        // the metadata is moved before it is changed
+        let execute_msg = ExecuteMsg::SetMetadata {
+            token_id: "BOX1".to_string(),
+            public_metadata: None,
+            private_metadata: uri_meta("new secret"),
+            padding: None,
+        };
+        execute(
+            deps.as_mut(),
+            env_at(now + 100),
+            mock_info("admin", &[]),
+            execute_msg,
+        )
+        .unwrap();
+        assert_eq!(public_uri(&deps, env_at(now + 100)), "secret1");
+
+        // owners may still call Reveal
+        let execute_msg = ExecuteMsg::Reveal {
+            token_id: "BOX2".to_string(),
+            padding: None,
+        };
+        execute(
+            deps.as_mut(),
+            env_at(now + 100),
+            mock_info("alice", &[]),
+            execute_msg,
+        )
+        .unwrap();
+        let error = extract_error_msg(reveal(&mut deps, "BOX2"));
+        assert!(error.contains("This token has already been unwrapped"));
+    }
+}
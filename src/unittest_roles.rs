@@ -0,0 +1,420 @@
+#[cfg(test)]
+mod tests {
+    use std::any::Any;
+
+    use cosmwasm_std::testing::*;
+    use cosmwasm_std::{from_binary, Addr, Binary, OwnedDeps, Response, StdError, StdResult};
+
+    use crate::contract::{execute, instantiate, query};
+    use crate::msg::{
+        Burn, ContractStatus, ExecuteMsg, InstantiateConfig, InstantiateMsg, QueryAnswer, QueryMsg,
+        Role, ViewerInfo,
+    };
+    use crate::royalties::{Royalty, RoyaltyInfo};
+    use crate::state::{load, Config, CONFIG_KEY};
+    use crate::token::{Extension, Metadata};
+
+    // Helper functions
+
+    fn init_helper_with_config(
+        public_token_supply: bool,
+        enable_burn: bool,
+    ) -> (
+        StdResult<Response>,
+        OwnedDeps<MockStorage, MockApi, MockQuerier>,
+    ) {
+        let mut deps = mock_dependencies();
+
+        let env = mock_env();
+        let init_config: InstantiateConfig = from_binary(&Binary::from(
+            format!(
+                "{{\"public_token_supply\":{},
+            \"enable_burn\":{}}}",
+                public_token_supply, enable_burn,
+            )
+            .as_bytes(),
+        ))
+        .unwrap();
+        let info = mock_info("instantiator", &[]);
+        let init_msg = InstantiateMsg {
+            name: "sec721".to_string(),
+            symbol: "S721".to_string(),
+            admin: Some("admin".to_string()),
+            entropy: "We're going to need a bigger boat".to_string(),
+            royalty_info: None,
+            config: Some(init_config),
+            post_init_callback: None,
+        };
+
+        (instantiate(deps.as_mut(), env, info, init_msg), deps)
+    }
+
+    fn extract_error_msg<T: Any>(error: StdResult<T>) -> String {
+        match error {
+            Ok(_response) => panic!("Expected error, but had Ok response"),
+            Err(err) => match err {
+                StdError::GenericErr { msg, .. } => msg,
+                _ => panic!("Unexpected error result {:?}", err),
+            },
+        }
+    }
+
+    fn role_holders(deps: &OwnedDeps<MockStorage, MockApi, MockQuerier>, role: Role) -> Vec<Addr> {
+        let query_result = query(deps.as_ref(), mock_env(), QueryMsg::Roles {});
+        let query_answer: QueryAnswer = from_binary(&query_result.unwrap()).unwrap();
+        match query_answer {
+            QueryAnswer::Roles { roles } => roles
+                .into_iter()
+                .find(|r| r.role == role)
+                .map(|r| r.addresses)
+                .unwrap(),
+            _ => panic!("unexpected"),
+        }
+    }
+
+    fn grant(
+        deps: &mut OwnedDeps<MockStorage, MockApi, MockQuerier>,
+        role: Role,
+        addresses: &[&str],
+    ) -> StdResult<Response> {
+        let execute_msg = ExecuteMsg::GrantRole {
+            role,
+            addresses: addresses.iter().map(|a| a.to_string()).collect(),
+            padding: None,
+        };
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("admin", &[]),
+            execute_msg,
+        )
+    }
+
+    fn mint_to(deps: &mut OwnedDeps<MockStorage, MockApi, MockQuerier>, id: &str, owner: &str) {
+        let execute_msg = ExecuteMsg::MintNft {
+            token_id: Some(id.to_string()),
+            owner: Some(owner.to_string()),
+            public_metadata: None,
+            private_metadata: None,
+            royalty_info: None,
+            serial_number: None,
+            transferable: None,
+            memo: None,
+            padding: None,
+        };
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("admin", &[]),
+            execute_msg,
+        )
+        .unwrap();
+    }
+
+    #[test]
+    fn test_grant_revoke_roles() {
+        let (init_result, mut deps) = init_helper_with_config(false, false);
+        assert!(
+            init_result.is_ok(),
+            "Init failed: {}",
+            init_result.err().unwrap()
+        );
+        let admin = Addr::unchecked("admin");
+        // the admin starts out with every creator power
+        assert_eq!(role_holders(&deps, Role::Minter), vec![admin.clone()]);
+        assert_eq!(
+            role_holders(&deps, Role::MetadataEditor),
+            vec![admin.clone()]
+        );
+        assert_eq!(
+            role_holders(&deps, Role::RoyaltyManager),
+            vec![admin.clone()]
+        );
+        assert!(role_holders(&deps, Role::Pauser).is_empty());
+
+        // test non admin trying to grant a role
+        let execute_msg = ExecuteMsg::GrantRole {
+            role: Role::Pauser,
+            addresses: vec!["alice".to_string()],
+            padding: None,
+        };
+        let handle_result = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("alice", &[]),
+            execute_msg,
+        );
+        let error = extract_error_msg(handle_result);
+        assert!(
+            error.contains("This is an admin command and can only be run from the admin address")
+        );
+
+        // grant with a duplicate
+        grant(&mut deps, Role::Auditor, &["alice", "bob", "alice"]).unwrap();
+        assert_eq!(
+            role_holders(&deps, Role::Auditor),
+            vec![Addr::unchecked("alice"), Addr::unchecked("bob")]
+        );
+
+        // revoke
+        let execute_msg = ExecuteMsg::RevokeRole {
+            role: Role::Auditor,
+            addresses: vec!["alice".to_string(), "charlie".to_string()],
+            padding: None,
+        };
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("admin", &[]),
+            execute_msg,
+        )
+        .unwrap();
+        assert_eq!(
+            role_holders(&deps, Role::Auditor),
+            vec![Addr::unchecked("bob")]
+        );
+
+        // the minter aliases operate on the minter role
+        let execute_msg = ExecuteMsg::AddMinters {
+            minters: vec!["charlie".to_string()],
+            padding: None,
+        };
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("admin", &[]),
+            execute_msg,
+        )
+        .unwrap();
+        assert_eq!(
+            role_holders(&deps, Role::Minter),
+            vec![admin.clone(), Addr::unchecked("charlie")]
+        );
+        let execute_msg = ExecuteMsg::RevokeRole {
+            role: Role::Minter,
+            addresses: vec!["admin".to_string()],
+            padding: None,
+        };
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("admin", &[]),
+            execute_msg,
+        )
+        .unwrap();
+        let query_result = query(deps.as_ref(), mock_env(), QueryMsg::Minters {});
+        let query_answer: QueryAnswer = from_binary(&query_result.unwrap()).unwrap();
+        match query_answer {
+            QueryAnswer::Minters { minters } => {
+                assert_eq!(minters, vec![Addr::unchecked("charlie")]);
+            }
+            _ => panic!("unexpected"),
+        }
+        let execute_msg = ExecuteMsg::SetMinters {
+            minters: vec![],
+            padding: None,
+        };
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("admin", &[]),
+            execute_msg,
+        )
+        .unwrap();
+        assert!(role_holders(&deps, Role::Minter).is_empty());
+    }
+
+    #[test]
+    fn test_creator_roles_are_separate() {
+        let (init_result, mut deps) = init_helper_with_config(true, false);
+        assert!(
+            init_result.is_ok(),
+            "Init failed: {}",
+            init_result.err().unwrap()
+        );
+        mint_to(&mut deps, "NFT1", "alice");
+        grant(&mut deps, Role::Minter, &["minty"]).unwrap();
+
+        // a minter without the metadata editor role can not update metadata
+        let set_meta = || ExecuteMsg::SetMetadata {
+            token_id: "NFT1".to_string(),
+            public_metadata: Some(Metadata {
+                token_uri: None,
+                extension: Some(Extension {
+                    name: Some("New Name".to_string()),
+                    ..Extension::default()
+                }),
+            }),
+            private_metadata: None,
+            padding: None,
+        };
+        let handle_result = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("minty", &[]),
+            set_meta(),
+        );
+        let error = extract_error_msg(handle_result);
+        assert!(error.contains("Not authorized to update metadata of token NFT1"));
+        grant(&mut deps, Role::MetadataEditor, &["minty"]).unwrap();
+        let handle_result = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("minty", &[]),
+            set_meta(),
+        );
+        assert!(handle_result.is_ok());
+
+        // a royalty manager who is not a minter can set the default royalties
+        let set_roy = || ExecuteMsg::SetRoyaltyInfo {
+            token_id: None,
+            royalty_info: Some(RoyaltyInfo {
+                decimal_places_in_rates: 2,
+                royalties: vec![Royalty {
+                    recipient: "steven".to_string(),
+                    rate: 10,
+                }],
+            }),
+            padding: None,
+        };
+        let handle_result = execute(deps.as_mut(), mock_env(), mock_info("roy", &[]), set_roy());
+        let error = extract_error_msg(handle_result);
+        assert!(error.contains(
+            "Only designated royalty managers can set default royalties for the contract"
+        ));
+        grant(&mut deps, Role::RoyaltyManager, &["roy"]).unwrap();
+        let handle_result = execute(deps.as_mut(), mock_env(), mock_info("roy", &[]), set_roy());
+        assert!(handle_result.is_ok());
+        // but can not mint
+        let execute_msg = ExecuteMsg::MintNft {
+            token_id: None,
+            owner: None,
+            public_metadata: None,
+            private_metadata: None,
+            royalty_info: None,
+            serial_number: None,
+            transferable: None,
+            memo: None,
+            padding: None,
+        };
+        let handle_result = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("roy", &[]),
+            execute_msg,
+        );
+        let error = extract_error_msg(handle_result);
+        assert!(error.contains("Only designated minters are allowed to mint"));
+    }
+
+    #[test]
+    fn test_pauser_burner_auditor() {
+        let (init_result, mut deps) = init_helper_with_config(false, true);
+        assert!(
+            init_result.is_ok(),
+            "Init failed: {}",
+            init_result.err().unwrap()
+        );
+        mint_to(&mut deps, "NFT1", "alice");
+        grant(&mut deps, Role::Pauser, &["paula"]).unwrap();
+        grant(&mut deps, Role::Burner, &["bernie"]).unwrap();
+        grant(&mut deps, Role::Auditor, &["audrey"]).unwrap();
+
+        // a pauser can make the status more restrictive
+        let execute_msg = ExecuteMsg::SetContractStatus {
+            level: ContractStatus::StopTransactions,
+            padding: None,
+        };
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("paula", &[]),
+            execute_msg,
+        )
+        .unwrap();
+        let config: Config = load(&deps.storage, CONFIG_KEY).unwrap();
+        assert_eq!(config.status, ContractStatus::StopTransactions.to_u8());
+        // but can not loosen it
+        let unpause = || ExecuteMsg::SetContractStatus {
+            level: ContractStatus::Normal,
+            padding: None,
+        };
+        let handle_result = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("paula", &[]),
+            unpause(),
+        );
+        let error = extract_error_msg(handle_result);
+        assert!(error.contains("Pausers may only make the contract status more restrictive"));
+        // someone without the role can not change it at all
+        let handle_result = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("alice", &[]),
+            unpause(),
+        );
+        let error = extract_error_msg(handle_result);
+        assert!(
+            error.contains("This is an admin command and can only be run from the admin address")
+        );
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("admin", &[]),
+            unpause(),
+        )
+        .unwrap();
+
+        // a burner may burn a token it has no approval for
+        let burn = || ExecuteMsg::BurnNft {
+            token_id: "NFT1".to_string(),
+            memo: None,
+            padding: None,
+        };
+        let handle_result = execute(deps.as_mut(), mock_env(), mock_info("charlie", &[]), burn());
+        assert!(handle_result.is_err());
+        let handle_result = execute(deps.as_mut(), mock_env(), mock_info("bernie", &[]), burn());
+        assert!(handle_result.is_ok());
+        let execute_msg = ExecuteMsg::BatchBurnNft {
+            burns: vec![Burn {
+                token_ids: vec!["NFT1".to_string()],
+                memo: None,
+            }],
+            padding: None,
+        };
+        let handle_result = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("bernie", &[]),
+            execute_msg,
+        );
+        let error = extract_error_msg(handle_result);
+        assert!(error.contains("Token ID: NFT1 not found"));
+
+        // an auditor can view the private token supply
+        let execute_msg = ExecuteMsg::SetViewingKey {
+            key: "akey".to_string(),
+            padding: None,
+        };
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("audrey", &[]),
+            execute_msg,
+        )
+        .unwrap();
+        let query_msg = QueryMsg::NumTokens {
+            viewer: Some(ViewerInfo {
+                address: "audrey".to_string(),
+                viewing_key: "akey".to_string(),
+            }),
+        };
+        let query_result = query(deps.as_ref(), mock_env(), query_msg);
+        let query_answer: QueryAnswer = from_binary(&query_result.unwrap()).unwrap();
+        match query_answer {
+            QueryAnswer::NumTokens { count } => assert_eq!(count, 0),
+            _ => panic!("unexpected"),
+        }
+    }
+}
@@ -324,7 +324,7 @@ mod tests {
             ],
         };
 
-        // test non-minter attempting to set default royalties
+        // test non-royalty manager attempting to set default royalties
         let execute_msg = ExecuteMsg::SetRoyaltyInfo {
             token_id: None,
             royalty_info: Some(royalties.clone()),
@@ -337,9 +337,9 @@ mod tests {
             execute_msg,
         );
         let error = extract_error_msg(handle_result);
-        assert!(
-            error.contains("Only designated minters can set default royalties for the contract")
-        );
+        assert!(error.contains(
+            "Only designated royalty managers can set default royalties for the contract"
+        ));
 
         // test royalties more than 100%
         let royalty_info_for_failure = RoyaltyInfo {
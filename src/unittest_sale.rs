@@ -0,0 +1,676 @@
+#[cfg(test)]
+mod tests {
+    use std::any::Any;
+
+    use cosmwasm_std::testing::*;
+    use cosmwasm_std::{
+        coin, coins, from_binary, to_binary, Addr, BankMsg, Binary, CosmosMsg, OwnedDeps, Response,
+        StdError, StdResult, Timestamp, Uint128, WasmMsg,
+    };
+    use serde::Deserialize;
+
+    use crate::contract::{execute, instantiate, query};
+    use crate::msg::{
+        ExecuteAnswer, ExecuteMsg, InstantiateConfig, InstantiateMsg, QueryAnswer, QueryMsg,
+        ReceiveMsg,
+    };
+    use crate::sale::{PoolEntry, Sale, SaleMetadata, Snip20Price};
+    use crate::token::Metadata;
+
+    // Helper functions
+
+    fn init_helper_default() -> (
+        StdResult<Response>,
+        OwnedDeps<MockStorage, MockApi, MockQuerier>,
+    ) {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+        let info = mock_info("instantiator", &[]);
+        let init_msg = InstantiateMsg {
+            name: "sec721".to_string(),
+            symbol: "S721".to_string(),
+            admin: Some("admin".to_string()),
+            entropy: "We're going to need a bigger boat".to_string(),
+            royalty_info: None,
+            config: Some(InstantiateConfig {
+                public_owner: Some(true),
+                ..InstantiateConfig::default()
+            }),
+            post_init_callback: None,
+        };
+
+        (instantiate(deps.as_mut(), env, info, init_msg), deps)
+    }
+
+    fn extract_error_msg<T: Any>(error: StdResult<T>) -> String {
+        match error {
+            Ok(_response) => panic!("Expected error, but had Ok response"),
+            Err(err) => match err {
+                StdError::GenericErr { msg, .. } => msg,
+                _ => panic!("Unexpected error result {:?}", err),
+            },
+        }
+    }
+
+    fn uri_meta(uri: &str) -> Option<Metadata> {
+        Some(Metadata {
+            token_uri: Some(uri.to_string()),
+            extension: None,
+        })
+    }
+
+    fn sale(metadata: SaleMetadata) -> Sale {
+        Sale {
+            price: Some(coin(100, "uscrt")),
+            start: None,
+            end: None,
+            wallet_limit: None,
+            metadata,
+        }
+    }
+
+    fn set_sale(
+        deps: &mut OwnedDeps<MockStorage, MockApi, MockQuerier>,
+        sale: Option<Sale>,
+    ) -> StdResult<Response> {
+        let execute_msg = ExecuteMsg::SetSale {
+            sale,
+            padding: None,
+        };
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("admin", &[]),
+            execute_msg,
+        )
+    }
+
+    fn public_mint(
+        deps: &mut OwnedDeps<MockStorage, MockApi, MockQuerier>,
+        buyer: &str,
+        quantity: Option<u32>,
+        funds: &[cosmwasm_std::Coin],
+    ) -> StdResult<Response> {
+        let execute_msg = ExecuteMsg::PublicMint {
+            quantity,
+            memo: None,
+            padding: None,
+        };
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(buyer, funds),
+            execute_msg,
+        )
+    }
+
+    fn minted_ids(response: &Response) -> Vec<String> {
+        let answer: ExecuteAnswer = from_binary(response.data.as_ref().unwrap()).unwrap();
+        match answer {
+            ExecuteAnswer::PublicMint { token_ids } => token_ids,
+            _ => panic!("unexpected"),
+        }
+    }
+
+    fn bank_sends(response: &Response) -> Vec<(String, Vec<cosmwasm_std::Coin>)> {
+        response
+            .messages
+            .iter()
+            .filter_map(|m| match &m.msg {
+                CosmosMsg::Bank(BankMsg::Send { to_address, amount }) => {
+                    Some((to_address.clone(), amount.clone()))
+                }
+                _ => None,
+            })
+            .collect()
+    }
+
+    // the SNIP-20 messages this contract sends
+    #[derive(Deserialize, Debug, PartialEq)]
+    #[serde(rename_all = "snake_case")]
+    enum MockSnip20Msg {
+        RegisterReceive {
+            code_hash: String,
+            padding: Option<String>,
+        },
+        Transfer {
+            recipient: String,
+            amount: Uint128,
+            memo: Option<String>,
+            padding: Option<String>,
+        },
+    }
+
+    fn snip20_msgs(response: &Response) -> Vec<(String, String, MockSnip20Msg)> {
+        response
+            .messages
+            .iter()
+            .filter_map(|m| match &m.msg {
+                CosmosMsg::Wasm(WasmMsg::Execute {
+                    contract_addr,
+                    code_hash,
+                    msg,
+                    ..
+                }) => Some((
+                    contract_addr.clone(),
+                    code_hash.clone(),
+                    from_binary(msg).unwrap(),
+                )),
+                _ => None,
+            })
+            .collect()
+    }
+
+    fn snip20_receive(
+        deps: &mut OwnedDeps<MockStorage, MockApi, MockQuerier>,
+        snip20: &str,
+        from: &str,
+        amount: u128,
+        msg: Option<Binary>,
+    ) -> StdResult<Response> {
+        let execute_msg = ExecuteMsg::Receive {
+            sender: from.to_string(),
+            from: from.to_string(),
+            amount: Uint128::new(amount),
+            memo: None,
+            msg,
+        };
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(snip20, &[]),
+            execute_msg,
+        )
+    }
+
+    #[test]
+    fn test_set_sale() {
+        let (init_result, mut deps) = init_helper_default();
+        assert!(
+            init_result.is_ok(),
+            "Init failed: {}",
+            init_result.err().unwrap()
+        );
+
+        // test no sale
+        let error = extract_error_msg(public_mint(&mut deps, "bob", None, &coins(100, "uscrt")));
+        assert!(error.contains("There is no public sale"));
+
+        // test non admin
+        let execute_msg = ExecuteMsg::SetSale {
+            sale: Some(sale(SaleMetadata::Pool {})),
+            padding: None,
+        };
+        let handle_result = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("alice", &[]),
+            execute_msg,
+        );
+        let error = extract_error_msg(handle_result);
+        assert!(
+            error.contains("This is an admin command and can only be run from the admin address")
+        );
+
+        // test a sale that ends before it starts
+        let mut bad = sale(SaleMetadata::Pool {});
+        bad.start = Some(100);
+        bad.end = Some(100);
+        let error = extract_error_msg(set_sale(&mut deps, Some(bad)));
+        assert!(error.contains("The sale must end after it starts"));
+
+        // test the sale window
+        let now = mock_env().block.time.seconds();
+        let mut windowed = sale(SaleMetadata::Template {
+            public_metadata: uri_meta("uri"),
+            private_metadata: None,
+        });
+        windowed.start = Some(now + 10);
+        windowed.end = Some(now + 20);
+        set_sale(&mut deps, Some(windowed.clone())).unwrap();
+        let error = extract_error_msg(public_mint(&mut deps, "bob", None, &coins(100, "uscrt")));
+        assert!(error.contains("The sale has not started yet"));
+        let mut env = mock_env();
+        env.block.time = Timestamp::from_seconds(now + 20);
+        let execute_msg = ExecuteMsg::PublicMint {
+            quantity: None,
+            memo: None,
+            padding: None,
+        };
+        let handle_result = execute(
+            deps.as_mut(),
+            env,
+            mock_info("bob", &coins(100, "uscrt")),
+            execute_msg,
+        );
+        let error = extract_error_msg(handle_result);
+        assert!(error.contains("The sale has ended"));
+        let mut env = mock_env();
+        env.block.time = Timestamp::from_seconds(now + 10);
+        let execute_msg = ExecuteMsg::PublicMint {
+            quantity: None,
+            memo: None,
+            padding: None,
+        };
+        execute(
+            deps.as_mut(),
+            env,
+            mock_info("bob", &coins(100, "uscrt")),
+            execute_msg,
+        )
+        .unwrap();
+
+        let query_result = query(deps.as_ref(), mock_env(), QueryMsg::Sale {});
+        let query_answer: QueryAnswer = from_binary(&query_result.unwrap()).unwrap();
+        match query_answer {
+            QueryAnswer::Sale {
+                sale,
+                sold,
+                pool_remaining,
+                snip20_prices,
+            } => {
+                assert_eq!(sale, Some(windowed));
+                assert_eq!(sold, 1);
+                assert_eq!(pool_remaining, 0);
+                assert!(snip20_prices.is_empty());
+            }
+            _ => panic!("unexpected"),
+        }
+
+        // test closing the sale
+        set_sale(&mut deps, None).unwrap();
+        let error = extract_error_msg(public_mint(&mut deps, "bob", None, &coins(100, "uscrt")));
+        assert!(error.contains("There is no public sale"));
+    }
+
+    #[test]
+    fn test_public_mint_payment() {
+        let (init_result, mut deps) = init_helper_default();
+        assert!(
+            init_result.is_ok(),
+            "Init failed: {}",
+            init_result.err().unwrap()
+        );
+        set_sale(
+            &mut deps,
+            Some(sale(SaleMetadata::Template {
+                public_metadata: uri_meta("template"),
+                private_metadata: None,
+            })),
+        )
+        .unwrap();
+
+        // test wrong denom
+        let error = extract_error_msg(public_mint(&mut deps, "bob", None, &coins(100, "uatom")));
+        assert!(error.contains("The sale only accepts payment in uscrt"));
+        let error = extract_error_msg(public_mint(
+            &mut deps,
+            "bob",
+            None,
+            &[coin(100, "uscrt"), coin(1, "uatom")],
+        ));
+        assert!(error.contains("The sale only accepts payment in uscrt"));
+
+        // test underpayment
+        let error = extract_error_msg(public_mint(&mut deps, "bob", Some(2), &coins(199, "uscrt")));
+        assert!(error.contains("Insufficient payment.  2 tokens cost 200uscrt"));
+        let error = extract_error_msg(public_mint(&mut deps, "bob", None, &[]));
+        assert!(error.contains("Insufficient payment.  1 tokens cost 100uscrt"));
+
+        // test exact payment
+        let response = public_mint(&mut deps, "bob", Some(2), &coins(200, "uscrt")).unwrap();
+        assert!(bank_sends(&response).is_empty());
+        assert_eq!(
+            minted_ids(&response),
+            vec!["0".to_string(), "1".to_string()]
+        );
+
+        // test overpayment is returned
+        let response = public_mint(&mut deps, "charlie", None, &coins(250, "uscrt")).unwrap();
+        assert_eq!(
+            bank_sends(&response),
+            vec![("charlie".to_string(), coins(150, "uscrt"))]
+        );
+        let token_ids = minted_ids(&response);
+        assert_eq!(token_ids, vec!["2".to_string()]);
+
+        // the buyer owns the token, which has the template metadata
+        let query_msg = QueryMsg::OwnerOf {
+            token_id: "2".to_string(),
+            viewer: None,
+            include_expired: None,
+        };
+        let query_answer: QueryAnswer =
+            from_binary(&query(deps.as_ref(), mock_env(), query_msg).unwrap()).unwrap();
+        match query_answer {
+            QueryAnswer::OwnerOf { owner, .. } => assert_eq!(owner, Addr::unchecked("charlie")),
+            _ => panic!("unexpected"),
+        }
+        let query_msg = QueryMsg::NftInfo {
+            token_id: "2".to_string(),
+        };
+        let query_answer: QueryAnswer =
+            from_binary(&query(deps.as_ref(), mock_env(), query_msg).unwrap()).unwrap();
+        match query_answer {
+            QueryAnswer::NftInfo { token_uri, .. } => {
+                assert_eq!(token_uri, Some("template".to_string()))
+            }
+            _ => panic!("unexpected"),
+        }
+
+        // test withdrawing the proceeds
+        let execute_msg = ExecuteMsg::WithdrawProceeds {
+            recipient: Some("treasury".to_string()),
+            padding: None,
+        };
+        let handle_result = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("alice", &[]),
+            execute_msg,
+        );
+        let error = extract_error_msg(handle_result);
+        assert!(
+            error.contains("This is an admin command and can only be run from the admin address")
+        );
+        let withdraw = || ExecuteMsg::WithdrawProceeds {
+            recipient: Some("treasury".to_string()),
+            padding: None,
+        };
+        let response = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("admin", &[]),
+            withdraw(),
+        )
+        .unwrap();
+        assert_eq!(
+            bank_sends(&response),
+            vec![("treasury".to_string(), coins(300, "uscrt"))]
+        );
+        let handle_result = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("admin", &[]),
+            withdraw(),
+        );
+        let error = extract_error_msg(handle_result);
+        assert!(error.contains("There are no proceeds to withdraw"));
+    }
+
+    #[test]
+    fn test_public_mint_limits() {
+        let (init_result, mut deps) = init_helper_default();
+        assert!(
+            init_result.is_ok(),
+            "Init failed: {}",
+            init_result.err().unwrap()
+        );
+        let mut pool_sale = sale(SaleMetadata::Pool {});
+        pool_sale.wallet_limit = Some(2);
+        set_sale(&mut deps, Some(pool_sale)).unwrap();
+
+        // test an empty pool
+        let error = extract_error_msg(public_mint(&mut deps, "bob", None, &coins(100, "uscrt")));
+        assert!(error.contains("The sale is sold out"));
+
+        let execute_msg = ExecuteMsg::LoadSalePool {
+            metadata: (1..=4)
+                .map(|i| PoolEntry {
+                    public_metadata: uri_meta(&format!("pool{}", i)),
+                    private_metadata: None,
+                })
+                .collect(),
+            padding: None,
+        };
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("admin", &[]),
+            execute_msg,
+        )
+        .unwrap();
+
+        // test the wallet limit
+        let error = extract_error_msg(public_mint(&mut deps, "bob", Some(3), &coins(300, "uscrt")));
+        assert!(error.contains("Each address may only buy 2 tokens, and you have already bought 0"));
+        public_mint(&mut deps, "bob", Some(2), &coins(200, "uscrt")).unwrap();
+        let error = extract_error_msg(public_mint(&mut deps, "bob", None, &coins(100, "uscrt")));
+        assert!(error.contains("Each address may only buy 2 tokens, and you have already bought 2"));
+
+        // pool entries are sold in the order they were loaded
+        let query_msg = QueryMsg::NftInfo {
+            token_id: "1".to_string(),
+        };
+        let query_answer: QueryAnswer =
+            from_binary(&query(deps.as_ref(), mock_env(), query_msg).unwrap()).unwrap();
+        match query_answer {
+            QueryAnswer::NftInfo { token_uri, .. } => {
+                assert_eq!(token_uri, Some("pool2".to_string()))
+            }
+            _ => panic!("unexpected"),
+        }
+
+        // test the collection's max supply limits the sale
+        let execute_msg = ExecuteMsg::SetMaxSupply {
+            mint_run_id: None,
+            max_supply: Some(3),
+            padding: None,
+        };
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("admin", &[]),
+            execute_msg,
+        )
+        .unwrap();
+        let error = extract_error_msg(public_mint(
+            &mut deps,
+            "charlie",
+            Some(2),
+            &coins(200, "uscrt"),
+        ));
+        assert!(error.contains("Only 1 tokens are left in the sale"));
+        public_mint(&mut deps, "charlie", None, &coins(100, "uscrt")).unwrap();
+
+        // test sold out
+        let error = extract_error_msg(public_mint(&mut deps, "david", None, &coins(100, "uscrt")));
+        assert!(error.contains("The sale is sold out"));
+        let query_result = query(deps.as_ref(), mock_env(), QueryMsg::Sale {});
+        let query_answer: QueryAnswer = from_binary(&query_result.unwrap()).unwrap();
+        match query_answer {
+            QueryAnswer::Sale {
+                sold,
+                pool_remaining,
+                ..
+            } => {
+                assert_eq!(sold, 3);
+                assert_eq!(pool_remaining, 1);
+            }
+            _ => panic!("unexpected"),
+        }
+    }
+
+    #[test]
+    fn test_snip20_payments() {
+        let (init_result, mut deps) = init_helper_default();
+        assert!(
+            init_result.is_ok(),
+            "Init failed: {}",
+            init_result.err().unwrap()
+        );
+        let mut snip20_sale = sale(SaleMetadata::Template {
+            public_metadata: uri_meta("template"),
+            private_metadata: None,
+        });
+        snip20_sale.price = None;
+        set_sale(&mut deps, Some(snip20_sale)).unwrap();
+        let mint_msg = || {
+            Some(
+                to_binary(&ReceiveMsg::PublicMint {
+                    quantity: Some(2),
+                    memo: None,
+                })
+                .unwrap(),
+            )
+        };
+
+        // test a sale without a native price
+        let error = extract_error_msg(public_mint(&mut deps, "bob", None, &coins(100, "uscrt")));
+        assert!(error.contains("The sale does not accept native coins"));
+
+        // test a SNIP-20 that has not been given a price
+        let error = extract_error_msg(snip20_receive(&mut deps, "snip20", "bob", 200, mint_msg()));
+        assert!(error.contains("The sale does not accept payment in snip20"));
+
+        // test non admin setting a price
+        let set_price = |price: Option<u128>| ExecuteMsg::SetSnip20Price {
+            contract_address: "snip20".to_string(),
+            code_hash: "snip20 hash".to_string(),
+            price: price.map(Uint128::new),
+            padding: None,
+        };
+        let handle_result = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("alice", &[]),
+            set_price(Some(100)),
+        );
+        let error = extract_error_msg(handle_result);
+        assert!(
+            error.contains("This is an admin command and can only be run from the admin address")
+        );
+
+        // the contract registers with the SNIP-20 the first time it is given a price
+        let response = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("admin", &[]),
+            set_price(Some(100)),
+        )
+        .unwrap();
+        assert_eq!(
+            snip20_msgs(&response),
+            vec![(
+                "snip20".to_string(),
+                "snip20 hash".to_string(),
+                MockSnip20Msg::RegisterReceive {
+                    code_hash: mock_env().contract.code_hash,
+                    padding: None,
+                }
+            )]
+        );
+        let response = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("admin", &[]),
+            set_price(Some(100)),
+        )
+        .unwrap();
+        assert!(response.messages.is_empty());
+
+        // test a missing ReceiveMsg
+        let error = extract_error_msg(snip20_receive(&mut deps, "snip20", "bob", 200, None));
+        assert!(error.contains("A ReceiveMsg is required with the payment"));
+
+        // test underpayment
+        let error = extract_error_msg(snip20_receive(&mut deps, "snip20", "bob", 199, mint_msg()));
+        assert!(error.contains("Insufficient payment.  2 tokens cost 200"));
+
+        // test overpayment is returned to the owner of the SNIP-20 tokens
+        let response = snip20_receive(&mut deps, "snip20", "bob", 250, mint_msg()).unwrap();
+        assert_eq!(
+            minted_ids(&response),
+            vec!["0".to_string(), "1".to_string()]
+        );
+        assert_eq!(
+            snip20_msgs(&response),
+            vec![(
+                "snip20".to_string(),
+                "snip20 hash".to_string(),
+                MockSnip20Msg::Transfer {
+                    recipient: "bob".to_string(),
+                    amount: Uint128::new(50),
+                    memo: None,
+                    padding: None,
+                }
+            )]
+        );
+        let query_msg = QueryMsg::OwnerOf {
+            token_id: "1".to_string(),
+            viewer: None,
+            include_expired: None,
+        };
+        let query_answer: QueryAnswer =
+            from_binary(&query(deps.as_ref(), mock_env(), query_msg).unwrap()).unwrap();
+        match query_answer {
+            QueryAnswer::OwnerOf { owner, .. } => assert_eq!(owner, Addr::unchecked("bob")),
+            _ => panic!("unexpected"),
+        }
+
+        let query_result = query(deps.as_ref(), mock_env(), QueryMsg::Sale {});
+        let query_answer: QueryAnswer = from_binary(&query_result.unwrap()).unwrap();
+        match query_answer {
+            QueryAnswer::Sale {
+                sold,
+                snip20_prices,
+                ..
+            } => {
+                assert_eq!(sold, 2);
+                assert_eq!(
+                    snip20_prices,
+                    vec![Snip20Price {
+                        address: Addr::unchecked("snip20"),
+                        code_hash: "snip20 hash".to_string(),
+                        price: Uint128::new(100),
+                    }]
+                );
+            }
+            _ => panic!("unexpected"),
+        }
+
+        // proceeds are kept after the SNIP-20 is no longer accepted
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("admin", &[]),
+            set_price(None),
+        )
+        .unwrap();
+        let error = extract_error_msg(snip20_receive(&mut deps, "snip20", "bob", 200, mint_msg()));
+        assert!(error.contains("The sale does not accept payment in snip20"));
+        let withdraw = || ExecuteMsg::WithdrawProceeds {
+            recipient: None,
+            padding: None,
+        };
+        let response = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("admin", &[]),
+            withdraw(),
+        )
+        .unwrap();
+        assert!(bank_sends(&response).is_empty());
+        assert_eq!(
+            snip20_msgs(&response),
+            vec![(
+                "snip20".to_string(),
+                "snip20 hash".to_string(),
+                MockSnip20Msg::Transfer {
+                    recipient: "admin".to_string(),
+                    amount: Uint128::new(200),
+                    memo: None,
+                    padding: None,
+                }
+            )]
+        );
+        let handle_result = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("admin", &[]),
+            withdraw(),
+        );
+        let error = extract_error_msg(handle_result);
+        assert!(error.contains("There are no proceeds to withdraw"));
+    }
+}
@@ -0,0 +1,651 @@
+#[cfg(test)]
+mod tests {
+    use std::any::Any;
+
+    use cosmwasm_std::testing::*;
+    use cosmwasm_std::{
+        coins, from_binary, Addr, BankMsg, Coin, CosmosMsg, Env, OwnedDeps, Response, StdError,
+        StdResult, WasmMsg,
+    };
+    use serde::Deserialize;
+
+    use crate::contract::{execute, instantiate, query};
+    use crate::expiration::Expiration;
+    use crate::msg::{
+        ExecuteAnswer, ExecuteMsg, InstantiateConfig, InstantiateMsg, QueryAnswer, QueryMsg,
+        ViewerInfo,
+    };
+    use crate::swap::{Swap, SwapToken};
+
+    // Helper functions
+
+    fn init_helper_default() -> (
+        StdResult<Response>,
+        OwnedDeps<MockStorage, MockApi, MockQuerier>,
+    ) {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+        let info = mock_info("instantiator", &[]);
+        let init_msg = InstantiateMsg {
+            name: "sec721".to_string(),
+            symbol: "S721".to_string(),
+            admin: Some("admin".to_string()),
+            entropy: "We're going to need a bigger boat".to_string(),
+            royalty_info: None,
+            config: Some(InstantiateConfig {
+                public_owner: Some(true),
+                ..InstantiateConfig::default()
+            }),
+            post_init_callback: None,
+        };
+
+        (instantiate(deps.as_mut(), env, info, init_msg), deps)
+    }
+
+    fn extract_error_msg<T: Any>(error: StdResult<T>) -> String {
+        match error {
+            Ok(_response) => panic!("Expected error, but had Ok response"),
+            Err(err) => match err {
+                StdError::GenericErr { msg, .. } => msg,
+                _ => panic!("Unexpected error result {:?}", err),
+            },
+        }
+    }
+
+    #[derive(Deserialize, Debug, PartialEq)]
+    struct MockTransfer {
+        recipient: String,
+        token_ids: Vec<String>,
+        memo: Option<String>,
+    }
+
+    // the SNIP-721 messages this contract sends
+    #[derive(Deserialize, Debug, PartialEq)]
+    #[serde(rename_all = "snake_case")]
+    enum MockSnip721Msg {
+        RegisterReceiveNft {
+            code_hash: String,
+            also_implements_batch_receive_nft: Option<bool>,
+            padding: Option<String>,
+        },
+        BatchTransferNft {
+            transfers: Vec<MockTransfer>,
+            padding: Option<String>,
+        },
+    }
+
+    fn snip721_msgs(response: &Response) -> Vec<(String, String, MockSnip721Msg)> {
+        response
+            .messages
+            .iter()
+            .filter_map(|m| match &m.msg {
+                CosmosMsg::Wasm(WasmMsg::Execute {
+                    contract_addr,
+                    code_hash,
+                    msg,
+                    ..
+                }) => Some((
+                    contract_addr.clone(),
+                    code_hash.clone(),
+                    from_binary(msg).unwrap(),
+                )),
+                _ => None,
+            })
+            .collect()
+    }
+
+    fn withdrawal(recipient: &str, token_id: &str) -> (String, String, MockSnip721Msg) {
+        (
+            "other".to_string(),
+            "other hash".to_string(),
+            MockSnip721Msg::BatchTransferNft {
+                transfers: vec![MockTransfer {
+                    recipient: recipient.to_string(),
+                    token_ids: vec![token_id.to_string()],
+                    memo: None,
+                }],
+                padding: None,
+            },
+        )
+    }
+
+    fn bank_sends(response: &Response) -> Vec<(String, Vec<Coin>)> {
+        response
+            .messages
+            .iter()
+            .filter_map(|m| match &m.msg {
+                CosmosMsg::Bank(BankMsg::Send { to_address, amount }) => {
+                    Some((to_address.clone(), amount.clone()))
+                }
+                _ => None,
+            })
+            .collect()
+    }
+
+    fn mint(deps: &mut OwnedDeps<MockStorage, MockApi, MockQuerier>, token_id: &str, owner: &str) {
+        let execute_msg = ExecuteMsg::MintNft {
+            token_id: Some(token_id.to_string()),
+            owner: Some(owner.to_string()),
+            public_metadata: None,
+            private_metadata: None,
+            royalty_info: None,
+            serial_number: None,
+            transferable: None,
+            memo: None,
+            padding: None,
+        };
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("admin", &[]),
+            execute_msg,
+        )
+        .unwrap();
+    }
+
+    fn local(token_id: &str) -> SwapToken {
+        SwapToken {
+            contract: None,
+            token_id: token_id.to_string(),
+        }
+    }
+
+    fn other(token_id: &str) -> SwapToken {
+        SwapToken {
+            contract: Some("other".to_string()),
+            token_id: token_id.to_string(),
+        }
+    }
+
+    fn expires() -> Expiration {
+        Expiration::AtHeight(mock_env().block.height + 100)
+    }
+
+    fn propose(
+        deps: &mut OwnedDeps<MockStorage, MockApi, MockQuerier>,
+        sender: &str,
+        funds: &[Coin],
+        offered: Vec<SwapToken>,
+        requested: Vec<SwapToken>,
+        expires: Expiration,
+    ) -> StdResult<Response> {
+        let counterparty = if sender == "alice" { "bob" } else { "alice" };
+        let execute_msg = ExecuteMsg::ProposeSwap {
+            counterparty: counterparty.to_string(),
+            offered,
+            requested,
+            expires,
+            padding: None,
+        };
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(sender, funds),
+            execute_msg,
+        )
+    }
+
+    fn swap_id(response: &Response) -> u64 {
+        match from_binary(response.data.as_ref().unwrap()).unwrap() {
+            ExecuteAnswer::ProposeSwap { swap_id } => swap_id,
+            _ => panic!("unexpected"),
+        }
+    }
+
+    fn accept(
+        deps: &mut OwnedDeps<MockStorage, MockApi, MockQuerier>,
+        env: Env,
+        sender: &str,
+        swap_id: u64,
+    ) -> StdResult<Response> {
+        let execute_msg = ExecuteMsg::AcceptSwap {
+            swap_id,
+            padding: None,
+        };
+        execute(deps.as_mut(), env, mock_info(sender, &[]), execute_msg)
+    }
+
+    fn query_swap(
+        deps: &OwnedDeps<MockStorage, MockApi, MockQuerier>,
+        address: &str,
+        swap_id: u64,
+    ) -> StdResult<Swap> {
+        let query_msg = QueryMsg::Swap {
+            swap_id,
+            viewer: ViewerInfo {
+                address: address.to_string(),
+                viewing_key: "key".to_string(),
+            },
+        };
+        let query_result = query(deps.as_ref(), mock_env(), query_msg)?;
+        match from_binary(&query_result).unwrap() {
+            QueryAnswer::Swap { swap } => Ok(swap),
+            _ => panic!("unexpected"),
+        }
+    }
+
+    fn owner(deps: &OwnedDeps<MockStorage, MockApi, MockQuerier>, token_id: &str) -> Addr {
+        let query_msg = QueryMsg::OwnerOf {
+            token_id: token_id.to_string(),
+            viewer: None,
+            include_expired: None,
+        };
+        let query_result = query(deps.as_ref(), mock_env(), query_msg).unwrap();
+        match from_binary(&query_result).unwrap() {
+            QueryAnswer::OwnerOf { owner, .. } => owner,
+            _ => panic!("unexpected"),
+        }
+    }
+
+    fn register_other(deps: &mut OwnedDeps<MockStorage, MockApi, MockQuerier>) -> Response {
+        let execute_msg = ExecuteMsg::RegisterSwapContract {
+            contract_address: "other".to_string(),
+            code_hash: "other hash".to_string(),
+            padding: None,
+        };
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("admin", &[]),
+            execute_msg,
+        )
+        .unwrap()
+    }
+
+    fn deposit(
+        deps: &mut OwnedDeps<MockStorage, MockApi, MockQuerier>,
+        contract: &str,
+        from: &str,
+        token_ids: &[&str],
+    ) -> StdResult<Response> {
+        let execute_msg = ExecuteMsg::BatchReceiveNft {
+            sender: from.to_string(),
+            from: from.to_string(),
+            token_ids: token_ids.iter().map(|t| t.to_string()).collect(),
+            msg: None,
+        };
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(contract, &[]),
+            execute_msg,
+        )
+    }
+
+    fn withdraw(
+        deps: &mut OwnedDeps<MockStorage, MockApi, MockQuerier>,
+        sender: &str,
+        token_id: &str,
+    ) -> StdResult<Response> {
+        let execute_msg = ExecuteMsg::WithdrawDeposits {
+            contract_address: "other".to_string(),
+            token_ids: vec![token_id.to_string()],
+            padding: None,
+        };
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(sender, &[]),
+            execute_msg,
+        )
+    }
+
+    #[test]
+    fn test_swap() {
+        let (init_result, mut deps) = init_helper_default();
+        assert!(
+            init_result.is_ok(),
+            "Init failed: {}",
+            init_result.err().unwrap()
+        );
+        mint(&mut deps, "NFT1", "alice");
+        mint(&mut deps, "NFT2", "alice");
+        mint(&mut deps, "NFT3", "bob");
+        for addr in ["alice", "bob", "carol"] {
+            let execute_msg = ExecuteMsg::SetViewingKey {
+                key: "key".to_string(),
+                padding: None,
+            };
+            execute(deps.as_mut(), mock_env(), mock_info(addr, &[]), execute_msg).unwrap();
+        }
+
+        // test bad proposals
+        let execute_msg = ExecuteMsg::ProposeSwap {
+            counterparty: "alice".to_string(),
+            offered: vec![local("NFT1")],
+            requested: vec![local("NFT3")],
+            expires: expires(),
+            padding: None,
+        };
+        let handle_result = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("alice", &[]),
+            execute_msg,
+        );
+        let error = extract_error_msg(handle_result);
+        assert!(error.contains("You can not propose a swap with yourself"));
+        let error = extract_error_msg(propose(
+            &mut deps,
+            "alice",
+            &[],
+            vec![local("NFT1")],
+            vec![],
+            expires(),
+        ));
+        assert!(error.contains("A swap must request at least one token"));
+        let error = extract_error_msg(propose(
+            &mut deps,
+            "alice",
+            &[],
+            vec![],
+            vec![local("NFT3")],
+            expires(),
+        ));
+        assert!(error.contains("A swap must offer at least one token or a sweetener"));
+        let error = extract_error_msg(propose(
+            &mut deps,
+            "alice",
+            &[],
+            vec![local("NFT1")],
+            vec![local("NFT3")],
+            Expiration::AtHeight(1),
+        ));
+        assert!(error.contains("The swap expiration has already passed"));
+        for id in ["NFT3", "NFT9"] {
+            let error = extract_error_msg(propose(
+                &mut deps,
+                "alice",
+                &[],
+                vec![local(id)],
+                vec![local("NFT3")],
+                expires(),
+            ));
+            assert!(error.contains(&format!("You do not own token {}", id)));
+        }
+        let error = extract_error_msg(propose(
+            &mut deps,
+            "alice",
+            &[],
+            vec![local("NFT1")],
+            vec![other("OTHER1")],
+            expires(),
+        ));
+        assert!(error.contains("Contract other is not registered for swaps"));
+
+        let response = propose(
+            &mut deps,
+            "alice",
+            &coins(100, "uscrt"),
+            vec![local("NFT1"), local("NFT2")],
+            vec![local("NFT3")],
+            expires(),
+        )
+        .unwrap();
+        assert_eq!(swap_id(&response), 0);
+
+        // only the proposer and the counterparty may view the swap
+        let swap = Swap {
+            swap_id: 0,
+            proposer: Addr::unchecked("alice"),
+            counterparty: Addr::unchecked("bob"),
+            offered: vec![local("NFT1"), local("NFT2")],
+            requested: vec![local("NFT3")],
+            sweetener: coins(100, "uscrt"),
+            expires: expires(),
+        };
+        assert_eq!(query_swap(&deps, "alice", 0).unwrap(), swap);
+        assert_eq!(query_swap(&deps, "bob", 0).unwrap(), swap);
+        let error = extract_error_msg(query_swap(&deps, "carol", 0));
+        assert!(error.contains("You are not authorized to view swap 0"));
+
+        // test bad acceptances
+        let error = extract_error_msg(accept(&mut deps, mock_env(), "carol", 0));
+        assert!(error.contains("Only the counterparty may accept swap 0"));
+        let mut env = mock_env();
+        env.block.height += 100;
+        let error = extract_error_msg(accept(&mut deps, env, "bob", 0));
+        assert!(error.contains("Swap 0 has expired"));
+
+        // the tokens and the sweetener are exchanged
+        let response = accept(&mut deps, mock_env(), "bob", 0).unwrap();
+        assert_eq!(
+            bank_sends(&response),
+            vec![("bob".to_string(), coins(100, "uscrt"))]
+        );
+        assert_eq!(owner(&deps, "NFT1"), Addr::unchecked("bob"));
+        assert_eq!(owner(&deps, "NFT2"), Addr::unchecked("bob"));
+        assert_eq!(owner(&deps, "NFT3"), Addr::unchecked("alice"));
+        let error = extract_error_msg(accept(&mut deps, mock_env(), "bob", 0));
+        assert!(error.contains("Swap 0 not found"));
+
+        // nothing is exchanged if a side no longer owns its tokens
+        let response = propose(
+            &mut deps,
+            "alice",
+            &[],
+            vec![local("NFT3")],
+            vec![local("NFT1")],
+            expires(),
+        )
+        .unwrap();
+        let execute_msg = ExecuteMsg::TransferNft {
+            recipient: "carol".to_string(),
+            token_id: "NFT1".to_string(),
+            memo: None,
+            padding: None,
+        };
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("bob", &[]),
+            execute_msg,
+        )
+        .unwrap();
+        assert!(accept(&mut deps, mock_env(), "bob", swap_id(&response)).is_err());
+
+        // either side may cancel, which returns the sweetener
+        let response = propose(
+            &mut deps,
+            "bob",
+            &coins(50, "uscrt"),
+            vec![],
+            vec![local("NFT3")],
+            expires(),
+        )
+        .unwrap();
+        let id = swap_id(&response);
+        let cancel = |swap_id: u64| ExecuteMsg::CancelSwap {
+            swap_id,
+            padding: None,
+        };
+        let handle_result = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("carol", &[]),
+            cancel(id),
+        );
+        let error = extract_error_msg(handle_result);
+        assert!(error.contains(&format!(
+            "Only the proposer or the counterparty may cancel swap {}",
+            id
+        )));
+        let response = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("alice", &[]),
+            cancel(id),
+        )
+        .unwrap();
+        assert_eq!(
+            bank_sends(&response),
+            vec![("bob".to_string(), coins(50, "uscrt"))]
+        );
+    }
+
+    #[test]
+    fn test_swap_requires_ownership() {
+        let (init_result, mut deps) = init_helper_default();
+        assert!(
+            init_result.is_ok(),
+            "Init failed: {}",
+            init_result.err().unwrap()
+        );
+        mint(&mut deps, "NFT1", "alice");
+        mint(&mut deps, "NFT2", "bob");
+        mint(&mut deps, "NFT3", "carol");
+        let approve_all = |operator: &str| ExecuteMsg::ApproveAll {
+            operator: operator.to_string(),
+            expires: None,
+            padding: None,
+        };
+        // bob and alice may transfer carol's tokens, but do not own them
+        for addr in ["alice", "bob"] {
+            execute(
+                deps.as_mut(),
+                mock_env(),
+                mock_info("carol", &[]),
+                approve_all(addr),
+            )
+            .unwrap();
+        }
+
+        // test a counterparty that is only an operator of the requested token's owner
+        let response = propose(
+            &mut deps,
+            "alice",
+            &[],
+            vec![local("NFT1")],
+            vec![local("NFT3")],
+            expires(),
+        )
+        .unwrap();
+        let error = extract_error_msg(accept(&mut deps, mock_env(), "bob", swap_id(&response)));
+        assert!(error.contains("You do not own token NFT3"));
+        assert_eq!(owner(&deps, "NFT3"), Addr::unchecked("carol"));
+
+        // test a proposer that gave its offered token away, but may still transfer it
+        let response = propose(
+            &mut deps,
+            "alice",
+            &[],
+            vec![local("NFT1")],
+            vec![local("NFT2")],
+            expires(),
+        )
+        .unwrap();
+        let execute_msg = ExecuteMsg::TransferNft {
+            recipient: "carol".to_string(),
+            token_id: "NFT1".to_string(),
+            memo: None,
+            padding: None,
+        };
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("alice", &[]),
+            execute_msg,
+        )
+        .unwrap();
+        let id = swap_id(&response);
+        let error = extract_error_msg(accept(&mut deps, mock_env(), "bob", id));
+        assert!(error.contains(&format!(
+            "The proposer of swap {} no longer owns token NFT1",
+            id
+        )));
+        assert_eq!(owner(&deps, "NFT1"), Addr::unchecked("carol"));
+        assert_eq!(owner(&deps, "NFT2"), Addr::unchecked("bob"));
+    }
+
+    #[test]
+    fn test_cross_contract_swap() {
+        let (init_result, mut deps) = init_helper_default();
+        assert!(
+            init_result.is_ok(),
+            "Init failed: {}",
+            init_result.err().unwrap()
+        );
+        mint(&mut deps, "NFT1", "alice");
+
+        // test a non-admin registering a contract
+        let execute_msg = ExecuteMsg::RegisterSwapContract {
+            contract_address: "other".to_string(),
+            code_hash: "other hash".to_string(),
+            padding: None,
+        };
+        let handle_result = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("alice", &[]),
+            execute_msg,
+        );
+        let error = extract_error_msg(handle_result);
+        assert!(
+            error.contains("This is an admin command and can only be run from the admin address")
+        );
+        let response = register_other(&mut deps);
+        assert_eq!(
+            snip721_msgs(&response),
+            vec![(
+                "other".to_string(),
+                "other hash".to_string(),
+                MockSnip721Msg::RegisterReceiveNft {
+                    code_hash: mock_env().contract.code_hash,
+                    also_implements_batch_receive_nft: Some(true),
+                    padding: None,
+                }
+            )]
+        );
+
+        // only registered contracts may deposit tokens
+        let error = extract_error_msg(deposit(&mut deps, "stranger", "bob", &["OTHER1"]));
+        assert!(error.contains("Contract stranger is not registered for swaps"));
+        deposit(&mut deps, "other", "bob", &["OTHER1", "OTHER2"]).unwrap();
+
+        // test withdrawing deposits
+        let error = extract_error_msg(withdraw(&mut deps, "alice", "OTHER2"));
+        assert!(error.contains("You have not deposited token OTHER2 of contract other"));
+        let response = withdraw(&mut deps, "bob", "OTHER2").unwrap();
+        assert_eq!(snip721_msgs(&response), vec![withdrawal("bob", "OTHER2")]);
+
+        // the offered tokens of other contracts must be deposited by the proposer
+        let error = extract_error_msg(propose(
+            &mut deps,
+            "bob",
+            &[],
+            vec![other("OTHER2")],
+            vec![local("NFT1")],
+            expires(),
+        ));
+        assert!(error.contains("You have not deposited token OTHER2 of contract other"));
+
+        let response = propose(
+            &mut deps,
+            "alice",
+            &[],
+            vec![local("NFT1")],
+            vec![other("OTHER1")],
+            expires(),
+        )
+        .unwrap();
+        let response = accept(&mut deps, mock_env(), "bob", swap_id(&response)).unwrap();
+        assert_eq!(snip721_msgs(&response), vec![withdrawal("alice", "OTHER1")]);
+        assert_eq!(owner(&deps, "NFT1"), Addr::unchecked("bob"));
+        let error = extract_error_msg(withdraw(&mut deps, "bob", "OTHER1"));
+        assert!(error.contains("You have not deposited token OTHER1 of contract other"));
+
+        // nothing is exchanged if the requested token is not deposited
+        let response = propose(
+            &mut deps,
+            "bob",
+            &[],
+            vec![local("NFT1")],
+            vec![other("OTHER3")],
+            expires(),
+        )
+        .unwrap();
+        let error = extract_error_msg(accept(&mut deps, mock_env(), "alice", swap_id(&response)));
+        assert!(error.contains("Token OTHER3 of contract other is not deposited by alice"));
+        assert_eq!(owner(&deps, "NFT1"), Addr::unchecked("bob"));
+    }
+}
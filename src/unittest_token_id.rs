@@ -0,0 +1,226 @@
+#[cfg(test)]
+mod tests {
+    use std::any::Any;
+
+    use cosmwasm_std::testing::*;
+    use cosmwasm_std::{from_binary, to_vec, OwnedDeps, Response, StdError, StdResult};
+    use secret_toolkit::crypto::sha_256;
+
+    use crate::contract::{execute, instantiate, query};
+    use crate::msg::{ExecuteAnswer, ExecuteMsg, InstantiateMsg, QueryAnswer, QueryMsg};
+    use crate::token::Metadata;
+    use crate::token_id::{TokenIdBase, TokenIdScheme};
+
+    // Helper functions
+
+    fn init_helper_default() -> (
+        StdResult<Response>,
+        OwnedDeps<MockStorage, MockApi, MockQuerier>,
+    ) {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+        let info = mock_info("instantiator", &[]);
+        let init_msg = InstantiateMsg {
+            name: "sec721".to_string(),
+            symbol: "S721".to_string(),
+            admin: Some("admin".to_string()),
+            entropy: "We're going to need a bigger boat".to_string(),
+            royalty_info: None,
+            config: None,
+            post_init_callback: None,
+        };
+
+        (instantiate(deps.as_mut(), env, info, init_msg), deps)
+    }
+
+    fn extract_error_msg<T: Any>(error: StdResult<T>) -> String {
+        match error {
+            Ok(_response) => panic!("Expected error, but had Ok response"),
+            Err(err) => match err {
+                StdError::GenericErr { msg, .. } => msg,
+                _ => panic!("Unexpected error result {:?}", err),
+            },
+        }
+    }
+
+    fn set_scheme(
+        deps: &mut OwnedDeps<MockStorage, MockApi, MockQuerier>,
+        sender: &str,
+        scheme: Option<TokenIdScheme>,
+    ) -> StdResult<Response> {
+        let execute_msg = ExecuteMsg::SetTokenIdScheme {
+            scheme,
+            padding: None,
+        };
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(sender, &[]),
+            execute_msg,
+        )
+    }
+
+    fn mint(
+        deps: &mut OwnedDeps<MockStorage, MockApi, MockQuerier>,
+        public_metadata: Option<Metadata>,
+    ) -> StdResult<String> {
+        let execute_msg = ExecuteMsg::MintNft {
+            token_id: None,
+            owner: None,
+            public_metadata,
+            private_metadata: None,
+            royalty_info: None,
+            serial_number: None,
+            transferable: None,
+            memo: None,
+            padding: None,
+        };
+        let response = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("admin", &[]),
+            execute_msg,
+        )?;
+        match from_binary(response.data.as_ref().unwrap()).unwrap() {
+            ExecuteAnswer::MintNft { token_id } => Ok(token_id),
+            _ => panic!("unexpected"),
+        }
+    }
+
+    fn mint_clones(
+        deps: &mut OwnedDeps<MockStorage, MockApi, MockQuerier>,
+        mint_run_id: &str,
+        quantity: u32,
+    ) -> (String, String) {
+        let execute_msg = ExecuteMsg::MintNftClones {
+            mint_run_id: Some(mint_run_id.to_string()),
+            quantity,
+            owner: None,
+            public_metadata: None,
+            private_metadata: None,
+            serial_overrides: None,
+            royalty_info: None,
+            memo: None,
+            padding: None,
+        };
+        let response = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("admin", &[]),
+            execute_msg,
+        )
+        .unwrap();
+        match from_binary(response.data.as_ref().unwrap()).unwrap() {
+            ExecuteAnswer::MintNftClones {
+                first_minted,
+                last_minted,
+            } => (first_minted, last_minted),
+            _ => panic!("unexpected"),
+        }
+    }
+
+    #[test]
+    fn test_set_token_id_scheme() {
+        let (init_result, mut deps) = init_helper_default();
+        assert!(
+            init_result.is_ok(),
+            "Init failed: {}",
+            init_result.err().unwrap()
+        );
+        let scheme = TokenIdScheme {
+            prefix: Some("ART-".to_string()),
+            suffix: Some("-v1".to_string()),
+            base: TokenIdBase::MintIndex,
+            pad_to: Some(4),
+        };
+
+        // test non admin
+        let error = extract_error_msg(set_scheme(&mut deps, "alice", Some(scheme.clone())));
+        assert!(
+            error.contains("This is an admin command and can only be run from the admin address")
+        );
+
+        set_scheme(&mut deps, "admin", Some(scheme.clone())).unwrap();
+        let query_result = query(deps.as_ref(), mock_env(), QueryMsg::ContractConfig {}).unwrap();
+        match from_binary(&query_result).unwrap() {
+            QueryAnswer::ContractConfig {
+                token_id_scheme, ..
+            } => assert_eq!(token_id_scheme, Some(scheme)),
+            _ => panic!("unexpected"),
+        }
+        assert_eq!(mint(&mut deps, None).unwrap(), "ART-0000-v1");
+        assert_eq!(mint(&mut deps, None).unwrap(), "ART-0001-v1");
+
+        // removing the scheme falls back to the mint index
+        set_scheme(&mut deps, "admin", None).unwrap();
+        assert_eq!(mint(&mut deps, None).unwrap(), "2");
+    }
+
+    #[test]
+    fn test_mint_run_counter() {
+        let (init_result, mut deps) = init_helper_default();
+        assert!(
+            init_result.is_ok(),
+            "Init failed: {}",
+            init_result.err().unwrap()
+        );
+        let scheme = TokenIdScheme {
+            prefix: None,
+            suffix: None,
+            base: TokenIdBase::MintRunCounter,
+            pad_to: Some(3),
+        };
+        set_scheme(&mut deps, "admin", Some(scheme)).unwrap();
+
+        assert_eq!(
+            mint_clones(&mut deps, "spring", 3),
+            ("spring-001".to_string(), "spring-003".to_string())
+        );
+        assert_eq!(
+            mint_clones(&mut deps, "summer", 2),
+            ("summer-001".to_string(), "summer-002".to_string())
+        );
+        // counters continue across runs with the same mint run id
+        assert_eq!(
+            mint_clones(&mut deps, "spring", 2),
+            ("spring-004".to_string(), "spring-005".to_string())
+        );
+        // tokens without a mint run id use the mint index
+        assert_eq!(mint(&mut deps, None).unwrap(), "007");
+    }
+
+    #[test]
+    fn test_metadata_hash() {
+        let (init_result, mut deps) = init_helper_default();
+        assert!(
+            init_result.is_ok(),
+            "Init failed: {}",
+            init_result.err().unwrap()
+        );
+        let scheme = TokenIdScheme {
+            prefix: Some("h".to_string()),
+            suffix: None,
+            base: TokenIdBase::MetadataHash,
+            pad_to: None,
+        };
+        set_scheme(&mut deps, "admin", Some(scheme)).unwrap();
+        let metadata = Metadata {
+            token_uri: Some("uri1".to_string()),
+            extension: None,
+        };
+        let expected = |index: u32| -> String {
+            let hash = sha_256(&to_vec(&(index, Some(&metadata), None::<&Metadata>)).unwrap());
+            hash.iter().map(|b| format!("{:02x}", b)).collect()
+        };
+
+        assert_eq!(
+            mint(&mut deps, Some(metadata.clone())).unwrap(),
+            format!("h{}", expected(0))
+        );
+        // identical metadata still produces a new id because the mint index is hashed too
+        assert_eq!(
+            mint(&mut deps, Some(metadata.clone())).unwrap(),
+            format!("h{}", expected(1))
+        );
+    }
+}
@@ -0,0 +1,299 @@
+#[cfg(test)]
+mod tests {
+    use std::any::Any;
+
+    use cosmwasm_std::testing::*;
+    use cosmwasm_std::{
+        from_binary, Addr, Binary, OwnedDeps, Response, StdError, StdResult, Timestamp,
+    };
+
+    use crate::contract::{execute, instantiate, query};
+    use crate::msg::{
+        ExecuteAnswer, ExecuteMsg, InstantiateConfig, InstantiateMsg, QueryAnswer, QueryMsg,
+    };
+    use crate::token::Metadata;
+    use crate::voucher::{MintVoucher, VoucherMetadata};
+
+    // compressed public key of the secp256k1 private key [7u8; 32]
+    const SIGNER: &str = "ApicC3bLVjlx/cm+8x7AbDVg8ySdbunl2DxXYlWW4F9v";
+    // signatures of the vouchers for NFT1, NFT2, and NFT3.  NFT3 is signed by the private key
+    // [9u8; 32] instead of the signer
+    const SIG1: &str =
+        "PnM5igHYx7957Ws8Lls2OU044D+VmjAUrvGVfD8Xlwo7KyHnXd3C3rGQeQbQCguZhOLAivRWX2PstiYevc2DCw==";
+    const SIG2: &str =
+        "HR5cvPx3BcwBP11AL+SYgePxo+rF3WYAjLZF1OtnnW0oUvf0KGoKYgoEM38E2sZXnDQYQrZgSlme06dkjHHj4A==";
+    const SIG3: &str =
+        "vty+7Ych6LzmYehS6PFFfuiShcHw2546OntejAzCMRQnITBKs7JjptO8bED/hWaJMJ084qMP55L0ONahRY/7jA==";
+
+    // Helper functions
+
+    fn init_helper_default() -> (
+        StdResult<Response>,
+        OwnedDeps<MockStorage, MockApi, MockQuerier>,
+    ) {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+        let info = mock_info("instantiator", &[]);
+        let init_msg = InstantiateMsg {
+            name: "sec721".to_string(),
+            symbol: "S721".to_string(),
+            admin: Some("admin".to_string()),
+            entropy: "We're going to need a bigger boat".to_string(),
+            royalty_info: None,
+            config: Some(InstantiateConfig {
+                public_owner: Some(true),
+                ..InstantiateConfig::default()
+            }),
+            post_init_callback: None,
+        };
+
+        (instantiate(deps.as_mut(), env, info, init_msg), deps)
+    }
+
+    fn extract_error_msg<T: Any>(error: StdResult<T>) -> String {
+        match error {
+            Ok(_response) => panic!("Expected error, but had Ok response"),
+            Err(err) => match err {
+                StdError::GenericErr { msg, .. } => msg,
+                _ => panic!("Unexpected error result {:?}", err),
+            },
+        }
+    }
+
+    fn metadata(uri: &str) -> VoucherMetadata {
+        VoucherMetadata {
+            public_metadata: Some(Metadata {
+                token_uri: Some(uri.to_string()),
+                extension: None,
+            }),
+            private_metadata: None,
+        }
+    }
+
+    fn voucher(token_id: &str, uri: &str, nonce: u64) -> MintVoucher {
+        MintVoucher {
+            chain_id: mock_env().block.chain_id,
+            contract: MOCK_CONTRACT_ADDR.to_string(),
+            token_id: token_id.to_string(),
+            metadata_hash: Binary::from(metadata(uri).hash().unwrap().to_vec()),
+            owner: "alice".to_string(),
+            expiry: 2_000_000_000,
+            nonce,
+        }
+    }
+
+    fn set_signer(
+        deps: &mut OwnedDeps<MockStorage, MockApi, MockQuerier>,
+        sender: &str,
+        public_key: Option<&str>,
+    ) -> StdResult<Response> {
+        let execute_msg = ExecuteMsg::SetVoucherSigner {
+            public_key: public_key.map(|k| Binary::from_base64(k).unwrap()),
+            padding: None,
+        };
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(sender, &[]),
+            execute_msg,
+        )
+    }
+
+    fn redeem(
+        deps: &mut OwnedDeps<MockStorage, MockApi, MockQuerier>,
+        voucher: MintVoucher,
+        signature: &str,
+        uri: &str,
+        time: Option<u64>,
+    ) -> StdResult<Response> {
+        let execute_msg = ExecuteMsg::RedeemVoucher {
+            voucher,
+            signature: Binary::from_base64(signature).unwrap(),
+            metadata: metadata(uri),
+            padding: None,
+        };
+        let mut env = mock_env();
+        if let Some(time) = time {
+            env.block.time = Timestamp::from_seconds(time);
+        }
+        execute(deps.as_mut(), env, mock_info("bob", &[]), execute_msg)
+    }
+
+    #[test]
+    fn test_set_voucher_signer() {
+        let (init_result, mut deps) = init_helper_default();
+        assert!(
+            init_result.is_ok(),
+            "Init failed: {}",
+            init_result.err().unwrap()
+        );
+
+        // test non admin
+        let error = extract_error_msg(set_signer(&mut deps, "alice", Some(SIGNER)));
+        assert!(
+            error.contains("This is an admin command and can only be run from the admin address")
+        );
+
+        // test a key of the wrong length
+        let error = extract_error_msg(set_signer(&mut deps, "admin", Some("AAAA")));
+        assert!(error.contains("The voucher signer must be a 33 byte compressed or 65 byte uncompressed secp256k1 public key"));
+
+        set_signer(&mut deps, "admin", Some(SIGNER)).unwrap();
+        let query_result = query(deps.as_ref(), mock_env(), QueryMsg::VoucherSigner {}).unwrap();
+        match from_binary(&query_result).unwrap() {
+            QueryAnswer::VoucherSigner { public_key } => {
+                assert_eq!(public_key, Some(Binary::from_base64(SIGNER).unwrap()))
+            }
+            _ => panic!("unexpected"),
+        }
+
+        // removing the signer stops redemptions
+        set_signer(&mut deps, "admin", None).unwrap();
+        let error = extract_error_msg(redeem(
+            &mut deps,
+            voucher("NFT1", "uri1", 1),
+            SIG1,
+            "uri1",
+            None,
+        ));
+        assert!(error.contains("There is no voucher signer"));
+    }
+
+    #[test]
+    fn test_redeem_voucher() {
+        let (init_result, mut deps) = init_helper_default();
+        assert!(
+            init_result.is_ok(),
+            "Init failed: {}",
+            init_result.err().unwrap()
+        );
+        set_signer(&mut deps, "admin", Some(SIGNER)).unwrap();
+
+        // test metadata that does not match the hash
+        let error = extract_error_msg(redeem(
+            &mut deps,
+            voucher("NFT1", "uri1", 1),
+            SIG1,
+            "uri2",
+            None,
+        ));
+        assert!(error.contains("The metadata does not match the voucher's metadata hash"));
+
+        // test a voucher that was altered after signing
+        let mut altered = voucher("NFT1", "uri1", 1);
+        altered.owner = "bob".to_string();
+        let error = extract_error_msg(redeem(&mut deps, altered, SIG1, "uri1", None));
+        assert!(error.contains("The voucher was not signed by the voucher signer"));
+
+        // test a voucher signed by another key
+        let error = extract_error_msg(redeem(
+            &mut deps,
+            voucher("NFT3", "uri3", 3),
+            SIG3,
+            "uri3",
+            None,
+        ));
+        assert!(error.contains("The voucher was not signed by the voucher signer"));
+
+        // test an expired voucher
+        let error = extract_error_msg(redeem(
+            &mut deps,
+            voucher("NFT1", "uri1", 1),
+            SIG1,
+            "uri1",
+            Some(2_000_000_000),
+        ));
+        assert!(error.contains("The voucher has expired"));
+
+        // anyone may redeem a voucher for the owner it names
+        let response = redeem(&mut deps, voucher("NFT1", "uri1", 1), SIG1, "uri1", None).unwrap();
+        let answer: ExecuteAnswer = from_binary(response.data.as_ref().unwrap()).unwrap();
+        match answer {
+            ExecuteAnswer::RedeemVoucher { token_id } => assert_eq!(token_id, "NFT1"),
+            _ => panic!("unexpected"),
+        }
+        let query_msg = QueryMsg::NftDossier {
+            token_id: "NFT1".to_string(),
+            viewer: None,
+            include_expired: None,
+        };
+        let query_result = query(deps.as_ref(), mock_env(), query_msg).unwrap();
+        match from_binary(&query_result).unwrap() {
+            QueryAnswer::NftDossier {
+                owner,
+                public_metadata,
+                ..
+            } => {
+                assert_eq!(owner, Some(Addr::unchecked("alice")));
+                assert_eq!(public_metadata, metadata("uri1").public_metadata);
+            }
+            _ => panic!("unexpected"),
+        }
+
+        // test replaying the voucher
+        let error = extract_error_msg(redeem(
+            &mut deps,
+            voucher("NFT1", "uri1", 1),
+            SIG1,
+            "uri1",
+            None,
+        ));
+        assert!(error.contains("Voucher nonce 1 has already been used"));
+
+        redeem(&mut deps, voucher("NFT2", "uri2", 2), SIG2, "uri2", None).unwrap();
+    }
+
+    #[test]
+    fn test_redeem_voucher_on_another_contract() {
+        let (init_result, mut deps) = init_helper_default();
+        assert!(
+            init_result.is_ok(),
+            "Init failed: {}",
+            init_result.err().unwrap()
+        );
+        set_signer(&mut deps, "admin", Some(SIGNER)).unwrap();
+        let mut env = mock_env();
+        env.contract.address = Addr::unchecked("another contract");
+        let redeem_on_other = |deps: &mut OwnedDeps<MockStorage, MockApi, MockQuerier>,
+                               voucher: MintVoucher| {
+            let execute_msg = ExecuteMsg::RedeemVoucher {
+                voucher,
+                signature: Binary::from_base64(SIG1).unwrap(),
+                metadata: metadata("uri1"),
+                padding: None,
+            };
+            execute(
+                deps.as_mut(),
+                env.clone(),
+                mock_info("bob", &[]),
+                execute_msg,
+            )
+        };
+
+        // a voucher signed for one deployment can not be redeemed on another that uses the
+        // same signer
+        let error = extract_error_msg(redeem_on_other(&mut deps, voucher("NFT1", "uri1", 1)));
+        assert!(error.contains("The voucher is not for this contract"));
+        let mut altered = voucher("NFT1", "uri1", 1);
+        altered.contract = "another contract".to_string();
+        let error = extract_error_msg(redeem_on_other(&mut deps, altered));
+        assert!(error.contains("The voucher was not signed by the voucher signer"));
+
+        // nor on another chain
+        let mut env = mock_env();
+        env.block.chain_id = "another-chain".to_string();
+        let execute_msg = ExecuteMsg::RedeemVoucher {
+            voucher: voucher("NFT1", "uri1", 1),
+            signature: Binary::from_base64(SIG1).unwrap(),
+            metadata: metadata("uri1"),
+            padding: None,
+        };
+        let error = extract_error_msg(execute(
+            deps.as_mut(),
+            env,
+            mock_info("bob", &[]),
+            execute_msg,
+        ));
+        assert!(error.contains("The voucher is not for this contract"));
+    }
+}
@@ -0,0 +1,49 @@
+use cosmwasm_std::{to_vec, Binary, StdResult};
+use schemars::JsonSchema;
+use secret_toolkit::crypto::sha_256;
+use serde::{Deserialize, Serialize};
+
+use crate::token::Metadata;
+
+/// an off-chain mint authorization signed by the voucher signer
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
+pub struct MintVoucher {
+    /// id of the chain the voucher may be redeemed on
+    pub chain_id: String,
+    /// address of the contract the voucher may be redeemed with
+    pub contract: String,
+    /// id of the token to mint
+    pub token_id: String,
+    /// sha256 hash of the JSON serialization of the token's VoucherMetadata
+    pub metadata_hash: Binary,
+    /// address that will own the token
+    pub owner: String,
+    /// time the voucher expires (in seconds since 01/01/1970)
+    pub expiry: u64,
+    /// number that may only be used by one voucher
+    pub nonce: u64,
+}
+
+impl MintVoucher {
+    /// Returns StdResult<[u8; 32]> of the hash the voucher signer signs, which is the sha256
+    /// hash of the voucher's JSON serialization
+    pub fn message_hash(&self) -> StdResult<[u8; 32]> {
+        Ok(sha_256(&to_vec(self)?))
+    }
+}
+
+/// the metadata of a token minted with a voucher
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
+pub struct VoucherMetadata {
+    /// optional public metadata
+    pub public_metadata: Option<Metadata>,
+    /// optional private metadata
+    pub private_metadata: Option<Metadata>,
+}
+
+impl VoucherMetadata {
+    /// Returns StdResult<[u8; 32]> of the sha256 hash of the metadata's JSON serialization
+    pub fn hash(&self) -> StdResult<[u8; 32]> {
+        Ok(sha_256(&to_vec(self)?))
+    }
+}